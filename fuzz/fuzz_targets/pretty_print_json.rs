@@ -0,0 +1,35 @@
+//! Fuzz target that feeds arbitrary JSON to the Solidity, Vyper and Yul pretty-printers.  The
+//! pretty-printers should never panic, no matter how malformed the AST is.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use mutagenyx_lib::{LanguageInterface, PrettyPrinter, SuperAST};
+use serde_json::Value;
+
+fuzz_target!(|data: &[u8]| {
+    let value = match serde_json::from_slice::<Value>(data) {
+        Ok(v) => v,
+        Err(_) => return,
+    };
+
+    let asts = vec![
+        SuperAST::Solidity(value.clone()),
+        SuperAST::Vyper(value.clone()),
+        SuperAST::Yul(value),
+    ];
+
+    for ast in asts {
+        let language = ast.language();
+        if let Ok(mut language_object) =
+            LanguageInterface::get_language_object_for_language(&language)
+        {
+            let mut pretty_printer = PrettyPrinter::new(4, 150);
+            let _ = language_object.pretty_print_ast_to_stream(
+                &ast,
+                &mut std::io::sink(),
+                &mut pretty_printer,
+            );
+        }
+    }
+});
@@ -0,0 +1,377 @@
+//! The `archive` module provides [`MutantArchive`], a tar archive that collects the output of a
+//! mutation run (mutants, pretty-printed originals, configuration files, and a manifest) in a
+//! single file instead of the output directory.
+
+use crate::manifest::{RunManifest, MANIFEST_FILE_NAME};
+use mutagenyx_lib::error::MutagenyxError;
+use std::fmt;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The size of a tar header and the unit of padding for file contents in a tar archive.
+const TAR_BLOCK_SIZE: usize = 512;
+
+/// The compression to apply to a tar archive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TarCompression {
+    /// Write an uncompressed tar archive.
+    #[default]
+    None,
+
+    /// Compress the tar archive with gzip.
+    Gzip,
+}
+
+impl TarCompression {
+    /// Return the file name extensions allowed for an archive with the compression.
+    fn extensions(&self) -> &'static [&'static str] {
+        match self {
+            TarCompression::None => &[".tar"],
+            TarCompression::Gzip => &[".tar.gz", ".tgz"],
+        }
+    }
+}
+
+impl FromStr for TarCompression {
+    type Err = MutagenyxError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(TarCompression::None),
+            "gzip" => Ok(TarCompression::Gzip),
+            _ => Err(MutagenyxError::ArchiveCompressionNotSupported(
+                String::from(s),
+            )),
+        }
+    }
+}
+
+impl fmt::Display for TarCompression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            TarCompression::None => "none",
+            TarCompression::Gzip => "gzip",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+/// The destination of the bytes of the tar archive.
+enum ArchiveSink {
+    /// Write the archive directly to the file.
+    File(BufWriter<File>),
+
+    /// Pipe the archive through a gzip process that writes to the file.
+    Gzip(Child, ChildStdin),
+}
+
+impl ArchiveSink {
+    /// Return the [`Write`] object that receives the bytes of the tar archive.
+    fn writer(&mut self) -> &mut dyn Write {
+        match self {
+            ArchiveSink::File(f) => f,
+            ArchiveSink::Gzip(_, stdin) => stdin,
+        }
+    }
+}
+
+/// A tar archive that receives the files of a mutation run.
+///
+/// The archive writes each file as soon as the caller adds the file, so the archive never holds
+/// more than one file in memory.  The archive writes the manifest of the run in
+/// [`MutantArchive::finish`].
+pub struct MutantArchive {
+    /// The path of the archive in the file system.
+    path: PathBuf,
+
+    /// The destination of the archive bytes.
+    sink: ArchiveSink,
+
+    /// The modification time to record for each file in the archive.
+    mtime: u64,
+}
+
+impl MutantArchive {
+    /// Create the archive at `path`.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the archive in the file system.
+    /// * `compression` - The compression to apply to the archive.
+    ///
+    /// # Errors
+    ///
+    /// The function returns [`MutagenyxError::ArchivePathNotValid`] if the extension of `path`
+    /// does not match `compression` or if `path` names a directory.
+    pub fn create(path: &str, compression: TarCompression) -> Result<Self, MutagenyxError> {
+        let extensions = compression.extensions();
+        if !extensions.iter().any(|e| path.ends_with(e)) {
+            return Err(MutagenyxError::ArchivePathNotValid(
+                String::from(path),
+                format!(
+                    "{} compression requires one of the extensions {:?}",
+                    compression, extensions
+                ),
+            ));
+        }
+
+        let archive_path = PathBuf::from(path);
+        if archive_path.is_dir() {
+            return Err(MutagenyxError::ArchivePathNotValid(
+                String::from(path),
+                String::from("the path is a directory"),
+            ));
+        }
+
+        if let Some(parent) = archive_path.parent() {
+            if parent != Path::new("") {
+                std::fs::create_dir_all(parent)?;
+            }
+        }
+
+        let file = File::create(&archive_path)?;
+        let sink = match compression {
+            TarCompression::None => ArchiveSink::File(BufWriter::new(file)),
+            TarCompression::Gzip => {
+                let mut child = Command::new("gzip")
+                    .arg("-c")
+                    .stdin(Stdio::piped())
+                    .stdout(file)
+                    .spawn()?;
+                let stdin = child.stdin.take().unwrap();
+                ArchiveSink::Gzip(child, stdin)
+            }
+        };
+
+        let mtime = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or_else(|_| 0, |d| d.as_secs());
+
+        Ok(MutantArchive {
+            path: archive_path,
+            sink,
+            mtime,
+        })
+    }
+
+    /// Return the path of the archive in the file system.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Add a file named `name` with `contents` to the archive.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The path of the file inside the archive.
+    /// * `contents` - The contents of the file.
+    pub fn append_file(&mut self, name: &str, contents: &[u8]) -> Result<(), MutagenyxError> {
+        let header = tar_header(name, contents.len(), self.mtime)?;
+        let writer = self.sink.writer();
+        writer.write_all(&header)?;
+        writer.write_all(contents)?;
+        let padding = (TAR_BLOCK_SIZE - contents.len() % TAR_BLOCK_SIZE) % TAR_BLOCK_SIZE;
+        writer.write_all(&vec![0u8; padding])?;
+        Ok(())
+    }
+
+    /// Write the manifest and the end of archive marker and close the archive.
+    ///
+    /// # Arguments
+    ///
+    /// * `manifest` - The manifest of the run.
+    pub fn finish(mut self, manifest: &RunManifest) -> Result<(), MutagenyxError> {
+        self.append_file(MANIFEST_FILE_NAME, manifest.to_text()?.as_bytes())?;
+
+        // A tar archive ends with two empty blocks.
+        self.sink.writer().write_all(&[0u8; 2 * TAR_BLOCK_SIZE])?;
+
+        match self.sink {
+            ArchiveSink::File(mut f) => {
+                f.flush()?;
+                f.get_ref().sync_all()?;
+            }
+            ArchiveSink::Gzip(mut child, stdin) => {
+                // Closing stdin lets gzip finish the compressed stream.
+                drop(stdin);
+                let status = child.wait()?;
+                if !status.success() {
+                    return Err(MutagenyxError::ArchivePathNotValid(
+                        String::from(self.path.to_str().unwrap_or_default()),
+                        format!("gzip exited with {}", status),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Write `value` into `field` as a zero terminated octal number that fills the field.
+///
+/// # Arguments
+///
+/// * `field` - The header field.
+/// * `value` - The number to write.
+fn write_octal_field(field: &mut [u8], value: u64) {
+    let digits = field.len() - 1;
+    let text = format!("{:0width$o}", value, width = digits);
+    field[..digits].copy_from_slice(&text.as_bytes()[text.len() - digits..]);
+    field[digits] = 0;
+}
+
+/// Return the ustar header block for a regular file.
+///
+/// # Arguments
+///
+/// * `name` - The path of the file inside the archive.
+/// * `size` - The size of the file contents in bytes.
+/// * `mtime` - The modification time of the file in seconds since the epoch.
+fn tar_header(name: &str, size: usize, mtime: u64) -> Result<[u8; TAR_BLOCK_SIZE], MutagenyxError> {
+    let name_too_long = || {
+        MutagenyxError::ArchivePathNotValid(
+            String::from(name),
+            String::from("the name is too long for a tar archive"),
+        )
+    };
+
+    // A ustar header holds a name of up to 100 bytes plus a prefix of up to 155 bytes that ends
+    // at a directory separator.
+    let bytes = name.as_bytes();
+    let (prefix, base) = if bytes.len() <= 100 {
+        (&bytes[..0], bytes)
+    } else {
+        let split = bytes[..bytes.len().min(156)]
+            .iter()
+            .rposition(|b| *b == b'/')
+            .filter(|i| bytes.len() - i - 1 <= 100)
+            .ok_or_else(name_too_long)?;
+        (&bytes[..split], &bytes[split + 1..])
+    };
+    if base.is_empty() {
+        return Err(name_too_long());
+    }
+
+    let mut header = [0u8; TAR_BLOCK_SIZE];
+    header[..base.len()].copy_from_slice(base);
+    write_octal_field(&mut header[100..108], 0o644);
+    write_octal_field(&mut header[108..116], 0);
+    write_octal_field(&mut header[116..124], 0);
+    write_octal_field(&mut header[124..136], size as u64);
+    write_octal_field(&mut header[136..148], mtime);
+    header[156] = b'0';
+    header[257..263].copy_from_slice(b"ustar\0");
+    header[263..265].copy_from_slice(b"00");
+    header[345..345 + prefix.len()].copy_from_slice(prefix);
+
+    // The checksum treats its own field as spaces.
+    header[148..156].copy_from_slice(b"        ");
+    let checksum: u64 = header.iter().map(|b| *b as u64).sum();
+    write_octal_field(&mut header[148..155], checksum);
+    header[155] = b' ';
+
+    Ok(header)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    /// Return the text of the header field in `field` up to the first zero byte.
+    fn field_text(field: &[u8]) -> &str {
+        let end = field.iter().position(|b| *b == 0).unwrap_or(field.len());
+        std::str::from_utf8(&field[..end]).unwrap()
+    }
+
+    #[test]
+    fn test_tar_header_fields() {
+        let header = tar_header("mutants/Token.sol_0.sol", 1000, 1_700_000_000).unwrap();
+        assert_eq!(field_text(&header[..100]), "mutants/Token.sol_0.sol");
+        assert_eq!(field_text(&header[100..108]), "0000644");
+        assert_eq!(field_text(&header[124..136]), "00000001750");
+        assert_eq!(field_text(&header[136..148]), "14524770400");
+        assert_eq!(header[156], b'0');
+        assert_eq!(&header[257..265], b"ustar\x0000");
+        assert_eq!(field_text(&header[345..500]), "");
+
+        // The checksum is the sum of the header bytes with the checksum field read as spaces.
+        let mut blank = header;
+        blank[148..156].copy_from_slice(b"        ");
+        let sum: u64 = blank.iter().map(|b| *b as u64).sum();
+        assert_eq!(&header[154..156], b"\0 ");
+        assert_eq!(
+            u64::from_str_radix(field_text(&header[148..156]), 8).unwrap(),
+            sum
+        );
+    }
+
+    #[test]
+    fn test_tar_header_long_names() {
+        // A name of exactly 100 bytes fills the name field without a terminator.
+        let name = "a".repeat(100);
+        let header = tar_header(&name, 0, 0).unwrap();
+        assert_eq!(&header[..100], name.as_bytes());
+        assert_eq!(field_text(&header[100..108]), "0000644");
+        assert_eq!(field_text(&header[345..500]), "");
+
+        // A longer name moves the directories in front of the last separator to the prefix.
+        let directory = "d".repeat(120);
+        let base = "b".repeat(100);
+        let header = tar_header(&format!("{directory}/{base}"), 0, 0).unwrap();
+        assert_eq!(&header[..100], base.as_bytes());
+        assert_eq!(field_text(&header[345..500]), directory);
+
+        // The names that do not fit in the prefix and the name fields.
+        for name in [
+            "a".repeat(101),
+            format!("{}/{}", "d".repeat(10), "b".repeat(101)),
+            format!("{}/{}", "d".repeat(156), "b".repeat(10)),
+            format!("{}/", "d".repeat(120)),
+        ] {
+            assert!(matches!(
+                tar_header(&name, 0, 0),
+                Err(MutagenyxError::ArchivePathNotValid(_, _))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_archive_lists_with_tar() {
+        let directory = env::temp_dir().join("mutagenyx_archive_lists_with_tar");
+        let _ = std::fs::remove_dir_all(&directory);
+        std::fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("mutants.tar");
+
+        let long_name = format!("{}/{}", "d".repeat(120), "Token.sol_1.sol");
+        let mut archive =
+            MutantArchive::create(path.to_str().unwrap(), TarCompression::None).unwrap();
+        archive
+            .append_file("Token.sol_0.sol", b"contract A {}\n")
+            .unwrap();
+        archive.append_file(&long_name, &[b'x'; 513]).unwrap();
+        archive.finish(&RunManifest::default()).unwrap();
+
+        // Only check the archive with tar where tar is available.
+        if let Ok(output) = Command::new("tar").arg("-tvf").arg(&path).output() {
+            assert!(output.status.success());
+            let listing = String::from_utf8_lossy(&output.stdout);
+            let names: Vec<&str> = listing
+                .lines()
+                .map(|l| l.split_whitespace().last().unwrap())
+                .collect();
+            assert_eq!(
+                names,
+                vec!["Token.sol_0.sol", long_name.as_str(), MANIFEST_FILE_NAME]
+            );
+            assert!(listing.lines().nth(1).unwrap().contains(" 513 "));
+        }
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -74,6 +74,9 @@ pub struct VyperCompilerSettings {
 
     /// Vyper root path (equivalent to -p)
     pub vyper_root_path: Option<String>,
+
+    /// Vyper import search paths (equivalent to --path)
+    pub vyper_search_paths: Vec<String>,
 }
 
 impl VyperCompilerSettings {
@@ -85,6 +88,14 @@ impl VyperCompilerSettings {
             preferences.set_string_for_key(ROOT_PATH_KEY, root_path.as_str());
         }
 
+        if !self.vyper_search_paths.is_empty() {
+            let mut search_path_array: Vec<PreferenceValue> = Vec::new();
+            for path in &self.vyper_search_paths {
+                search_path_array.push(PreferenceValue::String(path.clone()));
+            }
+            preferences.set_array_for_key(SEARCH_PATHS_KEY, search_path_array);
+        }
+
         preferences
     }
 }
@@ -96,6 +107,9 @@ pub struct CompilerSettings {
 
     /// Vyper settings
     pub vyper: VyperCompilerSettings,
+
+    /// True if compiler warnings reported while generating an AST are errors.
+    pub warnings_as_errors: bool,
 }
 
 impl CompilerSettings {
@@ -107,7 +121,8 @@ impl CompilerSettings {
     /// {
     ///     "Solidity:" {
     ///         "compiler": {
-    ///             "path": "..."
+    ///             "path": "...",
+    ///             "warnings-as-errors": true
     ///         }
     ///     },
     ///     "Vyper": {
@@ -117,13 +132,17 @@ impl CompilerSettings {
     ///     }
     /// }
     pub fn to_preferences(&self) -> Preferences {
-        let solidity_compiler_prefs = self.solidity.to_preferences();
+        let mut solidity_compiler_prefs = self.solidity.to_preferences();
+        let mut vyper_compiler_prefs = self.vyper.to_preferences();
+
+        if self.warnings_as_errors {
+            solidity_compiler_prefs.set_bool_for_key(WARNINGS_AS_ERRORS_KEY, true);
+            vyper_compiler_prefs.set_bool_for_key(WARNINGS_AS_ERRORS_KEY, true);
+        }
 
         let mut solidity_prefs = Preferences::new();
         solidity_prefs.set_preferences_for_key(COMPILER_KEY, solidity_compiler_prefs);
 
-        let vyper_compiler_prefs = self.vyper.to_preferences();
-
         let mut vyper_prefs = Preferences::new();
         vyper_prefs.set_preferences_for_key(COMPILER_KEY, vyper_compiler_prefs);
 
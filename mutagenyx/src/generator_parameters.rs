@@ -1,9 +1,16 @@
 //! The `generator_parameters` module provides the definition and functions for [`GeneratorParameters`].
 
+use mutagenyx_lib::config_file::{parse_seed, seed_to_json};
+use mutagenyx_lib::error::MutagenyxError;
 use mutagenyx_lib::mutation::MutationType;
 use mutagenyx_lib::preferences::Preferences;
 use rand_pcg::*;
-use std::path::PathBuf;
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::fs::read_to_string;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 /// Object to capture the parameters needed to guide the mutation generator algorithm.
 #[derive(Debug)]
@@ -15,28 +22,69 @@ pub struct GeneratorParameters {
     pub number_of_mutants: usize,
 
     /// The seed of the random number generator.
-    pub rng_seed: u64,
+    pub rng_seed: u128,
 
     /// A random number generator for use by the mutators that mutate
     /// the program ASTs.
     pub rng: Pcg64,
 
+    /// Seeds to replay.  If the list is not empty, the generator creates one mutant for each
+    /// seed, in order, as if run with `number_of_mutants` equal to one and the seed.
+    pub replay_seeds: Vec<u128>,
+
     /// The directory in the file system to put the generated mutations.
     pub output_directory: PathBuf,
 
+    /// The subdirectory of `output_directory` that receives the output files for the input
+    /// file, None to write the output files directly to `output_directory`.
+    pub output_subdirectory: Option<String>,
+
     /// Instead of `output_directory` use stdout to write output.
     pub use_stdout: bool,
 
+    /// If true, write the mutants and the original as JSON ASTs instead of source.
+    pub emit_ast: bool,
+
     /// The mutation algorithms to use to generate the mutants.
     pub mutations: Vec<MutationType>,
 
+    /// The number of mutants to generate with individual mutation algorithms.  The algorithms
+    /// in `mutations` without an entry share the `number_of_mutants` mutants.
+    pub mutation_counts: BTreeMap<MutationType, usize>,
+
     /// True if the mutation generator should verify with the language compiler that the generated
     /// mutants compile correctly.
     pub verify_mutant_viability: bool,
 
+    /// A command to run on each mutant.  The generator only keeps mutants for which the command
+    /// succeeds.
+    pub validate_command: Option<String>,
+
+    /// The number of seconds that the compiler may run while the generator checks that a mutant
+    /// compiles, None for no limit.
+    pub validate_timeout: Option<u64>,
+
     /// If true, then pretty-print a copy of the original AST in the output directory.
     pub print_original: bool,
 
+    /// If true, write the unmutated original AST as mutant index 0 alongside the mutants.
+    pub include_baseline: bool,
+
+    /// If true, number the mutants after the highest numbered mutant of the input file already in
+    /// the output directory instead of starting at index 0.
+    pub append: bool,
+
+    /// If true, print a diff of the first mutant to stdout instead of writing any output.
+    pub preview: bool,
+
+    /// If true, number the lines of the preview diff with the line numbers of the original
+    /// source file.
+    pub include_original_in_diff_header: bool,
+
+    /// If true, the mutators skip the pragma directives of the program, such as the
+    /// `pragma solidity` version requirement.
+    pub no_pragma_mutation: bool,
+
     /// If true, save a config file for each file the tool attempts to mutate.
     pub save_configuration_file: bool,
 
@@ -45,4 +93,278 @@ pub struct GeneratorParameters {
 
     /// Function names to mutate.
     pub functions: Vec<String>,
+
+    /// The node types and kinds (such as `constructor`) that the mutators must not visit.
+    pub forbidden_node_types: Vec<String>,
+
+    /// The ranges of source lines to mutate, empty to mutate every line.
+    pub lines: Vec<RangeInclusive<usize>>,
+}
+
+impl GeneratorParameters {
+    /// Return the directory that receives the output files for the input file.
+    pub fn mutant_directory(&self) -> PathBuf {
+        match &self.output_subdirectory {
+            Some(subdirectory) => self.output_directory.join(subdirectory),
+            None => self.output_directory.clone(),
+        }
+    }
+
+    /// Return the parameters as a JSON object.  The object leaves out the random number
+    /// generator because `rng_seed` recreates the generator.
+    pub fn to_json(&self) -> Value {
+        let mutation_counts: BTreeMap<String, usize> = self
+            .mutation_counts
+            .iter()
+            .map(|(t, c)| (t.to_string(), *c))
+            .collect();
+
+        json!({
+            "file-name": self.file_name,
+            "num-mutants": self.number_of_mutants,
+            "seed": seed_to_json(self.rng_seed),
+            "replay-seeds": self.replay_seeds.iter().map(|s| seed_to_json(*s)).collect::<Vec<Value>>(),
+            "output-directory": self.output_directory,
+            "output-subdirectory": self.output_subdirectory,
+            "stdout": self.use_stdout,
+            "emit-ast": self.emit_ast,
+            "mutations": self.mutations.iter().map(|t| t.to_string()).collect::<Vec<String>>(),
+            "mutation-counts": mutation_counts,
+            "validate-mutants": self.verify_mutant_viability,
+            "validate-command": self.validate_command,
+            "validate-timeout": self.validate_timeout,
+            "print-original": self.print_original,
+            "include-baseline": self.include_baseline,
+            "append": self.append,
+            "preview": self.preview,
+            "include-original-in-diff-header": self.include_original_in_diff_header,
+            "no-pragma-mutation": self.no_pragma_mutation,
+            "save-config-files": self.save_configuration_file,
+            "functions": self.functions,
+            "forbidden-node-types": self.forbidden_node_types,
+            "lines": self.lines.iter().map(|r| format!("{}-{}", r.start(), r.end())).collect::<Vec<String>>(),
+            "preferences": Value::try_from(self.preferences.clone()).unwrap_or(Value::Null),
+        })
+    }
+}
+
+/// Give the parameters for each input file a subdirectory of the output directory named from the
+/// stem of the input file, so that `out/Token.sol` receives its mutants in `out/Token`.  When
+/// two different input files with the same stem share an output directory, the function adds a
+/// number to the name of the later subdirectory (`Token_2`).
+///
+/// # Arguments
+///
+/// * `parameters` - The parameters for each input file.
+pub fn assign_output_subdirectories(parameters: &mut [GeneratorParameters]) {
+    // The input file that owns each subdirectory of each output directory.
+    let mut owners: HashMap<(PathBuf, String), String> = HashMap::new();
+
+    for params in parameters.iter_mut() {
+        let stem = match PathBuf::from(&params.file_name)
+            .file_stem()
+            .and_then(|s| s.to_str())
+        {
+            Some(stem) => String::from(stem),
+            None => continue,
+        };
+
+        let mut subdirectory = stem.clone();
+        let mut suffix = 2;
+        loop {
+            let key = (params.output_directory.clone(), subdirectory.clone());
+            match owners.get(&key) {
+                Some(owner) if *owner != params.file_name => {
+                    subdirectory = format!("{}_{}", stem, suffix);
+                    suffix += 1;
+                }
+                Some(_) => break,
+                None => {
+                    owners.insert(key, params.file_name.clone());
+                    break;
+                }
+            }
+        }
+
+        params.output_subdirectory = Some(subdirectory);
+    }
+}
+
+/// Convert the mutation algorithm requests from the command line to the list of requested
+/// [`MutationType`] values and the map of mutant counts for individual algorithms.
+///
+/// A request is either the name of a mutation algorithm (`ArithmeticBinaryOp`) or the name of a
+/// mutation algorithm and the number of mutants to generate with the algorithm
+/// (`ArithmeticBinaryOp=5`).
+///
+/// # Arguments
+///
+/// * `requests` - The mutation algorithm requests.
+pub fn parse_mutation_requests(
+    requests: &[String],
+) -> Result<(Vec<MutationType>, BTreeMap<MutationType, usize>), MutagenyxError> {
+    let mut mutations: Vec<MutationType> = Vec::new();
+    let mut mutation_counts: BTreeMap<MutationType, usize> = BTreeMap::new();
+
+    for request in requests {
+        let (name, count) = match request.split_once('=') {
+            Some((name, count)) => match count.trim().parse::<usize>() {
+                Ok(count) if count > 0 => (name.trim(), Some(count)),
+                _ => return Err(MutagenyxError::MutationCountNotValid(request.clone())),
+            },
+            None => (request.trim(), None),
+        };
+
+        let mutation_type = MutationType::from_str(name)?;
+        if !mutations.contains(&mutation_type) {
+            mutations.push(mutation_type);
+        }
+
+        match count {
+            Some(count) => {
+                mutation_counts.insert(mutation_type, count);
+            }
+            None => {
+                mutation_counts.remove(&mutation_type);
+            }
+        }
+    }
+
+    Ok((mutations, mutation_counts))
+}
+
+/// Parse the `--lines` requests of the form `FILE:START-END` or `FILE:LINE` into the file names
+/// and the ranges of lines, starting from 1.
+///
+/// # Arguments
+///
+/// * `requests` - The line range requests.
+pub fn parse_line_ranges(
+    requests: &[String],
+) -> Result<Vec<(PathBuf, RangeInclusive<usize>)>, MutagenyxError> {
+    let mut ranges: Vec<(PathBuf, RangeInclusive<usize>)> = Vec::new();
+
+    for request in requests {
+        let not_valid = || MutagenyxError::LineRangeNotValid(request.clone());
+
+        // Split at the last colon so that the file name may contain colons.
+        let (file_name, range) = request.rsplit_once(':').ok_or_else(not_valid)?;
+        let (start, end) = range.split_once('-').unwrap_or((range, range));
+        let start = start.trim().parse::<usize>().map_err(|_| not_valid())?;
+        let end = end.trim().parse::<usize>().map_err(|_| not_valid())?;
+        if file_name.is_empty() || start == 0 || end < start {
+            return Err(not_valid());
+        }
+
+        ranges.push((PathBuf::from(file_name), start..=end));
+    }
+
+    Ok(ranges)
+}
+
+/// Return the ranges of lines in `ranges` that belong to the input file `file_name`.  The
+/// function compares the canonical paths so that a relative and an absolute path name the same
+/// file.
+///
+/// # Arguments
+///
+/// * `ranges` - The file names and line ranges from [`parse_line_ranges`].
+/// * `file_name` - The name of the input file.
+pub fn line_ranges_for_file(
+    ranges: &[(PathBuf, RangeInclusive<usize>)],
+    file_name: &str,
+) -> Vec<RangeInclusive<usize>> {
+    ranges
+        .iter()
+        .filter(|(range_file, _)| names_same_file(range_file, file_name))
+        .map(|(_, range)| range.clone())
+        .collect()
+}
+
+/// Return the files in `ranges` that do not name any of the input files in `file_names`, each
+/// file once.
+///
+/// # Arguments
+///
+/// * `ranges` - The file names and line ranges from [`parse_line_ranges`].
+/// * `file_names` - The names of the input files.
+pub fn unmatched_line_range_files<'a>(
+    ranges: &'a [(PathBuf, RangeInclusive<usize>)],
+    file_names: &[&str],
+) -> Vec<&'a Path> {
+    let mut unmatched: Vec<&Path> = Vec::new();
+    for (range_file, _) in ranges {
+        if !unmatched.contains(&range_file.as_path())
+            && !file_names
+                .iter()
+                .any(|file_name| names_same_file(range_file, file_name))
+        {
+            unmatched.push(range_file);
+        }
+    }
+    unmatched
+}
+
+/// Return true if `range_file` and `file_name` name the same file.  The function compares the
+/// canonical paths so that a relative and an absolute path name the same file.
+///
+/// # Arguments
+///
+/// * `range_file` - The file name of a line range.
+/// * `file_name` - The name of an input file.
+fn names_same_file(range_file: &Path, file_name: &str) -> bool {
+    let path = Path::new(file_name);
+    range_file == path
+        || path
+            .canonicalize()
+            .is_ok_and(|canonical_path| range_file.canonicalize().ok() == Some(canonical_path))
+}
+
+/// Read the random number generator seeds from the seed file named by `file_name`.
+///
+/// The file has one seed per line, either a decimal number or a `0x` prefixed hexadecimal number.
+/// The function ignores blank lines and the text after a `#` character.
+///
+/// # Arguments
+///
+/// * `file_name` - The path of the seed file in the file system.
+pub fn read_seed_file(file_name: &str) -> Result<Vec<u128>, MutagenyxError> {
+    let contents = read_to_string(file_name)?;
+    let mut seeds: Vec<u128> = Vec::new();
+
+    for (index, line) in contents.lines().enumerate() {
+        let text = match line.split_once('#') {
+            Some((text, _)) => text.trim(),
+            None => line.trim(),
+        };
+        if text.is_empty() {
+            continue;
+        }
+
+        match parse_seed(text) {
+            Ok(seed) => seeds.push(seed),
+            Err(_) => {
+                return Err(MutagenyxError::SeedFileLineNotValid(
+                    String::from(file_name),
+                    index + 1,
+                    String::from(text),
+                ))
+            }
+        }
+    }
+
+    Ok(seeds)
+}
+
+/// Parse the text of the `--rng-seed` argument.  Return None if the text is a negative number,
+/// which requests a seed based on the time.
+///
+/// # Arguments
+///
+/// * `text` - The decimal or `0x` prefixed hexadecimal text of the seed.
+pub fn parse_rng_seed(text: &str) -> Result<Option<u128>, MutagenyxError> {
+    match text.trim().strip_prefix('-') {
+        Some(magnitude) if magnitude.parse::<u128>().is_ok() => Ok(None),
+        _ => parse_seed(text).map(Some),
+    }
 }
@@ -0,0 +1,137 @@
+//! The `input_glob` module expands the patterns given with `--input-glob` into the paths of
+//! the files that the patterns match.  A pattern is either a directory, which matches every file
+//! beneath the directory, or a path whose components may contain the wildcards `*` (any run of
+//! characters), `?` (any single character), and `**` (any number of directories).
+
+use std::path::{Path, PathBuf};
+
+/// Return true if `component` contains a wildcard character.
+///
+/// # Arguments
+///
+/// * `component` - A component of a pattern.
+fn has_wildcard(component: &str) -> bool {
+    component.contains(['*', '?'])
+}
+
+/// Return true if the file or directory name `name` matches the pattern component `pattern`.
+///
+/// # Arguments
+///
+/// * `pattern` - The characters of the pattern component.
+/// * `name` - The characters of the name.
+fn name_matches(pattern: &[char], name: &[char]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some(('*', rest)) => (0..=name.len()).any(|i| name_matches(rest, &name[i..])),
+        Some(('?', rest)) => !name.is_empty() && name_matches(rest, &name[1..]),
+        Some((c, rest)) => name.first() == Some(c) && name_matches(rest, &name[1..]),
+    }
+}
+
+/// Return true if the components of a relative path match the components of a pattern.
+///
+/// # Arguments
+///
+/// * `pattern` - The components of the pattern.
+/// * `path` - The components of the path.
+fn components_match(pattern: &[&str], path: &[String]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|i| components_match(rest, &path[i..])),
+        Some((component, rest)) => match path.split_first() {
+            Some((name, remaining)) => {
+                let pattern_chars: Vec<char> = component.chars().collect();
+                let name_chars: Vec<char> = name.chars().collect();
+                name_matches(&pattern_chars, &name_chars) && components_match(rest, remaining)
+            }
+            None => false,
+        },
+    }
+}
+
+/// Add the files beneath `directory` to `files`.  The function does not follow symbolic links to
+/// directories so that a link cannot make the walk loop forever.
+///
+/// # Arguments
+///
+/// * `directory` - The directory to walk.
+/// * `files` - The list that receives the paths of the files.
+fn collect_files(directory: &Path, files: &mut Vec<PathBuf>) {
+    let entries = match std::fs::read_dir(directory) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        match entry.file_type() {
+            Ok(file_type) if file_type.is_dir() => collect_files(&path, files),
+            Ok(_) if path.is_file() => files.push(path),
+            _ => {}
+        }
+    }
+}
+
+/// Return the paths of the files matched by `pattern`, sorted by path.
+///
+/// # Arguments
+///
+/// * `pattern` - A directory or a path with wildcards, such as `contracts/**/*.sol`.
+pub fn expand_input_glob(pattern: &str) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = Vec::new();
+
+    if !has_wildcard(pattern) {
+        let path = PathBuf::from(pattern);
+        if path.is_dir() {
+            collect_files(&path, &mut files);
+        } else if path.is_file() {
+            files.push(path);
+        }
+        files.sort();
+        return files;
+    }
+
+    // Walk the directory named by the components in front of the first wildcard and match the
+    // rest of the pattern against the paths relative to the directory.
+    let components: Vec<&str> = pattern.split('/').collect();
+    let literal_count = components
+        .iter()
+        .position(|c| has_wildcard(c))
+        .unwrap_or(components.len());
+    let base = components[..literal_count].join("/");
+    let base_directory = match base.as_str() {
+        "" if pattern.starts_with('/') => PathBuf::from("/"),
+        "" => PathBuf::from("."),
+        _ => PathBuf::from(&base),
+    };
+
+    let mut candidates: Vec<PathBuf> = Vec::new();
+    collect_files(&base_directory, &mut candidates);
+
+    let remaining: Vec<&str> = components[literal_count..]
+        .iter()
+        .copied()
+        .filter(|c| !c.is_empty())
+        .collect();
+    for candidate in candidates {
+        let relative = match candidate.strip_prefix(&base_directory) {
+            Ok(r) => r,
+            Err(_) => continue,
+        };
+        let relative_components: Vec<String> = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .collect();
+        if components_match(&remaining, &relative_components) {
+            if base.is_empty() && !pattern.starts_with('/') {
+                files.push(relative.to_path_buf());
+            } else {
+                files.push(candidate);
+            }
+        }
+    }
+
+    files.sort();
+    files
+}
@@ -0,0 +1,45 @@
+//! The `languages_info` module provides code to output the list of languages supported by the
+//! library along with the file extensions recognized for each language.
+
+use crate::LanguagesCLArgs;
+use mutagenyx_lib::language::all_languages;
+use serde_json::json;
+
+/// Function that displays the list of supported languages, either as plain text or as JSON.
+pub fn display_languages_info(params: LanguagesCLArgs) {
+    let languages = all_languages();
+
+    if params.json {
+        let list: Vec<serde_json::Value> = languages
+            .iter()
+            .map(|l| {
+                json!({
+                    "language": l.to_string(),
+                    "extensions": l.extensions(),
+                })
+            })
+            .collect();
+        println!("{}", serde_json::Value::Array(list));
+        return;
+    }
+
+    let name_width = languages
+        .iter()
+        .map(|l| l.to_string().len())
+        .max()
+        .unwrap_or(0);
+
+    for language in languages {
+        let extensions: Vec<String> = language
+            .extensions()
+            .iter()
+            .map(|e| String::from(".") + e)
+            .collect();
+        println!(
+            "{:width$} {}",
+            language.to_string(),
+            extensions.join(", "),
+            width = name_width
+        );
+    }
+}
@@ -1,18 +1,27 @@
 //! The main module contains the code to process the command line for the mutagenyx program and
 //! run the mutation generator.
 
+mod archive;
 mod compiler_settings;
 mod generator_parameters;
+mod input_glob;
+mod languages_info;
+mod manifest;
 mod mutation_generator;
 mod mutations_info;
 mod pretty_printing;
+#[cfg(all(test, unix))]
+mod test_fixtures;
 
+use crate::languages_info::display_languages_info;
 use crate::mutation_generator::generate_mutants;
 use crate::mutations_info::display_mutations_info;
 use crate::pretty_printing::pretty_print_files;
 use chrono::Local;
-use clap::{ArgGroup, Args, Parser, Subcommand};
+use clap::parser::ValueSource;
+use clap::{ArgGroup, ArgMatches, Args, CommandFactory, FromArgMatches, Parser, Subcommand};
 use env_logger::TimestampPrecision;
+use log::LevelFilter;
 use std::io::Write;
 use std::time::Instant;
 
@@ -28,8 +37,9 @@ struct MutagenyxCommand {
 #[derive(Subcommand, Debug, Clone)]
 pub enum Commands {
     Algorithms(AlgorithmsCLArgs),
-    Mutate(MutateCLArgs),
-    PrettyPrint(PrettyPrintCLArgs),
+    Languages(LanguagesCLArgs),
+    Mutate(Box<MutateCLArgs>),
+    PrettyPrint(Box<PrettyPrintCLArgs>),
 }
 
 /// Arguments for listing details about mutation algorithms
@@ -37,7 +47,7 @@ pub enum Commands {
 #[command(group(
 ArgGroup::new("info")
 .required(true)
-.args(["list", "describe"]),
+.args(["list", "describe", "count"]),
 ))]
 pub struct AlgorithmsCLArgs {
     /// List the available mutation algorithms
@@ -47,6 +57,22 @@ pub struct AlgorithmsCLArgs {
     /// Describe the available mutation algorithms
     #[arg(short, long)]
     pub describe: bool,
+
+    /// Print the number of available mutation algorithms
+    #[arg(short, long)]
+    pub count: bool,
+
+    /// With --count, also print the number of mutation algorithms for each language
+    #[arg(long, requires = "count")]
+    pub per_language: bool,
+}
+
+/// Arguments for listing the supported languages
+#[derive(Args, Debug, Clone)]
+pub struct LanguagesCLArgs {
+    /// Write the list of languages as JSON
+    #[arg(long)]
+    pub json: bool,
 }
 
 /// Arguments for generating mutations from source, AST, or configuration files.
@@ -57,18 +83,35 @@ pub struct MutateCLArgs {
     pub output_directory: String,
 
     /// Input file(s) to mutate
-    #[arg(short, long, required = true)]
+    #[arg(short, long, required_unless_present_any = ["config", "input_glob"])]
     pub file: Vec<String>,
 
-    /// Random number generator seed.
-    #[arg(long, default_value_t = -1)]
-    pub rng_seed: i64,
+    /// Directory or path pattern (with *, ?, and **) that selects input files to mutate.  The
+    /// tool skips the matched files that are not source, AST, or configuration files
+    #[arg(long, value_name = "PATTERN")]
+    pub input_glob: Vec<String>,
+
+    /// Configuration (.mgnx) file with settings for the run.  Repeat the flag to layer
+    /// configuration files: later files override earlier files and command line flags override
+    /// all the files
+    #[arg(long)]
+    pub config: Vec<String>,
+
+    /// Random number generator seed, a decimal or 0x prefixed hexadecimal number up to 128 bits.
+    /// A negative number selects a seed based on the time
+    #[arg(long, default_value = "-1", allow_hyphen_values = true)]
+    pub rng_seed: String,
 
     /// Number of mutants
     #[arg(long, default_value_t = 1)]
     pub num_mutants: usize,
 
-    /// Mutation algorithms to enable
+    /// File with one random number generator seed per line.  Generate one mutant per seed, in
+    /// the order of the file, instead of using --num-mutants and --rng-seed
+    #[arg(long)]
+    pub seed_file: Option<String>,
+
+    /// Mutation algorithms to enable, optionally with a number of mutants (Algorithm=NUMBER)
     #[arg(long)]
     pub mutation: Vec<String>,
 
@@ -76,29 +119,131 @@ pub struct MutateCLArgs {
     #[arg(short, long)]
     pub all_mutations: bool,
 
+    /// Enable the default set of mutation algorithms for the language of each input file, takes
+    /// precedence over --mutations
+    #[arg(long)]
+    pub default_mutations: bool,
+
     /// Pretty-print the original source or AST in the output directory.
     #[arg(long)]
     pub print_original: bool,
 
+    /// Write the unmutated original as the first output (index 0) alongside the mutants.
+    #[arg(long)]
+    pub include_baseline: bool,
+
+    /// Format of the comment that describes each mutation: text or structured
+    #[arg(long, default_value = "text")]
+    pub annotation_format: String,
+
+    /// Text to put at the start of the comment that describes each mutation
+    #[arg(long)]
+    pub annotation_prefix: Option<String>,
+
+    /// Log (at debug level) each node that each mutation algorithm examines and, for the operator
+    /// algorithms, the reason the algorithm rejects the node.
+    #[arg(long)]
+    pub trace_mutation_selection: bool,
+
+    /// Let the mutation algorithms mutate pragma directives such as `pragma solidity`, which
+    /// Mutagenyx otherwise leaves alone
+    #[arg(long)]
+    pub allow_pragma_mutation: bool,
+
+    /// Skip input files larger than this many bytes.
+    #[arg(long)]
+    pub max_file_size: Option<u64>,
+
+    /// Print a diff of the first mutant of the first input file to stdout instead of writing
+    /// any output files.
+    #[arg(long)]
+    pub preview: bool,
+
+    /// Number the lines in the hunk headers of the preview diff with the line numbers of the
+    /// original source file instead of the pretty-printed original (source file inputs only).
+    #[arg(long)]
+    pub include_original_in_diff_header: bool,
+
     /// Save the mutation information used to mutate each file in a config (.mgnx) file
     /// in the output directory
     #[arg(long)]
     pub save_config_files: bool,
 
+    /// Print the resolved parameters for each input file as JSON and exit without generating
+    /// any mutants
+    #[arg(long)]
+    pub dump_config: bool,
+
+    /// Print the nodes of each input file that the requested mutation algorithms can mutate as
+    /// JSON and exit without generating any mutants
+    #[arg(long)]
+    pub list_mutable_nodes: bool,
+
     /// Write output to stdout instead of the directory given in `output_directory`.
     #[arg(long)]
     pub stdout: bool,
 
+    /// Write the output files for every input file directly to `output_directory` instead of to
+    /// a subdirectory named after each input file
+    #[arg(long)]
+    pub flat_output: bool,
+
+    /// Number the mutants after the highest numbered mutant already in the output directory
+    /// instead of overwriting the mutants of earlier runs, skip the mutants already in the
+    /// directory, and add the new mutants to the manifest of the earlier runs
+    #[arg(long, conflicts_with_all = ["stdout", "tar", "preview"])]
+    pub append: bool,
+
+    /// Write the mutants (and the original for `print_original`) as JSON ASTs instead of
+    /// pretty-printed source
+    #[arg(long)]
+    pub emit_ast: bool,
+
+    /// Write the mutants, the other output files, and a manifest to this tar archive instead of
+    /// the directory given in `output_directory`
+    #[arg(long, conflicts_with_all = ["stdout", "preview"])]
+    pub tar: Option<String>,
+
+    /// Compression for the archive given in `tar`: none (.tar) or gzip (.tar.gz or .tgz)
+    #[arg(long, default_value = "none", requires = "tar")]
+    pub tar_compression: String,
+
     /// Only generate mutations in the listed functions.
     #[arg(long)]
     pub function: Vec<String>,
 
+    /// Never mutate nodes of this AST node type (such as `ImportDirective`) or their children.
+    #[arg(long, value_name = "TYPE")]
+    pub forbid_node_type: Vec<String>,
+
+    /// Never mutate inside this kind of definition.
+    #[arg(long, value_parser = ["constructor", "modifier"])]
+    pub forbid_in: Vec<String>,
+
+    /// Only mutate the nodes that lie entirely on the lines START to END (or on line START) of the
+    /// input file FILE.  The input files without a range keep all of their lines
+    #[arg(long, value_name = "FILE:START-END")]
+    pub lines: Vec<String>,
+
     /// Check each mutant for correctness. (Currently attempts to compile each mutant)
     #[arg(long)]
     pub validate_mutants: bool,
 
+    /// Run this command on each mutant and only keep mutants for which the command succeeds.
+    /// The tool replaces `{file}` in the command with the path of the mutant.
+    #[arg(long)]
+    pub validate_command: Option<String>,
+
+    /// Stop compiling a mutant after SECONDS seconds and treat the mutant as failing validation
+    #[arg(long, value_name = "SECONDS", value_parser = clap::value_parser!(u64).range(1..))]
+    pub validate_timeout: Option<u64>,
+
+    /// Treat warnings reported by a compiler while generating an AST as errors and skip the file
+    #[arg(long)]
+    pub compiler_warnings_as_errors: bool,
+
     /// Solidity compiler
-    #[arg(long, default_value = "solc")]
+    #[arg(long, env = "MGNX_SOLC", default_value = "solc")]
     pub solidity_compiler: String,
 
     /// Solidity base path (equivalent to --base-path)
@@ -122,12 +267,21 @@ pub struct MutateCLArgs {
     pub solidity_stop_after: Option<String>,
 
     /// Vyper compiler
-    #[arg(long, default_value = "vyper")]
+    #[arg(long, env = "MGNX_VYPER", default_value = "vyper")]
     pub vyper_compiler: String,
 
     /// Vyper root path (equivalent to -p)
     #[arg(long)]
     pub vyper_root_path: Option<String>,
+
+    /// Vyper import search paths (equivalent to --path)
+    #[arg(long)]
+    pub vyper_search_paths: Vec<String>,
+
+    /// The ids of the arguments set on the command line or in the environment.  Settings from
+    /// the files in `config` only apply to the other arguments.
+    #[arg(skip)]
+    pub explicit_args: Vec<String>,
 }
 
 /// Arguments for pretty-printing source or AST input.
@@ -145,8 +299,22 @@ pub struct PrettyPrintCLArgs {
     #[arg(long)]
     pub stdout: bool,
 
+    /// Write the AST as JSON instead of pretty-printed source
+    #[arg(long)]
+    pub emit_ast: bool,
+
+    /// Instead of writing the pretty-printed source, compile the pretty-printed source back to
+    /// an AST and report the differences from the AST of the input.  Exit with a non-zero status
+    /// if a round trip changes the AST or fails
+    #[arg(long, conflicts_with_all = ["stdout", "emit_ast"])]
+    pub verify_round_trip: bool,
+
+    /// Treat warnings reported by a compiler while generating an AST as errors and skip the file
+    #[arg(long)]
+    pub compiler_warnings_as_errors: bool,
+
     /// Solidity compiler
-    #[arg(long, default_value = "solc")]
+    #[arg(long, env = "MGNX_SOLC", default_value = "solc")]
     pub solidity_compiler: String,
 
     /// Solidity base path (equivalent to --base-path)
@@ -170,18 +338,53 @@ pub struct PrettyPrintCLArgs {
     pub solidity_stop_after: Option<String>,
 
     /// Vyper compiler
-    #[arg(long, default_value = "vyper")]
+    #[arg(long, env = "MGNX_VYPER", default_value = "vyper")]
     pub vyper_compiler: String,
 
     /// Vyper root path (equivalent to -p)
     #[arg(long)]
     pub vyper_root_path: Option<String>,
+
+    /// Vyper import search paths (equivalent to --path)
+    #[arg(long)]
+    pub vyper_search_paths: Vec<String>,
+}
+
+/// Return the ids of the arguments in `matches` whose values come from the command line or
+/// from the environment instead of from the argument defaults.
+///
+/// # Arguments
+///
+/// * `matches` - The parsed arguments of a sub-command.
+fn explicit_argument_ids(matches: &ArgMatches) -> Vec<String> {
+    matches
+        .ids()
+        .filter(|id| {
+            matches!(
+                matches.value_source(id.as_str()),
+                Some(ValueSource::CommandLine) | Some(ValueSource::EnvVariable)
+            )
+        })
+        .map(|id| String::from(id.as_str()))
+        .collect()
 }
 
 fn main() {
     let mut disable_timing = false;
+    let mut exit_code = 0;
     let beginning_of_run = Instant::now();
-    let _ = env_logger::builder()
+
+    let matches = MutagenyxCommand::command().get_matches();
+    let mut mutagenyx_command =
+        MutagenyxCommand::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    if let (Commands::Mutate(mutate_args), Some(("mutate", mutate_matches))) =
+        (&mut mutagenyx_command.command, matches.subcommand())
+    {
+        mutate_args.explicit_args = explicit_argument_ids(mutate_matches);
+    }
+
+    let mut logger_builder = env_logger::builder();
+    logger_builder
         .format(|buf, record| {
             writeln!(
                 buf,
@@ -191,22 +394,34 @@ fn main() {
                 record.args()
             )
         })
-        .format_timestamp(Some(TimestampPrecision::Seconds))
-        .try_init();
+        .format_timestamp(Some(TimestampPrecision::Seconds));
+    if let Commands::Mutate(mutate_args) = &mutagenyx_command.command {
+        if mutate_args.trace_mutation_selection {
+            logger_builder.filter_module("mutagenyx_lib::mutation_visitor", LevelFilter::Debug);
+        }
+    }
+    let _ = logger_builder.try_init();
 
-    let mutagenyx_command = MutagenyxCommand::parse();
     match &mutagenyx_command.command {
         Commands::Algorithms(alg_args) => {
             disable_timing = true;
             display_mutations_info(alg_args.clone());
         }
+        Commands::Languages(language_args) => {
+            disable_timing = true;
+            display_languages_info(language_args.clone());
+        }
         Commands::Mutate(mutate_args) => {
-            if let Err(e) = generate_mutants(mutate_args.clone()) {
+            disable_timing =
+                mutate_args.preview || mutate_args.dump_config || mutate_args.list_mutable_nodes;
+            if let Err(e) = generate_mutants(*mutate_args.clone()) {
                 println!("Unable to generate mutations: {}", e);
             }
         }
         Commands::PrettyPrint(pretty_print_args) => {
-            pretty_print_files(pretty_print_args.clone());
+            if !pretty_print_files(*pretty_print_args.clone()) {
+                exit_code = 1;
+            }
         }
     }
     if !disable_timing {
@@ -215,4 +430,7 @@ fn main() {
             beginning_of_run.elapsed().as_secs_f64()
         );
     }
+    if exit_code != 0 {
+        std::process::exit(exit_code);
+    }
 }
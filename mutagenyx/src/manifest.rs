@@ -0,0 +1,214 @@
+//! The `manifest` module provides [`RejectedMutantRecord`], the description of a mutant that
+//! failed validation, and [`RunManifest`], the summary of a run that links each written mutant
+//! ([`MutantRecord`]) back to its input file and mutation.  Mutagenyx writes the manifest to the
+//! output directory or to the tar archive at the end of a run.
+
+use mutagenyx_lib::config_file::seed_to_json;
+use mutagenyx_lib::error::MutagenyxError;
+use mutagenyx_lib::mutant_record::MutantRecord;
+use mutagenyx_lib::mutation::MutationType;
+use serde_json::{json, Value};
+use std::path::Path;
+
+/// The name of the manifest file that Mutagenyx writes at the end of a run.
+pub static MANIFEST_FILE_NAME: &str = "manifest.json";
+
+/// The description of a mutant that did not compile, that the compiler did not finish compiling
+/// within the time limit, or that failed the validation command, when the run validated the
+/// mutants.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RejectedMutantRecord {
+    /// The name of the input file mutated to create the mutant.
+    pub source: String,
+
+    /// The mutation algorithm that created the mutant.
+    pub mutation_type: Option<MutationType>,
+
+    /// The id of the mutated node.
+    pub node_id: Option<u64>,
+
+    /// The validation command that rejected the mutant, None if the compiler rejected the
+    /// mutant.
+    pub validate_command: Option<String>,
+
+    /// The exit status of the compiler or the validation command, None if the program did not
+    /// start or a signal stopped the program.
+    pub status: Option<i32>,
+
+    /// The text that the compiler or the validation command wrote to stderr.
+    pub stderr: String,
+
+    /// True if the compiler did not finish within the time limit.
+    pub timed_out: bool,
+}
+
+impl RejectedMutantRecord {
+    /// Return the JSON object that describes the rejected mutant in a manifest.
+    pub fn to_json(&self) -> Value {
+        json!({
+            "input": self.source,
+            "mutation": self.mutation_type.map(|t| t.to_string()),
+            "node-id": self.node_id,
+            "validate-command": self.validate_command,
+            "exit-status": self.status,
+            "stderr": self.stderr,
+            "timed-out": self.timed_out,
+        })
+    }
+}
+
+/// The summary of a mutation run: the input files, the seed used for each input file, a record
+/// for each mutant written, and a record for each mutant that failed validation.
+#[derive(Debug, Clone, Default)]
+pub struct RunManifest {
+    /// The name of each input file and the seed of the random number generator for the file.
+    inputs: Vec<(String, u128)>,
+
+    /// The mutants written by the run.
+    mutants: Vec<MutantRecord>,
+
+    /// The mutants that did not compile or failed the validation command when the run validated
+    /// the mutants.
+    rejected_mutants: Vec<RejectedMutantRecord>,
+}
+
+impl RunManifest {
+    /// Record an input file of the run.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_name` - The name of the input file.
+    /// * `seed` - The seed of the random number generator used to mutate the file.
+    pub fn add_input(&mut self, file_name: &str, seed: u128) {
+        self.inputs.push((String::from(file_name), seed));
+    }
+
+    /// Record a mutant written by the run.
+    ///
+    /// # Arguments
+    ///
+    /// * `record` - The description of the mutant.
+    pub fn add_mutant(&mut self, record: MutantRecord) {
+        self.mutants.push(record);
+    }
+
+    /// Return the mutants written by the run.
+    pub fn mutants(&self) -> &[MutantRecord] {
+        &self.mutants
+    }
+
+    /// Record a mutant that did not compile or failed the validation command.
+    ///
+    /// # Arguments
+    ///
+    /// * `record` - The description of the rejected mutant.
+    pub fn add_rejected_mutant(&mut self, record: RejectedMutantRecord) {
+        self.rejected_mutants.push(record);
+    }
+
+    /// Return the mutants that did not compile or failed the validation command.
+    pub fn rejected_mutants(&self) -> &[RejectedMutantRecord] {
+        &self.rejected_mutants
+    }
+
+    /// Return the manifest as a JSON object.
+    pub fn to_json(&self) -> Value {
+        let inputs: Vec<Value> = self
+            .inputs
+            .iter()
+            .map(|(file, seed)| json!({"file": file, "seed": seed_to_json(*seed)}))
+            .collect();
+        let mutants: Vec<Value> = self.mutants.iter().map(|m| m.to_json()).collect();
+        let mut manifest = json!({
+            "inputs": inputs,
+            "total-mutants": self.mutants.len(),
+            "mutants": mutants,
+        });
+
+        // Only runs that validate mutants can reject mutants.
+        if !self.rejected_mutants.is_empty() {
+            let rejected: Vec<Value> = self.rejected_mutants.iter().map(|m| m.to_json()).collect();
+            manifest["rejected-mutants"] = json!(rejected);
+        }
+        manifest
+    }
+
+    /// Return the text of the manifest file.
+    pub fn to_text(&self) -> Result<String, MutagenyxError> {
+        Ok(serde_json::to_string_pretty(&self.to_json())? + "\n")
+    }
+
+    /// Write the manifest file to `directory`.  An appending run adds its inputs and mutants to
+    /// the manifest that earlier runs left in the directory, so the manifest keeps listing every
+    /// mutant in the directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `directory` - The output directory of the run.
+    /// * `append` - True if the run added its mutants to the mutants of earlier runs.
+    pub fn write_to_directory(&self, directory: &Path, append: bool) -> Result<(), MutagenyxError> {
+        std::fs::create_dir_all(directory)?;
+        let manifest_path = directory.join(MANIFEST_FILE_NAME);
+
+        let earlier_manifest: Option<Value> = if append {
+            std::fs::read_to_string(&manifest_path)
+                .ok()
+                .and_then(|text| serde_json::from_str(&text).ok())
+        } else {
+            None
+        };
+        let manifest = match earlier_manifest {
+            Some(earlier_manifest) => merge_manifests(&earlier_manifest, self.to_json()),
+            None => self.to_json(),
+        };
+
+        std::fs::write(
+            manifest_path,
+            serde_json::to_string_pretty(&manifest)? + "\n",
+        )?;
+        Ok(())
+    }
+}
+
+/// Return the manifest that lists the entries of `earlier` followed by the entries of `latest`.
+/// An entry of `latest` replaces an equal input, or a mutant with the same file name, in
+/// `earlier`.
+///
+/// # Arguments
+///
+/// * `earlier` - The JSON manifest of the earlier runs.
+/// * `latest` - The JSON manifest of the latest run.
+fn merge_manifests(earlier: &Value, mut latest: Value) -> Value {
+    for (key, same_entry) in [
+        (
+            "inputs",
+            (|a: &Value, b: &Value| a == b) as fn(&Value, &Value) -> bool,
+        ),
+        ("mutants", |a: &Value, b: &Value| a["file"] == b["file"]),
+        ("rejected-mutants", |a: &Value, b: &Value| a == b),
+    ] {
+        let latest_entries: Vec<Value> = latest
+            .get(key)
+            .and_then(|v| v.as_array())
+            .cloned()
+            .unwrap_or_default();
+        let mut entries: Vec<Value> = earlier
+            .get(key)
+            .and_then(|v| v.as_array())
+            .map(|a| {
+                a.iter()
+                    .filter(|e| !latest_entries.iter().any(|l| same_entry(e, l)))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default();
+        entries.extend(latest_entries);
+
+        // Only runs that validate mutants can reject mutants.
+        if key != "rejected-mutants" || !entries.is_empty() {
+            latest[key] = json!(entries);
+        }
+    }
+    latest["total-mutants"] = json!(latest["mutants"].as_array().map_or(0, |m| m.len()));
+    latest
+}
@@ -1,25 +1,112 @@
 //! The `mutation_generator` module provides the `generate_mutations` function that implements
 //! the mutation generation algorithm.
 
+use crate::archive::{MutantArchive, TarCompression};
 use crate::compiler_settings::*;
-use crate::generator_parameters::GeneratorParameters;
-use crate::pretty_printing::{pretty_print_ast, pretty_print_ast_to_stream};
+use crate::generator_parameters::{
+    assign_output_subdirectories, line_ranges_for_file, parse_line_ranges, parse_mutation_requests,
+    parse_rng_seed, read_seed_file, unmatched_line_range_files, GeneratorParameters,
+};
+use crate::input_glob::expand_input_glob;
+use crate::manifest::{RejectedMutantRecord, RunManifest};
+use crate::pretty_printing::{
+    pretty_print_ast, pretty_print_ast_next_to_file, pretty_print_ast_to_archive,
+    pretty_print_ast_to_stream,
+};
 use crate::MutateCLArgs;
+use mutagenyx_lib::annotation::*;
 use mutagenyx_lib::compiler_details::*;
 use mutagenyx_lib::config_file::*;
+use mutagenyx_lib::diff::unified_diff_from_line;
 use mutagenyx_lib::error::MutagenyxError;
+use mutagenyx_lib::language::Language;
 use mutagenyx_lib::language_interface::*;
-use mutagenyx_lib::mutation::{get_all_mutation_algorithms, MutationType};
+use mutagenyx_lib::mutant_record::MutantRecord;
+use mutagenyx_lib::mutant_selector::MutantSelector;
+use mutagenyx_lib::mutation::{default_mutation_types, get_all_mutation_algorithms, MutationType};
 use mutagenyx_lib::mutator_result::MutatorResult;
 use mutagenyx_lib::permissions::*;
+use mutagenyx_lib::preferences::{PreferenceValue, Preferences};
 use mutagenyx_lib::recognizer::{FileType, Recognizer};
+use mutagenyx_lib::solidity::compiler_details::*;
+use mutagenyx_lib::vyper::compiler_details::{ROOT_PATH_KEY, SEARCH_PATHS_KEY};
+use mutagenyx_lib::SuperAST;
 use rand::seq::SliceRandom;
-use rand::RngCore;
-use rand::SeedableRng;
-use rand_pcg::*;
-use std::collections::{HashMap, VecDeque};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::process::{Command, Stdio};
 use std::time::{SystemTime, UNIX_EPOCH};
-use std::{path::PathBuf, str::FromStr};
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+/// Return true if `ast` is a Solidity AST that defines an abstract contract or a contract that
+/// does not implement all of its functions.
+///
+/// # Arguments
+///
+/// * `ast` - The AST of the program to mutate.
+fn has_abstract_contract(ast: &SuperAST) -> bool {
+    let source_unit = match ast {
+        SuperAST::Solidity(source_unit) => source_unit,
+        _ => return false,
+    };
+
+    match source_unit.get("nodes").and_then(|n| n.as_array()) {
+        Some(nodes) => nodes.iter().any(|node| {
+            node.get("nodeType").and_then(|t| t.as_str()) == Some("ContractDefinition")
+                && (node.get("abstract").and_then(|a| a.as_bool()) == Some(true)
+                    || node.get("fullyImplemented").and_then(|f| f.as_bool()) == Some(false))
+        }),
+        None => false,
+    }
+}
+
+/// Return a copy of `preferences` with the Solidity compiler setting that only checks that a
+/// mutant compiles to an AST.
+///
+/// # Arguments
+///
+/// * `preferences` - The preferences of the mutation run.
+fn compile_only_preferences(preferences: &Preferences) -> Preferences {
+    let language_key = Language::Solidity.to_string();
+    let mut preferences = preferences.clone();
+    let mut language_preferences = preferences
+        .get_preferences_for_key(&language_key)
+        .unwrap_or_default();
+    let mut compiler_preferences = language_preferences
+        .get_preferences_for_key(COMPILER_KEY)
+        .unwrap_or_default();
+    compiler_preferences.set_bool_for_key(COMPILE_ONLY_KEY, true);
+    language_preferences.set_preferences_for_key(COMPILER_KEY, compiler_preferences);
+    preferences.set_preferences_for_key(&language_key, language_preferences);
+    preferences
+}
+
+/// Return a copy of `preferences` with a time limit of `seconds` for the Solidity and Vyper
+/// compilers while checking that a mutant compiles.
+///
+/// # Arguments
+///
+/// * `preferences` - The preferences of the mutation run.
+/// * `seconds` - The time limit of each compiler invocation.
+fn validation_timeout_preferences(preferences: &Preferences, seconds: u64) -> Preferences {
+    let mut preferences = preferences.clone();
+    for language in [Language::Solidity, Language::Vyper] {
+        let language_key = language.to_string();
+        let mut language_preferences = preferences
+            .get_preferences_for_key(&language_key)
+            .unwrap_or_default();
+        let mut compiler_preferences = language_preferences
+            .get_preferences_for_key(COMPILER_KEY)
+            .unwrap_or_default();
+        compiler_preferences.set_int_for_key(TIMEOUT_KEY, seconds as i64);
+        language_preferences.set_preferences_for_key(COMPILER_KEY, compiler_preferences);
+        preferences.set_preferences_for_key(&language_key, language_preferences);
+    }
+    preferences
+}
 
 /// Convert a vector of [`MutationType`] to a vector of [`String`].
 ///
@@ -30,61 +117,280 @@ fn get_mutation_strings_from_types(array: &[MutationType]) -> Vec<String> {
     array.iter().map(|t| t.to_string()).collect()
 }
 
-/// Convert a vector of [`String`] to a vector of [`MutationType`].
-fn get_mutation_types_from_strings(array: &[String]) -> Vec<MutationType> {
-    array
-        .iter()
-        .filter_map(|s| MutationType::from_str(s).ok())
-        .collect()
+/// Return the permissions that select the nodes to mutate: the nodes in the functions named in
+/// `params` (or every function), except the nodes of the forbidden node types.
+///
+/// # Arguments
+///
+/// * `params` - The parameters with the function names and the forbidden node types.
+fn mutation_permissions(params: &GeneratorParameters) -> Permissions {
+    let mut builder = LanguageInterface::permissions_builder().only_functions(&params.functions);
+    for node_type in &params.forbidden_node_types {
+        builder = builder.forbid_node_type(node_type);
+    }
+    builder.build()
+}
+
+/// Return the size of the file named by `file_name` if the file is larger than `max_file_size`,
+/// otherwise return None.
+///
+/// # Arguments
+///
+/// * `file_name` - The path of the input file in the file system.
+/// * `max_file_size` - The largest allowed file size in bytes or None for no limit.
+fn file_size_over_limit(file_name: &str, max_file_size: Option<u64>) -> Option<u64> {
+    let max_file_size = max_file_size?;
+    let size = std::fs::metadata(file_name).ok()?.len();
+    if size > max_file_size {
+        Some(size)
+    } else {
+        None
+    }
 }
 
-/// Helper function to convert a vector of strings representing function names to a Permissions
-/// object suitable for use in AST traversal.
+/// Return the files matched by the patterns in `patterns` that look like source code, an AST, or a
+/// configuration for a supported language.  The function prints a warning for each pattern that
+/// does not match any file and for each matched file that it does not recognize.
+///
+/// The function only reads the start of each file and does not compile source files.  The files
+/// larger than `max_file_size` stay in the list without a look at their contents, so that the
+/// size check of the generator reports them.
+///
+/// # Arguments
+///
+/// * `patterns` - The directories or path patterns given with `--input-glob`.
+/// * `max_file_size` - The largest allowed file size in bytes or None for no limit.
+fn recognized_input_files(patterns: &[String], max_file_size: Option<u64>) -> Vec<String> {
+    let mut file_names: Vec<String> = Vec::new();
+
+    for pattern in patterns {
+        let matches = expand_input_glob(pattern);
+        if matches.is_empty() {
+            println!("Warning: {} does not match any file", pattern);
+            continue;
+        }
+
+        for path in matches {
+            let file_name = path.to_string_lossy().into_owned();
+            if file_names.contains(&file_name) {
+                continue;
+            }
+            if file_size_over_limit(&file_name, max_file_size).is_some()
+                || Recognizer::recognize_language_only(&file_name).is_some()
+            {
+                file_names.push(file_name);
+            } else {
+                println!(
+                    "Skipping {} (not a recognized source, AST, or configuration file)",
+                    file_name
+                );
+            }
+        }
+    }
+
+    file_names
+}
+
+/// Return the strings in the JSON list `value`.
 ///
 /// # Arguments
 ///
-/// * `names` - The vector of function names.
-fn convert_function_names_to_permissions(names: &Vec<String>) -> Permissions {
-    let mut permissions = Permissions::new();
+/// * `value` - The JSON list.
+fn string_list(value: &Value) -> Vec<String> {
+    value
+        .as_array()
+        .map(|a| {
+            a.iter()
+                .filter_map(|v| v.as_str())
+                .map(String::from)
+                .collect()
+        })
+        .unwrap_or_default()
+}
 
-    if !names.is_empty() {
-        for function_name in names {
-            // Give permission to mutate the node with the function name.
-            permissions.add_permission(PermissionAction::Mutate(PermissionScope::Name(
-                PermissionObject::Value(function_name.clone(), Permission::Allow),
-            )));
+/// Apply the settings of the layered configuration files in `args.config` to the arguments that
+/// the user did not set on the command line or in the environment.  Return the language and the
+/// compiler details from the configuration files, if the files have compiler details.
+///
+/// # Arguments
+///
+/// * `args` - The command line arguments.
+fn apply_configuration_layers(
+    args: &mut MutateCLArgs,
+) -> Result<Option<(Language, Value)>, MutagenyxError> {
+    let config = load_layered_configuration_files(&args.config)?;
+    let explicit_args = args.explicit_args.clone();
+    let layered = |key: &str, arg: &str| -> Option<&Value> {
+        if explicit_args.iter().any(|a| a == arg) {
+            None
+        } else {
+            config.get(key)
+        }
+    };
 
-            // Also grant permission to mutate any children of this node.
-            permissions.add_permission(PermissionAction::Mutate(PermissionScope::Children(
-                PermissionObject::Value(function_name.clone(), Permission::Allow),
-            )));
+    if let Some(v) = layered(FILENAMES_KEY, "file") {
+        args.file = string_list(v);
+    }
+    if let Some(v) = layered(NUMBER_OF_MUTANTS_KEY, "num_mutants").and_then(|v| v.as_u64()) {
+        args.num_mutants = v as usize;
+    }
+    if let Some(v) = layered(SEED_KEY, "rng_seed") {
+        match v {
+            Value::Number(n) => args.rng_seed = n.to_string(),
+            Value::String(s) => args.rng_seed = s.clone(),
+            _ => {}
         }
-    } else {
-        permissions.add_permission(PermissionAction::Mutate(PermissionScope::Any(
-            Permission::Allow,
-        )));
     }
+    if let Some(v) = layered(MUTATIONS_KEY, "mutation") {
+        args.mutation = string_list(v);
+    }
+    if let Some(v) = layered(ALL_MUTATIONS_KEY, "all_mutations").and_then(|v| v.as_bool()) {
+        args.all_mutations = v;
+    }
+    if let Some(v) = layered(FUNCTIONS_KEY, "function") {
+        args.function = string_list(v);
+    }
+    if let Some(v) = layered(VALIDATE_MUTANTS_KEY, "validate_mutants").and_then(|v| v.as_bool()) {
+        args.validate_mutants = v;
+    }
+    if let Some(v) = layered(VALIDATE_COMMAND_KEY, "validate_command").and_then(|v| v.as_str()) {
+        args.validate_command = Some(String::from(v));
+    }
+    if let Some(v) = layered(VALIDATE_TIMEOUT_KEY, "validate_timeout").and_then(|v| v.as_u64()) {
+        args.validate_timeout = Some(v).filter(|s| *s > 0);
+    }
+    if let Some(v) = layered(OUTPUT_DIR_KEY, "output_directory").and_then(|v| v.as_str()) {
+        if v == "stdout" {
+            args.stdout = true;
+        } else {
+            args.output_directory = String::from(v);
+        }
+    }
+    if let Some(v) = layered(PRINT_ORIGINAL_KEY, "print_original").and_then(|v| v.as_bool()) {
+        args.print_original = v;
+    }
+    if let Some(v) = layered(INCLUDE_BASELINE_KEY, "include_baseline").and_then(|v| v.as_bool()) {
+        args.include_baseline = v;
+    }
+
+    if args.file.is_empty() && args.input_glob.is_empty() {
+        return Err(MutagenyxError::ConfigFileMissingRequiredKey(
+            args.config.join(", "),
+            vec![String::from(FILENAMES_KEY)],
+        ));
+    }
+
+    let compiler_details = match config.get(COMPILER_DETAILS_KEY) {
+        Some(details) => details.clone(),
+        None => return Ok(None),
+    };
+    match config
+        .get(LANGUAGE_KEY)
+        .and_then(|l| l.as_str())
+        .and_then(|l| Language::from_str(l).ok())
+    {
+        Some(language) => Ok(Some((language, compiler_details))),
+        None => Err(MutagenyxError::ConfigFileMissingRequiredKey(
+            args.config.join(", "),
+            vec![String::from(LANGUAGE_KEY)],
+        )),
+    }
+}
+
+/// Copy the compiler details from the layered configuration files into the compiler settings
+/// for `language` in `preferences`.  The function skips the settings that the user set with
+/// command line flags or environment variables.
+///
+/// # Arguments
+///
+/// * `preferences` - The preferences built from the command line arguments.
+/// * `language` - The language of the compiler details.
+/// * `compiler_details` - The compiler details from the configuration files.
+/// * `explicit_args` - The ids of the arguments set on the command line or in the environment.
+fn apply_layered_compiler_details(
+    preferences: &mut Preferences,
+    language: Language,
+    compiler_details: &Value,
+    explicit_args: &[String],
+) {
+    // Standalone Yul programs use the Solidity compiler settings.
+    let (language_key, key_arguments) = match language {
+        Language::Solidity | Language::Yul => (
+            Language::Solidity.to_string(),
+            vec![
+                (PATH_KEY, "solidity_compiler"),
+                (BASE_PATH_KEY, "solidity_base_path"),
+                (INCLUDE_PATHS_KEY, "solidity_include_path"),
+                (ALLOW_PATHS_KEY, "solidity_allow_paths"),
+                (REMAPPINGS_KEY, "solidity_remapping"),
+                (STOP_AFTER_KEY, "solidity_stop_after"),
+                (WARNINGS_AS_ERRORS_KEY, "compiler_warnings_as_errors"),
+            ],
+        ),
+        Language::Vyper => (
+            Language::Vyper.to_string(),
+            vec![
+                (PATH_KEY, "vyper_compiler"),
+                (ROOT_PATH_KEY, "vyper_root_path"),
+                (SEARCH_PATHS_KEY, "vyper_search_paths"),
+                (WARNINGS_AS_ERRORS_KEY, "compiler_warnings_as_errors"),
+            ],
+        ),
+    };
+
+    let details_map = match compiler_details.as_object() {
+        Some(m) => m,
+        None => return,
+    };
 
-    permissions.add_permission(PermissionAction::Visit(PermissionScope::Any(
-        Permission::Allow,
-    )));
+    let mut language_preferences = preferences
+        .get_preferences_for_key(&language_key)
+        .unwrap_or_default();
+    let mut compiler_preferences = language_preferences
+        .get_preferences_for_key(COMPILER_KEY)
+        .unwrap_or_default();
+
+    for (key, value) in details_map {
+        let set_by_user = key_arguments
+            .iter()
+            .any(|(k, arg)| k == key && explicit_args.iter().any(|a| a == arg));
+        if set_by_user {
+            continue;
+        }
+        if let Ok(preference_value) = PreferenceValue::try_from(value.clone()) {
+            compiler_preferences.set_value_for_key(key, preference_value);
+        }
+    }
 
-    permissions
+    language_preferences.set_preferences_for_key(COMPILER_KEY, compiler_preferences);
+    preferences.set_preferences_for_key(&language_key, language_preferences);
 }
 
-/// Run the mutation generator algorithm.
+/// Run the mutation generator algorithm.  Return the record of each file written to the output
+/// directories or to the archive, in the order that the generator wrote the files.  The list is
+/// empty when the generator writes to stdout or does not generate mutants, as for `--preview`,
+/// `--dump-config`, and `--list-mutable-nodes`.
 ///
 /// # Arguments
 ///
 /// * `args` - The command line arguments that control the mutation algorithm.
-pub fn generate_mutants(args: MutateCLArgs) -> Result<(), MutagenyxError> {
+pub fn generate_mutants(mut args: MutateCLArgs) -> Result<Vec<MutantRecord>, MutagenyxError> {
+    // Fill in the settings from the layered configuration files before using any argument.
+    let layered_compiler_details = if args.config.is_empty() {
+        None
+    } else {
+        apply_configuration_layers(&mut args)?
+    };
+
     // Select the mutation algorithms to use while generating mutations.  Args.all_mutations takes
     // precedence over individual algorithms selected in args.mutations.
-    let mutations: Vec<MutationType> = if args.all_mutations {
-        get_all_mutation_algorithms()
+    // Change the algorithm strings from the command line into actual MutationType values.  Parse
+    // the requests even when args.all_mutations is set so that a typo fails early.
+    let (requested_mutations, requested_mutation_counts) = parse_mutation_requests(&args.mutation)?;
+    let (mutations, mutation_counts) = if args.all_mutations {
+        (get_all_mutation_algorithms(), BTreeMap::new())
     } else {
-        // Change the algorithm strings from the command line into actual MutationType values.
-        get_mutation_types_from_strings(&args.mutation)
+        (requested_mutations, requested_mutation_counts)
     };
 
     let solidity_compiler_settings = SolidityCompilerSettings {
@@ -99,50 +405,119 @@ pub fn generate_mutants(args: MutateCLArgs) -> Result<(), MutagenyxError> {
     let vyper_compiler_settings = VyperCompilerSettings {
         vyper_compiler: args.vyper_compiler,
         vyper_root_path: args.vyper_root_path,
+        vyper_search_paths: args.vyper_search_paths,
     };
 
     // We allow the user to pass in compilers for each supported language on the command line.
     let compiler_paths = CompilerSettings {
         solidity: solidity_compiler_settings,
         vyper: vyper_compiler_settings,
+        warnings_as_errors: args.compiler_warnings_as_errors,
     };
 
     let mut preferences = compiler_paths.to_preferences();
+    if let Some((language, compiler_details)) = &layered_compiler_details {
+        apply_layered_compiler_details(
+            &mut preferences,
+            *language,
+            compiler_details,
+            &args.explicit_args,
+        );
+    }
+
+    // Check the annotation format before doing any work so that a typo fails early.
+    let annotation_format = AnnotationFormat::from_str(&args.annotation_format)?;
+    preferences.set_string_for_key(ANNOTATION_FORMAT_KEY, &annotation_format.to_string());
+    if let Some(prefix) = &args.annotation_prefix {
+        preferences.set_string_for_key(ANNOTATION_PREFIX_KEY, prefix);
+    }
+    preferences.set_bool_for_key(TRACE_MUTATION_SELECTION_KEY, args.trace_mutation_selection);
+
+    // Likewise check the archive compression and the line ranges.
+    let tar_compression = TarCompression::from_str(&args.tar_compression)?;
+    let line_ranges = parse_line_ranges(&args.lines)?;
+
+    // Read the seed file before recognizing any input files.
+    let replay_seeds = match &args.seed_file {
+        Some(file_name) => read_seed_file(file_name)?,
+        None => Vec::new(),
+    };
 
     let mut generator_parameters: Vec<GeneratorParameters> = Vec::new();
 
     // Select a random number generator seed based on args.rng_seed. If args.rng_seed is less
     // than 0, then use a seed based off of time (we don't need cryptographic randomness).
-    let mut seed: u64 = if args.rng_seed < 0 {
-        let start = SystemTime::now();
-        let since_the_epoch = start.duration_since(UNIX_EPOCH);
-        match since_the_epoch {
-            // Todo: This step truncates a u128 to u64.  We probably need those extra bits.
-            Ok(t) => t.as_millis() as u64,
-            _ => 0,
+    let mut seed: u128 = match parse_rng_seed(&args.rng_seed)? {
+        Some(seed) => seed,
+        None => {
+            let start = SystemTime::now();
+            let since_the_epoch = start.duration_since(UNIX_EPOCH);
+            match since_the_epoch {
+                Ok(t) => t.as_millis(),
+                _ => 0,
+            }
         }
-    } else {
-        args.rng_seed as u64
     };
 
-    let pcg = Pcg64::seed_from_u64(seed);
+    let pcg = rng_from_seed(seed);
+
+    // The kinds of definition named with `forbid_in` are node kinds like the node types.
+    let mut forbidden_node_types = args.forbid_node_type.clone();
+    forbidden_node_types.extend(args.forbid_in.iter().cloned());
+
+    // The input files skipped because they exceed args.max_file_size.
+    let mut skipped_files: Vec<String> = Vec::new();
+
+    // Add the recognized files that match the input patterns to the explicit input files.
+    for file_name in recognized_input_files(&args.input_glob, args.max_file_size) {
+        if !args.file.contains(&file_name) {
+            args.file.push(file_name);
+        }
+    }
 
     for file_name in args.file {
+        // Check the size of the input before recognizing the file, since recognizing a source
+        // file may invoke the compiler.  Configuration files are exempt, the size check applies
+        // to the files listed in the configuration file instead.
+        let is_config_file = PathBuf::from(&file_name)
+            .extension()
+            .map_or_else(|| false, |e| e == CONFIG_FILE_EXTENSION);
+        if !is_config_file {
+            if let Some(size) = file_size_over_limit(&file_name, args.max_file_size) {
+                println!(
+                    "Skipping {} ({} bytes exceeds the maximum file size)",
+                    file_name, size
+                );
+                skipped_files.push(file_name);
+                continue;
+            }
+        }
+
         let mut actual_preferences = preferences.clone();
         let actual_file_name = file_name.clone();
         let mut actual_mutations = mutations.clone();
+        let mut actual_mutation_counts = mutation_counts.clone();
         let mut actual_functions = args.function.clone();
         let mut actual_number_of_mutants = args.num_mutants;
         let mut actual_verify = args.validate_mutants;
+        let mut actual_validate_command = args.validate_command.clone();
+        let mut actual_validate_timeout = args.validate_timeout;
         let mut actual_output_directory = PathBuf::from_str(&args.output_directory).unwrap();
         let mut actual_use_stdout = args.stdout;
         let mut actual_print_original = args.print_original;
+        let mut actual_include_baseline = args.include_baseline;
 
         // Try to recognize the language of the source file.  The file might be a source code file,
         // an AST file, or a configuration file.
         let recognizer = Recognizer::new(&mut preferences);
         let recognize_result = recognizer.recognize_file(&actual_file_name)?;
 
+        // The default set of mutation algorithms depends on the language of the input file.
+        if args.default_mutations && !args.all_mutations {
+            actual_mutations = default_mutation_types(recognize_result.language);
+            actual_mutation_counts.clear();
+        }
+
         if recognize_result.file_type == FileType::Config {
             // If we have a config file, then we need to extract the mutation parameters from
             // the configuration file.
@@ -153,8 +528,13 @@ pub fn generate_mutants(args: MutateCLArgs) -> Result<(), MutagenyxError> {
                 if let Some(mut language_preferences) =
                     actual_preferences.get_preferences_for_key(&language_key)
                 {
-                    language_preferences
-                        .set_preferences_for_key(COMPILER_KEY, compiler_details.clone());
+                    // The configuration file compiler details replace the compiler settings from
+                    // the command line, but keep the request to treat warnings as errors.
+                    let mut compiler_details = compiler_details.clone();
+                    if args.compiler_warnings_as_errors {
+                        compiler_details.set_bool_for_key(WARNINGS_AS_ERRORS_KEY, true);
+                    }
+                    language_preferences.set_preferences_for_key(COMPILER_KEY, compiler_details);
                     actual_preferences.set_preferences_for_key(&language_key, language_preferences);
                 }
             }
@@ -170,8 +550,10 @@ pub fn generate_mutants(args: MutateCLArgs) -> Result<(), MutagenyxError> {
             // Check to see if the configuration file requested a different set of mutation algorithms.
             if configuration_details.all_mutations {
                 actual_mutations = get_all_mutation_algorithms();
+                actual_mutation_counts.clear();
             } else if !configuration_details.mutations.is_empty() {
                 actual_mutations = configuration_details.mutations.clone();
+                actual_mutation_counts.clear();
             }
 
             if !configuration_details.functions.is_empty() {
@@ -180,6 +562,14 @@ pub fn generate_mutants(args: MutateCLArgs) -> Result<(), MutagenyxError> {
 
             actual_verify = configuration_details.verify_mutants;
 
+            if configuration_details.validate_command.is_some() {
+                actual_validate_command = configuration_details.validate_command;
+            }
+
+            if configuration_details.validate_timeout.is_some() {
+                actual_validate_timeout = configuration_details.validate_timeout;
+            }
+
             if let Some(output_directory) = configuration_details.output_directory {
                 if output_directory.to_str() == Some("stdout") {
                     actual_use_stdout = true;
@@ -189,6 +579,7 @@ pub fn generate_mutants(args: MutateCLArgs) -> Result<(), MutagenyxError> {
             }
 
             actual_print_original = configuration_details.print_original;
+            actual_include_baseline = configuration_details.include_baseline;
 
             // The configuration files can have multiple files to mutate using the same settings
             // for each file. Go through the filenames list and add a generator parameter object
@@ -196,19 +587,41 @@ pub fn generate_mutants(args: MutateCLArgs) -> Result<(), MutagenyxError> {
             for path_buf in configuration_details.filenames {
                 let file_to_mutate_name = String::from(path_buf.to_str().unwrap());
 
+                if let Some(size) = file_size_over_limit(&file_to_mutate_name, args.max_file_size) {
+                    println!(
+                        "Skipping {} ({} bytes exceeds the maximum file size)",
+                        file_to_mutate_name, size
+                    );
+                    skipped_files.push(file_to_mutate_name);
+                    continue;
+                }
+
                 let generator_params = GeneratorParameters {
                     file_name: file_to_mutate_name,
                     number_of_mutants: actual_number_of_mutants,
                     rng_seed: seed,
-                    rng: Pcg64::seed_from_u64(seed),
+                    rng: rng_from_seed(seed),
+                    replay_seeds: Vec::new(),
                     output_directory: actual_output_directory.clone(),
+                    output_subdirectory: None,
                     use_stdout: actual_use_stdout,
+                    emit_ast: args.emit_ast,
                     mutations: actual_mutations.clone(),
+                    mutation_counts: actual_mutation_counts.clone(),
                     verify_mutant_viability: actual_verify,
+                    validate_command: actual_validate_command.clone(),
+                    validate_timeout: actual_validate_timeout,
                     print_original: actual_print_original,
+                    include_baseline: actual_include_baseline,
+                    append: args.append,
+                    preview: false,
+                    include_original_in_diff_header: args.include_original_in_diff_header,
+                    no_pragma_mutation: !args.allow_pragma_mutation,
                     save_configuration_file: args.save_config_files,
                     preferences: actual_preferences.clone(),
                     functions: actual_functions.clone(),
+                    forbidden_node_types: forbidden_node_types.clone(),
+                    lines: Vec::new(),
                 };
 
                 generator_parameters.push(generator_params);
@@ -219,47 +632,193 @@ pub fn generate_mutants(args: MutateCLArgs) -> Result<(), MutagenyxError> {
                 number_of_mutants: actual_number_of_mutants,
                 rng_seed: seed,
                 rng: pcg.clone(),
+                replay_seeds: Vec::new(),
                 output_directory: actual_output_directory,
+                output_subdirectory: None,
                 use_stdout: actual_use_stdout,
+                emit_ast: args.emit_ast,
                 mutations: actual_mutations,
+                mutation_counts: actual_mutation_counts,
                 verify_mutant_viability: actual_verify,
+                validate_command: actual_validate_command,
+                validate_timeout: actual_validate_timeout,
                 print_original: actual_print_original,
+                include_baseline: actual_include_baseline,
+                append: args.append,
+                preview: false,
+                include_original_in_diff_header: args.include_original_in_diff_header,
+                no_pragma_mutation: !args.allow_pragma_mutation,
                 save_configuration_file: args.save_config_files,
                 preferences: actual_preferences,
                 functions: actual_functions,
+                forbidden_node_types: forbidden_node_types.clone(),
+                lines: Vec::new(),
             };
 
             generator_parameters.push(generator_params);
         }
     }
 
+    // Keep the output files of each input file apart unless the user asks for the flat layout.
+    // An archive keeps the flat layout.
+    if !args.flat_output && args.tar.is_none() {
+        assign_output_subdirectories(&mut generator_parameters);
+    }
+
+    // The seeds in the seed file replace the number of mutants and the seed of each input.
+    if !replay_seeds.is_empty() {
+        for params in &mut generator_parameters {
+            params.number_of_mutants = replay_seeds.len();
+            params.mutation_counts.clear();
+            params.replay_seeds = replay_seeds.clone();
+        }
+    }
+
+    // Restrict the mutations of each input file to the requested lines of the file.
+    if !line_ranges.is_empty() {
+        for params in &mut generator_parameters {
+            params.lines = line_ranges_for_file(&line_ranges, &params.file_name);
+        }
+        let file_names: Vec<&str> = generator_parameters
+            .iter()
+            .map(|p| p.file_name.as_str())
+            .collect();
+        for file in unmatched_line_range_files(&line_ranges, &file_names) {
+            println!(
+                "Warning: --lines file {} does not match any input file",
+                file.display()
+            );
+        }
+    }
+
+    // A preview only generates the first mutant of the first input and does not write any
+    // output files.
+    if args.preview {
+        generator_parameters.truncate(1);
+        for params in &mut generator_parameters {
+            params.number_of_mutants = 1;
+            params.replay_seeds.truncate(1);
+            params.print_original = false;
+            params.include_baseline = false;
+            params.save_configuration_file = false;
+            params.preview = true;
+        }
+    }
+
+    // Print the resolved parameters instead of generating mutants.
+    if args.dump_config {
+        let parameters: Vec<serde_json::Value> =
+            generator_parameters.iter().map(|p| p.to_json()).collect();
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&parameters).unwrap_or_default()
+        );
+        return Ok(Vec::new());
+    }
+
+    // Print the mutable nodes instead of generating mutants.
+    if args.list_mutable_nodes {
+        let mut files: Vec<Value> = Vec::new();
+        for params in &mut generator_parameters {
+            files.push(list_mutable_nodes(params)?);
+        }
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&files).unwrap_or_default()
+        );
+        return Ok(Vec::new());
+    }
+
+    // The input files skipped because the compiler reported warnings while generating the AST.
+    let mut files_with_warnings: Vec<String> = Vec::new();
+
+    let mut archive = match &args.tar {
+        Some(path) => Some(MutantArchive::create(path, tar_compression)?),
+        None => None,
+    };
+
+    // The manifest of each output directory, or of the archive.
+    let mut manifests: BTreeMap<PathBuf, RunManifest> = BTreeMap::new();
+
+    // The records of the files written by the run across all of the manifests.
+    let mut written_files: Vec<MutantRecord> = Vec::new();
+
     // Now, for each set of parameters, invoke the mutator.
     for params in &mut generator_parameters {
-        generate_mutations(params)?;
+        let manifest_directory = if archive.is_some() {
+            PathBuf::new()
+        } else {
+            params.output_directory.clone()
+        };
+        let manifest = manifests.entry(manifest_directory).or_default();
+        let first_record = manifest.mutants().len();
+        match generate_mutations(params, archive.as_mut(), manifest) {
+            Ok(_) => {
+                manifest.add_input(&params.file_name, params.rng_seed);
+                written_files.extend_from_slice(&manifest.mutants()[first_record..]);
+            }
+            Err(MutagenyxError::CompilerWarnings(file_name, warnings)) => {
+                println!(
+                    "Skipping {} (the compiler reported warnings: {})",
+                    file_name, warnings
+                );
+                files_with_warnings.push(file_name);
+            }
+            Err(e) => return Err(e),
+        }
     }
 
-    Ok(())
-}
+    if !files_with_warnings.is_empty() {
+        println!(
+            "Skipped {} file(s) with compiler warnings: {:?}",
+            files_with_warnings.len(),
+            files_with_warnings
+        );
+    }
 
-/// An upper bound on the number times to try to generate a particular mutant for an input file.
-static ATTEMPTS_TO_GENERATE_A_MUTANT: usize = 50;
+    if !skipped_files.is_empty() {
+        println!(
+            "Skipped {} file(s) larger than {} bytes: {:?}",
+            skipped_files.len(),
+            args.max_file_size.unwrap_or_default(),
+            skipped_files
+        );
+    }
 
-/// Generate mutations according the parameters.
+    if let Some(archive) = archive {
+        let archive_path = archive.path().to_path_buf();
+        archive.finish(&manifests.remove(&PathBuf::new()).unwrap_or_default())?;
+        println!("Wrote archive {}", archive_path.to_str().unwrap());
+    } else {
+        // Output written to stdout does not get a manifest.
+        for (directory, manifest) in manifests
+            .iter()
+            .filter(|(_, m)| !m.mutants().is_empty() || !m.rejected_mutants().is_empty())
+        {
+            manifest.write_to_directory(directory, args.append)?;
+        }
+    }
+
+    Ok(written_files)
+}
+
+/// Return a JSON object with the name of the input file in `params` and the list of the nodes in
+/// the file that the requested mutation algorithms can mutate.  Each node entry has the id of the
+/// node, the line of the node in the source file (null if the line is not known), and the
+/// mutation algorithms that can mutate the node.
 ///
 /// # Arguments
 ///
-/// * `params` - The parameters that control the mutation generation algorithm.
-fn generate_mutations(params: &mut GeneratorParameters) -> Result<(), MutagenyxError> {
-    // Try to recognize the language of the source file.  The file might be a source code file
-    // or perhaps an AST file.
+/// * `params` - The parameters that select the input file, the mutation algorithms, and the
+///   functions to mutate.
+fn list_mutable_nodes(params: &mut GeneratorParameters) -> Result<Value, MutagenyxError> {
     let recognizer = Recognizer::new(&mut params.preferences);
     let recognize_result = recognizer.recognize_file(&params.file_name)?;
 
     let mut language_object =
         LanguageInterface::get_language_object_for_language(&recognize_result.language)?;
 
-    // create the mutation permissions
-    let function_mutation_permissions = convert_function_names_to_permissions(&params.functions);
+    let function_mutation_permissions = mutation_permissions(params);
 
     let ast = language_object.load_ast_from_file(
         &params.file_name,
@@ -268,115 +827,381 @@ fn generate_mutations(params: &mut GeneratorParameters) -> Result<(), MutagenyxE
     )?;
 
     language_object.select_mutators_for_mutation_types(&params.mutations)?;
+    language_object.exclude_pragma_nodes(params.no_pragma_mutation);
+    restrict_mutations_to_lines(
+        params,
+        language_object.as_mut(),
+        &recognize_result.file_type,
+    )?;
 
-    let mutable_nodes_table = language_object.count_mutable_nodes(
+    // Only a source file input has source text that matches the node locations.
+    let source = if recognize_result.file_type == FileType::Source {
+        Some(std::fs::read_to_string(&params.file_name)?)
+    } else {
+        None
+    };
+
+    let mutable_nodes = language_object.list_mutable_nodes(
         &ast,
         &mut params.rng,
         &function_mutation_permissions,
+        source.as_deref(),
     )?;
 
-    // Calculate the paths to each node in the AST by node id.  The mutation step may make
-    // use of this information to insert comments in the AST.
-    let node_path_map =
-        language_object.calculate_node_paths(&ast, &function_mutation_permissions)?;
+    let nodes: Vec<Value> = mutable_nodes
+        .iter()
+        .map(|n| {
+            json!({
+                "node_id": n.node_id,
+                "line": n.line,
+                "mutation_types": get_mutation_strings_from_types(&n.mutation_types),
+            })
+        })
+        .collect();
 
-    if mutable_nodes_table.is_empty() {
-        return Err(MutagenyxError::NoMutableNode);
-    }
+    Ok(json!({"file": params.file_name, "nodes": nodes}))
+}
 
-    // Only pretty-print the original file after verifying that we can load the AST, and that
-    // we have valid mutators for the AST.
-    if params.print_original {
-        if params.use_stdout {
-            let mut stdout = std::io::stdout();
-            pretty_print_ast_to_stream(&ast, &mut stdout)?;
-        } else {
-            let original_file = PathBuf::from_str(&params.file_name).unwrap();
-            pretty_print_ast(&ast, &params.file_name, &params.output_directory)?;
-            println!(
-                "Pretty-printing original file {:?} to {}",
-                original_file.file_name().unwrap(),
-                &params.output_directory.to_str().unwrap()
-            );
-        }
+/// Write `ast` to stdout, to `archive`, or to the output directory as the mutant numbered
+/// `index`. Return the path of the written file (inside the archive path for an archive) or None
+/// if the function wrote the mutant to stdout.  The function records each written file in
+/// `manifest`.
+///
+/// # Arguments
+///
+/// * `params` - The parameters that control the mutation generation algorithm.
+/// * `ast` - The (possibly mutated) AST to write.
+/// * `index` - The index of the mutant, used to build the name of the output file.
+/// * `mutator_result` - The details of the mutation, None for the unmutated baseline.
+/// * `archive` - The archive that receives the output files, if any.
+/// * `manifest` - The manifest of the run.
+fn write_mutant(
+    params: &GeneratorParameters,
+    ast: &SuperAST,
+    index: usize,
+    mutator_result: Option<&MutatorResult>,
+    archive: Option<&mut MutantArchive>,
+    manifest: &mut RunManifest,
+) -> Result<Option<PathBuf>, MutagenyxError> {
+    if params.use_stdout {
+        let mut stdout = std::io::stdout();
+        pretty_print_ast_to_stream(ast, &mut stdout, params.emit_ast)?;
+        return Ok(None);
     }
 
-    // Now, see if we need to create a configuration file with the details used to mutate
-    // params.file_name.
-    if params.save_configuration_file {
-        let compiler_details = if let Some(details) = params
-            .preferences
-            .get_preferences_for_key(COMPILER_DETAILS_KEY)
-        {
-            Some(details)
-        } else {
-            let language_key = format!("{}", language_object.implements());
-            if let Some(language_preferences) =
-                params.preferences.get_preferences_for_key(&language_key)
-            {
-                language_preferences.get_preferences_for_key(COMPILER_KEY)
-            } else {
-                Some(language_object.default_compiler_settings())
-            }
-        };
+    // Calculate the name of the output file.
+    let input_file_path = PathBuf::from(&params.file_name);
+    let base_file_name = input_file_path.file_name().unwrap();
+    let mutant_name = String::from(base_file_name.to_str().unwrap()) + "_" + &index.to_string();
+
+    let record = |file: &str| MutantRecord {
+        file: String::from(file),
+        source: params.file_name.clone(),
+        index,
+        mutation_type: mutator_result.and_then(|r| r.mutation_type),
+        node_id: mutator_result.and_then(|r| r.mutated_node_id),
+    };
 
-        let filenames: Vec<PathBuf> = vec![PathBuf::from(params.file_name.clone())];
+    if let Some(archive) = archive {
+        let entry_name = pretty_print_ast_to_archive(ast, &mutant_name, archive, params.emit_ast)?;
+        manifest.add_mutant(record(&entry_name));
+        return Ok(Some(archive.path().join(entry_name)));
+    }
 
-        let details = ConfigurationFileDetails {
-            language: Some(recognize_result.language),
-            filenames,
-            number_of_mutants: params.number_of_mutants as i64,
-            seed: Some(params.rng_seed),
-            mutations: params.mutations.clone(),
-            all_mutations: false,
-            compiler_details,
-            functions: params.functions.clone(),
-            verify_mutants: params.verify_mutant_viability,
-            output_directory: Some(params.output_directory.clone()),
-            print_original: params.print_original,
-        };
+    let mutant_directory = params.mutant_directory();
+    let outfile_name = mutant_directory.join(mutant_name);
 
-        // Build the output file name.
-        let input_file_name = PathBuf::from(&params.file_name);
-        let mut base_file_name =
-            String::from(input_file_name.file_name().unwrap().to_str().unwrap());
-        let base_out_file_name: String = if let Some(index) = base_file_name.rfind('.') {
-            base_file_name.drain(..index).collect()
-        } else {
-            base_file_name
-        };
-        let file_extension = String::from(".") + CONFIG_FILE_EXTENSION;
-        let out_file_name = base_out_file_name + file_extension.as_str();
-        let out_file_path = params.output_directory.join(out_file_name);
+    let outfile = String::from(outfile_name.to_str().unwrap());
 
-        if params.use_stdout {
-            let mut stdout = std::io::stdout();
-            details.write_to_stream_as_json(&mut stdout)?;
-        } else {
-            println!(
-                "Writing configuration file {}",
-                out_file_path.to_str().unwrap()
-            );
-            details.write_to_file_as_json(out_file_path.to_str().unwrap())?;
-        }
-    }
+    let final_file = pretty_print_ast(ast, &outfile, &mutant_directory, params.emit_ast)?;
 
-    // This list now holds the mutation types for which the AST has nodes to mutate.
-    let mut mutation_type_list: Vec<MutationType> = mutable_nodes_table
+    // The manifest lives in the output directory, so name the file relative to the directory.
+    let manifest_file = final_file
+        .strip_prefix(&params.output_directory)
+        .unwrap_or(&final_file);
+    manifest.add_mutant(record(manifest_file.to_str().unwrap_or_default()));
+    Ok(Some(final_file))
+}
+
+/// Return the index and the path of each mutant of `file_name` in `directory`.  The function
+/// recognizes the mutants from the names that [`write_mutant`] gives them, such as
+/// `Token.sol_3.sol`.
+///
+/// # Arguments
+///
+/// * `directory` - The directory that receives the mutants of the file.
+/// * `file_name` - The name of the input file.
+fn existing_mutants(directory: &Path, file_name: &str) -> Vec<(usize, PathBuf)> {
+    let prefix = match Path::new(file_name).file_name().and_then(|n| n.to_str()) {
+        Some(name) => String::from(name) + "_",
+        None => return Vec::new(),
+    };
+
+    let entries = match std::fs::read_dir(directory) {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+
+    entries
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            let rest = name.strip_prefix(&prefix)?;
+            let digits = rest.split('.').next()?;
+            if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+                return None;
+            }
+            Some((digits.parse::<usize>().ok()?, entry.path()))
+        })
+        .collect()
+}
+
+/// Return the index that follows the highest index of the mutants of `file_name` in `directory`,
+/// or 0 if the directory does not contain any mutants of the file.
+///
+/// # Arguments
+///
+/// * `directory` - The directory that receives the mutants of the file.
+/// * `file_name` - The name of the input file.
+fn next_mutant_index(directory: &Path, file_name: &str) -> usize {
+    existing_mutants(directory, file_name)
         .iter()
-        .filter(|(_, v)| **v > 0)
-        .map(|(k, _)| *k)
-        .collect();
+        .map(|(index, _)| *index)
+        .max()
+        .map_or(0, |index| index + 1)
+}
 
-    // Sort the list so we get more deterministic behavior when selecting mutation algorithms.
-    mutation_type_list.sort();
+/// Restrict the mutations of `language_object` to the lines in `params`, if any.  Only a source
+/// file input has source text that matches the node locations, so for an AST input the
+/// restriction only works for languages that record line numbers in the AST, such as Vyper.
+///
+/// # Arguments
+///
+/// * `params` - The parameters with the input file and the ranges of lines to mutate.
+/// * `language_object` - The language interface that mutates the input file.
+/// * `file_type` - The type of the input file.
+fn restrict_mutations_to_lines(
+    params: &GeneratorParameters,
+    language_object: &mut dyn MutableLanguage,
+    file_type: &FileType,
+) -> Result<(), MutagenyxError> {
+    if params.lines.is_empty() {
+        return Ok(());
+    }
+
+    let source = if *file_type == FileType::Source {
+        Some(std::fs::read_to_string(&params.file_name)?)
+    } else {
+        None
+    };
+    language_object.restrict_mutations_to_lines(&params.lines, source.as_deref());
+    Ok(())
+}
+
+/// Return `path` quoted so that the shell treats the path as a single word.
+///
+/// # Arguments
+///
+/// * `path` - The path to quote.
+fn shell_quote(path: &str) -> String {
+    if cfg!(target_os = "windows") {
+        format!("\"{}\"", path)
+    } else {
+        format!("'{}'", path.replace('\'', "'\\''"))
+    }
+}
+
+/// Return None if `command` succeeds when run on the pretty-printed `mutant`, otherwise return
+/// the record of the mutant with the exit status and the stderr output of the command.
+///
+/// The function writes the mutant to a new file next to the input file, replaces `{file}` in
+/// `command` with the path of the file (or appends the path if `command` does not contain
+/// `{file}`), runs the command with the shell, and removes the file.
+///
+/// # Arguments
+///
+/// * `command` - The validation command.
+/// * `mutant` - The mutated AST.
+/// * `file_name` - The name of the input file.
+/// * `mutator_result` - The details of the mutation.
+fn validate_command_rejection(
+    command: &str,
+    mutant: &SuperAST,
+    file_name: &str,
+    mutator_result: &MutatorResult,
+) -> Result<Option<RejectedMutantRecord>, MutagenyxError> {
+    let mutant_file = pretty_print_ast_next_to_file(mutant, file_name, "validate")?;
+    let quoted_file = shell_quote(mutant_file.to_str().unwrap());
+
+    let full_command = if command.contains("{file}") {
+        command.replace("{file}", &quoted_file)
+    } else {
+        format!("{} {}", command, quoted_file)
+    };
 
-    println!(
-        "Of the requested mutation algorithms, the AST contains nodes for {:?}",
-        get_mutation_strings_from_types(&mutation_type_list)
+    // Keep the stdout of the command on the terminal and capture the stderr for the manifest.
+    let mut shell = if cfg!(target_os = "windows") {
+        let mut shell = Command::new("cmd");
+        shell.arg("/C");
+        shell
+    } else {
+        let mut shell = Command::new("sh");
+        shell.arg("-c");
+        shell
+    };
+    let output = shell
+        .arg(&full_command)
+        .stderr(Stdio::piped())
+        .spawn()
+        .and_then(|child| child.wait_with_output());
+
+    if std::fs::remove_file(&mutant_file).is_err() {
+        log::debug!("Failed to remove temporary mutant file: {:?}", mutant_file);
+    }
+
+    let output = output?;
+    log::debug!(
+        "Validation command '{}' exited with {}",
+        full_command,
+        output.status
     );
+    if output.status.success() {
+        return Ok(None);
+    }
+    Ok(Some(RejectedMutantRecord {
+        source: String::from(file_name),
+        mutation_type: mutator_result.mutation_type,
+        node_id: mutator_result.mutated_node_id,
+        validate_command: Some(String::from(command)),
+        status: output.status.code(),
+        stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        timed_out: false,
+    }))
+}
 
-    // Now we generate a list of mutation types of length self.parameters.number_of_mutants
+/// Return the record of the mutant described by `mutator_result` that did not compile because of
+/// `error`.
+///
+/// # Arguments
+///
+/// * `file_name` - The name of the input file.
+/// * `mutator_result` - The details of the mutation.
+/// * `error` - The error returned by the compilation of the mutant.
+fn rejected_mutant_record(
+    file_name: &str,
+    mutator_result: &MutatorResult,
+    error: MutagenyxError,
+) -> RejectedMutantRecord {
+    let (status, stderr, timed_out) = match error {
+        MutagenyxError::CompilerInvocation { status, stderr, .. } => (status, stderr, false),
+        MutagenyxError::CompilerTimeout { .. } => (None, String::new(), true),
+        e => (None, e.to_string(), false),
+    };
+    RejectedMutantRecord {
+        source: String::from(file_name),
+        mutation_type: mutator_result.mutation_type,
+        node_id: mutator_result.mutated_node_id,
+        validate_command: None,
+        status,
+        stderr,
+        timed_out,
+    }
+}
+
+/// Return the line of the validation summary that describes a mutant that did not compile or
+/// failed the validation command.  The line only contains the first line of the output of the
+/// compiler or the command; the manifest contains the rest.
+///
+/// # Arguments
+///
+/// * `record` - The description of the rejected mutant.
+fn rejected_mutant_summary(record: &RejectedMutantRecord) -> String {
+    let mutation = record
+        .mutation_type
+        .map_or_else(|| String::from("unknown mutation"), |t| t.to_string());
+    let node = record
+        .node_id
+        .map_or_else(String::new, |id| format!(" of node {}", id));
+    if record.timed_out {
+        return format!("    {}{} (timed out)", mutation, node);
+    }
+    let status = record
+        .status
+        .map_or_else(|| String::from("none"), |s| s.to_string());
+    let first_line = record
+        .stderr
+        .lines()
+        .find(|l| !l.trim().is_empty())
+        .unwrap_or("no output");
+    let program = if record.validate_command.is_some() {
+        "validation command exit status"
+    } else {
+        "exit status"
+    };
+    format!(
+        "    {}{} ({} {}): {}",
+        mutation,
+        node,
+        program,
+        status,
+        first_line.trim()
+    )
+}
+
+/// Print a unified diff of the pretty-printed `original` and `mutant` ASTs to stdout.
+///
+/// # Arguments
+///
+/// * `params` - The parameters that control the mutation generation algorithm.
+/// * `original` - The unmutated AST.
+/// * `mutant` - The mutated AST.
+/// * `mutation_type` - The mutation algorithm that generated `mutant`.
+/// * `source_line` - The line number of the mutated node in the original source file, if known.
+fn print_preview(
+    params: &GeneratorParameters,
+    original: &SuperAST,
+    mutant: &SuperAST,
+    mutation_type: &MutationType,
+    source_line: Option<usize>,
+) -> Result<(), MutagenyxError> {
+    let mut original_contents: Vec<u8> = Vec::new();
+    pretty_print_ast_to_stream(original, &mut original_contents, false)?;
+
+    let mut mutant_contents: Vec<u8> = Vec::new();
+    pretty_print_ast_to_stream(mutant, &mut mutant_contents, false)?;
+
+    let diff = unified_diff_from_line(
+        &String::from_utf8_lossy(&original_contents),
+        &String::from_utf8_lossy(&mutant_contents),
+        &params.file_name,
+        &format!("{} ({})", params.file_name, mutation_type),
+        3,
+        source_line,
+    );
+    print!("{}", diff);
+
+    Ok(())
+}
+
+/// Return the list of mutation algorithms to use for the next mutants.  The function selects
+/// the algorithms with a requested number of mutants that many times and randomly selects the
+/// other algorithms for `number_of_mutants` mutants, without selecting an algorithm more times
+/// than the number of nodes the algorithm can mutate.
+///
+/// # Arguments
+///
+/// * `params` - The parameters that control the mutation generation algorithm.
+/// * `mutation_type_list` - The mutation algorithms with mutable nodes in the AST.
+/// * `mutable_nodes_table` - The number of mutable nodes in the AST for each algorithm.
+/// * `number_of_mutants` - The number of mutants to generate with the randomly selected
+///   algorithms.
+fn select_mutation_kinds(
+    params: &mut GeneratorParameters,
+    mutation_type_list: &[MutationType],
+    mutable_nodes_table: &HashMap<MutationType, usize>,
+    number_of_mutants: usize,
+) -> VecDeque<MutationType> {
+    // Now we generate a list of mutation types of length number_of_mutants
     // with mutation types chosen randomly from list of usable mutation types.
     let mut mutation_kinds_todo: VecDeque<MutationType> = VecDeque::new();
 
@@ -394,31 +1219,56 @@ fn generate_mutations(params: &mut GeneratorParameters) -> Result<(), MutagenyxE
     let mut selected_algorithm_map: HashMap<MutationType, (usize, usize, bool)> = HashMap::new();
 
     // fill the map.
-    for mutation_type in &mutation_type_list {
+    for mutation_type in mutation_type_list {
         let number_of_mutable_nodes = mutable_nodes_table.get(mutation_type).unwrap();
         selected_algorithm_map.insert(*mutation_type, (0, *number_of_mutable_nodes, false));
     }
 
+    // Algorithms with a requested number of mutants get that many mutants, up to the number of
+    // mutable nodes for the algorithm.  The remaining algorithms share number_of_mutants.
+    let mut random_mutation_type_list: Vec<MutationType> = Vec::new();
+    for mutation_type in mutation_type_list {
+        let data_tuple = selected_algorithm_map.get_mut(mutation_type).unwrap();
+        match params.mutation_counts.get(mutation_type) {
+            Some(count) => {
+                let usable_count = (*count).min(data_tuple.1);
+                if usable_count < *count {
+                    println!(
+                        "Reached the limit of mutable nodes in the AST for {}, lowering requested mutants by {} to {}",
+                        mutation_type,
+                        count - usable_count,
+                        usable_count
+                    );
+                }
+                data_tuple.0 = usable_count;
+                mutation_kinds_todo.extend(std::iter::repeat_n(*mutation_type, usable_count));
+            }
+            None => random_mutation_type_list.push(*mutation_type),
+        }
+    }
+
     // Now try to randomly select the mutation algorithms, but only allow the max number of
     // algorithm usages by the number of mutable nodes for each algorithm.
-    let mut requested_mutants_remaining: usize = params.number_of_mutants;
+    let mut requested_mutants_remaining: usize = if random_mutation_type_list.is_empty() {
+        0
+    } else {
+        number_of_mutants
+    };
 
     let mut viable_mutations_selected: usize = 0;
-    let mut available_mutations: usize = 0;
+    let mut available_random_mutations: usize = 0;
 
-    // Run through the selected_algorithm_map and sum the max possible mutations for each algorithm.
-    for data_tuple in selected_algorithm_map.values() {
-        available_mutations += data_tuple.1;
+    // Run through the selected_algorithm_map and sum the max possible mutations for each
+    // randomly selected algorithm.
+    for (mutation_type, data_tuple) in &selected_algorithm_map {
+        if random_mutation_type_list.contains(mutation_type) {
+            available_random_mutations += data_tuple.1;
+        }
     }
 
-    println!(
-        "AST supports at most {} different mutations using the requested mutation algorithms",
-        available_mutations
-    );
-
     while requested_mutants_remaining > 0 {
         // Select a random algorithm
-        let mutation_type = match mutation_type_list.choose(&mut params.rng) {
+        let mutation_type = match random_mutation_type_list.choose(&mut params.rng) {
             Some(t) => t,
             None => continue,
         };
@@ -433,8 +1283,8 @@ fn generate_mutations(params: &mut GeneratorParameters) -> Result<(), MutagenyxE
             mutation_kinds_todo.push_back(*mutation_type);
             viable_mutations_selected += 1;
         } else {
-            if viable_mutations_selected >= available_mutations {
-                println!("Reached the limit of mutable nodes in the AST, lowering requested mutants by {} to {}", requested_mutants_remaining, mutation_kinds_todo.len());
+            if viable_mutations_selected >= available_random_mutations {
+                println!("Reached the limit of mutable nodes in the AST, lowering requested mutants by {} to {}", requested_mutants_remaining, viable_mutations_selected);
                 break;
             }
 
@@ -449,71 +1299,365 @@ fn generate_mutations(params: &mut GeneratorParameters) -> Result<(), MutagenyxE
         requested_mutants_remaining -= 1;
     }
 
-    let mut files_written: usize = 0;
-    let mut attempts: usize = 0;
-    let mut observed_mutator_results: Vec<MutatorResult> = Vec::new();
+    mutation_kinds_todo
+}
+
+/// Generate mutations according the parameters.
+///
+/// Each mutant is written (and flushed) to its output file, or to stdout, as soon as it is
+/// generated.  The function does not accumulate the mutated ASTs, so memory use stays roughly
+/// constant at the size of the original AST, one mutated copy of the AST, and the small
+/// [`MutatorResult`] records used to reject duplicate mutants.
+///
+/// # Arguments
+///
+/// * `params` - The parameters that control the mutation generation algorithm.
+/// * `archive` - The archive that receives the output files instead of the output directory.
+/// * `manifest` - The manifest that records each file written by the function.
+fn generate_mutations(
+    params: &mut GeneratorParameters,
+    mut archive: Option<&mut MutantArchive>,
+    manifest: &mut RunManifest,
+) -> Result<(), MutagenyxError> {
+    // Try to recognize the language of the source file.  The file might be a source code file
+    // or perhaps an AST file.
+    let recognizer = Recognizer::new(&mut params.preferences);
+    let recognize_result = recognizer.recognize_file(&params.file_name)?;
+
+    let mut language_object =
+        LanguageInterface::get_language_object_for_language(&recognize_result.language)?;
+
+    // create the mutation permissions
+    let function_mutation_permissions = mutation_permissions(params);
+
+    let ast = language_object.load_ast_from_file(
+        &params.file_name,
+        &recognize_result.file_type,
+        &params.preferences,
+    )?;
+
+    language_object.select_mutators_for_mutation_types(&params.mutations)?;
+    language_object.select_annotation_style(AnnotationStyle::from_preferences(&params.preferences));
+    language_object.trace_mutation_selection(
+        params
+            .preferences
+            .get_bool_for_key(TRACE_MUTATION_SELECTION_KEY)
+            .unwrap_or(false),
+    );
+    language_object.exclude_pragma_nodes(params.no_pragma_mutation);
+    restrict_mutations_to_lines(
+        params,
+        language_object.as_mut(),
+        &recognize_result.file_type,
+    )?;
+
+    let mutable_nodes_table = language_object.count_mutable_nodes(
+        &ast,
+        &mut params.rng,
+        &function_mutation_permissions,
+    )?;
+
+    // Calculate the paths to each node in the AST by node id.  The mutation step may make
+    // use of this information to insert comments in the AST.
+    let node_path_map =
+        language_object.calculate_node_paths(&ast, &function_mutation_permissions)?;
+
+    if mutable_nodes_table.is_empty() {
+        return Err(MutagenyxError::NoMutableNode);
+    }
 
-    'mutation_loops: while !mutation_kinds_todo.is_empty() {
-        while attempts < ATTEMPTS_TO_GENERATE_A_MUTANT {
-            // Get the next mutation type.
-            let mutation_type = mutation_kinds_todo.get(0).unwrap();
+    // Only pretty-print the original file after verifying that we can load the AST, and that
+    // we have valid mutators for the AST.
+    if params.print_original {
+        if params.use_stdout {
+            let mut stdout = std::io::stdout();
+            pretty_print_ast_to_stream(&ast, &mut stdout, params.emit_ast)?;
+        } else if let Some(archive) = archive.as_deref_mut() {
+            let entry_name =
+                pretty_print_ast_to_archive(&ast, &params.file_name, archive, params.emit_ast)?;
+            println!(
+                "Pretty-printing original file to {}",
+                archive.path().join(entry_name).to_str().unwrap()
+            );
+        } else {
+            let original_file = PathBuf::from_str(&params.file_name).unwrap();
+            let mutant_directory = params.mutant_directory();
+            pretty_print_ast(&ast, &params.file_name, &mutant_directory, params.emit_ast)?;
+            println!(
+                "Pretty-printing original file {:?} to {}",
+                original_file.file_name().unwrap(),
+                mutant_directory.to_str().unwrap()
+            );
+        }
+    }
 
-            // Randomly try to pick the node to mutate.
-            let number_of_mutable_nodes = mutable_nodes_table.get(mutation_type).unwrap();
-            let index = if *number_of_mutable_nodes > 1 {
-                (params.rng.next_u64() % *number_of_mutable_nodes as u64) as usize
+    // Now, see if we need to create a configuration file with the details used to mutate
+    // params.file_name.
+    if params.save_configuration_file {
+        let compiler_details = if let Some(details) = params
+            .preferences
+            .get_preferences_for_key(COMPILER_DETAILS_KEY)
+        {
+            Some(details)
+        } else {
+            let language_key = format!("{}", language_object.implements());
+            if let Some(language_preferences) =
+                params.preferences.get_preferences_for_key(&language_key)
+            {
+                language_preferences.get_preferences_for_key(COMPILER_KEY)
             } else {
-                0
-            };
+                Some(language_object.default_compiler_settings())
+            }
+        };
 
-            // Generate the mutated AST.
-            let mutate_ast_result = language_object.mutate_ast(
-                &ast,
-                mutation_type,
-                index,
-                &mut params.rng,
-                &function_mutation_permissions,
-                &node_path_map,
-            )?;
-
-            // See if we have already seen this ast result before. This step prevents us from
-            // making non-unique mutations.
-            if observed_mutator_results.contains(&mutate_ast_result.mutator_result) {
-                attempts += 1;
-                continue;
+        let filenames: Vec<PathBuf> = vec![PathBuf::from(params.file_name.clone())];
+
+        let details = ConfigurationFileDetails {
+            language: Some(recognize_result.language),
+            filenames,
+            number_of_mutants: params.number_of_mutants as i64,
+            seed: Some(params.rng_seed),
+            mutations: params.mutations.clone(),
+            all_mutations: false,
+            compiler_details,
+            functions: params.functions.clone(),
+            verify_mutants: params.verify_mutant_viability,
+            validate_command: params.validate_command.clone(),
+            validate_timeout: params.validate_timeout,
+            output_directory: Some(params.output_directory.clone()),
+            print_original: params.print_original,
+            include_baseline: params.include_baseline,
+        };
+
+        // Build the output file name.
+        let input_file_name = PathBuf::from(&params.file_name);
+        let mut base_file_name =
+            String::from(input_file_name.file_name().unwrap().to_str().unwrap());
+        let base_out_file_name: String = if let Some(index) = base_file_name.rfind('.') {
+            base_file_name.drain(..index).collect()
+        } else {
+            base_file_name
+        };
+        let file_extension = String::from(".") + CONFIG_FILE_EXTENSION;
+        let out_file_name = base_out_file_name + file_extension.as_str();
+        let out_file_path = params.mutant_directory().join(out_file_name);
+
+        if params.use_stdout {
+            let mut stdout = std::io::stdout();
+            details.write_to_stream_as_json(&mut stdout)?;
+        } else if let Some(archive) = archive.as_deref_mut() {
+            let mut contents: Vec<u8> = Vec::new();
+            details.write_to_stream_as_json(&mut contents)?;
+            let entry_name = out_file_path.file_name().unwrap().to_str().unwrap();
+            println!(
+                "Writing configuration file {}",
+                archive.path().join(entry_name).to_str().unwrap()
+            );
+            archive.append_file(entry_name, &contents)?;
+        } else {
+            println!(
+                "Writing configuration file {}",
+                out_file_path.to_str().unwrap()
+            );
+            std::fs::create_dir_all(params.mutant_directory())?;
+            details.write_to_file_as_json(out_file_path.to_str().unwrap())?;
+        }
+    }
+
+    // This list now holds the mutation types for which the AST has nodes to mutate.
+    let mut mutation_type_list: Vec<MutationType> = mutable_nodes_table
+        .iter()
+        .filter(|(_, v)| **v > 0)
+        .map(|(k, _)| *k)
+        .collect();
+
+    // Sort the list so we get more deterministic behavior when selecting mutation algorithms.
+    mutation_type_list.sort();
+
+    if !params.preview {
+        println!(
+            "Of the requested mutation algorithms, the AST contains nodes for {:?}",
+            get_mutation_strings_from_types(&mutation_type_list)
+        );
+    }
+
+    if !params.preview {
+        let available_mutations: usize = mutation_type_list
+            .iter()
+            .map(|t| mutable_nodes_table.get(t).unwrap())
+            .sum();
+        println!(
+            "AST supports at most {} different mutations using the requested mutation algorithms",
+            available_mutations
+        );
+    }
+
+    // Appending continues the numbering of the mutants that earlier runs left in the directory.
+    let mut files_written: usize = if params.append {
+        next_mutant_index(&params.mutant_directory(), &params.file_name)
+    } else {
+        0
+    };
+
+    // Appending skips the mutants that earlier runs already wrote to the directory.
+    let earlier_mutants: HashSet<Vec<u8>> =
+        if params.append && !params.use_stdout && archive.is_none() {
+            existing_mutants(&params.mutant_directory(), &params.file_name)
+                .into_iter()
+                .filter_map(|(_, path)| std::fs::read(path).ok())
+                .collect()
+        } else {
+            HashSet::new()
+        };
+
+    // The baseline is the unmutated original and takes the first index of the run.
+    if params.include_baseline {
+        if let Some(final_file) = write_mutant(
+            params,
+            &ast,
+            files_written,
+            None,
+            archive.as_deref_mut(),
+            manifest,
+        )? {
+            println!(
+                "Baseline (no mutation) written to {}",
+                final_file.to_str().unwrap()
+            );
+        }
+        files_written += 1;
+    }
+
+    // The compiler cannot generate bytecode for abstract contracts, so only check that the
+    // mutants of an abstract contract compile to an AST.
+    let mut validation_preferences =
+        if params.verify_mutant_viability && has_abstract_contract(&ast) {
+            compile_only_preferences(&params.preferences)
+        } else {
+            params.preferences.clone()
+        };
+    if let Some(seconds) = params.validate_timeout {
+        validation_preferences = validation_timeout_preferences(&validation_preferences, seconds);
+    }
+
+    // The number of mutants that passed and failed the validation command.
+    let mut validate_command_passes: usize = 0;
+    let mut validate_command_failures: usize = 0;
+
+    // The manifest records the mutants that did not compile after the mutants of earlier files.
+    let first_rejected_mutant = manifest.rejected_mutants().len();
+
+    // The number of times each mutation algorithm claimed a node but did not produce a mutant.
+    let mut failed_mutations: BTreeMap<MutationType, usize> = BTreeMap::new();
+
+    // Replaying seeds generates one mutant from each seed as if the generator had started with
+    // the seed, otherwise generate all the mutants from the current random number generator.
+    let rounds: Vec<Option<u128>> = if params.replay_seeds.is_empty() {
+        vec![None]
+    } else {
+        params.replay_seeds.iter().map(|s| Some(*s)).collect()
+    };
+
+    for seed in rounds {
+        let number_of_mutants = match seed {
+            Some(seed) => {
+                params.rng = rng_from_seed(seed);
+                1
             }
+            None => params.number_of_mutants,
+        };
 
-            let mutated_ast = mutate_ast_result.ast.unwrap();
+        let mutation_kinds_todo = select_mutation_kinds(
+            params,
+            &mutation_type_list,
+            &mutable_nodes_table,
+            number_of_mutants,
+        );
+        let mut selector = MutantSelector::new(mutation_kinds_todo);
+
+        while let Some(candidate) = selector.next_mutant(
+            language_object.as_mut(),
+            &ast,
+            &mutable_nodes_table,
+            &mut params.rng,
+            &function_mutation_permissions,
+            &node_path_map,
+        )? {
+            let mutation_type = &candidate.mutation_type;
+            let mutated_ast = &candidate.ast;
+
+            if !earlier_mutants.is_empty() {
+                let mut contents: Vec<u8> = Vec::new();
+                pretty_print_ast_to_stream(mutated_ast, &mut contents, params.emit_ast)?;
+                if earlier_mutants.contains(&contents) {
+                    selector.reject(candidate.mutator_result);
+                    continue;
+                }
+            }
 
-            if params.verify_mutant_viability
-                && !language_object.mutant_compiles(
+            if params.verify_mutant_viability {
+                if let Err(e) = language_object.check_mutant_compiles(
                     &params.file_name,
-                    &mutated_ast,
-                    &params.preferences,
-                )
-            {
-                attempts += 1;
-                continue;
+                    mutated_ast,
+                    &validation_preferences,
+                ) {
+                    log::debug!(
+                        "{} mutant of {} failed validation: {}",
+                        mutation_type,
+                        params.file_name,
+                        e
+                    );
+                    manifest.add_rejected_mutant(rejected_mutant_record(
+                        &params.file_name,
+                        &candidate.mutator_result,
+                        e,
+                    ));
+                    selector.reject(candidate.mutator_result);
+                    continue;
+                }
             }
 
-            if params.use_stdout {
-                let mut stdout = std::io::stdout();
-                pretty_print_ast_to_stream(&mutated_ast, &mut stdout)?;
-            } else {
-                // Calculate the name of the output file.
-                let input_file_path = PathBuf::from(&params.file_name);
-                let base_file_name = input_file_path.file_name().unwrap();
-                let outfile_name = params.output_directory.join(
-                    String::from(base_file_name.to_str().unwrap())
-                        + "_"
-                        + &files_written.to_string(),
-                );
-
-                let outfile = String::from(outfile_name.to_str().unwrap());
+            if let Some(command) = &params.validate_command {
+                if let Some(record) = validate_command_rejection(
+                    command,
+                    mutated_ast,
+                    &params.file_name,
+                    &candidate.mutator_result,
+                )? {
+                    manifest.add_rejected_mutant(record);
+                    validate_command_failures += 1;
+                    selector.reject(candidate.mutator_result);
+                    continue;
+                }
+                validate_command_passes += 1;
+            }
 
-                let final_file =
-                    pretty_print_ast(&mutated_ast, &outfile, &params.output_directory)?;
+            if params.preview {
+                // Only a source file input has source text that matches the node locations.
+                let source_line = if params.include_original_in_diff_header
+                    && recognize_result.file_type == FileType::Source
+                {
+                    candidate.mutator_result.mutated_node_id.and_then(|id| {
+                        let source = std::fs::read_to_string(&params.file_name).ok()?;
+                        language_object
+                            .source_line_for_node(&ast, id, &source)
+                            .ok()
+                            .flatten()
+                    })
+                } else {
+                    None
+                };
+                return print_preview(params, &ast, mutated_ast, mutation_type, source_line);
+            }
 
+            if let Some(final_file) = write_mutant(
+                params,
+                mutated_ast,
+                files_written,
+                Some(&candidate.mutator_result),
+                archive.as_deref_mut(),
+                manifest,
+            )? {
                 println!(
                     "{} used to create mutant written to {}",
                     mutation_type,
@@ -521,23 +1665,1009 @@ fn generate_mutations(params: &mut GeneratorParameters) -> Result<(), MutagenyxE
                 );
             }
 
-            // Remove the item from the top of the VecDeque.
-            mutation_kinds_todo.remove(0);
+            files_written += 1;
+            selector.accept(candidate.mutator_result);
+        }
 
-            // Add the mutator result to the list of results that we have observed.
-            observed_mutator_results.push(mutate_ast_result.mutator_result);
+        for (mutation_type, failures) in &selector.failed_mutations {
+            *failed_mutations.entry(*mutation_type).or_insert(0) += failures;
+        }
+    }
 
-            files_written += 1;
+    if params.validate_command.is_some() {
+        println!(
+            "{} mutant(s) passed and {} mutant(s) failed the validation command",
+            validate_command_passes, validate_command_failures
+        );
+    }
 
-            // Reset attempts for the next mutation type.
-            attempts = 0;
-            continue 'mutation_loops;
+    let rejected_mutants = &manifest.rejected_mutants()[first_rejected_mutant..];
+    if !rejected_mutants.is_empty() {
+        println!(
+            "{} mutant(s) of {} failed to compile, timed out or failed the validation command:",
+            rejected_mutants.len(),
+            params.file_name
+        );
+        for record in rejected_mutants {
+            println!("{}", rejected_mutant_summary(record));
         }
+    }
 
-        // We reached the limits of attempts on the last mutation, move on to the next.
-        attempts = 0;
-        mutation_kinds_todo.remove(0);
+    for (mutation_type, failures) in &failed_mutations {
+        println!(
+            "{} failed to mutate a node it reported as mutable in {} attempt(s)",
+            mutation_type, failures
+        );
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manifest::MANIFEST_FILE_NAME;
+    #[cfg(unix)]
+    use crate::test_fixtures::{compiler_language_preferences, install_stub_compiler};
+    use mutagenyx_lib::mutation::GenericMutation;
+    use rand::{RngCore, SeedableRng};
+    use rand_pcg::Pcg64;
+    use std::env;
+    use std::ops::RangeInclusive;
+
+    /// Return the AST of a contract with a function that returns `(a + b) * (c - d)`.
+    fn solidity_ast() -> Value {
+        let identifier =
+            |id: u64, name: &str| json!({"nodeType": "Identifier", "id": id, "name": name});
+        let operation = |id: u64, operator: &str, left: Value, right: Value| {
+            json!({
+                "nodeType": "BinaryOperation", "id": id, "operator": operator,
+                "leftExpression": left, "rightExpression": right,
+                "typeDescriptions": {"typeIdentifier": "t_uint256", "typeString": "uint256"}
+            })
+        };
+        let expression = operation(
+            30,
+            "*",
+            operation(31, "+", identifier(32, "a"), identifier(33, "b")),
+            operation(34, "-", identifier(35, "c"), identifier(36, "d")),
+        );
+        json!({
+            "nodeType": "SourceUnit", "id": 100, "absolutePath": "sample.sol", "nodes": [{
+                "nodeType": "ContractDefinition", "id": 50, "name": "C",
+                "contractKind": "contract", "abstract": false, "baseContracts": [], "nodes": [{
+                    "nodeType": "FunctionDefinition", "id": 40, "name": "f",
+                    "kind": "function", "visibility": "public", "stateMutability": "pure",
+                    "virtual": false, "implemented": true, "modifiers": [],
+                    "parameters": {"nodeType": "ParameterList", "id": 10, "parameters": []},
+                    "returnParameters": {"nodeType": "ParameterList", "id": 11, "parameters": []},
+                    "body": {"nodeType": "Block", "id": 20, "statements": [
+                        {"nodeType": "Return", "id": 21, "expression": expression}
+                    ]}
+                }]
+            }]
+        })
+    }
+
+    /// Return the parameters to generate `number_of_mutants` mutants of `file_name` in
+    /// `output_directory`.
+    fn parameters(
+        file_name: &str,
+        output_directory: PathBuf,
+        number_of_mutants: usize,
+        seed: u128,
+        replay_seeds: Vec<u128>,
+    ) -> GeneratorParameters {
+        GeneratorParameters {
+            file_name: String::from(file_name),
+            number_of_mutants,
+            rng_seed: seed,
+            rng: rng_from_seed(seed),
+            replay_seeds,
+            output_directory,
+            output_subdirectory: None,
+            use_stdout: false,
+            emit_ast: false,
+            mutations: vec![
+                MutationType::Generic(GenericMutation::ArithmeticBinaryOp),
+                MutationType::Generic(GenericMutation::SwapOperatorArguments),
+            ],
+            mutation_counts: BTreeMap::new(),
+            verify_mutant_viability: false,
+            validate_command: None,
+            validate_timeout: None,
+            print_original: false,
+            include_baseline: false,
+            append: false,
+            preview: false,
+            include_original_in_diff_header: false,
+            no_pragma_mutation: true,
+            save_configuration_file: false,
+            preferences: Preferences::new(),
+            functions: vec![],
+            forbidden_node_types: vec![],
+            lines: vec![],
+        }
+    }
+
+    #[test]
+    fn test_emit_ast_mutants() {
+        let directory = env::temp_dir().join("mutagenyx_emit_ast");
+        let _ = std::fs::remove_dir_all(&directory);
+        std::fs::create_dir_all(&directory).unwrap();
+        let file_name = directory.join("sample.json");
+        let file_name = file_name.to_str().unwrap();
+        std::fs::write(file_name, solidity_ast().to_string()).unwrap();
+
+        let output_directory = directory.join("mutants");
+        let mut params = parameters(file_name, output_directory.clone(), 2, 3, vec![]);
+        params.emit_ast = true;
+        generate_mutations(&mut params, None, &mut RunManifest::default()).unwrap();
+
+        // Each mutant is an AST without the comment nodes of the pretty-printed mutants.
+        for index in 0..2 {
+            let file = output_directory.join(format!("sample.json_{}.json", index));
+            let text = std::fs::read_to_string(file).unwrap();
+            let mutant: Value = serde_json::from_str(&text).unwrap();
+            assert_eq!(mutant["nodeType"], json!("SourceUnit"));
+            assert_ne!(mutant, solidity_ast());
+            assert!(!text.contains("\"Comment\""), "{text}");
+            assert!(!text.contains("9999997"), "{text}");
+        }
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_replay_seeds_reproduces_mutants() {
+        let directory = env::temp_dir().join("mutagenyx_replay_seeds");
+        let _ = std::fs::remove_dir_all(&directory);
+        std::fs::create_dir_all(&directory).unwrap();
+        let file_name = directory.join("sample.json");
+        let file_name = file_name.to_str().unwrap();
+        std::fs::write(file_name, solidity_ast().to_string()).unwrap();
+
+        let seeds: Vec<u128> = vec![7, 1234];
+
+        // Generate the mutant from each seed on its own.
+        let mut expected: Vec<String> = Vec::new();
+        for seed in &seeds {
+            let output_directory = directory.join(format!("seed_{}", seed));
+            let mut params = parameters(file_name, output_directory.clone(), 1, *seed, vec![]);
+            generate_mutations(&mut params, None, &mut RunManifest::default()).unwrap();
+            let mutant = std::fs::read_to_string(output_directory.join("sample.json_0.sol"));
+            expected.push(mutant.unwrap());
+        }
+
+        // Replay the seeds in one run.
+        let output_directory = directory.join("replay");
+        let mut params = parameters(file_name, output_directory.clone(), 1, 0, seeds.clone());
+        generate_mutations(&mut params, None, &mut RunManifest::default()).unwrap();
+        for (index, mutant) in expected.iter().enumerate() {
+            let file = output_directory.join(format!("sample.json_{}.sol", index));
+            assert_eq!(&std::fs::read_to_string(file).unwrap(), mutant);
+        }
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_validate_abstract_contract_mutants() {
+        let directory = env::temp_dir().join("mutagenyx_validate_abstract");
+        let _ = std::fs::remove_dir_all(&directory);
+        std::fs::create_dir_all(&directory).unwrap();
+
+        // The stub compiler records the arguments of each compilation of a mutant and accepts
+        // the mutant.  The stub rejects AST files so that the generator loads the input files as
+        // ASTs.
+        let log = directory.join("arguments.log");
+        let stub = install_stub_compiler(
+            &directory,
+            "solc",
+            &format!(
+                "for file; do :; done\n\
+                if [ \"$(head -c 1 \"$file\")\" = \"{{\" ]; then exit 1; fi\n\
+                echo \"$*\" >> {}\n\
+                exit 0\n",
+                log.to_str().unwrap()
+            ),
+        );
+        let language_preferences = compiler_language_preferences(&stub);
+
+        let compiler_arguments = |is_abstract: bool| {
+            let _ = std::fs::remove_file(&log);
+            let mut ast = solidity_ast();
+            ast["nodes"][0]["abstract"] = json!(is_abstract);
+            let file_name = directory.join(format!("abstract_{}.json", is_abstract));
+            let file_name = file_name.to_str().unwrap();
+            std::fs::write(file_name, ast.to_string()).unwrap();
+
+            let output_directory = directory.join(format!("mutants_{}", is_abstract));
+            let mut params = parameters(file_name, output_directory.clone(), 2, 0, vec![]);
+            params.verify_mutant_viability = true;
+            params.preferences.set_preferences_for_key(
+                &Language::Solidity.to_string(),
+                language_preferences.clone(),
+            );
+            generate_mutations(&mut params, None, &mut RunManifest::default()).unwrap();
+            assert_eq!(std::fs::read_dir(output_directory).unwrap().count(), 2);
+            let arguments = std::fs::read_to_string(&log).unwrap();
+            arguments.lines().map(String::from).collect::<Vec<String>>()
+        };
+
+        // The mutants of the concrete contract compile with the usual arguments.
+        let arguments = compiler_arguments(false);
+        assert_eq!(arguments.len(), 2);
+        assert!(arguments.iter().all(|a| a.starts_with("--overwrite -o ")));
+
+        // The mutants of the abstract contract only need to compile to an AST.
+        let arguments = compiler_arguments(true);
+        assert_eq!(arguments.len(), 2);
+        assert!(arguments
+            .iter()
+            .all(|a| a.starts_with("--ast-compact-json --overwrite -o ")));
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_validation_reports_compiler_errors() {
+        let directory = env::temp_dir().join("mutagenyx_validation_report");
+        let _ = std::fs::remove_dir_all(&directory);
+        std::fs::create_dir_all(&directory).unwrap();
+
+        // The stub compiler rejects every mutant with a known message.  The stub rejects AST files
+        // so that the generator loads the input file as an AST.
+        let stub = install_stub_compiler(
+            &directory,
+            "solc",
+            "for file; do :; done\n\
+            if [ \"$(head -c 1 \"$file\")\" = \"{\" ]; then exit 1; fi\n\
+            echo \"Error: stub rejects the mutant\" >&2\n\
+            echo \"  --> mutant.sol:1:1\" >&2\n\
+            exit 3\n",
+        );
+        let language_preferences = compiler_language_preferences(&stub);
+
+        let file_name = directory.join("sample.json");
+        let file_name = file_name.to_str().unwrap();
+        std::fs::write(file_name, solidity_ast().to_string()).unwrap();
+
+        let output_directory = directory.join("mutants");
+        let mut params = parameters(file_name, output_directory.clone(), 2, 0, vec![]);
+        params.verify_mutant_viability = true;
+        params
+            .preferences
+            .set_preferences_for_key(&Language::Solidity.to_string(), language_preferences);
+        let mut manifest = RunManifest::default();
+        generate_mutations(&mut params, None, &mut manifest).unwrap();
+
+        // Each mutant that failed carries the output of the compiler.
+        assert!(manifest.mutants().is_empty());
+        let rejected = manifest.rejected_mutants();
+        assert!(!rejected.is_empty());
+        for record in rejected {
+            assert_eq!(record.source, file_name);
+            assert_eq!(record.status, Some(3));
+            assert!(record.stderr.contains("Error: stub rejects the mutant"));
+            assert!(record.stderr.contains("--> mutant.sol:1:1"));
+            assert!(rejected_mutant_summary(record)
+                .ends_with("(exit status 3): Error: stub rejects the mutant"));
+        }
+
+        let manifest_json = manifest.to_json();
+        let rejected_json = manifest_json["rejected-mutants"].as_array().unwrap();
+        assert_eq!(rejected_json.len(), rejected.len());
+        assert_eq!(rejected_json[0]["exit-status"], json!(3));
+        assert!(rejected_json[0]["stderr"]
+            .as_str()
+            .unwrap()
+            .starts_with("Error: stub rejects the mutant"));
+
+        // A run without validation does not reject mutants.
+        let mut params = parameters(file_name, output_directory, 2, 0, vec![]);
+        let mut manifest = RunManifest::default();
+        generate_mutations(&mut params, None, &mut manifest).unwrap();
+        assert!(manifest.rejected_mutants().is_empty());
+        assert!(manifest.to_json().get("rejected-mutants").is_none());
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_validate_command_failures_reach_the_manifest() {
+        let directory = env::temp_dir().join("mutagenyx_validate_command_report");
+        let _ = std::fs::remove_dir_all(&directory);
+        std::fs::create_dir_all(&directory).unwrap();
+
+        let file_name = directory.join("sample.json");
+        let file_name = file_name.to_str().unwrap();
+        std::fs::write(file_name, solidity_ast().to_string()).unwrap();
+
+        // The command records the directory of each mutant and rejects the mutant.
+        let seen = directory.join("seen");
+        let command = format!(
+            "dirname {{file}} >> '{}'; echo \"Error: command rejects the mutant\" >&2; exit 4",
+            seen.to_str().unwrap()
+        );
+        let mut params = parameters(file_name, directory.join("mutants"), 2, 0, vec![]);
+        params.validate_command = Some(command.clone());
+        let mut manifest = RunManifest::default();
+        generate_mutations(&mut params, None, &mut manifest).unwrap();
+
+        assert!(manifest.mutants().is_empty());
+        let rejected = manifest.rejected_mutants();
+        assert!(!rejected.is_empty());
+        for record in rejected {
+            assert_eq!(record.validate_command, Some(command.clone()));
+            assert_eq!(record.status, Some(4));
+            assert!(!record.timed_out);
+            assert!(rejected_mutant_summary(record).ends_with(
+                "(validation command exit status 4): Error: command rejects the mutant"
+            ));
+        }
+        let manifest_json = manifest.to_json();
+        assert_eq!(
+            manifest_json["rejected-mutants"][0]["validate-command"],
+            json!(command)
+        );
+
+        // The command sees each mutant next to the input file, and the mutants do not remain.
+        let seen = std::fs::read_to_string(&seen).unwrap();
+        assert!(seen.lines().all(|l| Path::new(l) == directory));
+        let leftovers = std::fs::read_dir(&directory)
+            .unwrap()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_name().to_string_lossy().contains(".validate_"))
+            .count();
+        assert_eq!(leftovers, 0);
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_validate_timeout_stops_slow_compiler() {
+        let directory = env::temp_dir().join("mutagenyx_validate_timeout");
+        let _ = std::fs::remove_dir_all(&directory);
+        std::fs::create_dir_all(&directory).unwrap();
+
+        // The stub compiler never finishes a mutant in time.  The stub rejects AST files so that
+        // the generator loads the input file as an AST.
+        let stub = install_stub_compiler(
+            &directory,
+            "solc",
+            "for file; do :; done\n\
+            if [ \"$(head -c 1 \"$file\")\" = \"{\" ]; then exit 1; fi\n\
+            sleep 5\n\
+            exit 0\n",
+        );
+        let language_preferences = compiler_language_preferences(&stub);
+
+        let file_name = directory.join("sample.json");
+        let file_name = file_name.to_str().unwrap();
+        std::fs::write(file_name, solidity_ast().to_string()).unwrap();
+
+        let output_directory = directory.join("mutants");
+        let mut params = parameters(file_name, output_directory, 1, 0, vec![]);
+        params.mutations = vec![MutationType::Generic(
+            GenericMutation::SwapOperatorArguments,
+        )];
+        params.verify_mutant_viability = true;
+        params.validate_timeout = Some(1);
+        params
+            .preferences
+            .set_preferences_for_key(&Language::Solidity.to_string(), language_preferences);
+
+        let start = std::time::Instant::now();
+        let mut manifest = RunManifest::default();
+        generate_mutations(&mut params, None, &mut manifest).unwrap();
+
+        // The generator stops the compiler of each mutant after a second.
+        assert!(start.elapsed() < std::time::Duration::from_secs(5));
+        assert!(manifest.mutants().is_empty());
+        let rejected = manifest.rejected_mutants();
+        assert!(!rejected.is_empty());
+        for record in rejected {
+            assert!(record.timed_out);
+            assert_eq!(record.status, None);
+            assert!(rejected_mutant_summary(record).ends_with("(timed out)"));
+            assert_eq!(record.to_json()["timed-out"], json!(true));
+        }
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_generate_mutants_returns_written_files() {
+        use clap::Parser;
+
+        let directory = env::temp_dir().join("mutagenyx_generate_mutants_records");
+        let _ = std::fs::remove_dir_all(&directory);
+        std::fs::create_dir_all(&directory).unwrap();
+        let file_name = directory.join("sample.json");
+        std::fs::write(&file_name, solidity_ast().to_string()).unwrap();
+        let output_directory = directory.join("out");
+
+        let command = crate::MutagenyxCommand::try_parse_from([
+            "mutagenyx",
+            "mutate",
+            "--file",
+            file_name.to_str().unwrap(),
+            "--mutation",
+            "ArithmeticBinaryOp",
+            "--num-mutants",
+            "3",
+            "--rng-seed",
+            "5",
+            "--include-baseline",
+            "--output-directory",
+            output_directory.to_str().unwrap(),
+        ])
+        .unwrap();
+        let args = match command.command {
+            crate::Commands::Mutate(args) => *args,
+            _ => panic!("expected the mutate command"),
+        };
+        let records = generate_mutants(args).unwrap();
+
+        // The baseline and the three mutants, in the order of the output files.
+        assert_eq!(records.len(), 4);
+        for (index, record) in records.iter().enumerate() {
+            assert_eq!(record.index, index);
+            assert_eq!(record.source, file_name.to_str().unwrap());
+            assert!(output_directory.join(&record.file).is_file());
+        }
+        assert_eq!(records[0].mutation_type, None);
+        for record in &records[1..] {
+            assert_eq!(
+                record.mutation_type,
+                Some(MutationType::Generic(GenericMutation::ArithmeticBinaryOp))
+            );
+            assert!(record.node_id.is_some());
+        }
+
+        // The returned records match the manifest and the files in the output directory.
+        let manifest_text =
+            std::fs::read_to_string(output_directory.join(MANIFEST_FILE_NAME)).unwrap();
+        let manifest: Value = serde_json::from_str(&manifest_text).unwrap();
+        let listed: Vec<Value> = records.iter().map(|r| r.to_json()).collect();
+        assert_eq!(manifest["mutants"], json!(listed));
+        let files_on_disk = std::fs::read_dir(output_directory.join("sample"))
+            .unwrap()
+            .count();
+        assert_eq!(files_on_disk, records.len());
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_parse_rng_seed() {
+        assert_eq!(parse_rng_seed("42").unwrap(), Some(42));
+        assert_eq!(parse_rng_seed("0x2A").unwrap(), Some(42));
+        assert_eq!(parse_rng_seed("-1").unwrap(), None);
+        assert_eq!(
+            parse_rng_seed("0xffffffffffffffffffffffffffffffff").unwrap(),
+            Some(u128::MAX)
+        );
+        assert_eq!(
+            parse_rng_seed("340282366920938463463374607431768211455").unwrap(),
+            Some(u128::MAX)
+        );
+        assert!(matches!(
+            parse_rng_seed("12z"),
+            Err(MutagenyxError::SeedNotValid(_))
+        ));
+
+        // Seeds that fit in 64 bits seed the generator like earlier versions of the tool.
+        let mut rng = rng_from_seed(7);
+        let mut old_rng = Pcg64::seed_from_u64(7);
+        assert_eq!(rng.next_u64(), old_rng.next_u64());
+    }
+
+    #[test]
+    fn test_128_bit_seed_reproduces_mutants() {
+        let directory = env::temp_dir().join("mutagenyx_128_bit_seed");
+        let _ = std::fs::remove_dir_all(&directory);
+        std::fs::create_dir_all(&directory).unwrap();
+        let file_name = directory.join("sample.json");
+        let file_name = file_name.to_str().unwrap();
+        std::fs::write(file_name, solidity_ast().to_string()).unwrap();
+
+        // The decimal and the hexadecimal text of the seed generate the same mutants.
+        let seed_texts = [
+            "0x1234567890abcdef1234567890abcdef",
+            "24197857200151252728969465429440056815",
+        ];
+        let mut mutants: Vec<String> = Vec::new();
+        for (index, text) in seed_texts.iter().enumerate() {
+            let seed = parse_rng_seed(text).unwrap().unwrap();
+            assert!(seed > u64::MAX as u128);
+            let output_directory = directory.join(format!("seed_{}", index));
+            let mut params = parameters(file_name, output_directory.clone(), 2, seed, vec![]);
+            generate_mutations(&mut params, None, &mut RunManifest::default()).unwrap();
+            for mutant_index in 0..2 {
+                let mutant = output_directory.join(format!("sample.json_{}.sol", mutant_index));
+                mutants.push(std::fs::read_to_string(mutant).unwrap());
+            }
+        }
+        assert_eq!(mutants[0..2], mutants[2..4]);
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_manifest_lists_each_mutant_once() {
+        let directory = env::temp_dir().join("mutagenyx_manifest");
+        let _ = std::fs::remove_dir_all(&directory);
+        std::fs::create_dir_all(&directory).unwrap();
+        let file_name = directory.join("sample.json");
+        let file_name = file_name.to_str().unwrap();
+        std::fs::write(file_name, solidity_ast().to_string()).unwrap();
+
+        let output_directory = directory.join("out");
+        let mut params = parameters(file_name, output_directory.clone(), 3, 11, vec![]);
+        params.include_baseline = true;
+        let mut manifest = RunManifest::default();
+        generate_mutations(&mut params, None, &mut manifest).unwrap();
+        manifest.add_input(file_name, 11);
+        manifest
+            .write_to_directory(&output_directory, false)
+            .unwrap();
+
+        let manifest_text =
+            std::fs::read_to_string(output_directory.join(MANIFEST_FILE_NAME)).unwrap();
+        let manifest: Value = serde_json::from_str(&manifest_text).unwrap();
+        assert_eq!(manifest["inputs"], json!([{"file": file_name, "seed": 11}]));
+        assert_eq!(manifest["total-mutants"], json!(4));
+
+        let mut listed: Vec<String> = manifest["mutants"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|m| String::from(m["file"].as_str().unwrap()))
+            .collect();
+        listed.sort();
+        let mut written: Vec<String> = std::fs::read_dir(&output_directory)
+            .unwrap()
+            .map(|e| e.unwrap().file_name().into_string().unwrap())
+            .filter(|name| name != MANIFEST_FILE_NAME)
+            .collect();
+        written.sort();
+        assert_eq!(listed, written);
+
+        let mutants = manifest["mutants"].as_array().unwrap();
+        assert_eq!(mutants[0]["mutation"], Value::Null);
+        for mutant in &mutants[1..] {
+            assert_eq!(mutant["input"], json!(file_name));
+            assert!(mutant["mutation"].is_string());
+            assert!(mutant["node-id"].is_u64());
+        }
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_output_subdirectory_for_each_input() {
+        let directory = env::temp_dir().join("mutagenyx_output_subdirectories");
+        let _ = std::fs::remove_dir_all(&directory);
+        let file_names: Vec<String> = ["token.json", "vault.json", "other/token.json"]
+            .iter()
+            .map(|name| {
+                let path = directory.join(name);
+                std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+                std::fs::write(&path, solidity_ast().to_string()).unwrap();
+                String::from(path.to_str().unwrap())
+            })
+            .collect();
+
+        let output_directory = directory.join("out");
+        let mut all_params: Vec<GeneratorParameters> = file_names
+            .iter()
+            .map(|name| parameters(name, output_directory.clone(), 1, 3, vec![]))
+            .collect();
+        assign_output_subdirectories(&mut all_params);
+
+        let mut manifest = RunManifest::default();
+        for params in &mut all_params {
+            generate_mutations(params, None, &mut manifest).unwrap();
+        }
+
+        // The second input named token gets its own subdirectory.
+        for (subdirectory, mutant) in [
+            ("token", "token.json_0.sol"),
+            ("vault", "vault.json_0.sol"),
+            ("token_2", "token.json_0.sol"),
+        ] {
+            let path = output_directory.join(subdirectory).join(mutant);
+            assert!(path.is_file(), "missing {:?}", path);
+        }
+        assert_eq!(manifest.mutants()[2].file, "token_2/token.json_0.sol");
+
+        // The flat layout writes the mutants directly to the output directory.
+        let flat_directory = directory.join("flat");
+        let mut params = parameters(&file_names[1], flat_directory.clone(), 1, 3, vec![]);
+        generate_mutations(&mut params, None, &mut RunManifest::default()).unwrap();
+        assert!(flat_directory.join("vault.json_0.sol").is_file());
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_list_mutable_nodes() {
+        let directory = env::temp_dir().join("mutagenyx_list_mutable_nodes");
+        let _ = std::fs::remove_dir_all(&directory);
+        std::fs::create_dir_all(&directory).unwrap();
+        let file_name = directory.join("sample.json");
+        let file_name = file_name.to_str().unwrap();
+        std::fs::write(file_name, solidity_ast().to_string()).unwrap();
+
+        let mut params = parameters(file_name, directory.clone(), 1, 1, vec![]);
+        let listing = list_mutable_nodes(&mut params).unwrap();
+        assert_eq!(listing["file"], file_name);
+
+        // The subtraction `c - d` can change its operator or swap its arguments, but swapping
+        // the arguments of the addition `a + b` would not change the program.
+        let nodes = listing["nodes"].as_array().unwrap();
+        let node = |id: u64| nodes.iter().find(|n| n["node_id"] == id).unwrap();
+        assert_eq!(
+            node(34)["mutation_types"],
+            json!(["ArithmeticBinaryOp", "SwapOperatorArguments"])
+        );
+        assert_eq!(node(31)["mutation_types"], json!(["ArithmeticBinaryOp"]));
+
+        // An AST input has no source text to find the line of the node.
+        assert_eq!(node(34)["line"], Value::Null);
+
+        // Identifiers are not mutable.
+        assert!(nodes.iter().all(|n| n["node_id"] != 32));
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_mutants_keep_pragma() {
+        let directory = env::temp_dir().join("mutagenyx_keep_pragma");
+        let _ = std::fs::remove_dir_all(&directory);
+        std::fs::create_dir_all(&directory).unwrap();
+        let file_name = directory.join("sample.json");
+        let file_name = file_name.to_str().unwrap();
+        let mut ast = solidity_ast();
+        ast["nodes"].as_array_mut().unwrap().insert(
+            0,
+            json!({
+                "nodeType": "PragmaDirective", "id": 1,
+                "literals": ["solidity", "^", "0.8", ".0"]
+            }),
+        );
+        std::fs::write(file_name, ast.to_string()).unwrap();
+
+        let mut params = parameters(file_name, directory.clone(), 5, 3, vec![]);
+        params.mutations = get_all_mutation_algorithms();
+        generate_mutations(&mut params, None, &mut RunManifest::default()).unwrap();
+
+        let mut mutants = 0;
+        for entry in std::fs::read_dir(&directory).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().and_then(|e| e.to_str()) == Some("sol") {
+                let text = std::fs::read_to_string(path).unwrap();
+                assert!(text.starts_with("pragma solidity ^0.8.0;\n"), "{text}");
+                mutants += 1;
+            }
+        }
+        assert!(mutants > 0);
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_input_glob_selects_recognized_files() {
+        let directory = env::temp_dir().join("mutagenyx_input_glob");
+        let _ = std::fs::remove_dir_all(&directory);
+        std::fs::create_dir_all(directory.join("nested")).unwrap();
+        let ast = solidity_ast().to_string();
+        std::fs::write(directory.join("token.json"), &ast).unwrap();
+        std::fs::write(directory.join("nested").join("vault.json"), &ast).unwrap();
+        std::fs::write(directory.join("data.json"), "{\"key\": 1}").unwrap();
+        std::fs::write(directory.join("notes.txt"), "not a program").unwrap();
+
+        // Neither the text file nor the JSON file without a syntax tree is a recognized input.
+        let path = |p: &std::path::Path| String::from(p.to_str().unwrap());
+        let token = path(&directory.join("token.json"));
+        let vault = path(&directory.join("nested").join("vault.json"));
+
+        // A directory matches every file beneath the directory.
+        let files = recognized_input_files(&[path(&directory)], None);
+        assert_eq!(files, vec![vault.clone(), token.clone()]);
+
+        // A wildcard only matches names in one directory, ** matches any number of directories.
+        let top = format!("{}/*.json", path(&directory));
+        let files = recognized_input_files(std::slice::from_ref(&top), None);
+        assert_eq!(files, vec![token.clone()]);
+
+        let all = format!("{}/**/*.json", path(&directory));
+        let files = recognized_input_files(&[all, top], None);
+        assert_eq!(files, vec![vault, token]);
+
+        // A pattern that matches nothing is not an error.
+        let none = format!("{}/*.vy", path(&directory));
+        assert!(recognized_input_files(&[none], None).is_empty());
+
+        // A source file is selected by its contents, without a compiler.
+        let source = path(&directory.join("source.sol"));
+        std::fs::write(&source, "pragma solidity ^0.8.0;\ncontract C {}\n").unwrap();
+        let pattern = format!("{}/*.sol", path(&directory));
+        let files = recognized_input_files(std::slice::from_ref(&pattern), None);
+        assert_eq!(files, vec![source.clone()]);
+
+        // A file over the size limit stays in the list for the size check of the generator.
+        let notes = path(&directory.join("notes.txt"));
+        let pattern = format!("{}/*.txt", path(&directory));
+        assert!(recognized_input_files(std::slice::from_ref(&pattern), None).is_empty());
+        let files = recognized_input_files(std::slice::from_ref(&pattern), Some(4));
+        assert_eq!(files, vec![notes]);
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_append_continues_mutant_numbering() {
+        let directory = env::temp_dir().join("mutagenyx_append");
+        let _ = std::fs::remove_dir_all(&directory);
+        std::fs::create_dir_all(&directory).unwrap();
+        let file_name = directory.join("sample.json");
+        let file_name = file_name.to_str().unwrap();
+        std::fs::write(file_name, solidity_ast().to_string()).unwrap();
+
+        let output_directory = directory.join("out");
+        assert_eq!(next_mutant_index(&output_directory, file_name), 0);
+
+        // Two appended runs with seeds that generate different mutants.
+        let mut written: Vec<usize> = Vec::new();
+        for seed in [3, 7] {
+            let mut params = parameters(file_name, output_directory.clone(), 2, seed, vec![]);
+            params.append = true;
+            let mut manifest = RunManifest::default();
+            generate_mutations(&mut params, None, &mut manifest).unwrap();
+            written.extend(manifest.mutants().iter().map(|r| r.index));
+        }
+        assert_eq!(written, vec![0, 1, 2, 3]);
+
+        let mut files: Vec<String> = std::fs::read_dir(&output_directory)
+            .unwrap()
+            .map(|e| e.unwrap().file_name().into_string().unwrap())
+            .collect();
+        files.sort();
+        assert_eq!(
+            files,
+            vec![
+                "sample.json_0.sol",
+                "sample.json_1.sol",
+                "sample.json_2.sol",
+                "sample.json_3.sol"
+            ]
+        );
+        assert_eq!(next_mutant_index(&output_directory, file_name), 4);
+
+        // The mutants of other input files do not count.
+        assert_eq!(next_mutant_index(&output_directory, "other.json"), 0);
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_append_skips_earlier_mutants_and_merges_manifest() {
+        let directory = env::temp_dir().join("mutagenyx_append_manifest");
+        let _ = std::fs::remove_dir_all(&directory);
+        std::fs::create_dir_all(&directory).unwrap();
+        let file_name = directory.join("sample.json");
+        let file_name = file_name.to_str().unwrap();
+        std::fs::write(file_name, solidity_ast().to_string()).unwrap();
+        let output_directory = directory.join("out");
+
+        // The second run with the same seed finds the same mutants, which the first run already
+        // wrote to the directory.
+        let mut written: Vec<Vec<usize>> = Vec::new();
+        for _ in 0..2 {
+            let mut params = parameters(file_name, output_directory.clone(), 2, 3, vec![]);
+            params.append = true;
+            let mut manifest = RunManifest::default();
+            generate_mutations(&mut params, None, &mut manifest).unwrap();
+            manifest.add_input(file_name, 3);
+            manifest
+                .write_to_directory(&output_directory, true)
+                .unwrap();
+            written.push(manifest.mutants().iter().map(|r| r.index).collect());
+        }
+        assert_eq!(written[0], vec![0, 1]);
+        assert!(written[1].iter().all(|index| *index >= 2));
+
+        let mutant_files = existing_mutants(&output_directory, file_name);
+        let mut contents: Vec<String> = mutant_files
+            .iter()
+            .map(|(_, path)| std::fs::read_to_string(path).unwrap())
+            .collect();
+        contents.sort();
+        contents.dedup();
+        assert_eq!(contents.len(), mutant_files.len());
+
+        // The manifest lists the mutants of both runs.
+        let manifest_text =
+            std::fs::read_to_string(output_directory.join(MANIFEST_FILE_NAME)).unwrap();
+        let manifest: Value = serde_json::from_str(&manifest_text).unwrap();
+        assert_eq!(manifest["inputs"], json!([{"file": file_name, "seed": 3}]));
+        assert_eq!(manifest["total-mutants"], json!(mutant_files.len()));
+        let mut listed: Vec<u64> = manifest["mutants"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|m| m["index"].as_u64().unwrap())
+            .collect();
+        listed.sort();
+        let mut indexes: Vec<u64> = mutant_files.iter().map(|(i, _)| *i as u64).collect();
+        indexes.sort();
+        assert_eq!(listed, indexes);
+
+        // A run that does not append replaces the manifest.
+        RunManifest::default()
+            .write_to_directory(&output_directory, false)
+            .unwrap();
+        let manifest_text =
+            std::fs::read_to_string(output_directory.join(MANIFEST_FILE_NAME)).unwrap();
+        let manifest: Value = serde_json::from_str(&manifest_text).unwrap();
+        assert_eq!(manifest["total-mutants"], json!(0));
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_parse_line_ranges() {
+        let requests = vec![String::from("Token.sol:3-7"), String::from("C:/a.sol:9")];
+        let ranges = parse_line_ranges(&requests).unwrap();
+        assert_eq!(
+            ranges,
+            vec![
+                (PathBuf::from("Token.sol"), 3..=7),
+                (PathBuf::from("C:/a.sol"), 9..=9)
+            ]
+        );
+        assert_eq!(line_ranges_for_file(&ranges, "Token.sol"), vec![3..=7]);
+        assert!(line_ranges_for_file(&ranges, "Other.sol").is_empty());
+        assert_eq!(
+            unmatched_line_range_files(&ranges, &["Token.sol", "Other.sol"]),
+            vec![Path::new("C:/a.sol")]
+        );
+        assert!(unmatched_line_range_files(&ranges, &["C:/a.sol", "Token.sol"]).is_empty());
+
+        for request in [
+            "Token.sol",
+            "Token.sol:7-3",
+            "Token.sol:0-2",
+            ":1-2",
+            "Token.sol:a-b",
+        ] {
+            assert!(matches!(
+                parse_line_ranges(&[String::from(request)]),
+                Err(MutagenyxError::LineRangeNotValid(_))
+            ));
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_lines_restrict_mutations() {
+        let directory = env::temp_dir().join("mutagenyx_lines");
+        let _ = std::fs::remove_dir_all(&directory);
+        std::fs::create_dir_all(&directory).unwrap();
+
+        // A contract with two functions, each returning the difference of two numbers.
+        let source = "contract C {\n\
+                      \x20   function f() public pure returns (uint256) {\n\
+                      \x20       return 8 - 1;\n\
+                      \x20   }\n\
+                      \n\
+                      \x20   function g() public pure returns (uint256) {\n\
+                      \x20       return 9 - 2;\n\
+                      \x20   }\n\
+                      }\n";
+        let src = |text: &str| format!("{}:{}:0", source.find(text).unwrap(), text.len());
+        let literal = |id: u64, value: &str, offset: usize| {
+            json!({
+                "nodeType": "Literal", "id": id, "kind": "number", "value": value,
+                "src": format!("{}:1:0", offset)
+            })
+        };
+        let function = |id: u64, name: &str, left: &str, right: &str| {
+            let operation = format!("{} - {}", left, right);
+            let offset = source.find(&operation).unwrap();
+            json!({
+                "nodeType": "FunctionDefinition", "id": id, "name": name,
+                "src": src(&format!("function {}", name)),
+                "kind": "function", "visibility": "public", "stateMutability": "pure",
+                "virtual": false, "implemented": true, "modifiers": [],
+                "parameters": {"nodeType": "ParameterList", "id": id + 1, "parameters": []},
+                "returnParameters": {"nodeType": "ParameterList", "id": id + 2, "parameters": []},
+                "body": {"nodeType": "Block", "id": id + 3, "statements": [{
+                    "nodeType": "Return", "id": id + 4, "src": src(&format!("return {}", operation)),
+                    "expression": {
+                        "nodeType": "BinaryOperation", "id": id + 5, "operator": "-",
+                        "src": src(&operation),
+                        "leftExpression": literal(id + 6, left, offset),
+                        "rightExpression": literal(id + 7, right, offset + 4),
+                        "typeDescriptions": {"typeIdentifier": "t_uint256", "typeString": "uint256"}
+                    }
+                }]}
+            })
+        };
+        let ast = json!({
+            "nodeType": "SourceUnit", "id": 100, "absolutePath": "lines.sol", "src": "0:0:0",
+            "nodes": [{
+                "nodeType": "ContractDefinition", "id": 50, "name": "C", "src": src("contract"),
+                "contractKind": "contract", "abstract": false, "baseContracts": [],
+                "nodes": [function(10, "f", "8", "1"), function(20, "g", "9", "2")]
+            }]
+        });
+
+        // The stub compiler writes the AST of the contract for every source file.
+        let canned = directory.join("canned.json");
+        std::fs::write(&canned, ast.to_string()).unwrap();
+        let stub = install_stub_compiler(
+            &directory,
+            "solc",
+            &format!(
+                "for file; do\n\
+                  if [ \"$previous\" = \"-o\" ]; then out=\"$file\"; fi\n\
+                  previous=\"$file\"\n\
+                done\n\
+                cp {} \"$out/$(basename \"$file\")_json.ast\"\n",
+                canned.to_str().unwrap()
+            ),
+        );
+        let language_preferences = compiler_language_preferences(&stub);
+
+        let file_name = directory.join("lines.sol");
+        let file_name = file_name.to_str().unwrap();
+        std::fs::write(file_name, source).unwrap();
+
+        // Return the ids of the nodes mutated when the mutations are limited to `lines`.
+        let mutated_nodes = |lines: Vec<RangeInclusive<usize>>| {
+            let mut params = parameters(file_name, directory.join("out"), 6, 5, vec![]);
+            params.mutations = get_all_mutation_algorithms();
+            params.preferences.set_preferences_for_key(
+                &Language::Solidity.to_string(),
+                language_preferences.clone(),
+            );
+            params.lines = lines;
+            let mut manifest = RunManifest::default();
+            let result = generate_mutations(&mut params, None, &mut manifest);
+            result.map(|_| {
+                manifest
+                    .mutants()
+                    .iter()
+                    .map(|r| r.node_id.unwrap())
+                    .collect::<Vec<u64>>()
+            })
+        };
+
+        // The line of `return 9 - 2;` in g only has the nodes of g.
+        let nodes = mutated_nodes(vec![7..=7]).unwrap();
+        assert!(!nodes.is_empty());
+        assert!(nodes.iter().all(|id| (20..30).contains(id)), "{nodes:?}");
+
+        // The body of f only has the nodes of f.
+        let nodes = mutated_nodes(vec![2..=4]).unwrap();
+        assert!(!nodes.is_empty());
+        assert!(nodes.iter().all(|id| (10..20).contains(id)), "{nodes:?}");
+
+        // The blank line between the functions does not have any mutable node.
+        assert!(matches!(
+            mutated_nodes(vec![5..=5]),
+            Err(MutagenyxError::NoMutableNode)
+        ));
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
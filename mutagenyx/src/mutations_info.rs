@@ -2,7 +2,12 @@
 //! descriptions of the supported mutations.
 
 use crate::AlgorithmsCLArgs;
-use mutagenyx_lib::mutation::{all_algorithm_descriptions, MutationType};
+use mutagenyx_lib::language::all_languages;
+use mutagenyx_lib::language_interface::LanguageInterface;
+use mutagenyx_lib::mutation::{
+    all_algorithm_descriptions, all_mutation_types, MutationAlgorithmDescription, MutationType,
+};
+use mutagenyx_lib::mutator::MutatorDescription;
 use mutagenyx_lib::pretty_printer::{
     write_flowable_text, write_indent, write_newline, write_space, write_spaces, write_token,
     write_tokens, PrettyPrinter,
@@ -36,6 +41,65 @@ pub fn display_mutations_info(params: AlgorithmsCLArgs) {
     if params.describe {
         display_algorithm_descriptions();
     }
+
+    if params.count {
+        if let Err(e) = write_algorithm_count(&mut stdout(), params.per_language) {
+            println!("Unable to write the algorithm count: {e}");
+        }
+    }
+}
+
+/// Write the number of available mutation algorithms to `stream`.  When `per_language` is true,
+/// follow the total with one line for each language with the number of algorithms that can
+/// mutate programs in the language.
+///
+/// # Arguments
+///
+/// * `stream` - The [`Write`] object that will receive the text.
+/// * `per_language` - True to write the number of algorithms for each language.
+fn write_algorithm_count<W: Write>(stream: &mut W, per_language: bool) -> std::io::Result<()> {
+    writeln!(stream, "{}", all_algorithm_descriptions().len())?;
+
+    if per_language {
+        let languages = all_languages();
+        let name_width = languages
+            .iter()
+            .map(|l| l.to_string().len())
+            .max()
+            .unwrap_or(0);
+        for language in languages {
+            writeln!(
+                stream,
+                "{:width$} {}",
+                language.to_string(),
+                all_mutation_types(language).len(),
+                width = name_width
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Return the description and the example that the mutator of `mutation_type` supplies, or the
+/// summary and the examples of `description` if no language has a mutator for the algorithm.
+///
+/// # Arguments
+///
+/// * `mutation_type` - The mutation algorithm.
+/// * `description` - The library's description of the algorithm.
+fn mutator_description(
+    mutation_type: &MutationType,
+    description: &MutationAlgorithmDescription,
+) -> MutatorDescription {
+    all_languages()
+        .into_iter()
+        .filter(|l| mutation_type.supports_language(*l))
+        .filter_map(|l| LanguageInterface::get_language_object_for_language(&l).ok())
+        .find_map(|o| o.mutator_description(mutation_type))
+        .unwrap_or(MutatorDescription {
+            description: description.summary,
+            example: description.examples,
+        })
 }
 
 /// Wrapper function around termsize::get() that can provide sane default terminal size values
@@ -97,7 +161,7 @@ fn display_simple_algorithm_list() {
 
     while i < key_list.len() {
         let algorithm_name = key_list[i].to_string();
-        let description = &descriptions[key_list[i]];
+        let description = mutator_description(key_list[i], &descriptions[key_list[i]]);
 
         // Write the algorithm name.
         write_token(&mut printer, &mut stream, &algorithm_name);
@@ -112,7 +176,7 @@ fn display_simple_algorithm_list() {
         write_space(&mut printer, &mut stream);
 
         printer.increase_indent_by(arg_name_width + 1);
-        write_flowable_text(&mut printer, &mut stream, description.summary, "");
+        write_flowable_text(&mut printer, &mut stream, description.description, "");
         printer.decrease_indent_by(arg_name_width + 1);
 
         if i < (key_list.len() - 1) {
@@ -169,6 +233,7 @@ fn display_algorithm_descriptions() {
     while i < key_list.len() {
         let algorithm_name = key_list[i].to_string();
         let description = &descriptions[key_list[i]];
+        let mutator_text = mutator_description(key_list[i], description);
 
         // Write the algorithm name.
         write_token(&mut printer, &mut stream, &algorithm_name);
@@ -185,7 +250,7 @@ fn display_algorithm_descriptions() {
             &mut printer,
             &mut stream,
             "",
-            description.summary,
+            mutator_text.description,
             &column_sizes,
             false,
         );
@@ -221,7 +286,7 @@ fn display_algorithm_descriptions() {
             );
         }
 
-        if !description.examples.is_empty() {
+        if !mutator_text.example.is_empty() {
             // Start the next line.
             write_newline(&mut printer, &mut stream);
             write_newline(&mut printer, &mut stream);
@@ -230,7 +295,7 @@ fn display_algorithm_descriptions() {
                 &mut printer,
                 &mut stream,
                 examples_text,
-                description.examples,
+                mutator_text.example,
                 &column_sizes,
                 true,
             );
@@ -291,3 +356,31 @@ fn write_lines<W: Write>(
     write_flowable_text(printer, stream, text, "");
     printer.decrease_indent_by(column_sizes.alg_name_width + 1);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mutagenyx_lib::mutation::get_all_mutation_algorithms;
+
+    #[test]
+    fn test_write_algorithm_count() {
+        let mut output: Vec<u8> = Vec::new();
+        write_algorithm_count(&mut output, false).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(output, format!("{}\n", get_all_mutation_algorithms().len()));
+
+        let mut output: Vec<u8> = Vec::new();
+        write_algorithm_count(&mut output, true).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), all_languages().len() + 1);
+        for (line, language) in lines[1..].iter().zip(all_languages()) {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            assert_eq!(fields[0], language.to_string());
+            assert_eq!(
+                fields[1].parse::<usize>().unwrap(),
+                all_mutation_types(language).len()
+            );
+        }
+    }
+}
@@ -2,25 +2,67 @@
 //! using the tool's pretty-printing format.  Use these services to change the input file into a form
 //! that you can easily compare with the generated mutants using a diff tool.
 
+use crate::archive::MutantArchive;
 use crate::compiler_settings::*;
 use crate::PrettyPrintCLArgs;
 use mutagenyx_lib::error::MutagenyxError;
 use mutagenyx_lib::language_interface::*;
 use mutagenyx_lib::preferences::Preferences;
 use mutagenyx_lib::pretty_printer::PrettyPrinter;
-use mutagenyx_lib::recognizer::Recognizer;
-use mutagenyx_lib::super_ast::language_for_ast;
+use mutagenyx_lib::recognizer::{FileType, Recognizer};
+use mutagenyx_lib::super_ast::NodeDiff;
 use mutagenyx_lib::SuperAST;
 use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
-/// Iterate through the files in the args.file_names vector and pretty-print each file.
+/// The extension of the files that receive an AST as JSON.
+static AST_FILE_EXTENSION: &str = "json";
+
+/// The keys of the AST values that record the name of the source file or the location of a node
+/// in the source file.  The values change whenever the printer lays out the program differently
+/// from the original source, so the round trip check ignores the differences beneath the keys.
+static ROUND_TRIP_IGNORED_KEYS: [&str; 10] = [
+    "src",
+    "nameLocation",
+    "absolutePath",
+    "lineno",
+    "col_offset",
+    "end_lineno",
+    "end_col_offset",
+    "path",
+    "resolved_path",
+    "source_sha256sum",
+];
+
+/// The keys of the AST values that hold node ids or the ids of the nodes that a node refers to.
+/// The compiler numbers the nodes of every file it compiles, so the ids of a program change when
+/// the compiler also compiles the imports of the program.  The round trip check ignores the
+/// differences beneath the keys.
+static ROUND_TRIP_NODE_ID_KEYS: [&str; 13] = [
+    "id",
+    "node_id",
+    "referencedDeclaration",
+    "scope",
+    "overloadedDeclarations",
+    "baseFunctions",
+    "linearizedBaseContracts",
+    "contractDependencies",
+    "usedErrors",
+    "usedEvents",
+    "exportedSymbols",
+    "typeIdentifier",
+    "declaration",
+];
+
+/// Iterate through the files in the args.file_names vector and pretty-print each file.  Return
+/// false if `args.verify_round_trip` is set and the round trip of a file changes the AST or
+/// fails.
 ///
 /// # Arguments
 ///
 /// * `args` - The [`PrettyPrintCLArgs`] object.
-pub fn pretty_print_files(args: PrettyPrintCLArgs) {
+pub fn pretty_print_files(args: PrettyPrintCLArgs) -> bool {
     let solidity_compiler_settings = SolidityCompilerSettings {
         solidity_compiler: args.solidity_compiler,
         solidity_base_path: args.solidity_base_path,
@@ -33,25 +75,59 @@ pub fn pretty_print_files(args: PrettyPrintCLArgs) {
     let vyper_compiler_settings = VyperCompilerSettings {
         vyper_compiler: args.vyper_compiler,
         vyper_root_path: args.vyper_root_path,
+        vyper_search_paths: args.vyper_search_paths,
     };
     let compiler_paths = CompilerSettings {
         solidity: solidity_compiler_settings,
         vyper: vyper_compiler_settings,
+        warnings_as_errors: args.compiler_warnings_as_errors,
     };
 
     let mut preferences = compiler_paths.to_preferences();
+    let mut round_trips_preserve_asts = true;
 
     for file_name in args.file {
-        if args.stdout {
+        if args.verify_round_trip {
+            match verify_round_trip(&file_name, &mut preferences) {
+                Ok(diffs) if diffs.is_empty() => {
+                    println!("{}: the round trip preserves the AST", file_name)
+                }
+                Ok(diffs) => {
+                    round_trips_preserve_asts = false;
+                    println!(
+                        "{}: the round trip changes the AST in {} place(s):",
+                        file_name,
+                        diffs.len()
+                    );
+                    for diff in &diffs {
+                        println!("    {}", round_trip_difference_summary(diff));
+                    }
+                }
+                Err(e) => {
+                    round_trips_preserve_asts = false;
+                    println!("Unable to verify the round trip of {}: {}", file_name, e)
+                }
+            }
+        } else if args.stdout {
             let mut stdout = std::io::stdout();
-            match pretty_print_file_to_stream(&file_name, &mut stdout, &mut preferences) {
-                Ok(_) => return,
+            match pretty_print_file_to_stream(
+                &file_name,
+                &mut stdout,
+                &mut preferences,
+                args.emit_ast,
+            ) {
+                Ok(_) => return true,
                 Err(e) => println!("Unable to pretty-print {}: {}", file_name, e),
             }
         } else {
             let original_file = PathBuf::from_str(&file_name).unwrap();
             let original_file_str = original_file.file_name().unwrap();
-            match pretty_print_file(&file_name, &args.output_directory, &mut preferences) {
+            match pretty_print_file(
+                &file_name,
+                &args.output_directory,
+                &mut preferences,
+                args.emit_ast,
+            ) {
                 Ok(_buf) => log::info!(
                     "Pretty-printing original file {:?} to {}",
                     original_file_str,
@@ -61,6 +137,8 @@ pub fn pretty_print_files(args: PrettyPrintCLArgs) {
             }
         }
     }
+
+    round_trips_preserve_asts
 }
 
 /// Pretty-print an individual file.
@@ -70,10 +148,12 @@ pub fn pretty_print_files(args: PrettyPrintCLArgs) {
 /// * `file_name` - The path to the file to pretty-print in the file system.
 /// * `output_directory` - The path to the location to save the pretty-printed file.
 /// * `preferences` - [`Preferences`] object.
+/// * `emit_ast` - True to write the AST as JSON instead of source.
 pub fn pretty_print_file(
-    file_name: &String,
+    file_name: &str,
     output_directory: &str,
     preferences: &mut Preferences,
+    emit_ast: bool,
 ) -> Result<PathBuf, MutagenyxError> {
     // Convert the output_directory to a PathBuf
     let out_dir = PathBuf::from_str(output_directory).unwrap();
@@ -91,28 +171,7 @@ pub fn pretty_print_file(
     let ast =
         language_object.load_ast_from_file(file_name, &recognize_result.file_type, preferences)?;
 
-    // Calculate the name of the output file.
-    let input_file_path = PathBuf::from(file_name);
-    let base_file_name = input_file_path.file_name().unwrap();
-    let file_extension = String::from(".") + language_object.get_extension_for_output_file();
-    let sfile_name = String::from(file_name);
-    let extension = if sfile_name.ends_with(file_extension.as_str()) {
-        ""
-    } else {
-        file_extension.as_str()
-    };
-    let outfile_name = out_dir.join(String::from(base_file_name.to_str().unwrap()) + extension);
-
-    let outfile = String::from(outfile_name.to_str().unwrap());
-
-    // Try to create the output directory:
-    std::fs::create_dir_all(outfile_name.parent().unwrap())?;
-
-    // Create a pretty printer for printing this AST.
-    let mut pretty_printer = PrettyPrinter::new(4, 150);
-    language_object.pretty_print_ast_to_file(&ast, &outfile, &mut pretty_printer)?;
-
-    Ok(outfile_name)
+    pretty_print_ast(&ast, file_name, &out_dir, emit_ast)
 }
 
 /// Pretty-print an individual file and write the output to `stream`.
@@ -122,10 +181,12 @@ pub fn pretty_print_file(
 /// * `file_name` - The name of the file to pretty-print.
 /// * `stream` - The [`Write`] trait object that will receive the pretty-printed output.
 /// * `preferences` - The [`Preferences`] object containing compiler settings.
+/// * `emit_ast` - True to write the AST as JSON instead of source.
 pub fn pretty_print_file_to_stream(
     file_name: &str,
     stream: &mut dyn Write,
     preferences: &mut Preferences,
+    emit_ast: bool,
 ) -> Result<(), MutagenyxError> {
     let recognizer = Recognizer::new(preferences);
 
@@ -141,7 +202,125 @@ pub fn pretty_print_file_to_stream(
     let ast =
         language_object.load_ast_from_file(file_name, &recognize_result.file_type, preferences)?;
 
-    pretty_print_ast_to_stream(&ast, stream)
+    pretty_print_ast_to_stream(&ast, stream, emit_ast)
+}
+
+/// Return the differences between the AST of the file named by `file_name` and the AST that the
+/// compiler generates from the pretty-printed program.  An empty list means that the
+/// pretty-printer reproduces the program without loss.
+///
+/// The function ignores the differences in the values that record source file names and source
+/// locations (see [`ROUND_TRIP_IGNORED_KEYS`]) and in the node ids (see
+/// [`ROUND_TRIP_NODE_ID_KEYS`]).  The function writes the pretty-printed program next to
+/// `file_name` so that the compiler resolves the relative imports of the program the same way,
+/// and removes the program afterwards.
+///
+/// # Arguments
+///
+/// * `file_name` - The path of the source or AST file in the file system.
+/// * `preferences` - The [`Preferences`] object containing compiler settings.
+///
+/// # Errors
+///
+/// The function returns the errors from recognizing the file, loading the AST, writing the
+/// pretty-printed program, and compiling the pretty-printed program.
+pub fn verify_round_trip(
+    file_name: &str,
+    preferences: &mut Preferences,
+) -> Result<Vec<NodeDiff>, MutagenyxError> {
+    let recognizer = Recognizer::new(preferences);
+
+    // Recognize the language.
+    let recognize_result = recognizer.recognize_file(file_name)?;
+
+    // Get the language interface object for the language.
+    let mut language_object =
+        LanguageInterface::get_language_object_for_language(&recognize_result.language)?;
+
+    // Load the ast.
+    let ast =
+        language_object.load_ast_from_file(file_name, &recognize_result.file_type, preferences)?;
+
+    // Compile the pretty-printed program back to an AST.
+    let printed_file = pretty_print_ast_next_to_file(&ast, file_name, "round_trip")?;
+    let round_trip_ast = language_object.load_ast_from_file(
+        &printed_file.to_string_lossy(),
+        &FileType::Source,
+        preferences,
+    );
+    let _ = std::fs::remove_file(&printed_file);
+
+    let diffs = ast
+        .diff(&round_trip_ast?)
+        .into_iter()
+        .filter(|d| {
+            !d.path.split('/').any(|key| {
+                ROUND_TRIP_IGNORED_KEYS.contains(&key) || ROUND_TRIP_NODE_ID_KEYS.contains(&key)
+            })
+        })
+        .collect();
+    Ok(diffs)
+}
+
+/// Return a one line description of a difference found by [`verify_round_trip`].
+///
+/// # Arguments
+///
+/// * `diff` - The difference between the original AST and the round trip AST.
+pub fn round_trip_difference_summary(diff: &NodeDiff) -> String {
+    let value_text = |value: &Option<serde_json::Value>| match value {
+        Some(v) => v.to_string(),
+        None => String::from("(missing)"),
+    };
+    let node = match diff.node_id {
+        Some(id) => format!("node {}", id),
+        None => String::from("root"),
+    };
+    format!(
+        "{} at {}: {} became {}",
+        node,
+        if diff.path.is_empty() {
+            "/"
+        } else {
+            &diff.path
+        },
+        value_text(&diff.left),
+        value_text(&diff.right)
+    )
+}
+
+/// Return the name of the file that receives the pretty-printed `ast` for the input file
+/// `file_name`.  The name is the basename of `file_name` with the extension for the language of
+/// `ast` (or `.json` for an AST) added if `file_name` does not already end with the extension.
+///
+/// # Arguments
+///
+/// * `ast` - Any reference to a [`SuperAST`] abstract syntax tree.
+/// * `file_name` - A reference to the text of the file name. May be a complete path.
+/// * `emit_ast` - True if the file receives the AST as JSON instead of source.
+pub fn output_file_name(
+    ast: &SuperAST,
+    file_name: &str,
+    emit_ast: bool,
+) -> Result<String, MutagenyxError> {
+    let language = ast.language();
+
+    let language_object = LanguageInterface::get_language_object_for_language(&language)?;
+
+    let input_file_name = PathBuf::from(file_name);
+    let base_file_name = input_file_name.file_name().unwrap();
+    let file_extension = if emit_ast {
+        String::from(".") + AST_FILE_EXTENSION
+    } else {
+        String::from(".") + language_object.get_extension_for_output_file()
+    };
+    let extension = if file_name.ends_with(file_extension.as_str()) {
+        ""
+    } else {
+        file_extension.as_str()
+    };
+
+    Ok(String::from(base_file_name.to_str().unwrap()) + extension)
 }
 
 /// Pretty print an AST to the file named `file_name` in `output_dir`.
@@ -154,32 +333,29 @@ pub fn pretty_print_file_to_stream(
 /// * `ast` - Any reference to a [`SuperAST`] abstract syntax tree.
 /// * `file_name` - A reference to the text of the file name. May be a complete path.
 /// * `output_dir` - The directory in which to place the pretty-printed ast.
+/// * `emit_ast` - True to write the AST as JSON instead of source.
 pub fn pretty_print_ast(
     ast: &SuperAST,
     file_name: &str,
     output_dir: &Path,
+    emit_ast: bool,
 ) -> Result<PathBuf, MutagenyxError> {
-    let language = language_for_ast(ast);
+    let language = ast.language();
 
     let mut language_object = LanguageInterface::get_language_object_for_language(&language)?;
 
-    let input_file_name = PathBuf::from(file_name);
-    let base_file_name = input_file_name.file_name().unwrap();
-    let file_extension = String::from(".") + language_object.get_extension_for_output_file();
-    let sfile_name = String::from(file_name);
-    let extension = if sfile_name.ends_with(file_extension.as_str()) {
-        ""
-    } else {
-        file_extension.as_str()
-    };
-
-    let outfile_name = output_dir.join(String::from(base_file_name.to_str().unwrap()) + extension);
+    let outfile_name = output_dir.join(output_file_name(ast, file_name, emit_ast)?);
 
     let outfile = String::from(outfile_name.to_str().unwrap());
 
     // Try to create the output directory:
     std::fs::create_dir_all(outfile_name.parent().unwrap())?;
 
+    if emit_ast {
+        std::fs::write(&outfile_name, ast.to_json_string(true)? + "\n")?;
+        return Ok(outfile_name);
+    }
+
     // Create a pretty printer for printing this AST.
     let mut pretty_printer = PrettyPrinter::new(4, 150);
     language_object.pretty_print_ast_to_file(ast, &outfile, &mut pretty_printer)?;
@@ -187,19 +363,181 @@ pub fn pretty_print_ast(
     Ok(outfile_name)
 }
 
+/// Pretty print an AST to a new source file in the directory of `file_name` and return the path
+/// of the new file.  The name of the new file adds `suffix` and the id of the process to the name
+/// of `file_name`, so concurrent runs do not share the file, and tools resolve the relative
+/// imports of the program the same way as for `file_name`.  The caller removes the file.
+///
+/// # Arguments
+///
+/// * `ast` - Any reference to a [`SuperAST`] abstract syntax tree.
+/// * `file_name` - The path of the input file of the AST.
+/// * `suffix` - The text that distinguishes the new file from the input file.
+pub fn pretty_print_ast_next_to_file(
+    ast: &SuperAST,
+    file_name: &str,
+    suffix: &str,
+) -> Result<PathBuf, MutagenyxError> {
+    let input_file = Path::new(file_name);
+    let directory = match input_file.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => PathBuf::from("."),
+    };
+    let printed_name = format!(
+        "{}.{}_{}",
+        input_file.file_name().unwrap_or_default().to_string_lossy(),
+        suffix,
+        std::process::id()
+    );
+    pretty_print_ast(ast, &printed_name, &directory, false)
+}
+
+/// Pretty print an AST to the file named `file_name` in `archive`.  Return the path of the file
+/// inside the archive.
+///
+/// # Arguments
+///
+/// * `ast` - Any reference to a [`SuperAST`] abstract syntax tree.
+/// * `file_name` - A reference to the text of the file name. May be a complete path.
+/// * `archive` - The archive that receives the pretty-printed ast.
+/// * `emit_ast` - True to write the AST as JSON instead of source.
+pub fn pretty_print_ast_to_archive(
+    ast: &SuperAST,
+    file_name: &str,
+    archive: &mut MutantArchive,
+    emit_ast: bool,
+) -> Result<String, MutagenyxError> {
+    let entry_name = output_file_name(ast, file_name, emit_ast)?;
+
+    let mut contents: Vec<u8> = Vec::new();
+    pretty_print_ast_to_stream(ast, &mut contents, emit_ast)?;
+    archive.append_file(&entry_name, &contents)?;
+
+    Ok(entry_name)
+}
+
 /// Pretty-print the AST in `ast` to the [`Write`] object `stream`
 ///
 /// # Arguments
 ///
 /// * `ast` - Any reference to a [`SuperAST`] abstract syntax tree.
 /// * `stream` - A [`Write`] trait object that can receive the pretty-printed output.
+/// * `emit_ast` - True to write the AST as JSON instead of source.
 pub fn pretty_print_ast_to_stream(
     ast: &SuperAST,
     stream: &mut dyn Write,
+    emit_ast: bool,
 ) -> Result<(), MutagenyxError> {
-    let language = language_for_ast(ast);
+    if emit_ast {
+        writeln!(stream, "{}", ast.to_json_string(true)?)?;
+        return Ok(());
+    }
+
+    let language = ast.language();
     let mut language_object = LanguageInterface::get_language_object_for_language(&language)?;
     let mut pretty_printer = PrettyPrinter::new(4, 150);
     language_object.pretty_print_ast_to_stream(ast, stream, &mut pretty_printer)?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(unix)]
+    use crate::test_fixtures::{compiler_language_preferences, install_stub_compiler};
+    use mutagenyx_lib::language::Language;
+    use serde_json::{json, Value};
+    #[cfg(unix)]
+    use std::env;
+
+    /// Return the AST of a contract with a function that returns `a + b`.  `src` is the source
+    /// location of the addition.
+    fn addition_ast(operator: &str, src: &str) -> Value {
+        let addition = json!({
+            "nodeType": "BinaryOperation", "id": 30, "operator": operator, "src": src,
+            "leftExpression": {"nodeType": "Identifier", "id": 31, "name": "a"},
+            "rightExpression": {"nodeType": "Identifier", "id": 32, "name": "b"}
+        });
+        json!({
+            "nodeType": "SourceUnit", "id": 100, "absolutePath": "sample.sol", "nodes": [{
+                "nodeType": "ContractDefinition", "id": 50, "name": "C",
+                "contractKind": "contract", "abstract": false, "baseContracts": [], "nodes": [{
+                    "nodeType": "FunctionDefinition", "id": 40, "name": "f",
+                    "kind": "function", "visibility": "public", "stateMutability": "pure",
+                    "virtual": false, "implemented": true, "modifiers": [],
+                    "parameters": {"nodeType": "ParameterList", "id": 10, "parameters": []},
+                    "returnParameters": {"nodeType": "ParameterList", "id": 11, "parameters": []},
+                    "body": {"nodeType": "Block", "id": 20, "statements": [
+                        {"nodeType": "Return", "id": 21, "expression": addition}
+                    ]}
+                }]
+            }]
+        })
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_verify_round_trip() {
+        let directory = env::temp_dir().join("mutagenyx_verify_round_trip");
+        let _ = std::fs::remove_dir_all(&directory);
+        std::fs::create_dir_all(&directory).unwrap();
+
+        // The stub compiler writes the canned AST for every source file.  The stub rejects AST
+        // files so that the recognizer loads the input file as an AST.
+        let canned = directory.join("canned.json");
+        let stub = install_stub_compiler(
+            &directory,
+            "solc",
+            &format!(
+                "for file; do\n\
+                  if [ \"$previous\" = \"-o\" ]; then out=\"$file\"; fi\n\
+                  previous=\"$file\"\n\
+                done\n\
+                if [ \"$(head -c 1 \"$file\")\" = \"{{\" ]; then exit 1; fi\n\
+                cp {} \"$out/$(basename \"$file\")_json.ast\"\n",
+                canned.to_str().unwrap()
+            ),
+        );
+        let language_preferences = compiler_language_preferences(&stub);
+        let mut preferences = Preferences::new();
+        preferences.set_preferences_for_key(&Language::Solidity.to_string(), language_preferences);
+
+        let file_name = directory.join("round_trip.json");
+        let file_name = file_name.to_str().unwrap();
+        std::fs::write(file_name, addition_ast("+", "40:5:0").to_string()).unwrap();
+
+        // The round trip only moves the addition in the source, which does not count.
+        std::fs::write(&canned, addition_ast("+", "52:5:0").to_string()).unwrap();
+        let diffs = verify_round_trip(file_name, &mut preferences).unwrap();
+        assert!(diffs.is_empty());
+
+        // The compiler numbers the nodes differently, which does not count either.
+        let mut renumbered = addition_ast("+", "52:5:0");
+        renumbered["id"] = json!(200);
+        renumbered["nodes"][0]["scope"] = json!(200);
+        std::fs::write(&canned, renumbered.to_string()).unwrap();
+        let diffs = verify_round_trip(file_name, &mut preferences).unwrap();
+        assert!(diffs.is_empty());
+
+        // The round trip changes the operator of the addition.
+        std::fs::write(&canned, addition_ast("-", "52:5:0").to_string()).unwrap();
+        let diffs = verify_round_trip(file_name, &mut preferences).unwrap();
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(diffs[0].node_id, Some(30));
+        assert!(diffs[0].path.ends_with("/expression/operator"));
+        assert_eq!(
+            round_trip_difference_summary(&diffs[0]),
+            format!("node 30 at {}: \"+\" became \"-\"", diffs[0].path)
+        );
+
+        // The round trip leaves no printed program behind.
+        let leftovers: Vec<_> = std::fs::read_dir(&directory)
+            .unwrap()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_name().to_string_lossy().contains(".round_trip_"))
+            .collect();
+        assert!(leftovers.is_empty());
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
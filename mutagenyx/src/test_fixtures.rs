@@ -0,0 +1,35 @@
+//! The `test_fixtures` module provides the helper functions that the tests of several modules
+//! share.
+
+use mutagenyx_lib::compiler_details::{COMPILER_KEY, PATH_KEY};
+use mutagenyx_lib::preferences::Preferences;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+
+/// Write the shell script `body` to the executable file `name` in `directory` and return the
+/// path of the file.  Tests use the script as a stand-in for a compiler.
+///
+/// # Arguments
+///
+/// * `directory` - The directory that receives the script.
+/// * `name` - The file name of the script.
+/// * `body` - The commands of the script, without the `#!/bin/sh` line.
+pub fn install_stub_compiler(directory: &Path, name: &str, body: &str) -> PathBuf {
+    let stub = directory.join(name);
+    std::fs::write(&stub, format!("#!/bin/sh\n{body}")).unwrap();
+    std::fs::set_permissions(&stub, std::fs::Permissions::from_mode(0o755)).unwrap();
+    stub
+}
+
+/// Return the language preferences that select the compiler at `compiler`.
+///
+/// # Arguments
+///
+/// * `compiler` - The path of the compiler.
+pub fn compiler_language_preferences(compiler: &Path) -> Preferences {
+    let mut compiler_preferences = Preferences::new();
+    compiler_preferences.set_string_for_key(PATH_KEY, compiler.to_str().unwrap());
+    let mut language_preferences = Preferences::new();
+    language_preferences.set_preferences_for_key(COMPILER_KEY, compiler_preferences);
+    language_preferences
+}
@@ -0,0 +1,158 @@
+//! The `annotation` module provides [`AnnotationFormat`] and [`AnnotationStyle`] that control the
+//! text of the comment a mutator inserts next to a mutated node.
+
+use crate::error::MutagenyxError;
+use crate::mutator_result::MutatorResult;
+use crate::preferences::Preferences;
+use std::fmt;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+/// The key in a [`Preferences`] object for the name of the annotation format.
+pub static ANNOTATION_FORMAT_KEY: &str = "annotation-format";
+
+/// The key in a [`Preferences`] object for the text to put at the start of every annotation.
+pub static ANNOTATION_PREFIX_KEY: &str = "annotation-prefix";
+
+/// The marker that starts a structured annotation.
+pub static STRUCTURED_ANNOTATION_MARKER: &str = "MGNX";
+
+/// The formats available for the comment that describes a mutation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AnnotationFormat {
+    /// Human readable prose such as `ArithmeticBinaryOp Mutator: changed 'a + b' to 'a - b'`.
+    #[default]
+    Text,
+
+    /// A machine readable marker such as `MGNX:{type=ArithmeticBinaryOp,id=42}`.
+    Structured,
+}
+
+impl FromStr for AnnotationFormat {
+    type Err = MutagenyxError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(AnnotationFormat::Text),
+            "structured" => Ok(AnnotationFormat::Structured),
+            _ => Err(MutagenyxError::AnnotationFormatNotSupported(String::from(
+                s,
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for AnnotationFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            AnnotationFormat::Text => "text",
+            AnnotationFormat::Structured => "structured",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+/// The settings that control the text of mutation annotations.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AnnotationStyle {
+    /// The format of the annotation.
+    pub format: AnnotationFormat,
+
+    /// Optional text to put at the start of every annotation.
+    pub prefix: Option<String>,
+}
+
+impl AnnotationStyle {
+    /// Create a new annotation style from the settings in `prefs`.  Missing or unrecognized
+    /// settings use the default human readable annotations.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefs` - The [`Preferences`] object that may contain annotation settings.
+    pub fn from_preferences(prefs: &Preferences) -> AnnotationStyle {
+        let format = prefs
+            .get_string_for_key(ANNOTATION_FORMAT_KEY)
+            .and_then(|f| AnnotationFormat::from_str(&f).ok())
+            .unwrap_or_default();
+
+        AnnotationStyle {
+            format,
+            prefix: prefs.get_string_for_key(ANNOTATION_PREFIX_KEY),
+        }
+    }
+
+    /// Return the annotation text for a mutation.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The human readable comment text produced by the mutator.
+    /// * `mutator_result` - The [`MutatorResult`] describing the mutation.
+    pub fn annotate(&self, text: &str, mutator_result: &MutatorResult) -> String {
+        let annotation = match self.format {
+            AnnotationFormat::Text => String::from(text),
+            AnnotationFormat::Structured => {
+                let mut fields: Vec<String> = vec![];
+                if let Some(mutation_type) = &mutator_result.mutation_type {
+                    fields.push(format!("type={}", mutation_type));
+                }
+                if let Some(id) = mutator_result.mutated_node_id {
+                    fields.push(format!("id={}", id));
+                }
+                format!("{}:{{{}}}", STRUCTURED_ANNOTATION_MARKER, fields.join(","))
+            }
+        };
+
+        match &self.prefix {
+            Some(prefix) => format!("{} {}", prefix, annotation),
+            None => annotation,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mutation::{GenericMutation, MutationType};
+
+    fn mutator_result() -> MutatorResult {
+        let mut result = MutatorResult::new();
+        result.mutation_type = Some(MutationType::Generic(GenericMutation::Integer));
+        result.mutated_node_id = Some(42);
+        result
+    }
+
+    #[test]
+    fn test_annotation_format_round_trip() {
+        for format in [AnnotationFormat::Text, AnnotationFormat::Structured] {
+            assert_eq!(
+                AnnotationFormat::from_str(&format.to_string()).unwrap(),
+                format
+            );
+        }
+        assert!(AnnotationFormat::from_str("json").is_err());
+    }
+
+    #[test]
+    fn test_annotation_style_annotate() {
+        let text = "Integer Mutator: changed '1' to '2'";
+
+        let style = AnnotationStyle::default();
+        assert_eq!(style.annotate(text, &mutator_result()), text);
+
+        let mut prefs = Preferences::new();
+        prefs.set_string_for_key(ANNOTATION_FORMAT_KEY, "structured");
+        let style = AnnotationStyle::from_preferences(&prefs);
+        assert_eq!(
+            style.annotate(text, &mutator_result()),
+            "MGNX:{type=Integer,id=42}"
+        );
+
+        prefs.set_string_for_key(ANNOTATION_FORMAT_KEY, "text");
+        prefs.set_string_for_key(ANNOTATION_PREFIX_KEY, "@mutant");
+        let style = AnnotationStyle::from_preferences(&prefs);
+        assert_eq!(
+            style.annotate(text, &mutator_result()),
+            format!("@mutant {}", text)
+        );
+    }
+}
@@ -0,0 +1,154 @@
+//! The `api` module provides [`mutate_file`], the function that covers the common use of the
+//! library: recognize the language of a file, generate a number of mutants of the program in
+//! the file, and write the mutants to a directory.
+//!
+//! Applications that need more control over the mutation process (compiler settings, the
+//! functions to mutate, or the comments inserted next to mutated nodes) can assemble the same
+//! steps from [`Recognizer`] and [`LanguageInterface`].
+
+use crate::config_file::rng_from_seed;
+use crate::error::MutagenyxError;
+use crate::language_interface::LanguageInterface;
+use crate::mutant_record::MutantRecord;
+use crate::mutant_selector::MutantSelector;
+use crate::mutation::{default_mutation_types, MutationType};
+use crate::permissions::*;
+use crate::preferences::Preferences;
+use crate::pretty_printer::PrettyPrinter;
+use crate::recognizer::Recognizer;
+use rand::seq::SliceRandom;
+use std::collections::VecDeque;
+use std::path::Path;
+
+/// Generate `count` mutants of the program in the source or AST file `path` and write the
+/// pretty-printed mutants to `out_dir`.  Return the record of each written mutant, in the order
+/// that the function wrote the mutants.  The `file` of a record is the name of the mutant in
+/// `out_dir`.
+///
+/// The function recognizes the language of the file, uses the default compiler for the language
+/// to convert a source file to an AST, and names each mutant after the input file and the index
+/// of the mutant, as in `Token.sol_0.sol`.  The function writes fewer than `count` mutants if
+/// the program does not have enough distinct mutations.
+///
+/// # Arguments
+///
+/// * `path` - The path of the source or AST file in the file system.
+/// * `mutations` - The mutation algorithms to use, or an empty slice for the default algorithms
+///   of the language.  The function ignores the algorithms that the language does not support.
+/// * `count` - The number of mutants to generate.
+/// * `seed` - The seed of the random number generator.  The same seed generates the same mutants,
+///   and the mutants of the `mutate` command run with `--rng-seed` set to the seed.
+/// * `out_dir` - The directory that receives the mutants.
+///
+/// # Errors
+///
+/// The function returns [`MutagenyxError::NoMutableNode`] if the requested algorithms cannot
+/// mutate any node in the program, and passes on the errors from recognizing the language,
+/// loading the AST and writing the mutants.
+pub fn mutate_file(
+    path: &Path,
+    mutations: &[MutationType],
+    count: usize,
+    seed: u128,
+    out_dir: &Path,
+) -> Result<Vec<MutantRecord>, MutagenyxError> {
+    let file_name = path.to_string_lossy();
+    let file_name = file_name.as_ref();
+
+    let mut preferences = Preferences::new();
+    let recognizer = Recognizer::new(&mut preferences);
+    let recognize_result = recognizer.recognize_file(file_name)?;
+
+    let mut language_object =
+        LanguageInterface::get_language_object_for_language(&recognize_result.language)?;
+
+    let ast =
+        language_object.load_ast_from_file(file_name, &recognize_result.file_type, &preferences)?;
+
+    if mutations.is_empty() {
+        language_object.select_mutators_for_mutation_types(&default_mutation_types(
+            recognize_result.language,
+        ))?;
+    } else {
+        language_object.select_mutators_for_mutation_types(mutations)?;
+    }
+
+    // Allow the mutators to visit and mutate every node.
+    let mut permissions = Permissions::new();
+    permissions.add_permission(PermissionAction::Mutate(PermissionScope::Any(
+        Permission::Allow,
+    )));
+    permissions.add_permission(PermissionAction::Visit(PermissionScope::Any(
+        Permission::Allow,
+    )));
+
+    let mut rng = rng_from_seed(seed);
+
+    let mutable_nodes_table = language_object.count_mutable_nodes(&ast, &mut rng, &permissions)?;
+    let node_path_map = language_object.calculate_node_paths(&ast, &permissions)?;
+
+    // Sort the mutation types so that the same seed always selects the same mutations.
+    let mut mutation_type_list: Vec<MutationType> = mutable_nodes_table
+        .iter()
+        .filter(|(_, v)| **v > 0)
+        .map(|(k, _)| *k)
+        .collect();
+    mutation_type_list.sort();
+
+    if mutation_type_list.is_empty() {
+        return Err(MutagenyxError::NoMutableNode);
+    }
+
+    std::fs::create_dir_all(out_dir)?;
+
+    let base_file_name = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or(file_name);
+    let extension = language_object.get_extension_for_output_file().to_string();
+
+    // Randomly pick the mutation algorithm of each mutant.
+    let mut mutation_kinds: VecDeque<MutationType> = VecDeque::new();
+    for _ in 0..count {
+        match mutation_type_list.choose(&mut rng) {
+            Some(t) => mutation_kinds.push_back(*t),
+            None => {
+                return Err(MutagenyxError::RandomOperationFailure(
+                    "Unable to choose a mutation algorithm",
+                ))
+            }
+        }
+    }
+
+    let mut written_files: Vec<MutantRecord> = Vec::new();
+    let mut selector = MutantSelector::new(mutation_kinds);
+
+    while let Some(candidate) = selector.next_mutant(
+        language_object.as_mut(),
+        &ast,
+        &mutable_nodes_table,
+        &mut rng,
+        &permissions,
+        &node_path_map,
+    )? {
+        let out_file_name = format!("{}_{}.{}", base_file_name, written_files.len(), extension);
+        let out_file = out_dir.join(&out_file_name);
+        let mut pretty_printer = PrettyPrinter::new(4, 150);
+        language_object.pretty_print_ast_to_file(
+            &candidate.ast,
+            &out_file.to_string_lossy(),
+            &mut pretty_printer,
+        )?;
+
+        written_files.push(MutantRecord {
+            file: out_file_name,
+            source: String::from(file_name),
+            index: written_files.len(),
+            mutation_type: Some(candidate.mutation_type),
+            node_id: candidate.mutator_result.mutated_node_id,
+        });
+        selector.accept(candidate.mutator_result);
+    }
+
+    Ok(written_files)
+}
@@ -4,6 +4,22 @@
 
 use crate::id::Id;
 
+/// The name of the pretty-printer setting that selects block comments (`/* ... */`) for comment
+/// nodes that hold commented-out code.  Line comments break when the commented-out code spans
+/// several lines.  Languages without block comments keep line comments, but start each line of
+/// the commented-out code with the line comment marker.
+pub static BLOCK_COMMENTS_KEY: &str = "block_comments";
+
+/// Return `text` with each `*/` broken up as `* /` so that the text cannot end the block comment
+/// that contains it.
+///
+/// # Arguments
+///
+/// * `text` - The text to put in a block comment.
+pub fn escape_block_comment_text(text: &str) -> String {
+    text.replace("*/", "* /")
+}
+
 pub trait Commenter<AST> {
     /// Insert `comment_node` in `node` if `node` contains a direct child that has id `node_id`.
     ///
@@ -32,3 +48,16 @@ pub trait CommenterFactory<AST> {
     /// * `node` - The AST node for which the function should return a [`Commenter<AST>`] trait object.
     fn commenter_for(&self, node: &AST) -> Box<dyn Commenter<AST>>;
 }
+
+pub trait CommentDetector<AST> {
+    /// Return true if `node` is a comment node.
+    ///
+    /// Comment nodes are not part of the official AST of a language.  The mutation algorithms
+    /// insert them next to mutated nodes, so they appear in the AST of a previously mutated
+    /// program.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The AST node.
+    fn is_comment(&self, node: &AST) -> bool;
+}
@@ -5,3 +5,11 @@ pub static COMPILER_KEY: &str = "compiler";
 
 /// Key for getting the path to the compiler in the file system.
 pub static PATH_KEY: &str = "path";
+
+/// Key for the boolean setting that treats warnings reported by the compiler while generating an
+/// AST as errors.
+pub static WARNINGS_AS_ERRORS_KEY: &str = "warnings-as-errors";
+
+/// Key for the integer setting with the number of seconds that a compiler may run while checking
+/// that a mutant compiles.
+pub static TIMEOUT_KEY: &str = "timeout";
@@ -6,6 +6,8 @@ use crate::language::Language;
 use crate::preferences::Preferences;
 use crate::{MutagenyxError, MutationType};
 use jsonxf;
+use rand::SeedableRng;
+use rand_pcg::Pcg64;
 use serde_json::{from_str, json, Value};
 use std::ffi::OsStr;
 use std::io::Write;
@@ -41,6 +43,12 @@ pub static FUNCTIONS_KEY: &str = "functions";
 /// The key to indicate whether the tool should compile the mutants.
 pub static VALIDATE_MUTANTS_KEY: &str = "validate-mutants";
 
+/// The key for the command that the tool should run on each mutant to validate the mutant.
+pub static VALIDATE_COMMAND_KEY: &str = "validate-command";
+
+/// The key for the number of seconds that the compiler may run while the tool compiles a mutant.
+pub static VALIDATE_TIMEOUT_KEY: &str = "validate-timeout";
+
 /// The key for the string value containing the path to where the tool should place
 /// output.
 pub static OUTPUT_DIR_KEY: &str = "output-directory";
@@ -49,6 +57,76 @@ pub static OUTPUT_DIR_KEY: &str = "output-directory";
 /// source or AST file.
 pub static PRINT_ORIGINAL_KEY: &str = "print-original";
 
+/// The key for the boolean value indicating whether the tool should write the unmutated
+/// original as the first (index 0) output alongside the mutants.
+pub static INCLUDE_BASELINE_KEY: &str = "include-baseline";
+
+/// The suffix of a configuration key whose list extends the list of the same key from earlier
+/// configuration files instead of replacing it (for example `"mutations+"`).
+pub static APPEND_KEY_SUFFIX: &str = "+";
+
+/// Parse the text of a random number generator seed.  The text is either a decimal number or a
+/// hexadecimal number with a `0x` prefix.
+///
+/// # Arguments
+///
+/// * `text` - The text of the seed.
+pub fn parse_seed(text: &str) -> Result<u128, MutagenyxError> {
+    let text = text.trim();
+    let result = match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        Some(hex) => u128::from_str_radix(hex, 16),
+        None => text.parse::<u128>(),
+    };
+    result.map_err(|_| MutagenyxError::SeedNotValid(String::from(text)))
+}
+
+/// Return a new random number generator seeded with `seed`.
+///
+/// Seeds that fit in a `u64` seed the generator exactly like earlier versions of the tool, so
+/// old seeds still reproduce the same mutants.  Larger seeds become the 128-bit state of the
+/// generator.
+///
+/// # Arguments
+///
+/// * `seed` - The random number generator seed.
+pub fn rng_from_seed(seed: u128) -> Pcg64 {
+    match u64::try_from(seed) {
+        Ok(seed) => Pcg64::seed_from_u64(seed),
+        Err(_) => {
+            let mut bytes = [0u8; 32];
+            bytes[..16].copy_from_slice(&seed.to_le_bytes());
+            Pcg64::from_seed(bytes)
+        }
+    }
+}
+
+/// Return the JSON value for `seed`.  A JSON number holds seeds up to [`u64::MAX`], so the
+/// function writes larger seeds as a string with the hexadecimal value of the seed.
+///
+/// # Arguments
+///
+/// * `seed` - The random number generator seed.
+pub fn seed_to_json(seed: u128) -> Value {
+    match u64::try_from(seed) {
+        Ok(seed) => json![seed],
+        Err(_) => json![format!("{seed:#x}")],
+    }
+}
+
+/// Return the random number generator seed in `value`, either a JSON number or a string that
+/// [`parse_seed`] accepts.
+///
+/// # Arguments
+///
+/// * `value` - The JSON value of the seed.
+pub fn seed_from_json(value: &Value) -> Option<u128> {
+    match value {
+        Value::Number(n) => n.as_u64().map(u128::from),
+        Value::String(s) => parse_seed(s).ok(),
+        _ => None,
+    }
+}
+
 /// Configuration details loaded from a .mgnx configuration file.
 pub struct ConfigurationFileDetails {
     /// Language specified in configuration file.
@@ -61,7 +139,7 @@ pub struct ConfigurationFileDetails {
     pub number_of_mutants: i64,
 
     /// The random number generator seed, if present from the configuration file.
-    pub seed: Option<u64>,
+    pub seed: Option<u128>,
 
     /// The list of requested mutations.
     pub mutations: Vec<MutationType>,
@@ -78,11 +156,20 @@ pub struct ConfigurationFileDetails {
     /// True if the tool should compile the mutants for viability.
     pub verify_mutants: bool,
 
+    /// The command the tool should run on each mutant to validate the mutant.
+    pub validate_command: Option<String>,
+
+    /// The number of seconds that the compiler may run while the tool compiles a mutant.
+    pub validate_timeout: Option<u64>,
+
     /// The location where the tool should put generated output files.
     pub output_directory: Option<PathBuf>,
 
     /// True if the tool should pretty-print the original source or AST file.
     pub print_original: bool,
+
+    /// True if the tool should write the unmutated original as mutant index 0.
+    pub include_baseline: bool,
 }
 
 impl ConfigurationFileDetails {
@@ -120,8 +207,11 @@ impl ConfigurationFileDetails {
             all_mutations: false,
             functions: Vec::new(),
             verify_mutants: false,
+            validate_command: None,
+            validate_timeout: None,
             output_directory: None,
             print_original: false,
+            include_baseline: false,
         };
 
         if let Ok(json_value) = load_json_from_file_with_name(config_file) {
@@ -168,8 +258,8 @@ impl ConfigurationFileDetails {
                 details.number_of_mutants = number_of_mutants;
             }
 
-            if let Some(seed) = json_value.get_int_for_key(SEED_KEY) {
-                details.seed = Some(seed as u64);
+            if let Some(seed) = json_value.get(SEED_KEY).and_then(seed_from_json) {
+                details.seed = Some(seed);
             }
 
             if let Some(mutations_array) = json_value.get_array_for_key(MUTATIONS_KEY) {
@@ -205,6 +295,14 @@ impl ConfigurationFileDetails {
                 details.verify_mutants = check;
             }
 
+            if let Some(command) = json_value.get_str_for_key(VALIDATE_COMMAND_KEY) {
+                details.validate_command = Some(String::from(command));
+            }
+
+            if let Some(seconds) = json_value.get_int_for_key(VALIDATE_TIMEOUT_KEY) {
+                details.validate_timeout = u64::try_from(seconds).ok().filter(|s| *s > 0);
+            }
+
             if let Some(output_directory) = json_value.get_str_for_key(OUTPUT_DIR_KEY) {
                 details.output_directory = Some(PathBuf::from(output_directory));
             }
@@ -212,6 +310,10 @@ impl ConfigurationFileDetails {
             if let Some(print_original) = json_value.get_bool_for_key(PRINT_ORIGINAL_KEY) {
                 details.print_original = print_original;
             }
+
+            if let Some(include_baseline) = json_value.get_bool_for_key(INCLUDE_BASELINE_KEY) {
+                details.include_baseline = include_baseline;
+            }
         } else {
             return Err(MutagenyxError::ConfigFileNotSupported(String::from(
                 config_file,
@@ -242,7 +344,7 @@ impl ConfigurationFileDetails {
         json_value.set_node_for_key(NUMBER_OF_MUTANTS_KEY, json![self.number_of_mutants]);
 
         if let Some(seed) = self.seed {
-            json_value.set_node_for_key(SEED_KEY, json![seed]);
+            json_value.set_node_for_key(SEED_KEY, seed_to_json(seed));
         }
 
         if !self.mutations.is_empty() {
@@ -269,12 +371,24 @@ impl ConfigurationFileDetails {
 
         json_value.set_node_for_key(VALIDATE_MUTANTS_KEY, json![self.verify_mutants]);
 
+        if let Some(command) = &self.validate_command {
+            json_value.set_str_for_key(VALIDATE_COMMAND_KEY, command);
+        }
+
+        if let Some(seconds) = self.validate_timeout {
+            json_value.set_node_for_key(VALIDATE_TIMEOUT_KEY, json![seconds]);
+        }
+
         if let Some(output_directory) = &self.output_directory {
             json_value.set_node_for_key(OUTPUT_DIR_KEY, json![output_directory.to_str()]);
         }
 
         json_value.set_node_for_key(PRINT_ORIGINAL_KEY, json![self.print_original]);
 
+        if self.include_baseline {
+            json_value.set_node_for_key(INCLUDE_BASELINE_KEY, json![self.include_baseline]);
+        }
+
         Ok(json_value)
     }
 
@@ -319,3 +433,155 @@ impl ConfigurationFileDetails {
         Ok(())
     }
 }
+
+/// Merge the configuration in `layer` into the configuration in `base`.  Values in `layer`
+/// override the values in `base`: the function merges objects key by key, and replaces scalars
+/// and lists.  A list under a key with the [`APPEND_KEY_SUFFIX`] suffix extends the list under
+/// the key without the suffix instead of replacing it.
+///
+/// # Arguments
+///
+/// * `base` - The configuration that receives the values.
+/// * `layer` - The configuration with the overriding values.
+pub fn merge_configuration_values(base: &mut Value, layer: &Value) {
+    let (base_map, layer_map) = match (base.as_object_mut(), layer.as_object()) {
+        (Some(b), Some(l)) => (b, l),
+        _ => {
+            *base = layer.clone();
+            return;
+        }
+    };
+
+    for (key, value) in layer_map {
+        if let Some(list_key) = key.strip_suffix(APPEND_KEY_SUFFIX) {
+            if let Some(items) = value.as_array() {
+                let entry = base_map
+                    .entry(list_key)
+                    .or_insert_with(|| Value::Array(vec![]));
+                match entry.as_array_mut() {
+                    Some(list) => list.extend(items.iter().cloned()),
+                    None => *entry = value.clone(),
+                }
+                continue;
+            }
+        }
+
+        match base_map.get_mut(key) {
+            Some(existing) if existing.is_object() && value.is_object() => {
+                merge_configuration_values(existing, value)
+            }
+            _ => {
+                base_map.insert(key.clone(), value.clone());
+            }
+        }
+    }
+}
+
+/// Load the configuration files in `config_files` and merge them in order, so that later files
+/// override earlier files.  Unlike the configuration files that describe the files to mutate,
+/// the files do not need the `language` or `filenames` keys.
+///
+/// # Arguments
+///
+/// * `config_files` - The paths to the configuration files in the file system.
+pub fn load_layered_configuration_files(config_files: &[String]) -> Result<Value, MutagenyxError> {
+    let mut merged = json!({});
+
+    for config_file in config_files {
+        let config_path = PathBuf::from(config_file);
+        if config_path.extension() != Some(OsStr::new(CONFIG_FILE_EXTENSION)) {
+            return Err(MutagenyxError::ConfigFileBadExtension(config_file.clone()));
+        }
+
+        let layer = load_json_from_file_with_name(config_file)?;
+        if !layer.is_object() {
+            return Err(MutagenyxError::ConfigFileNotSupported(config_file.clone()));
+        }
+
+        merge_configuration_values(&mut merged, &layer);
+    }
+
+    Ok(merged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_merge_configuration_values() {
+        let mut base = json!({
+            "seed": 1,
+            "language": "Solidity",
+            "compiler": {"path": "solc", "optimizer": {"enabled": false, "runs": 200}},
+            "mutations": ["ArithmeticBinaryOp", "Integer"],
+            "functions": ["f"]
+        });
+        let layer = json!({
+            "seed": 2,
+            "compiler": {"optimizer": {"enabled": true}, "evm-version": "paris"},
+            "mutations": ["UnaryOp"],
+            "functions+": ["g"]
+        });
+        merge_configuration_values(&mut base, &layer);
+
+        assert_eq!(
+            base,
+            json!({
+                // Scalars in the layer override the base, other keys stay.
+                "seed": 2,
+                "language": "Solidity",
+                // Objects merge key by key at every depth.
+                "compiler": {
+                    "path": "solc",
+                    "optimizer": {"enabled": true, "runs": 200},
+                    "evm-version": "paris"
+                },
+                // Arrays replace the base array.
+                "mutations": ["UnaryOp"],
+                // Arrays under a key with the append suffix extend the base array.
+                "functions": ["f", "g"]
+            })
+        );
+
+        // A layer value of another type replaces the base value.
+        let mut base = json!({"compiler": {"path": "solc"}, "filenames": ["a.sol"]});
+        merge_configuration_values(
+            &mut base,
+            &json!({"compiler": "vyper", "filenames": {"a": 1}}),
+        );
+        assert_eq!(base, json!({"compiler": "vyper", "filenames": {"a": 1}}));
+    }
+
+    #[test]
+    fn test_layered_configuration_load_errors() {
+        let directory = std::env::temp_dir().join("mutagenyx_layered_configuration_errors");
+        let _ = std::fs::remove_dir_all(&directory);
+        std::fs::create_dir_all(&directory).unwrap();
+        let path = |name: &str| String::from(directory.join(name).to_str().unwrap());
+
+        // A missing file keeps the IO error.
+        let result = load_layered_configuration_files(&[path("missing.mgnx")]);
+        assert!(matches!(result, Err(MutagenyxError::IO(_))));
+
+        // A syntax error keeps the location of the error.
+        std::fs::write(path("broken.mgnx"), "{\"seed\": }").unwrap();
+        match load_layered_configuration_files(&[path("broken.mgnx")]) {
+            Err(MutagenyxError::MalformedAST { detail, .. }) => {
+                assert!(detail.contains("byte offset 9"), "{}", detail)
+            }
+            other => panic!("unexpected result {:?}", other),
+        }
+
+        // Only JSON that is not an object is not supported.
+        std::fs::write(path("array.mgnx"), "[1, 2]").unwrap();
+        let result = load_layered_configuration_files(&[path("array.mgnx")]);
+        assert!(matches!(
+            result,
+            Err(MutagenyxError::ConfigFileNotSupported(_))
+        ));
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,273 @@
+//! The `diff` module provides a line based unified diff for comparing a pretty-printed original
+//! program with a pretty-printed mutant.
+
+/// One line of the edit script that transforms the original text into the modified text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DiffLine<'a> {
+    /// The line appears in both texts.
+    Equal(&'a str),
+    /// The line only appears in the original text.
+    Delete(&'a str),
+    /// The line only appears in the modified text.
+    Insert(&'a str),
+}
+
+/// Calculate the edit script that transforms `original` into `modified` using the longest common
+/// subsequence of lines.
+///
+/// Mutants usually differ from the original program in a small region, so the function first
+/// strips the common prefix and suffix and only runs the quadratic algorithm on the remainder.
+///
+/// # Arguments
+///
+/// * `original` - The lines of the original text.
+/// * `modified` - The lines of the modified text.
+fn edit_script<'a>(original: &[&'a str], modified: &[&'a str]) -> Vec<DiffLine<'a>> {
+    let prefix = original
+        .iter()
+        .zip(modified.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let suffix = original[prefix..]
+        .iter()
+        .rev()
+        .zip(modified[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let old = &original[prefix..original.len() - suffix];
+    let new = &modified[prefix..modified.len() - suffix];
+
+    // lcs[i][j] holds the length of the longest common subsequence of old[i..] and new[j..].
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut script: Vec<DiffLine> = original[..prefix]
+        .iter()
+        .map(|l| DiffLine::Equal(l))
+        .collect();
+
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            script.push(DiffLine::Equal(old[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            script.push(DiffLine::Delete(old[i]));
+            i += 1;
+        } else {
+            script.push(DiffLine::Insert(new[j]));
+            j += 1;
+        }
+    }
+    script.extend(old[i..].iter().map(|l| DiffLine::Delete(l)));
+    script.extend(new[j..].iter().map(|l| DiffLine::Insert(l)));
+    script.extend(
+        original[original.len() - suffix..]
+            .iter()
+            .map(|l| DiffLine::Equal(l)),
+    );
+
+    script
+}
+
+/// Return the text of a hunk range (`start,length`) in the form used by unified diff headers.
+///
+/// # Arguments
+///
+/// * `lines_before` - The number of lines of the text that precede the hunk.
+/// * `length` - The number of lines of the text in the hunk.
+/// * `line_offset` - The amount to add to the line numbers of the text.
+fn hunk_range(lines_before: usize, length: usize, line_offset: isize) -> String {
+    // An empty range refers to the line before the (empty) hunk.
+    let (start, first_line) = if length == 0 {
+        (lines_before as isize, 0)
+    } else {
+        (lines_before as isize + 1, 1)
+    };
+    format!("{},{}", (start + line_offset).max(first_line), length)
+}
+
+/// Return a unified diff of the lines in `original` and `modified`.  The function returns an
+/// empty string when the two texts contain the same lines.
+///
+/// # Arguments
+///
+/// * `original` - The original text.
+/// * `modified` - The modified text.
+/// * `original_name` - The name to use for the original text in the diff header.
+/// * `modified_name` - The name to use for the modified text in the diff header.
+/// * `context` - The number of unchanged lines to show around each change.
+pub fn unified_diff(
+    original: &str,
+    modified: &str,
+    original_name: &str,
+    modified_name: &str,
+    context: usize,
+) -> String {
+    unified_diff_from_line(
+        original,
+        modified,
+        original_name,
+        modified_name,
+        context,
+        None,
+    )
+}
+
+/// Return a unified diff of the lines in `original` and `modified` whose hunk headers use the
+/// line numbers of another text, such as the source file that `original` reproduces.  The
+/// function shifts the line numbers of every hunk so that the first changed line of `original`
+/// has the number `first_change_line`.  Without `first_change_line` the function behaves like
+/// [`unified_diff`].
+///
+/// # Arguments
+///
+/// * `original` - The original text.
+/// * `modified` - The modified text.
+/// * `original_name` - The name to use for the original text in the diff header.
+/// * `modified_name` - The name to use for the modified text in the diff header.
+/// * `context` - The number of unchanged lines to show around each change.
+/// * `first_change_line` - The line number to report for the first changed line.
+pub fn unified_diff_from_line(
+    original: &str,
+    modified: &str,
+    original_name: &str,
+    modified_name: &str,
+    context: usize,
+    first_change_line: Option<usize>,
+) -> String {
+    let original_lines: Vec<&str> = original.lines().collect();
+    let modified_lines: Vec<&str> = modified.lines().collect();
+    let script = edit_script(&original_lines, &modified_lines);
+
+    let changes: Vec<usize> = script
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| !matches!(l, DiffLine::Equal(_)))
+        .map(|(i, _)| i)
+        .collect();
+
+    if changes.is_empty() {
+        return String::new();
+    }
+
+    let line_offset = match first_change_line {
+        Some(line) => {
+            let lines_before_change = script[..changes[0]]
+                .iter()
+                .filter(|l| !matches!(l, DiffLine::Insert(_)))
+                .count();
+            line as isize - (lines_before_change as isize + 1)
+        }
+        None => 0,
+    };
+
+    // Group the changes into hunks.  Two changes belong to the same hunk when their context
+    // lines would touch or overlap.
+    let mut hunks: Vec<(usize, usize)> = vec![];
+    for change in changes {
+        let start = change.saturating_sub(context);
+        let end = (change + context + 1).min(script.len());
+        match hunks.last_mut() {
+            Some(hunk) if start <= hunk.1 => hunk.1 = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    let mut output = format!("--- {}\n+++ {}\n", original_name, modified_name);
+
+    for (start, end) in hunks {
+        let old_before = script[..start]
+            .iter()
+            .filter(|l| !matches!(l, DiffLine::Insert(_)))
+            .count();
+        let new_before = script[..start]
+            .iter()
+            .filter(|l| !matches!(l, DiffLine::Delete(_)))
+            .count();
+        let old_length = script[start..end]
+            .iter()
+            .filter(|l| !matches!(l, DiffLine::Insert(_)))
+            .count();
+        let new_length = script[start..end]
+            .iter()
+            .filter(|l| !matches!(l, DiffLine::Delete(_)))
+            .count();
+
+        output += &format!(
+            "@@ -{} +{} @@\n",
+            hunk_range(old_before, old_length, line_offset),
+            hunk_range(new_before, new_length, line_offset)
+        );
+
+        for line in &script[start..end] {
+            let (marker, text) = match line {
+                DiffLine::Equal(t) => (' ', t),
+                DiffLine::Delete(t) => ('-', t),
+                DiffLine::Insert(t) => ('+', t),
+            };
+            output.push(marker);
+            output += text;
+            output.push('\n');
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unified_diff_identical() {
+        assert_eq!(unified_diff("a\nb\n", "a\nb\n", "a", "b", 3), "");
+    }
+
+    #[test]
+    fn test_unified_diff_single_change() {
+        let original = "1\n2\n3\n4\n5\n6\n7\n8\n9\n";
+        let modified = "1\n2\n3\n4\nfive\n6\n7\n8\n9\n";
+        let expected = "--- a\n+++ b\n@@ -3,5 +3,5 @@\n 3\n 4\n-5\n+five\n 6\n 7\n";
+        assert_eq!(unified_diff(original, modified, "a", "b", 2), expected);
+    }
+
+    #[test]
+    fn test_unified_diff_separate_hunks() {
+        let original = "1\n2\n3\n4\n5\n6\n7\n8\n9\n";
+        let modified = "one\n2\n3\n4\n5\n6\n7\n8\n";
+        let expected = "--- a\n+++ b\n@@ -1,2 +1,2 @@\n-1\n+one\n 2\n@@ -8,2 +8,1 @@\n 8\n-9\n";
+        assert_eq!(unified_diff(original, modified, "a", "b", 1), expected);
+    }
+
+    #[test]
+    fn test_unified_diff_from_line() {
+        let original = "1\n2\n3\n4\n5\n6\n7\n8\n9\n";
+        let modified = "1\n2\n3\n4\nfive\n6\n7\n8\n9\n";
+        let expected = "--- a\n+++ b\n@@ -41,5 +41,5 @@\n 3\n 4\n-5\n+five\n 6\n 7\n";
+        assert_eq!(
+            unified_diff_from_line(original, modified, "a", "b", 2, Some(43)),
+            expected
+        );
+        assert_eq!(
+            unified_diff_from_line(original, modified, "a", "b", 2, None),
+            unified_diff(original, modified, "a", "b", 2)
+        );
+    }
+
+    #[test]
+    fn test_unified_diff_insertion() {
+        let expected = "--- a\n+++ b\n@@ -1,0 +2,1 @@\n+2\n";
+        assert_eq!(unified_diff("1\n", "1\n2\n", "a", "b", 0), expected);
+    }
+}
@@ -55,18 +55,88 @@ pub enum MutagenyxError {
     #[error("Language does not support this AST type")]
     ASTTypeNotSupported,
 
+    /// An error indicating that the requested mutation annotation format does not exist.
+    #[error("Annotation format {0} not supported")]
+    AnnotationFormatNotSupported(String),
+
+    /// An error indicating that the requested archive compression does not exist.
+    #[error("Archive compression {0} not supported")]
+    ArchiveCompressionNotSupported(String),
+
+    /// An error indicating that the tool cannot write an archive to the requested path.
+    #[error("Archive {0} not valid: {1}")]
+    ArchivePathNotValid(String, String),
+
     /// An error indicating that a language does not implement a mutation algorithm.
     #[error("Language does not support mutation algorithm: {0}")]
     MutationAlgorithmNotSupported(String),
 
+    /// An error indicating that a mutation algorithm request has an invalid number of mutants.
+    #[error("Invalid number of mutants in mutation algorithm request: {0}")]
+    MutationCountNotValid(String),
+
+    /// An error indicating that a line of a seed file does not contain a valid seed.
+    #[error("Seed file {0} has an invalid seed on line {1}: {2}")]
+    SeedFileLineNotValid(String, usize, String),
+
+    /// An error indicating that a random number generator seed is not a decimal number or a
+    /// `0x` prefixed hexadecimal number.
+    #[error("Invalid random number generator seed: {0}")]
+    SeedNotValid(String),
+
+    /// An error indicating that a source line range request is not of the form
+    /// `FILE:START-END`.
+    #[error("Invalid source line range: {0}")]
+    LineRangeNotValid(String),
+
     /// An error indicating that source file did not compile.
     #[error("Source file {0} would not compile")]
     SourceDoesNotCompile(String),
 
+    /// An error indicating that a file or a compiler produced JSON that is not an AST of the
+    /// expected language.
+    #[error("{file_name} does not contain a valid AST: {detail}")]
+    MalformedAST { file_name: String, detail: String },
+
+    /// An error indicating that the compiler failed while generating the AST of a source file.
+    /// The `status` is None if the compiler did not start or a signal stopped the compiler.
+    #[error(
+        "Compiler {compiler} failed to compile {file_name} (exit status {}): {stderr}",
+        status.map_or_else(|| String::from("none"), |s| s.to_string())
+    )]
+    CompilerInvocation {
+        compiler: String,
+        file_name: String,
+        status: Option<i32>,
+        stderr: String,
+    },
+
+    /// An error indicating that the compiler did not finish within the time limit and that the
+    /// tool stopped the compiler.
+    #[error("Compiler {compiler} did not finish compiling {file_name} within {seconds} second(s)")]
+    CompilerTimeout {
+        compiler: String,
+        file_name: String,
+        seconds: u64,
+    },
+
+    /// An error indicating that the AST does not contain a node with the requested id.
+    #[error("AST does not contain a node with id {id}")]
+    NodeNotFound { id: u64 },
+
+    /// An error indicating that a mutation algorithm cannot mutate the node with the requested id.
+    #[error("Mutation algorithm {mutation_type} cannot mutate the node with id {id}")]
+    NodeNotMutable { id: u64, mutation_type: String },
+
     /// An error indicating the tool could not determine the compiler version.
     #[error("Compiler {0} does not report its version number")]
     CompilerNoVersion(String),
 
+    /// An error indicating that the compiler reported warnings while generating the AST of a
+    /// source file and the settings treat compiler warnings as errors.
+    #[error("Compiler reported warnings for {0}: {1}")]
+    CompilerWarnings(String, String),
+
     /// An error indicating that the tool received a config file that it does not support or
     /// cannot support in the current function.
     #[error("Configuration file {0} not supported")]
@@ -102,6 +172,10 @@ pub enum MutagenyxError {
     /// in a language while mutating an AST.
     #[error("Unrecognized type: {0}")]
     UnrecognizedLanguageType(String),
+
+    /// An error for failures that do not have a more specific variant.
+    #[error("{0}")]
+    Other(String),
 }
 
 impl From<std::io::Error> for MutagenyxError {
@@ -5,11 +5,55 @@ use crate::error::MutagenyxError;
 use crate::id::Id;
 #[cfg(test)]
 use crate::json_ast_id_maker::JSONIDMaker;
+use serde::de::{DeserializeSeed, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
 use serde_json::{from_str, json, Map, Value};
+use std::fmt;
 use std::fs::File;
-use std::io::BufReader;
+use std::io::{BufRead, BufReader, Read};
 
-/// Reads the contents of the JSON in the file named by `file_name`.
+/// The byte order mark that some tools write at the start of UTF-8 files.
+static UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// Return the JSON text in `bytes` without a leading UTF-8 byte order mark and without trailing
+/// whitespace, along with the offset of the text in `bytes`.
+///
+/// # Arguments
+///
+/// * `bytes` - The contents of a JSON file.
+fn json_text(bytes: &[u8]) -> (&[u8], usize) {
+    let start = if bytes.starts_with(UTF8_BOM) {
+        UTF8_BOM.len()
+    } else {
+        0
+    };
+    let text = &bytes[start..];
+    let end = text
+        .iter()
+        .rposition(|b| !b.is_ascii_whitespace())
+        .map_or(0, |i| i + 1);
+    (&text[..end], start)
+}
+
+/// Return the offset in `text` of the byte at the one-based `line` and `column` that
+/// [`serde_json::Error`] reports.  Column 0 refers to the newline that ends the previous line.
+///
+/// # Arguments
+///
+/// * `text` - The JSON text.
+/// * `line` - The line of the byte.
+/// * `column` - The column of the byte in the line.
+fn byte_offset_for_position(text: &[u8], line: usize, column: usize) -> usize {
+    let line_start = text
+        .iter()
+        .enumerate()
+        .filter(|(_, b)| **b == b'\n')
+        .nth(line.saturating_sub(2))
+        .map_or(0, |(i, _)| if line > 1 { i + 1 } else { 0 });
+    (line_start + column).saturating_sub(1).min(text.len())
+}
+
+/// Reads the contents of the JSON in the file named by `file_name`.  The function ignores a
+/// UTF-8 byte order mark at the start of the file and whitespace at the end of the file.
 ///
 /// # Arguments
 ///
@@ -20,23 +64,147 @@ use std::io::BufReader;
 /// This function interacts with lower-level code to read JSON data from a file. As a result,
 /// the function may return [`MutagenyxError::IO`] that contains a [`std::io::Error`].
 ///
-/// Also, the function invokes the `serde_json` JSON parser.  The JSON parser may return a
-/// [`serde_json::Error`] which the function will return as a [`MutagenyxError::JSON`] enum that
-/// wraps the error from `serde_json`.
+/// If the file does not contain valid JSON, the function returns
+/// [`MutagenyxError::MalformedAST`] with the error from the `serde_json` JSON parser and the
+/// offset in the file of the byte where parsing failed.
 pub fn load_json_from_file_with_name(file_name: &str) -> Result<Value, MutagenyxError> {
-    let file = match File::open(file_name) {
-        Ok(f) => f,
-        Err(e) => return Err(MutagenyxError::from(e)),
-    };
+    let bytes = std::fs::read(file_name)?;
+    let (text, start) = json_text(&bytes);
+
+    match serde_json::from_slice(text) {
+        Ok(value) => Ok(value),
+        Err(e) => Err(MutagenyxError::MalformedAST {
+            file_name: String::from(file_name),
+            detail: format!(
+                "{} (byte offset {})",
+                e,
+                start + byte_offset_for_position(text, e.line(), e.column())
+            ),
+        }),
+    }
+}
 
-    let reader = BufReader::new(file);
+/// Reads the discriminators of the JSON in the file named by `file_name` without building the
+/// complete [`Value`] for the JSON.  Like [`load_json_from_file_with_name`], the function ignores
+/// a UTF-8 byte order mark at the start of the file and whitespace at the end of the file.
+///
+/// The function returns the top level of the JSON: scalar values keep their value, but the
+/// function replaces each nested object or array with an empty object or array.  The parser
+/// still reads the whole file, so the function detects malformed JSON, but it does not allocate
+/// the nested nodes.  The result contains enough information (such as the `nodeType` of a
+/// Solidity `SourceUnit` or the `ast` and `contract_name` keys of a Vyper AST) to recognize
+/// the language of an AST file before paying for a full parse.
+///
+/// # Arguments
+///
+/// * `file_name` - The string slice containing the path to the file in the file system.
+///
+/// # Errors
+///
+/// The function returns [`MutagenyxError::IO`] if it cannot read the file and
+/// [`MutagenyxError::JSON`] if the file does not contain valid JSON.
+pub fn scan_json_discriminators_from_file_with_name(
+    file_name: &str,
+) -> Result<Value, MutagenyxError> {
+    let file = File::open(file_name)?;
+    let mut reader = BufReader::new(file);
+    if reader.fill_buf()?.starts_with(UTF8_BOM) {
+        reader.consume(UTF8_BOM.len());
+    }
+    scan_json_discriminators(reader)
+}
 
-    let v = match serde_json::from_reader(reader) {
-        Ok(val) => val,
-        Err(e) => return Err(MutagenyxError::from(e)),
-    };
+/// Reads the discriminators of the JSON in `reader`.  See
+/// [`scan_json_discriminators_from_file_with_name`].
+///
+/// # Arguments
+///
+/// * `reader` - The source of the JSON text.
+fn scan_json_discriminators<R: Read>(reader: R) -> Result<Value, MutagenyxError> {
+    let mut deserializer = serde_json::Deserializer::from_reader(reader);
+    let discriminators = JSONShapeSeed { levels: 1 }.deserialize(&mut deserializer)?;
+    deserializer.end()?;
+    Ok(discriminators)
+}
+
+/// Deserialize a JSON value, keeping only the first `levels` levels of nested objects and
+/// arrays.  Deeper objects and arrays become empty.
+struct JSONShapeSeed {
+    /// The number of container levels to keep.
+    levels: usize,
+}
+
+impl<'de> DeserializeSeed<'de> for JSONShapeSeed {
+    type Value = Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for JSONShapeSeed {
+    type Value = Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a JSON value")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Value, E> {
+        Ok(Value::Bool(v))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Value, E> {
+        Ok(Value::from(v))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Value, E> {
+        Ok(Value::from(v))
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Value, E> {
+        Ok(Value::from(v))
+    }
 
-    Ok(v)
+    fn visit_str<E>(self, v: &str) -> Result<Value, E> {
+        Ok(Value::from(v))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Value, E> {
+        Ok(Value::String(v))
+    }
+
+    fn visit_unit<E>(self) -> Result<Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Value, A::Error> {
+        let mut elements: Vec<Value> = vec![];
+        if self.levels == 0 {
+            while seq.next_element::<IgnoredAny>()?.is_some() {}
+        } else {
+            while let Some(element) = seq.next_element_seed(JSONShapeSeed {
+                levels: self.levels - 1,
+            })? {
+                elements.push(element);
+            }
+        }
+        Ok(Value::Array(elements))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Value, A::Error> {
+        let mut entries: Map<String, Value> = Map::new();
+        if self.levels == 0 {
+            while map.next_entry::<IgnoredAny, IgnoredAny>()?.is_some() {}
+        } else {
+            while let Some(key) = map.next_key::<String>()? {
+                let value = map.next_value_seed(JSONShapeSeed {
+                    levels: self.levels - 1,
+                })?;
+                entries.insert(key, value);
+            }
+        }
+        Ok(Value::Object(entries))
+    }
 }
 
 /// Add a '/' character as a prefix to a string slice.
@@ -54,6 +222,36 @@ fn json_path(path: &str) -> String {
     jp
 }
 
+/// Return a reference to the value at `path` in `value`.
+///
+/// Most lookups use a single key, so the function looks the key up directly in the JSON map and
+/// only builds a JSON pointer (an allocation for the pointer text and for each path segment) when
+/// `path` contains more than one segment.
+///
+/// # Arguments
+///
+/// * `value` - The [`Value`] object to search.
+/// * `path` - The `/` separated path of keys.
+fn value_for_path<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    if value.is_object() && !path.contains(['/', '~']) {
+        return value.get(path);
+    }
+    value.pointer(&json_path(path))
+}
+
+/// Return a mutable reference to the value at `path` in `value`.
+///
+/// # Arguments
+///
+/// * `value` - The [`Value`] object to search.
+/// * `path` - The `/` separated path of keys.
+fn value_for_path_mut<'a>(value: &'a mut Value, path: &str) -> Option<&'a mut Value> {
+    if value.is_object() && !path.contains(['/', '~']) {
+        return value.get_mut(path);
+    }
+    value.pointer_mut(&json_path(path))
+}
+
 /// Return a JSON node by creating the node from `text`.
 ///
 /// # Arguments
@@ -107,8 +305,7 @@ impl JSONMutate for Value {
     ///
     /// * `key` - A string slice referring to the text of the key.
     fn has_value_for_key(&self, key: &str) -> bool {
-        let json_key = json_path(key);
-        matches!(self.pointer(&json_key), Some(_))
+        matches!(value_for_path(self, key), Some(_))
     }
 
     /// Assuming the [`Value`] object represents a JSON dictionary/map object, then
@@ -123,8 +320,7 @@ impl JSONMutate for Value {
     ///
     /// * `key` - The string slice referencing the text comprising the key.
     fn take_value_for_key(&mut self, key: &str) -> Option<Value> {
-        let json_key = json_path(key);
-        self.pointer_mut(&json_key).map(|v| v.take())
+        value_for_path_mut(self, key).map(|v| v.take())
     }
 
     /// Assuming the [`Value`] object represents a JSON dictionary/map object, then
@@ -139,8 +335,7 @@ impl JSONMutate for Value {
     /// * `node` - The [`Value`] object to use as the new value for `key` in the JSON
     /// dictionary.
     fn set_node_for_key(&mut self, key: &str, node: Value) {
-        let json_path = json_path(key);
-        if let Some(v) = self.pointer_mut(&json_path) {
+        if let Some(v) = value_for_path_mut(self, key) {
             *v = node;
         } else {
             // The node does not have an existing value for `key`.  We need to get
@@ -164,8 +359,7 @@ impl JSONMutate for Value {
     /// * `index` - The index in the JSON array that will contain `node`.
     /// * `node` - The [`Value`] object to insert into the array.
     fn set_node_for_key_at_index(&mut self, key: &str, index: usize, node: Value) {
-        let json_path = json_path(key);
-        if let Some(v) = self.pointer_mut(&json_path) {
+        if let Some(v) = value_for_path_mut(self, key) {
             if let Some(a) = v.get_mut(index) {
                 *a = node;
             }
@@ -197,8 +391,7 @@ impl JSONMutate for Value {
     ///
     /// * `key` - The string slice referencing the text comprising the key.
     fn get_array_for_key(&self, key: &str) -> Option<&Vec<Value>> {
-        let json_path = json_path(key);
-        match self.pointer(&json_path) {
+        match value_for_path(self, key) {
             Some(v) => v.as_array(),
             _ => None,
         }
@@ -215,8 +408,7 @@ impl JSONMutate for Value {
     ///
     /// * `key` - The string slice referencing the text comprising the key.
     fn get_array_for_key_mut(&mut self, key: &str) -> Option<&mut Vec<Value>> {
-        let json_path = json_path(key);
-        match self.pointer_mut(&json_path) {
+        match value_for_path_mut(self, key) {
             Some(v) => v.as_array_mut(),
             _ => None,
         }
@@ -233,8 +425,7 @@ impl JSONMutate for Value {
     ///
     /// * `key` - The string slice referencing the text comprising the key.
     fn get_map_for_key(&self, key: &str) -> Option<&Map<String, Value>> {
-        let json_path = json_path(key);
-        match self.pointer(&json_path) {
+        match value_for_path(self, key) {
             Some(v) => v.as_object(),
             _ => None,
         }
@@ -251,8 +442,7 @@ impl JSONMutate for Value {
     ///
     /// * `key` - The string slice referencing the text comprising the key.
     fn get_map_for_key_mut(&mut self, key: &str) -> Option<&mut Map<String, Value>> {
-        let json_path = json_path(key);
-        match self.pointer_mut(&json_path) {
+        match value_for_path_mut(self, key) {
             Some(v) => v.as_object_mut(),
             _ => None,
         }
@@ -269,8 +459,7 @@ impl JSONMutate for Value {
     ///
     /// * `key` - The string slice referencing the text comprising the key.
     fn get_str_for_key(&self, key: &str) -> Option<&str> {
-        let json_path = json_path(key);
-        match self.pointer(&json_path) {
+        match value_for_path(self, key) {
             Some(v) => v.as_str(),
             _ => None,
         }
@@ -287,8 +476,7 @@ impl JSONMutate for Value {
     /// * `key` - The string slice referencing the text comprising the key.
     /// * `value` - The string slice referencing the text comprising the value.
     fn set_str_for_key(&mut self, key: &str, value: &str) {
-        let json_path = json_path(key);
-        match self.pointer_mut(&json_path) {
+        match value_for_path_mut(self, key) {
             Some(v) => v.set_string(value),
             _ => {
                 // The json object has no existing value for 'key'.  Get the map and insert it.
@@ -309,8 +497,7 @@ impl JSONMutate for Value {
     ///
     /// * `key` - The string slice referencing the text comprising the key.
     fn get_bool_for_key(&self, key: &str) -> Option<bool> {
-        let json_path = json_path(key);
-        match self.pointer(&json_path) {
+        match value_for_path(self, key) {
             Some(v) => v.as_bool(),
             _ => None,
         }
@@ -326,8 +513,7 @@ impl JSONMutate for Value {
     ///
     /// * `key` - The string slice referencing the text comprising the key.
     fn get_int_for_key(&self, key: &str) -> Option<i64> {
-        let json_path = json_path(key);
-        match self.pointer(&json_path) {
+        match value_for_path(self, key) {
             Some(v) => v.as_i64(),
             _ => None,
         }
@@ -343,8 +529,7 @@ impl JSONMutate for Value {
     ///
     /// * `key` - The string slice referencing the text comprising the key.
     fn get_float_for_key(&self, key: &str) -> Option<f64> {
-        let json_path = json_path(key);
-        match self.pointer(&json_path) {
+        match value_for_path(self, key) {
             Some(v) => v.as_f64(),
             _ => None,
         }
@@ -435,6 +620,21 @@ impl JSONMutate for Value {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_scan_json_discriminators() {
+        let text = "{\"nodeType\": \"SourceUnit\", \"id\": 7, \"nodes\": [{\"id\": 1}], \
+            \"ast\": {\"ast_type\": \"Module\"}}";
+        let discriminators = scan_json_discriminators(text.as_bytes()).unwrap();
+        assert_eq!(
+            discriminators,
+            json!({"nodeType": "SourceUnit", "id": 7, "nodes": [], "ast": {}})
+        );
+        assert!(discriminators.has_value_for_key("ast"));
+
+        assert!(scan_json_discriminators("{\"nodeType\": [1, 2}".as_bytes()).is_err());
+        assert!(scan_json_discriminators("{} trailing".as_bytes()).is_err());
+    }
+
     #[test]
     fn test_json_mutate_set_string() {
         let mut value = Value::Null;
@@ -797,4 +997,68 @@ mod tests {
             assert!(false, "Get_node_with_id failed to locate node with id=11.");
         }
     }
+
+    #[test]
+    fn test_json_mutate_get_str_for_key_paths() {
+        let value: Value = from_str(
+            "{\
+            \"nodeType\": \"FunctionCall\",
+            \"expression\": {\"name\": \"foo\"},
+            \"arguments\": [{\"name\": \"a\"}]
+        }",
+        )
+        .unwrap();
+
+        assert_eq!(value.get_str_for_key("nodeType"), Some("FunctionCall"));
+        assert_eq!(value.get_str_for_key("expression/name"), Some("foo"));
+        assert_eq!(value.get_str_for_key("arguments/0/name"), Some("a"));
+        assert_eq!(value.get_str_for_key("missing"), None);
+
+        let array = value.get("arguments").unwrap();
+        assert!(array.get_map_for_key("0").is_some());
+    }
+
+    #[test]
+    fn test_load_json_with_bom_and_trailing_whitespace() {
+        let directory = std::env::temp_dir().join("mutagenyx_json_loader");
+        let _ = std::fs::remove_dir_all(&directory);
+        std::fs::create_dir_all(&directory).unwrap();
+        let expected = json!({"nodeType": "SourceUnit", "id": 3, "nodes": []});
+
+        let bom_file = directory.join("bom.json");
+        let mut contents = UTF8_BOM.to_vec();
+        contents.extend_from_slice(expected.to_string().as_bytes());
+        std::fs::write(&bom_file, &contents).unwrap();
+        let bom_file = bom_file.to_str().unwrap();
+        assert_eq!(load_json_from_file_with_name(bom_file).unwrap(), expected);
+        assert_eq!(
+            scan_json_discriminators_from_file_with_name(bom_file).unwrap(),
+            expected
+        );
+
+        let newline_file = directory.join("newline.json");
+        std::fs::write(&newline_file, expected.to_string() + "\n\r\n\t ").unwrap();
+        let newline_file = newline_file.to_str().unwrap();
+        assert_eq!(
+            load_json_from_file_with_name(newline_file).unwrap(),
+            expected
+        );
+
+        // The error names the offset in the file of the byte that stopped the parser.
+        let broken_file = directory.join("broken.json");
+        contents.truncate(UTF8_BOM.len());
+        contents.extend_from_slice(b"{\n  \"id\": 3,\n  \"nodes\": ]\n}\n");
+        std::fs::write(&broken_file, &contents).unwrap();
+        let broken_file = broken_file.to_str().unwrap();
+        let offset = contents.iter().position(|b| *b == b']').unwrap();
+        match load_json_from_file_with_name(broken_file) {
+            Err(MutagenyxError::MalformedAST { file_name, detail }) => {
+                assert_eq!(file_name, broken_file);
+                assert!(detail.ends_with(&format!("(byte offset {})", offset)));
+            }
+            _ => panic!("expected a malformed AST error"),
+        }
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
 }
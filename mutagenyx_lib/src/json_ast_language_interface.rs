@@ -3,35 +3,60 @@
 //! a trait object for [`JSONLanguageDelegate`] to provide the behavior that will differ from
 //! language to language.
 
+use crate::annotation::AnnotationStyle;
 use crate::ast::ASTTraverser;
 use crate::error::MutagenyxError;
 use crate::json::*;
 use crate::json_language_delegate::JSONLanguageDelegate;
 use crate::language::Language;
 use crate::language_interface::{MutableLanguage, MutateASTResult};
+use crate::line_table::LineTable;
 use crate::mutation::MutationType;
 use crate::mutation_visitor::*;
 use crate::mutator::*;
+use crate::operators::OperatorRegistry;
 use crate::permissions::Permissions;
 use crate::preferences::Preferences;
 use crate::pretty_printer::PrettyPrinter;
 use crate::recognizer::FileType;
 use crate::super_ast::SuperAST;
+use rand::SeedableRng;
 use rand_pcg::Pcg64;
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::env;
 use std::io::Write;
+use std::ops::RangeInclusive;
 use std::path::PathBuf;
 
 /// The interface object for the programming languages with JSON encoded ASTs.
 pub struct JSONLanguageInterface {
     /// The map of mutators that the interface object can use when
-    /// mutating a JSON-based AST.
-    mutators: HashMap<MutationType, Box<dyn Mutator<Value>>>,
+    /// mutating a JSON-based AST.  The map is ordered by [`MutationType`] so that the mutators
+    /// visit nodes in a deterministic order.
+    mutators: BTreeMap<MutationType, Box<dyn Mutator<Value>>>,
 
     /// The language specific delegate to use for invoking language-specific behavior.
     delegate: Box<dyn JSONLanguageDelegate>,
+
+    /// The style of the comments inserted next to mutated nodes.
+    annotation_style: AnnotationStyle,
+
+    /// The operators that the operator mutators use.
+    operators: OperatorRegistry,
+
+    /// True if the interface logs the selection of mutable nodes.
+    trace_mutation_selection: bool,
+
+    /// True if the mutators skip the pragma directives of the program.
+    exclude_pragma_nodes: bool,
+
+    /// The ranges of source lines that the mutators may mutate, empty to mutate every line.
+    mutation_lines: Vec<RangeInclusive<usize>>,
+
+    /// The line table of the source file, used to find the line of the nodes for
+    /// `mutation_lines`.
+    mutation_line_table: Option<LineTable>,
 }
 
 impl JSONLanguageInterface {
@@ -42,8 +67,14 @@ impl JSONLanguageInterface {
     /// * `sub_interface` - The language specific delegate to use for language-specific behavior.
     pub fn new(delegate: Box<dyn JSONLanguageDelegate>) -> JSONLanguageInterface {
         JSONLanguageInterface {
-            mutators: HashMap::new(),
+            mutators: BTreeMap::new(),
             delegate,
+            annotation_style: AnnotationStyle::default(),
+            operators: OperatorRegistry::default(),
+            trace_mutation_selection: false,
+            exclude_pragma_nodes: true,
+            mutation_lines: Vec::new(),
+            mutation_line_table: None,
         }
     }
 
@@ -59,6 +90,51 @@ impl JSONLanguageInterface {
     }
 }
 
+/// Return the function that selects the nodes that lie entirely on the lines in `lines`, or
+/// None if `lines` is empty and the mutators may mutate nodes on every line.  A mutator that
+/// changes a statement of a block, such as the DeleteStatement mutator, matches the block, so
+/// the selector only selects the block when the whole block, and therefore any statement the
+/// mutator picks, lies on the selected lines.
+///
+/// # Arguments
+///
+/// * `delegate` - The language specific delegate that finds the line of a node.
+/// * `lines` - The ranges of source lines to mutate.
+/// * `line_table` - The line table of the source file, if available.
+fn line_selector<'a>(
+    delegate: &'a dyn JSONLanguageDelegate,
+    lines: &'a [RangeInclusive<usize>],
+    line_table: Option<&'a LineTable>,
+) -> Option<NodeSelector<'a, Value>> {
+    if lines.is_empty() {
+        return None;
+    }
+    Some(Box::new(move |node: &Value| {
+        delegate
+            .get_source_lines_for_node(node, line_table)
+            .is_some_and(|mut span| span.all(|line| lines.iter().any(|r| r.contains(&line))))
+    }))
+}
+
+/// Return true if `error` from a mutator means that the mutator could not mutate the node after
+/// all: the node lacks an element that the mutator needs, or the mutator has no alternative to
+/// choose.  Other errors, such as a failure to hash the mutation or to generate a new node, are
+/// real errors.
+///
+/// # Arguments
+///
+/// * `error` - The error returned by the mutator.
+fn is_node_not_mutated_error(error: &MutagenyxError) -> bool {
+    matches!(
+        error,
+        MutagenyxError::MalformedNode(..)
+            | MutagenyxError::IncorrectJSONNodeType(..)
+            | MutagenyxError::UnrecognizedLanguageType(..)
+            | MutagenyxError::RandomOperationFailure(..)
+            | MutagenyxError::NodeNotMutable { .. }
+    )
+}
+
 impl MutableLanguage for JSONLanguageInterface {
     fn load_ast_from_file(
         &mut self,
@@ -72,7 +148,15 @@ impl MutableLanguage for JSONLanguageInterface {
                 let ast = load_json_from_file_with_name(file_name)?;
 
                 // Defer the conversion of the JSON to the AST to the delegate.
-                self.delegate.get_value_as_super_ast(ast)
+                self.delegate
+                    .get_value_as_super_ast(ast)
+                    .map_err(|e| match e {
+                        MutagenyxError::LanguageNotRecognized => MutagenyxError::MalformedAST {
+                            file_name: String::from(file_name),
+                            detail: format!("the JSON is not a {} AST", self.implements()),
+                        },
+                        e => e,
+                    })
             }
             FileType::Config => Err(MutagenyxError::ConfigFileNotSupported(String::from(
                 file_name,
@@ -85,7 +169,7 @@ impl MutableLanguage for JSONLanguageInterface {
         mutation_types: &[MutationType],
     ) -> Result<(), MutagenyxError> {
         // Get the mutator factory
-        let mutator_factory = self.delegate.get_mutator_factory();
+        let mutator_factory = self.delegate.get_mutator_factory(&self.operators);
 
         // Walk through the list of mutation types and convert the list into
         // a list of mutators that implement the mutation type.
@@ -102,6 +186,50 @@ impl MutableLanguage for JSONLanguageInterface {
         Ok(())
     }
 
+    fn add_custom_mutator(&mut self, mutator: Box<dyn Mutator<Value>>) {
+        self.mutators.insert(mutator.implements(), mutator);
+    }
+
+    fn mutator_description(&self, mutation_type: &MutationType) -> Option<MutatorDescription> {
+        let describe = |mutator: &dyn Mutator<Value>| MutatorDescription {
+            description: mutator.description(),
+            example: mutator.example(),
+        };
+        match self.mutators.get(mutation_type) {
+            Some(mutator) => Some(describe(mutator.as_ref())),
+            None => self
+                .delegate
+                .get_mutator_factory(&self.operators)
+                .mutator_for(mutation_type)
+                .map(|m| describe(m.as_ref())),
+        }
+    }
+
+    fn trace_mutation_selection(&mut self, trace: bool) {
+        self.trace_mutation_selection = trace;
+    }
+
+    fn exclude_pragma_nodes(&mut self, exclude: bool) {
+        self.exclude_pragma_nodes = exclude;
+    }
+
+    fn restrict_mutations_to_lines(
+        &mut self,
+        lines: &[RangeInclusive<usize>],
+        source: Option<&str>,
+    ) {
+        self.mutation_lines = lines.to_vec();
+        self.mutation_line_table = source.map(LineTable::new);
+    }
+
+    fn select_annotation_style(&mut self, style: AnnotationStyle) {
+        self.annotation_style = style;
+    }
+
+    fn select_operator_registry(&mut self, operators: OperatorRegistry) {
+        self.operators = operators;
+    }
+
     fn count_mutable_nodes(
         &mut self,
         ast: &SuperAST,
@@ -112,8 +240,35 @@ impl MutableLanguage for JSONLanguageInterface {
         let namer = self.delegate.get_namer();
         let actual_ast = self.recover_json_ast(ast)?;
 
+        let comment_detector = self.delegate.get_comment_detector();
+
         let mut counter_visitor: MutableNodesCounter<Value> =
-            MutableNodesCounter::new(&mut self.mutators, permitter, rng, namer);
+            MutableNodesCounter::new(&mut self.mutators, permitter, rng, namer, comment_detector);
+        if self.trace_mutation_selection {
+            let id_maker = self.delegate.get_node_id_maker();
+            counter_visitor = counter_visitor.trace_selection(Box::new(move |node: &Value| {
+                let id = id_maker
+                    .get_id(node)
+                    .map_or_else(|| String::from("<no id>"), |id| id.to_string());
+                let node_type = node
+                    .get_str_for_key("nodeType")
+                    .or_else(|| node.get_str_for_key("ast_type"))
+                    .unwrap_or("<unknown type>");
+                format!("{id} ({node_type})")
+            }));
+        }
+        if self.exclude_pragma_nodes {
+            let delegate = &self.delegate;
+            counter_visitor = counter_visitor
+                .exclude_nodes(Box::new(move |node: &Value| delegate.is_pragma_node(node)));
+        }
+        if let Some(selector) = line_selector(
+            self.delegate.as_ref(),
+            &self.mutation_lines,
+            self.mutation_line_table.as_ref(),
+        ) {
+            counter_visitor = counter_visitor.select_nodes(selector);
+        }
 
         // Traverse the AST and count the number of nodes that a mutator can mutate for each
         // mutation type supported in the mutator map.
@@ -129,6 +284,46 @@ impl MutableLanguage for JSONLanguageInterface {
         Ok(node_map)
     }
 
+    fn list_mutable_nodes(
+        &mut self,
+        ast: &SuperAST,
+        rng: &mut Pcg64,
+        permissions: &Permissions,
+        source: Option<&str>,
+    ) -> Result<Vec<MutableNode>, MutagenyxError> {
+        let permitter = self.delegate.get_node_permitter(permissions);
+        let namer = self.delegate.get_namer();
+        let id_maker = self.delegate.get_node_id_maker();
+        let comment_detector = self.delegate.get_comment_detector();
+        let actual_ast = self.recover_json_ast(ast)?;
+        let delegate = &self.delegate;
+        let line_table = source.map(LineTable::new);
+
+        let mut counter_visitor: MutableNodesCounter<Value> =
+            MutableNodesCounter::new(&mut self.mutators, permitter, rng, namer, comment_detector)
+                .collect_mutable_nodes(
+                    id_maker,
+                    Box::new(move |node: &Value| {
+                        delegate.get_source_line_for_node(node, line_table.as_ref())
+                    }),
+                );
+        if self.exclude_pragma_nodes {
+            counter_visitor = counter_visitor
+                .exclude_nodes(Box::new(move |node: &Value| delegate.is_pragma_node(node)));
+        }
+        if let Some(selector) = line_selector(
+            delegate.as_ref(),
+            &self.mutation_lines,
+            self.mutation_line_table.as_ref(),
+        ) {
+            counter_visitor = counter_visitor.select_nodes(selector);
+        }
+
+        ASTTraverser::traverse(actual_ast, &mut counter_visitor);
+
+        Ok(counter_visitor.mutable_nodes)
+    }
+
     fn mutate_ast(
         &mut self,
         ast: &SuperAST,
@@ -141,6 +336,7 @@ impl MutableLanguage for JSONLanguageInterface {
         let permitter = self.delegate.get_node_permitter(permissions);
         let namer = self.delegate.get_namer();
         let id_maker = self.delegate.get_node_id_maker();
+        let comment_detector = self.delegate.get_comment_detector();
 
         let actual_ast = self.recover_json_ast(ast)?;
 
@@ -153,19 +349,58 @@ impl MutableLanguage for JSONLanguageInterface {
             permitter,
             id_maker,
             namer,
+            comment_detector,
         );
+        if self.exclude_pragma_nodes {
+            let delegate = &self.delegate;
+            mutation_maker = mutation_maker
+                .exclude_nodes(Box::new(move |node: &Value| delegate.is_pragma_node(node)));
+        }
+        if let Some(selector) = line_selector(
+            self.delegate.as_ref(),
+            &self.mutation_lines,
+            self.mutation_line_table.as_ref(),
+        ) {
+            mutation_maker = mutation_maker.select_nodes(selector);
+        }
 
         // Traverse the cloned AST, only mutating the index(th) node in the tree that the mutation
         // maker can mutate for `mutation_type`.
         ASTTraverser::traverse_mut(&mut mutated_ast, &mut mutation_maker);
 
+        // A mutator that cannot mutate the node after all does not produce a mutant.  Report the
+        // failure with an empty result so the caller can account for the failed attempt and move
+        // on, but pass on the errors that do not depend on the node.
         if let Some(e) = mutation_maker.error {
-            return Err(e);
+            if !is_node_not_mutated_error(&e) {
+                return Err(e);
+            }
+            log::debug!(
+                "{} mutator failed to mutate node {}: {}",
+                mutation_type,
+                index,
+                e
+            );
+        }
+
+        if !mutation_maker.mutation_made {
+            let mut mutate_ast_result = MutateASTResult::new();
+            mutate_ast_result.mutator_result.mutation_type = Some(*mutation_type);
+            mutate_ast_result.mutator_result.index = Some(index);
+            return Ok(mutate_ast_result);
         }
 
         // We now have the path map and the index of the node we mutated in mutation_maker.mutated_node_id.
         // We can now walk down the ast to insert a comment.
-        if let Some(node) = mutation_maker.mutator_comment {
+        if let Some(mut node) = mutation_maker.mutator_comment {
+            let text_key = self.delegate.get_comment_text_key();
+            if let Some(text) = node.get_str_for_key(text_key) {
+                let annotation = self
+                    .annotation_style
+                    .annotate(text, &mutation_maker.mutation_results);
+                node.set_str_for_key(text_key, &annotation);
+            }
+
             if let Some(node_path) = path_map.get(&mutation_maker.mutated_node_id) {
                 self.delegate
                     .insert_comment_by_path(&mut mutated_ast, node, node_path);
@@ -182,6 +417,38 @@ impl MutableLanguage for JSONLanguageInterface {
         Ok(mutate_ast_result)
     }
 
+    fn apply_mutation_at_node(
+        &self,
+        ast: &SuperAST,
+        mutation_type: &MutationType,
+        node_id: u64,
+    ) -> Result<SuperAST, MutagenyxError> {
+        let mut mutator = self
+            .delegate
+            .get_mutator_factory(&self.operators)
+            .mutator_for(mutation_type)
+            .ok_or_else(|| {
+                MutagenyxError::MutationAlgorithmNotSupported(mutation_type.to_string())
+            })?;
+
+        let mut mutated_ast = self.recover_json_ast(ast)?.clone();
+        let id_maker = self.delegate.get_node_id_maker();
+        let node = mutated_ast
+            .get_node_with_id_mut(node_id, id_maker.as_ref())
+            .ok_or(MutagenyxError::NodeNotFound { id: node_id })?;
+
+        let mut rng = Pcg64::seed_from_u64(0);
+        if !mutator.is_mutable_node(node, &mut rng) {
+            return Err(MutagenyxError::NodeNotMutable {
+                id: node_id,
+                mutation_type: mutation_type.to_string(),
+            });
+        }
+        mutator.mutate(node, &mut rng)?;
+
+        self.delegate.get_value_as_super_ast(mutated_ast)
+    }
+
     fn pretty_print_ast_to_file(
         &mut self,
         ast: &SuperAST,
@@ -197,6 +464,12 @@ impl MutableLanguage for JSONLanguageInterface {
 
         // Traverse each node of the tree, process the node, and recover the original program.
         ASTTraverser::traverse(actual_ast, &mut *pretty_print_visitor);
+        drop(pretty_print_visitor);
+
+        // Make sure the file contents reach the disk before returning so that anything
+        // watching the output directory sees a complete file.
+        f.flush()?;
+        f.sync_all()?;
 
         Ok(())
     }
@@ -215,6 +488,9 @@ impl MutableLanguage for JSONLanguageInterface {
 
         // Traverse each node of the tree, process the node, and recover the original program.
         ASTTraverser::traverse(actual_ast, &mut *pretty_print_visitor);
+        drop(pretty_print_visitor);
+
+        stream.flush()?;
 
         Ok(())
     }
@@ -236,22 +512,26 @@ impl MutableLanguage for JSONLanguageInterface {
     }
 
     fn file_is_language_ast_file(&self, file_name: &str) -> bool {
-        if let Ok(ast_candidate) = load_json_from_file_with_name(file_name) {
-            return self.delegate.json_is_language_ast_json(&ast_candidate);
+        if let Ok(discriminators) = scan_json_discriminators_from_file_with_name(file_name) {
+            return self.discriminators_are_language_ast(&discriminators);
         }
         false
     }
 
+    fn discriminators_are_language_ast(&self, discriminators: &Value) -> bool {
+        self.delegate.json_is_language_ast_json(discriminators)
+    }
+
     fn default_compiler_settings(&self) -> Preferences {
         self.delegate.default_compiler_settings()
     }
 
-    fn mutant_compiles(
+    fn check_mutant_compiles(
         &mut self,
         original_file_name: &str,
         ast: &SuperAST,
         prefs: &Preferences,
-    ) -> bool {
+    ) -> Result<(), MutagenyxError> {
         // We will pretty print the AST to a file in the temp directory.
         let mut source_file = env::temp_dir();
 
@@ -261,16 +541,11 @@ impl MutableLanguage for JSONLanguageInterface {
 
         let mut pretty_printer = PrettyPrinter::new(4, 150);
 
-        if self
-            .pretty_print_ast_to_file(ast, source_file.to_str().unwrap(), &mut pretty_printer)
-            .is_err()
-        {
-            return false;
-        }
+        self.pretty_print_ast_to_file(ast, source_file.to_str().unwrap(), &mut pretty_printer)?;
 
         let compile_result = self
             .delegate
-            .mutant_compiles(source_file.to_str().unwrap(), prefs);
+            .check_mutant_compiles(source_file.to_str().unwrap(), prefs);
 
         if std::fs::remove_file(source_file.clone()).is_err() {
             log::debug!("Failed to remove temporary source file: {:?}", source_file);
@@ -279,6 +554,22 @@ impl MutableLanguage for JSONLanguageInterface {
         compile_result
     }
 
+    fn source_line_for_node(
+        &self,
+        ast: &SuperAST,
+        node_id: u64,
+        source: &str,
+    ) -> Result<Option<usize>, MutagenyxError> {
+        let actual_ast = self.recover_json_ast(ast)?;
+        let id_maker = self.delegate.get_node_id_maker();
+        let node = actual_ast
+            .get_node_with_id(node_id, id_maker.as_ref())
+            .ok_or(MutagenyxError::NodeNotFound { id: node_id })?;
+        Ok(self
+            .delegate
+            .get_source_line_for_node(node, Some(&LineTable::new(source))))
+    }
+
     fn calculate_node_paths(
         &mut self,
         ast: &SuperAST,
@@ -288,7 +579,8 @@ impl MutableLanguage for JSONLanguageInterface {
         let permitter = self.delegate.get_node_permitter(permissions);
         let namer = self.delegate.get_namer();
         let id_maker = self.delegate.get_node_id_maker();
-        let mut path_visitor = PathVisitor::new(permitter, id_maker, namer);
+        let comment_detector = self.delegate.get_comment_detector();
+        let mut path_visitor = PathVisitor::new(permitter, id_maker, namer, comment_detector);
 
         // Walk the AST and calculate the path to each node in the AST.
         ASTTraverser::traverse(actual_ast, &mut path_visitor);
@@ -300,3 +592,564 @@ impl MutableLanguage for JSONLanguageInterface {
         self.delegate.implements()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language::all_languages;
+    use crate::language_interface::LanguageInterface;
+    use crate::mutation::{all_mutation_types, GenericMutation};
+    use crate::mutator_result::MutatorResult;
+    use crate::recognizer::Recognizer;
+    use crate::test_fixtures::{
+        all_permissions, compiler_preferences, solidity_ast_with_statements,
+    };
+    use serde_json::json;
+
+    /// Return the AST of a contract that an earlier mutation changed by deleting the statement
+    /// `a + b;`.  The AST holds the comment that describes the mutation and the comment that
+    /// holds the deleted statement.
+    fn mutated_solidity_ast() -> Value {
+        let addition = |id: u64| {
+            json!({
+                "nodeType": "BinaryOperation", "id": id, "operator": "+",
+                "leftExpression": {"nodeType": "Identifier", "id": id + 1, "name": "a"},
+                "rightExpression": {"nodeType": "Identifier", "id": id + 2, "name": "b"},
+                "typeDescriptions": {"typeIdentifier": "t_uint256", "typeString": "uint256"}
+            })
+        };
+        solidity_ast_with_statements(json!([
+            {
+                "nodeType": "Comment", "id": 9999997,
+                "text": "DeleteStatement Mutator: deleted statement 'a + b;'"
+            },
+            {
+                "nodeType": "Comment", "id": 25, "value": {
+                    "nodeType": "ExpressionStatement", "id": 25,
+                    "expression": addition(20)
+                }
+            },
+            {"nodeType": "Return", "id": 29, "expression": addition(26)}
+        ]))
+    }
+
+    #[test]
+    fn test_mutate_ast_with_comment_nodes() {
+        let file_name = env::temp_dir().join("mutagenyx_commented_ast.json");
+        let file_name = file_name.to_str().unwrap();
+        std::fs::write(file_name, mutated_solidity_ast().to_string()).unwrap();
+
+        let mut prefs = Preferences::new();
+        let recognizer = Recognizer::new(&mut prefs);
+        let recognized = recognizer.recognize_ast_file(file_name).unwrap();
+        assert_eq!(recognized.language, Language::Solidity);
+
+        let mut language_object =
+            LanguageInterface::get_language_object_for_language(&Language::Solidity).unwrap();
+        let ast = language_object
+            .load_ast_from_file(file_name, &FileType::AST, &prefs)
+            .unwrap();
+        std::fs::remove_file(file_name).unwrap();
+
+        // The original program pretty-prints with the comments from the earlier mutation.
+        let mut printed: Vec<u8> = Vec::new();
+        let mut pretty_printer = PrettyPrinter::new(4, 150);
+        language_object
+            .pretty_print_ast_to_stream(&ast, &mut printed, &mut pretty_printer)
+            .unwrap();
+        let printed = String::from_utf8(printed).unwrap();
+        assert!(printed.contains("DeleteStatement Mutator: deleted statement 'a + b;'"));
+
+        // Only the addition in the return statement is mutable, the addition in the deleted
+        // statement belongs to a comment.
+        let mutation_type = MutationType::Generic(GenericMutation::ArithmeticBinaryOp);
+        language_object
+            .select_mutators_for_mutation_types(&[mutation_type])
+            .unwrap();
+        let permissions = all_permissions();
+        let mut rng = Pcg64::seed_from_u64(42);
+        let counts = language_object
+            .count_mutable_nodes(&ast, &mut rng, &permissions)
+            .unwrap();
+        assert_eq!(counts[&mutation_type], 1);
+
+        let path_map = language_object
+            .calculate_node_paths(&ast, &permissions)
+            .unwrap();
+        let result = language_object
+            .mutate_ast(&ast, &mutation_type, 0, &mut rng, &permissions, &path_map)
+            .unwrap();
+        assert_eq!(result.mutator_result.mutated_node_id, Some(26));
+
+        let mut mutated: Vec<u8> = Vec::new();
+        language_object
+            .pretty_print_ast_to_stream(&result.ast.unwrap(), &mut mutated, &mut pretty_printer)
+            .unwrap();
+        let mutated = String::from_utf8(mutated).unwrap();
+        assert!(mutated.contains("DeleteStatement Mutator: deleted statement 'a + b;'"));
+        assert!(mutated.contains("ArithmeticBinaryOp Mutator"));
+    }
+
+    /// A mutator from outside the library that tags identifier nodes.
+    struct TagMutator {}
+
+    impl Mutator<Value> for TagMutator {
+        fn is_mutable_node(&mut self, node: &Value, _rand: &mut Pcg64) -> bool {
+            node.get_str_for_key("nodeType") == Some("Identifier")
+        }
+
+        fn mutate(
+            &mut self,
+            node: &mut Value,
+            _rand: &mut Pcg64,
+        ) -> Result<MutatorResult, MutagenyxError> {
+            node.set_node_for_key("tagged", json!(true));
+            let mut mutator_result = MutatorResult::new();
+            mutator_result.mutation_type = Some(self.implements());
+            mutator_result.mutated_node_id = node.get_int_for_key("id").map(|id| id as u64);
+            Ok(mutator_result)
+        }
+
+        fn implements(&self) -> MutationType {
+            MutationType::Custom("Tag")
+        }
+
+        fn description(&self) -> &'static str {
+            "Tag the right hand side of a binary operation."
+        }
+    }
+
+    #[test]
+    fn test_custom_mutator() {
+        let ast = SuperAST::Solidity(solidity_ast_with_statements(json!([{
+            "nodeType": "ExpressionStatement", "id": 60, "expression": {
+                "nodeType": "BinaryOperation", "id": 61, "operator": "+",
+                "leftExpression": {"nodeType": "Identifier", "id": 62, "name": "a"},
+                "rightExpression": {"nodeType": "Identifier", "id": 63, "name": "b"},
+                "typeDescriptions": {"typeIdentifier": "t_uint256", "typeString": "uint256"}
+            }
+        }])));
+
+        let built_in = MutationType::Generic(GenericMutation::ArithmeticBinaryOp);
+        let custom = MutationType::Custom("Tag");
+        let permissions = all_permissions();
+        let mut language_object =
+            LanguageInterface::get_language_object_for_language(&Language::Solidity).unwrap();
+        language_object
+            .select_mutators_for_mutation_types(&[built_in])
+            .unwrap();
+        language_object.add_custom_mutator(Box::new(TagMutator {}));
+
+        // The custom mutator counts nodes alongside the built-in mutator.
+        let mut rng = Pcg64::seed_from_u64(0);
+        let counts = language_object
+            .count_mutable_nodes(&ast, &mut rng, &permissions)
+            .unwrap();
+        assert_eq!(counts[&built_in], 1);
+        assert_eq!(counts[&custom], 2);
+
+        let path_map = language_object
+            .calculate_node_paths(&ast, &permissions)
+            .unwrap();
+        let result = language_object
+            .mutate_ast(&ast, &custom, 1, &mut rng, &permissions, &path_map)
+            .unwrap();
+        assert_eq!(result.mutator_result.mutation_type, Some(custom));
+        assert_eq!(result.mutator_result.mutated_node_id, Some(63));
+        let mutated = match result.ast.unwrap() {
+            SuperAST::Solidity(a) => a,
+            _ => panic!("expected a Solidity AST"),
+        };
+        let expression = &mutated["nodes"][0]["nodes"][0]["body"]["statements"][0]["expression"];
+        assert_eq!(expression["leftExpression"].get("tagged"), None);
+        assert_eq!(expression["rightExpression"]["tagged"], json!(true));
+        assert_eq!(custom.to_string(), "Tag");
+    }
+
+    /// The logger that keeps the messages that the mutable node counter logs.
+    struct SelectionTraceLogger {
+        messages: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl log::Log for SelectionTraceLogger {
+        fn enabled(&self, metadata: &log::Metadata) -> bool {
+            metadata.target() == "mutagenyx_lib::mutation_visitor"
+        }
+
+        fn log(&self, record: &log::Record) {
+            if self.enabled(record.metadata()) {
+                self.messages
+                    .lock()
+                    .unwrap()
+                    .push(record.args().to_string());
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    static SELECTION_TRACE_LOGGER: SelectionTraceLogger = SelectionTraceLogger {
+        messages: std::sync::Mutex::new(Vec::new()),
+    };
+
+    #[test]
+    fn test_trace_mutation_selection() {
+        let ast = SuperAST::Solidity(solidity_ast_with_statements(json!([
+            {"nodeType": "ExpressionStatement", "id": 60, "expression": {
+                "nodeType": "BinaryOperation", "id": 61, "operator": "+",
+                "leftExpression": {"nodeType": "Identifier", "id": 62, "name": "a"},
+                "rightExpression": {"nodeType": "Identifier", "id": 63, "name": "b"},
+                "typeDescriptions": {"typeIdentifier": "t_uint256", "typeString": "uint256"}
+            }},
+            {"nodeType": "ExpressionStatement", "id": 70, "expression": {
+                "nodeType": "BinaryOperation", "id": 71, "operator": "&&",
+                "leftExpression": {"nodeType": "Identifier", "id": 72, "name": "c"},
+                "rightExpression": {"nodeType": "Identifier", "id": 73, "name": "d"},
+                "typeDescriptions": {"typeIdentifier": "t_bool", "typeString": "bool"}
+            }}
+        ])));
+
+        let _ = log::set_logger(&SELECTION_TRACE_LOGGER);
+        log::set_max_level(log::LevelFilter::Debug);
+
+        let mutation_type = MutationType::Generic(GenericMutation::ArithmeticBinaryOp);
+        let permissions = all_permissions();
+        let mut language_object =
+            LanguageInterface::get_language_object_for_language(&Language::Solidity).unwrap();
+        language_object
+            .select_mutators_for_mutation_types(&[mutation_type])
+            .unwrap();
+        let trace_messages = |language_object: &mut Box<dyn MutableLanguage>| {
+            SELECTION_TRACE_LOGGER.messages.lock().unwrap().clear();
+            let mut rng = Pcg64::seed_from_u64(0);
+            language_object
+                .count_mutable_nodes(&ast, &mut rng, &permissions)
+                .unwrap();
+            SELECTION_TRACE_LOGGER.messages.lock().unwrap().clone()
+        };
+
+        // Without the trace the counter does not log anything.
+        assert!(trace_messages(&mut language_object).is_empty());
+
+        language_object.trace_mutation_selection(true);
+        let messages = trace_messages(&mut language_object);
+        let expected = [
+            format!("{mutation_type} accepted node 61 (BinaryOperation)"),
+            format!("{mutation_type} rejected node 62 (Identifier): not a BinaryOperation node"),
+            format!(
+                "{mutation_type} rejected node 71 (BinaryOperation): operator '&&' is not one of \
+                [\"+\", \"-\", \"*\", \"/\", \"%\", \"**\"]"
+            ),
+        ];
+        for message in expected {
+            assert!(
+                messages.contains(&message),
+                "missing {message} in {messages:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_apply_mutation_at_node() {
+        let addition = |id: u64| {
+            json!({
+                "nodeType": "BinaryOperation", "id": id, "operator": "+",
+                "leftExpression": {"nodeType": "Identifier", "id": id + 1, "name": "a"},
+                "rightExpression": {"nodeType": "Identifier", "id": id + 2, "name": "b"},
+                "typeDescriptions": {"typeIdentifier": "t_uint256", "typeString": "uint256"}
+            })
+        };
+        let ast = SuperAST::Solidity(solidity_ast_with_statements(json!([
+            {"nodeType": "ExpressionStatement", "id": 60, "expression": addition(61)},
+            {"nodeType": "ExpressionStatement", "id": 70, "expression": addition(71)}
+        ])));
+
+        let mutation_type = MutationType::Generic(GenericMutation::ArithmeticBinaryOp);
+        let language_object =
+            LanguageInterface::get_language_object_for_language(&Language::Solidity).unwrap();
+        let mutate = || match language_object
+            .apply_mutation_at_node(&ast, &mutation_type, 71)
+            .unwrap()
+        {
+            SuperAST::Solidity(a) => a,
+            _ => panic!("expected a Solidity AST"),
+        };
+
+        // The mutation only changes the requested node and is the same every time.
+        let mutated = mutate();
+        let statements = &mutated["nodes"][0]["nodes"][0]["body"]["statements"];
+        assert_eq!(statements[0]["expression"]["operator"], "+");
+        assert_eq!(statements[1]["expression"]["operator"], "-");
+        assert_eq!(mutate(), mutated);
+
+        assert!(matches!(
+            language_object.apply_mutation_at_node(&ast, &mutation_type, 12345),
+            Err(MutagenyxError::NodeNotFound { id: 12345 })
+        ));
+        assert!(matches!(
+            language_object.apply_mutation_at_node(&ast, &mutation_type, 72),
+            Err(MutagenyxError::NodeNotMutable { id: 72, .. })
+        ));
+        assert!(matches!(
+            language_object.apply_mutation_at_node(&ast, &MutationType::Custom("Tag"), 71),
+            Err(MutagenyxError::MutationAlgorithmNotSupported(_))
+        ));
+    }
+
+    #[test]
+    fn test_list_mutable_nodes() {
+        // The statement `a + b;` starts on the second line of the source.
+        let source = "contract C {\n    function add() public pure { a + b; }\n}\n";
+        let ast = SuperAST::Solidity(solidity_ast_with_statements(json!([{
+            "nodeType": "ExpressionStatement", "id": 60, "src": "46:6:0", "expression": {
+                "nodeType": "BinaryOperation", "id": 61, "src": "46:5:0", "operator": "+",
+                "leftExpression": {"nodeType": "Identifier", "id": 62, "name": "a"},
+                "rightExpression": {"nodeType": "Identifier", "id": 63, "name": "b"},
+                "typeDescriptions": {"typeIdentifier": "t_uint256", "typeString": "uint256"}
+            }
+        }])));
+
+        let mut language_object =
+            LanguageInterface::get_language_object_for_language(&Language::Solidity).unwrap();
+        language_object
+            .select_mutators_for_mutation_types(&[MutationType::Generic(
+                GenericMutation::ArithmeticBinaryOp,
+            )])
+            .unwrap();
+
+        let mut rng = Pcg64::seed_from_u64(1);
+        let nodes = language_object
+            .list_mutable_nodes(&ast, &mut rng, &all_permissions(), Some(source))
+            .unwrap();
+        assert_eq!(
+            nodes,
+            vec![MutableNode {
+                node_id: 61,
+                line: Some(2),
+                mutation_types: vec![MutationType::Generic(GenericMutation::ArithmeticBinaryOp)],
+            }]
+        );
+
+        // Without the source text the offsets in the AST do not give a line.
+        let nodes = language_object
+            .list_mutable_nodes(&ast, &mut rng, &all_permissions(), None)
+            .unwrap();
+        assert!(nodes.iter().all(|n| n.line.is_none()));
+    }
+
+    #[test]
+    fn test_lines_select_whole_blocks() {
+        // The body of the function spans the lines 2 to 5 and holds a statement on each of the
+        // lines 3 and 4.
+        let source =
+            "contract C {\n    function add() public pure {\n        a;\n        b;\n    }\n}\n";
+        let statement = |id: u64, name: &str, offset: usize| {
+            json!({
+                "nodeType": "ExpressionStatement", "id": id, "src": format!("{}:2:0", offset),
+                "expression": {"nodeType": "Identifier", "id": id + 1, "name": name,
+                    "src": format!("{}:1:0", offset)}
+            })
+        };
+        let mut ast =
+            solidity_ast_with_statements(json!([statement(60, "a", 54), statement(62, "b", 65)]));
+        ast["nodes"][0]["nodes"][0]["body"]["src"] = json!("44:29:0");
+        let ast = SuperAST::Solidity(ast);
+        let delete_statement = MutationType::Generic(GenericMutation::DeleteStatement);
+
+        let mut language_object =
+            LanguageInterface::get_language_object_for_language(&Language::Solidity).unwrap();
+        language_object
+            .select_mutators_for_mutation_types(&[delete_statement])
+            .unwrap();
+        let mut rng = Pcg64::seed_from_u64(1);
+
+        // The statement `a;` lies on the selected line but the block does not, so the mutator
+        // could delete the statement `b;` outside the selected line.
+        language_object.restrict_mutations_to_lines(&[3..=3], Some(source));
+        let counts = language_object
+            .count_mutable_nodes(&ast, &mut rng, &all_permissions())
+            .unwrap();
+        assert_eq!(counts.get(&delete_statement).copied().unwrap_or(0), 0);
+
+        language_object.restrict_mutations_to_lines(&[2..=3, 4..=5], Some(source));
+        let counts = language_object
+            .count_mutable_nodes(&ast, &mut rng, &all_permissions())
+            .unwrap();
+        assert_eq!(counts.get(&delete_statement), Some(&1));
+    }
+
+    #[test]
+    fn test_recognizer_and_loader_error_variants() {
+        let mut prefs = Preferences::new();
+        let recognizer = Recognizer::new(&mut prefs);
+        let text_file = env::temp_dir().join("mutagenyx_not_a_program.txt");
+        let text_file = text_file.to_str().unwrap();
+        std::fs::write(text_file, "not a program").unwrap();
+        assert!(matches!(
+            recognizer.recognize_file(text_file),
+            Err(MutagenyxError::LanguageNotRecognized)
+        ));
+        std::fs::remove_file(text_file).unwrap();
+
+        let mut language_object =
+            LanguageInterface::get_language_object_for_language(&Language::Solidity).unwrap();
+        let missing_file = env::temp_dir().join("mutagenyx_missing_ast.json");
+        match language_object.load_ast_from_file(
+            missing_file.to_str().unwrap(),
+            &FileType::AST,
+            &prefs,
+        ) {
+            Err(MutagenyxError::IO(e)) => assert_eq!(e.kind(), std::io::ErrorKind::NotFound),
+            _ => panic!("expected an IO error"),
+        }
+
+        let vyper_file = env::temp_dir().join("mutagenyx_vyper_ast.json");
+        let vyper_file = vyper_file.to_str().unwrap();
+        std::fs::write(
+            vyper_file,
+            json!({"ast_type": "Module", "body": []}).to_string(),
+        )
+        .unwrap();
+        let result = language_object.load_ast_from_file(vyper_file, &FileType::AST, &prefs);
+        std::fs::remove_file(vyper_file).unwrap();
+        match result {
+            Err(MutagenyxError::MalformedAST { file_name, .. }) => {
+                assert_eq!(file_name, vyper_file)
+            }
+            _ => panic!("expected a malformed AST error"),
+        }
+
+        let ast = SuperAST::Solidity(mutated_solidity_ast());
+        assert!(matches!(
+            language_object.source_line_for_node(&ast, 12345, ""),
+            Err(MutagenyxError::NodeNotFound { id: 12345 })
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_compiler_failure_error_variant() {
+        let source_file = env::temp_dir().join("mutagenyx_compiler_failure.sol");
+        let source_file = source_file.to_str().unwrap();
+        std::fs::write(source_file, "contract C {}").unwrap();
+
+        let language_object =
+            LanguageInterface::get_language_object_for_language(&Language::Solidity).unwrap();
+        let prefs_with_compiler =
+            |compiler: &str| compiler_preferences(Language::Solidity, compiler, Preferences::new());
+
+        // The compiler runs and exits with a failure status.
+        match language_object.convert_source_file_to_ast(source_file, &prefs_with_compiler("false"))
+        {
+            Err(MutagenyxError::CompilerInvocation {
+                compiler, status, ..
+            }) => {
+                assert_eq!(compiler, "false");
+                assert_eq!(status, Some(1));
+            }
+            _ => panic!("expected a compiler invocation error"),
+        }
+
+        // The compiler does not start.
+        let missing_compiler = "/nonexistent/mutagenyx/solc";
+        match language_object
+            .convert_source_file_to_ast(source_file, &prefs_with_compiler(missing_compiler))
+        {
+            Err(MutagenyxError::CompilerInvocation { status, .. }) => assert_eq!(status, None),
+            _ => panic!("expected a compiler invocation error"),
+        }
+
+        std::fs::remove_file(source_file).unwrap();
+    }
+
+    #[test]
+    fn test_every_mutator_has_a_description() {
+        for language in all_languages() {
+            let mut language_object =
+                LanguageInterface::get_language_object_for_language(&language).unwrap();
+            for entry in all_mutation_types(language) {
+                let description = language_object
+                    .mutator_description(&entry.mutation_type)
+                    .unwrap_or_else(|| panic!("{language} has no {} mutator", entry.mutation_type));
+                assert!(
+                    !description.description.is_empty(),
+                    "the {language} {} mutator has no description",
+                    entry.mutation_type
+                );
+                assert!(
+                    !description.example.is_empty(),
+                    "the {language} {} mutator has no example",
+                    entry.mutation_type
+                );
+            }
+
+            // A custom mutator supplies its own description.
+            let custom = MutationType::Custom("Tag");
+            assert_eq!(language_object.mutator_description(&custom), None);
+            language_object.add_custom_mutator(Box::new(TagMutator {}));
+            let description = language_object.mutator_description(&custom).unwrap();
+            assert_eq!(
+                description.description,
+                "Tag the right hand side of a binary operation."
+            );
+            assert_eq!(description.example, "");
+        }
+    }
+
+    /// A mutator that claims every literal but fails to mutate it with `error`.
+    struct FailingMutator {
+        error: fn() -> MutagenyxError,
+    }
+
+    impl Mutator<Value> for FailingMutator {
+        fn is_mutable_node(&mut self, node: &Value, _rand: &mut Pcg64) -> bool {
+            node.get_str_for_key("nodeType") == Some("Literal")
+        }
+
+        fn mutate(
+            &mut self,
+            _node: &mut Value,
+            _rand: &mut Pcg64,
+        ) -> Result<MutatorResult, MutagenyxError> {
+            Err((self.error)())
+        }
+
+        fn implements(&self) -> MutationType {
+            MutationType::Custom("Failing")
+        }
+    }
+
+    #[test]
+    fn test_mutate_ast_mutator_errors() {
+        let ast = SuperAST::Solidity(solidity_ast_with_statements(json!([{
+            "nodeType": "ExpressionStatement", "id": 60, "expression": {
+                "nodeType": "Literal", "id": 61, "kind": "number", "value": "1"
+            }
+        }])));
+        let permissions = all_permissions();
+        let failing = MutationType::Custom("Failing");
+        let mutate = |error: fn() -> MutagenyxError| {
+            let mut language_object =
+                LanguageInterface::get_language_object_for_language(&Language::Solidity).unwrap();
+            language_object.add_custom_mutator(Box::new(FailingMutator { error }));
+            let path_map = language_object
+                .calculate_node_paths(&ast, &permissions)
+                .unwrap();
+            let mut rng = Pcg64::seed_from_u64(0);
+            language_object.mutate_ast(&ast, &failing, 0, &mut rng, &permissions, &path_map)
+        };
+
+        // A node that the mutator cannot mutate after all produces an empty result.
+        let result = mutate(|| MutagenyxError::MalformedNode("Literal".into(), "value".into()));
+        let result = result.unwrap();
+        assert!(result.ast.is_none());
+        assert_eq!(result.mutator_result.mutation_type, Some(failing));
+
+        // Other errors reach the caller.
+        let result = mutate(|| MutagenyxError::UnableToGenerateNode("integer constant"));
+        assert!(matches!(
+            result,
+            Err(MutagenyxError::UnableToGenerateNode("integer constant"))
+        ));
+    }
+}
@@ -1,6 +1,7 @@
 //! The `json_comment_helpers` module contains code shared between language specific commenters
 //! when the language has a JSON AST representation.
 
+use crate::commenter::CommentDetector;
 use crate::id::Id;
 use crate::json::JSONMutate;
 use serde_json::Value;
@@ -30,3 +31,35 @@ pub fn inserted_comment_in_array(
     }
     false
 }
+
+/// Generic JSON [`CommentDetector<Value>`] object that recognizes comment nodes in a JSON AST.
+pub struct JSONCommentDetector<F>
+where
+    F: Fn(&Value) -> bool,
+{
+    /// The closure that implements the AST specific check for a comment node.
+    f: F,
+}
+
+impl<F> JSONCommentDetector<F>
+where
+    F: Fn(&Value) -> bool,
+{
+    /// Create a new JSON comment detector object.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The closure that returns true if a node is a comment node.
+    pub fn new(f: F) -> JSONCommentDetector<F> {
+        JSONCommentDetector { f }
+    }
+}
+
+impl<F> CommentDetector<Value> for JSONCommentDetector<F>
+where
+    F: Fn(&Value) -> bool,
+{
+    fn is_comment(&self, node: &Value) -> bool {
+        (self.f)(node)
+    }
+}
@@ -1,12 +1,15 @@
 //! The `json_language_delegate` provides an interface to language-specific behavior for each
 //! language the has a JSON encoded AST.
 
+use crate::commenter::CommentDetector;
 use crate::error::MutagenyxError;
 use crate::id::Id;
 use crate::language::Language;
+use crate::line_table::LineTable;
 use crate::mutation_visitor::NodePath;
 use crate::mutator::MutatorFactory;
 use crate::namer::Namer;
+use crate::operators::OperatorRegistry;
 use crate::permissions::Permissions;
 use crate::permit::Permit;
 use crate::preferences::Preferences;
@@ -15,6 +18,7 @@ use crate::super_ast::SuperAST;
 use crate::visitor::Visitor;
 use serde_json::Value;
 use std::io::Write;
+use std::ops::RangeInclusive;
 
 /// Trait that defines a delegate interface for engaging language specific behavior.
 pub trait JSONLanguageDelegate {
@@ -35,7 +39,11 @@ pub trait JSONLanguageDelegate {
 
     /// Get a language-specific object that conforms to the [`MutatorFactory<T>`] trait for getting
     /// access to language specific [`crate::mutator::Mutator<T>`] objects.
-    fn get_mutator_factory(&self) -> Box<dyn MutatorFactory<Value>>;
+    ///
+    /// # Arguments
+    ///
+    /// * `operators` - The [`OperatorRegistry`] that holds the operators of the operator mutators.
+    fn get_mutator_factory(&self, operators: &OperatorRegistry) -> Box<dyn MutatorFactory<Value>>;
 
     /// Get a language-specific object that conforms to the [`Visitor<T>`] trait for traversing
     /// the AST for the purpose of pretty-printing the AST back to source code form.
@@ -91,13 +99,22 @@ pub trait JSONLanguageDelegate {
         permissions: &'a Permissions,
     ) -> Box<dyn Permit<Value> + '_>;
 
-    /// Return true if the program in `file_name` successfully compiles.
+    /// Compile the program in `file_name`.
     ///
     /// # Arguments
     ///
     /// * `file_name` - The string slice referencing the text comprising the file name.
     /// * `prefs` - The [`Preferences`] object that contains compiler settings.
-    fn mutant_compiles(&self, file_name: &str, prefs: &Preferences) -> bool;
+    ///
+    /// # Errors
+    ///
+    /// The function returns [`MutagenyxError::CompilerInvocation`] if the program does not
+    /// compile.
+    fn check_mutant_compiles(
+        &self,
+        file_name: &str,
+        prefs: &Preferences,
+    ) -> Result<(), MutagenyxError>;
 
     /// Return a trait object for [`Id<AST>`] that can uniquely identify a node in the AST.
     fn get_node_id_maker(&self) -> Box<dyn Id<Value>>;
@@ -117,6 +134,49 @@ pub trait JSONLanguageDelegate {
     ) {
     }
 
+    /// Return true if `node` is a pragma directive, such as the `pragma solidity` version
+    /// requirement of a Solidity program.  Mutating a pragma does not produce a useful mutant, so
+    /// the mutation visitors can skip pragma nodes and their children.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The AST node.
+    fn is_pragma_node(&self, _node: &Value) -> bool {
+        false
+    }
+
+    /// Return the key of the comment node entry that holds the comment text.
+    fn get_comment_text_key(&self) -> &str;
+
+    /// Return the line number of the source location recorded in `node`.  Return None if the
+    /// node does not have a source location, or if the language records source locations as
+    /// offsets into the source text and `lines` is None.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The AST node.
+    /// * `lines` - The [`LineTable`] of the source file that the compiler used to generate the
+    ///   AST, if available.
+    fn get_source_line_for_node(&self, node: &Value, lines: Option<&LineTable>) -> Option<usize>;
+
+    /// Return the range of lines, from the first line to the last line, that the source
+    /// location recorded in `node` covers.  Return None under the same conditions as
+    /// [`JSONLanguageDelegate::get_source_line_for_node`].
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The AST node.
+    /// * `lines` - The [`LineTable`] of the source file that the compiler used to generate the
+    ///   AST, if available.
+    fn get_source_lines_for_node(
+        &self,
+        node: &Value,
+        lines: Option<&LineTable>,
+    ) -> Option<RangeInclusive<usize>>;
+
     /// Return a trait object that can provide a name for JSON AST nodes.
     fn get_namer(&self) -> Box<dyn Namer<Value>>;
+
+    /// Return a trait object that can recognize the comment nodes in a JSON AST.
+    fn get_comment_detector(&self) -> Box<dyn CommentDetector<Value>>;
 }
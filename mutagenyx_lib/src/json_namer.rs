@@ -5,6 +5,9 @@
 use crate::namer::Namer;
 use serde_json::Value;
 
+/// The type of the closure that returns the kinds of a node in a JSON AST.
+type KindsConverter = Box<dyn Fn(&Value) -> Vec<String>>;
+
 /// Generic JSON [`Namer<Value>`] object that can convert a node in a JSON AST to a [`String`]
 /// representing the node.
 pub struct JSONNamer<F>
@@ -14,6 +17,9 @@ where
     /// A closure that implements AST specific code to convert an AST node for a particular language
     /// to a name string.
     value_to_name_converter: F,
+
+    /// A closure that implements AST specific code to return the kinds of an AST node.
+    value_to_kinds_converter: Option<KindsConverter>,
 }
 
 impl<F> JSONNamer<F>
@@ -29,8 +35,19 @@ where
     pub fn new(f: F) -> JSONNamer<F> {
         JSONNamer {
             value_to_name_converter: f,
+            value_to_kinds_converter: None,
         }
     }
+
+    /// Use `f` to return the kinds of a node.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The closure that returns the kinds of an AST node for a particular language.
+    pub fn with_kinds(mut self, f: impl Fn(&Value) -> Vec<String> + 'static) -> JSONNamer<F> {
+        self.value_to_kinds_converter = Some(Box::new(f));
+        self
+    }
 }
 
 impl<F> Namer<Value> for JSONNamer<F>
@@ -40,4 +57,11 @@ where
     fn get_name(&self, thing: &Value) -> Option<String> {
         (self.value_to_name_converter)(thing)
     }
+
+    fn get_kinds(&self, thing: &Value) -> Vec<String> {
+        match &self.value_to_kinds_converter {
+            Some(f) => f(thing),
+            None => Vec::new(),
+        }
+    }
 }
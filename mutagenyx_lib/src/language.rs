@@ -17,6 +17,40 @@ pub enum Language {
     /// [Vyper]: https://vyper.readthedocs.io
     /// [Ethereum Virtual Machine]: https://ethereum.org/en/developers/docs/evm
     Vyper,
+
+    /// Standalone programs written in [Yul], the intermediate language of the Solidity compiler.
+    ///
+    /// [Yul]: https://docs.soliditylang.org/en/latest/yul.html
+    Yul,
+}
+
+impl Language {
+    /// Return the file extensions (without the leading `.`) of source files written in the
+    /// language.
+    pub fn extensions(&self) -> &'static [&'static str] {
+        match self {
+            Language::Solidity => &["sol"],
+            Language::Vyper => &["vy"],
+            Language::Yul => &["yul"],
+        }
+    }
+
+    /// Return the language whose source files use the extension `extension`.
+    ///
+    /// # Arguments
+    ///
+    /// * `extension` - The file extension with or without the leading `.`.
+    pub fn from_extension(extension: &str) -> Option<Language> {
+        let extension = extension.strip_prefix('.').unwrap_or(extension);
+        all_languages()
+            .into_iter()
+            .find(|l| l.extensions().contains(&extension))
+    }
+}
+
+/// Return the list of all the languages supported by the library.
+pub fn all_languages() -> Vec<Language> {
+    vec![Language::Solidity, Language::Vyper, Language::Yul]
 }
 
 impl FromStr for Language {
@@ -26,6 +60,7 @@ impl FromStr for Language {
         match s {
             "Solidity" => Ok(Language::Solidity),
             "Vyper" => Ok(Language::Vyper),
+            "Yul" => Ok(Language::Yul),
             _ => Err(MutagenyxError::LanguageNotSupported(String::from(s))),
         }
     }
@@ -36,8 +71,22 @@ impl fmt::Display for Language {
         let text = match self {
             Language::Solidity => "Solidity",
             Language::Vyper => "Vyper",
+            Language::Yul => "Yul",
         };
 
         write!(f, "{}", text)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_language_from_extension() {
+        assert_eq!(Language::from_extension("sol"), Some(Language::Solidity));
+        assert_eq!(Language::from_extension(".vy"), Some(Language::Vyper));
+        assert_eq!(Language::from_extension("yul"), Some(Language::Yul));
+        assert_eq!(Language::from_extension("rs"), None);
+    }
+}
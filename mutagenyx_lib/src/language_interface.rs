@@ -2,22 +2,31 @@
 //! module for the purpose of generating mutations. The programming language abstraction provides
 //! common APIs that a programming language module can implement and thereby plug-in to the library.
 
+use crate::annotation::AnnotationStyle;
 use crate::error::MutagenyxError;
 use crate::json_ast_language_interface::JSONLanguageInterface;
 use crate::language::Language;
 use crate::mutation::MutationType;
-use crate::mutation_visitor::NodePathMap;
+use crate::mutation_visitor::{MutableNode, NodePathMap};
+use crate::mutator::{Mutator, MutatorDescription};
 use crate::mutator_result::MutatorResult;
-use crate::permissions::Permissions;
+use crate::operators::OperatorRegistry;
+use crate::permissions::{Permissions, PermissionsBuilder};
 use crate::preferences::Preferences;
 use crate::pretty_printer::PrettyPrinter;
 use crate::recognizer::FileType;
 use crate::solidity::delegate::get_solidity_delegate;
 use crate::super_ast::SuperAST;
 use crate::vyper::delegate::get_vyper_delegate;
+use crate::yul::delegate::get_yul_delegate;
 use rand_pcg::*;
+use serde_json::Value;
 use std::collections::HashMap;
 use std::io::Write;
+use std::ops::RangeInclusive;
+
+/// The key for the preference that turns on the trace of the mutable node selection.
+pub static TRACE_MUTATION_SELECTION_KEY: &str = "trace-mutation-selection";
 
 /// The type to use for the result of the mutation function.
 pub struct MutateASTResult {
@@ -77,6 +86,82 @@ pub trait MutableLanguage {
         mutation_types: &[MutationType],
     ) -> Result<(), MutagenyxError>;
 
+    /// Add a mutator implemented outside of the library.  The interface consults the mutator
+    /// alongside the mutators selected with `select_mutators_for_mutation_types` when counting
+    /// and mutating nodes, so the caller can mutate the AST with the algorithm returned by the
+    /// mutator's [`Mutator::implements`] function.  Custom mutators should implement a
+    /// [`MutationType::Custom`] algorithm, a mutator that implements a built-in algorithm replaces
+    /// the built-in mutator.
+    ///
+    /// # Arguments
+    ///
+    /// * `mutator` - The mutator object.
+    fn add_custom_mutator(&mut self, mutator: Box<dyn Mutator<Value>>);
+
+    /// Return the description and the example that the mutator for `mutation_type` supplies, or
+    /// None if the language does not have a mutator for the algorithm.  The function consults the
+    /// mutators added with `add_custom_mutator` before the built-in mutators.
+    ///
+    /// # Arguments
+    ///
+    /// * `mutation_type` - The mutation algorithm.
+    fn mutator_description(&self, mutation_type: &MutationType) -> Option<MutatorDescription>;
+
+    /// Turn on or off the trace of the mutable node selection.  When on, `count_mutable_nodes`
+    /// logs (at debug level) each node that each mutator examines and the reason that the
+    /// mutator rejects the node.
+    ///
+    /// # Arguments
+    ///
+    /// * `trace` - True to trace the mutable node selection.
+    fn trace_mutation_selection(&mut self, trace: bool);
+
+    /// Select whether the mutators skip the pragma directives of the program (such as the
+    /// `pragma solidity` version requirement) and the children of the directives.  Mutating a
+    /// pragma does not produce a useful mutant and may keep the mutant from compiling, so the
+    /// interface skips pragma directives by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `exclude` - True to skip the pragma directives.
+    fn exclude_pragma_nodes(&mut self, exclude: bool);
+
+    /// Only mutate the nodes that lie entirely on the lines in the ranges in `lines`.  The
+    /// mutators still look inside the nodes on other lines, so a statement on a selected line
+    /// in a function that starts on another line remains mutable.  The algorithms that mutate
+    /// a statement of a block, such as DeleteStatement or SwapLines, only mutate a block that
+    /// lies entirely on the selected lines.  An empty list of ranges allows mutations on every
+    /// line.
+    ///
+    /// # Arguments
+    ///
+    /// * `lines` - The ranges of line numbers, starting from 1.
+    /// * `source` - The text of the source file that the compiler used to generate the AST, if
+    ///   available.  Languages that record source locations as offsets into the source text
+    ///   need the text to find the line of a node, and do not mutate any node without it.
+    fn restrict_mutations_to_lines(
+        &mut self,
+        lines: &[RangeInclusive<usize>],
+        source: Option<&str>,
+    );
+
+    /// Select the style of the comments inserted next to mutated nodes.
+    ///
+    /// # Arguments
+    ///
+    /// * `style` - The [`AnnotationStyle`] to use for mutation comments.
+    fn select_annotation_style(&mut self, style: AnnotationStyle);
+
+    /// Select the groups of operators that the operator mutators use, such as the arithmetic
+    /// operators of ArithmeticBinaryOp.  The interface uses [`OperatorRegistry::default`] until
+    /// the caller selects another registry.  Call the function before
+    /// `select_mutators_for_mutation_types`.
+    ///
+    /// # Arguments
+    ///
+    /// * `operators` - The [`OperatorRegistry`] with the operator groups.
+    fn select_operator_registry(&mut self, operators: OperatorRegistry);
+
     /// Traverse the abstract syntax tree `ast` to count the number of nodes in the tree that
     /// each requested mutation algorithm can mutate.  Return a [`HashMap`] of counts by
     /// [`MutationType`].
@@ -99,9 +184,35 @@ pub trait MutableLanguage {
         permissions: &Permissions,
     ) -> Result<HashMap<MutationType, usize>, MutagenyxError>;
 
+    /// Traverse the abstract syntax tree `ast` and return each node that one or more of the
+    /// requested mutation algorithms can mutate, in traversal order, along with the algorithms
+    /// that can mutate the node and the source line of the node.
+    ///
+    /// As with `count_mutable_nodes`, the caller should call this function after
+    /// `select_mutators_for_mutation_types`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ast` - The [`SuperAST`] object that encapsulates the concrete language AST.
+    /// * `rng` - The random number generator to give to the mutators.
+    /// * `permissions` - A [`Permissions`] object containing permission settings that control
+    ///   which nodes to consider.
+    /// * `source` - The text of the source file that the compiler used to generate `ast`, if
+    ///   available.  Languages that record source locations as offsets into the source text
+    ///   need the text to find the line of a node.
+    fn list_mutable_nodes(
+        &mut self,
+        ast: &SuperAST,
+        rng: &mut Pcg64,
+        permissions: &Permissions,
+        source: Option<&str>,
+    ) -> Result<Vec<MutableNode>, MutagenyxError>;
+
     /// Make a copy of `ast`, traverse the copy and mutate one node in the AST using the
     /// `mutation_type` algorithm.  Return a [`MutateASTResult`] object that contains the mutator
-    /// results and the [`SuperAST`] result if present.
+    /// results and the [`SuperAST`] result if present.  The result does not contain an AST if the
+    /// mutator found that it could not mutate the node, as for a malformed node.  The function
+    /// returns the other errors of the mutator.
     ///
     /// # Arguments
     ///
@@ -125,6 +236,33 @@ pub trait MutableLanguage {
         path_map: &NodePathMap,
     ) -> Result<MutateASTResult, MutagenyxError>;
 
+    /// Make a copy of `ast` and mutate the node with id `node_id` in the copy using the
+    /// `mutation_type` algorithm.  Return the mutated copy.
+    ///
+    /// Unlike `mutate_ast`, the function does not need the caller to select mutators, count
+    /// mutable nodes or provide a random number generator.  The mutator always receives a random
+    /// number generator with the same seed, so the function always makes the same mutation for
+    /// the same inputs.  The function does not insert a comment describing the mutation.
+    ///
+    /// # Arguments
+    ///
+    /// * `ast` - The syntax tree to mutate.
+    /// * `mutation_type` - The mutation algorithm to use to mutate `ast`.
+    /// * `node_id` - The id of the node to mutate.
+    ///
+    /// # Errors
+    ///
+    /// The function returns [`MutagenyxError::MutationAlgorithmNotSupported`] if the language
+    /// does not implement `mutation_type`, [`MutagenyxError::NodeNotFound`] if `ast` does not
+    /// contain the node, and [`MutagenyxError::NodeNotMutable`] if the algorithm cannot mutate
+    /// the node.
+    fn apply_mutation_at_node(
+        &self,
+        ast: &SuperAST,
+        mutation_type: &MutationType,
+        node_id: u64,
+    ) -> Result<SuperAST, MutagenyxError>;
+
     /// Pretty-print the contents of `ast` to the file named in `file_name`.
     ///
     /// # Arguments
@@ -187,6 +325,15 @@ pub trait MutableLanguage {
     /// * `file_name` - The string slice referencing the text containing the file name.
     fn file_is_language_ast_file(&self, file_name: &str) -> bool;
 
+    /// Check that the discriminators of an AST file identify an AST representation of a program
+    /// for the language implementing the trait.
+    ///
+    /// # Arguments
+    ///
+    /// * `discriminators` - The top level of the AST file as read by
+    ///   [`crate::json::scan_json_discriminators_from_file_with_name`].
+    fn discriminators_are_language_ast(&self, discriminators: &Value) -> bool;
+
     /// Return a [`Preferences`] object containing default compiler settings for the language.
     fn default_compiler_settings(&self) -> Preferences;
 
@@ -201,7 +348,49 @@ pub trait MutableLanguage {
         original_file_name: &str,
         ast: &SuperAST,
         prefs: &Preferences,
-    ) -> bool;
+    ) -> bool {
+        self.check_mutant_compiles(original_file_name, ast, prefs)
+            .is_ok()
+    }
+
+    /// Compile the program in `ast` and return the reason that the program does not compile.
+    ///
+    /// # Arguments
+    ///
+    /// * `original_file_name` - The name of the file that contains the original program.
+    /// * `ast` - The AST of the (mutated) program.
+    /// * `prefs` - The preferences object that contains compiler settings.
+    ///
+    /// # Errors
+    ///
+    /// The function returns [`MutagenyxError::CompilerInvocation`] with the exit status and the
+    /// stderr text of the compiler if the program does not compile.
+    fn check_mutant_compiles(
+        &mut self,
+        original_file_name: &str,
+        ast: &SuperAST,
+        prefs: &Preferences,
+    ) -> Result<(), MutagenyxError>;
+
+    /// Return the line number in `source` of the node with id `node_id` in `ast`, where `source`
+    /// is the text of the source file that the compiler used to generate `ast`.  Return None if
+    /// the node does not have a source location.
+    ///
+    /// # Arguments
+    ///
+    /// * `ast` - The AST object.
+    /// * `node_id` - The id of the node.
+    /// * `source` - The text of the source file.
+    ///
+    /// # Errors
+    ///
+    /// The function returns [`MutagenyxError::NodeNotFound`] if `ast` does not contain the node.
+    fn source_line_for_node(
+        &self,
+        ast: &SuperAST,
+        node_id: u64,
+        source: &str,
+    ) -> Result<Option<usize>, MutagenyxError>;
 
     /// Calculate all the paths to all the nodes in `ast`.
     ///
@@ -237,6 +426,7 @@ impl LanguageInterface {
                 JSONLanguageInterface::new(get_solidity_delegate()),
             )),
             Language::Vyper => Ok(Box::new(JSONLanguageInterface::new(get_vyper_delegate()))),
+            Language::Yul => Ok(Box::new(JSONLanguageInterface::new(get_yul_delegate()))),
         }
     }
 
@@ -247,7 +437,14 @@ impl LanguageInterface {
         let language_list: Vec<Box<dyn MutableLanguage>> = vec![
             Box::new(JSONLanguageInterface::new(get_solidity_delegate())),
             Box::new(JSONLanguageInterface::new(get_vyper_delegate())),
+            Box::new(JSONLanguageInterface::new(get_yul_delegate())),
         ];
         Ok(language_list)
     }
+
+    /// Return a [`PermissionsBuilder`] for the permissions passed to the [`MutableLanguage`]
+    /// functions that traverse an AST.
+    pub fn permissions_builder() -> PermissionsBuilder {
+        PermissionsBuilder::new()
+    }
 }
@@ -12,20 +12,37 @@
 //! - Select mutation algorithms to use to mutate the input files.
 //! - Mutate the program source.
 //! - Output a reconstruction of the mutated source AST.
+//!
+//! ## Getting Started
+//!
+//! [`mutate_file`] covers the common case of writing a number of mutants of one file to a
+//! directory:
+//!
+//! ```no_run
+//! use mutagenyx_lib::mutate_file;
+//! use std::path::Path;
+//!
+//! let mutants = mutate_file(Path::new("Token.sol"), &[], 10, 42, Path::new("out")).unwrap();
+//! ```
 
+pub use self::api::mutate_file;
 pub use self::error::MutagenyxError;
 pub use self::language::Language;
 pub use self::language_interface::LanguageInterface;
 pub use self::language_interface::MutableLanguage;
+pub use self::mutant_record::MutantRecord;
 pub use self::mutation::MutationType;
 pub use self::pretty_printer::PrettyPrinter;
 pub use self::recognizer::Recognizer;
 pub use self::super_ast::SuperAST;
 
+pub mod annotation;
+pub mod api;
 mod ast;
 mod commenter;
 pub mod compiler_details;
 pub mod config_file;
+pub mod diff;
 pub mod error;
 mod id;
 mod json;
@@ -40,15 +57,18 @@ mod json_language_delegate;
 mod json_namer;
 pub mod language;
 pub mod language_interface;
+mod line_table;
+pub mod mutant_record;
+pub mod mutant_selector;
 pub mod mutation;
 mod mutation_visitor;
-mod mutator;
+pub mod mutator;
 pub mod mutator_result;
 mod namer;
 mod node_finder;
-mod node_printer;
+pub mod node_printer;
 mod node_printer_helpers;
-mod operators;
+pub mod operators;
 pub mod permissions;
 mod permit;
 pub mod preferences;
@@ -58,6 +78,9 @@ pub mod recognizer;
 pub mod solidity;
 pub mod string;
 pub mod super_ast;
+#[cfg(test)]
+mod test_fixtures;
 mod utility;
 mod visitor;
 pub mod vyper;
+pub mod yul;
@@ -0,0 +1,45 @@
+//! The `line_table` module provides [`LineTable`], a table that converts the byte offsets of
+//! the source locations in an AST into the line numbers of the source file.
+
+/// The table of the byte offsets at which the lines of a source file start.  Building the table
+/// once lets the caller find the line of many nodes without rescanning the source text.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LineTable {
+    /// The byte offset of the first character of each line after the first line.
+    line_starts: Vec<usize>,
+
+    /// The length of the source text in bytes.
+    length: usize,
+}
+
+impl LineTable {
+    /// Create the table for the text of a source file.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The text of the source file.
+    pub fn new(source: &str) -> LineTable {
+        LineTable {
+            line_starts: source
+                .bytes()
+                .enumerate()
+                .filter(|(_, b)| *b == b'\n')
+                .map(|(i, _)| i + 1)
+                .collect(),
+            length: source.len(),
+        }
+    }
+
+    /// Return the line, starting from 1, that contains the byte at `offset`.  Return None if
+    /// the offset lies past the end of the source text.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The byte offset in the source text.
+    pub fn line_for_offset(&self, offset: usize) -> Option<usize> {
+        if offset > self.length {
+            return None;
+        }
+        Some(self.line_starts.partition_point(|start| *start <= offset) + 1)
+    }
+}
@@ -0,0 +1,37 @@
+//! The `mutant_record` module provides [`MutantRecord`], the description of one file written by a
+//! mutation run.
+
+use crate::mutation::MutationType;
+use serde_json::{json, Value};
+
+/// The description of one file written by a mutation run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MutantRecord {
+    /// The name of the file in the output directory or in the archive.
+    pub file: String,
+
+    /// The name of the input file mutated to create the file.
+    pub source: String,
+
+    /// The index of the mutant among the mutants of `source`.
+    pub index: usize,
+
+    /// The mutation algorithm that created the mutant, None for the unmutated baseline.
+    pub mutation_type: Option<MutationType>,
+
+    /// The id of the mutated node, None for the unmutated baseline.
+    pub node_id: Option<u64>,
+}
+
+impl MutantRecord {
+    /// Return the JSON object that describes the mutant in a manifest.
+    pub fn to_json(&self) -> Value {
+        json!({
+            "file": self.file,
+            "input": self.source,
+            "index": self.index,
+            "mutation": self.mutation_type.map(|t| t.to_string()),
+            "node-id": self.node_id,
+        })
+    }
+}
@@ -0,0 +1,178 @@
+//! The `mutant_selector` module provides [`MutantSelector`], the loop that turns a list of
+//! mutation algorithms into distinct mutants of an AST.
+
+use crate::error::MutagenyxError;
+use crate::language_interface::MutableLanguage;
+use crate::mutation::MutationType;
+use crate::mutation_visitor::NodePathMap;
+use crate::mutator_result::MutatorResult;
+use crate::permissions::Permissions;
+use crate::super_ast::SuperAST;
+use rand::RngCore;
+use rand_pcg::Pcg64;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+/// An upper bound on the number times to try to generate a mutant with one mutation algorithm
+/// before moving on to the next algorithm.
+pub const ATTEMPTS_TO_GENERATE_A_MUTANT: usize = 50;
+
+/// A mutant proposed by [`MutantSelector::next_mutant`].
+pub struct MutantCandidate {
+    /// The mutation algorithm that created the mutant.
+    pub mutation_type: MutationType,
+
+    /// The mutated AST.
+    pub ast: SuperAST,
+
+    /// The details of the mutation.
+    pub mutator_result: MutatorResult,
+}
+
+/// The state of the loop that generates one distinct mutant for each mutation algorithm in a
+/// list.
+///
+/// The caller asks for the next mutant with [`MutantSelector::next_mutant`] and then either
+/// keeps the mutant with [`MutantSelector::accept`] or turns the mutant down with
+/// [`MutantSelector::reject`].  The selector never proposes a mutant that repeats an accepted or
+/// rejected mutant, and moves on to the next algorithm after
+/// [`ATTEMPTS_TO_GENERATE_A_MUTANT`] attempts fail to produce a mutant for the algorithm.
+pub struct MutantSelector {
+    /// The mutation algorithms of the mutants still to generate, in order.
+    mutation_kinds_todo: VecDeque<MutationType>,
+
+    /// The number of attempts to generate a mutant for the algorithm at the front of the list.
+    attempts: usize,
+
+    /// The results of the accepted mutations.
+    observed_mutator_results: Vec<MutatorResult>,
+
+    /// The results of the rejected mutations.
+    rejected_mutator_results: Vec<MutatorResult>,
+
+    /// The number of times each mutation algorithm claimed a node but did not produce a mutant.
+    pub failed_mutations: BTreeMap<MutationType, usize>,
+}
+
+impl MutantSelector {
+    /// Create a new selector that generates one mutant for each item of `mutation_kinds`.
+    ///
+    /// # Arguments
+    ///
+    /// * `mutation_kinds` - The mutation algorithm of each mutant to generate.
+    pub fn new(mutation_kinds: VecDeque<MutationType>) -> MutantSelector {
+        MutantSelector {
+            mutation_kinds_todo: mutation_kinds,
+            attempts: 0,
+            observed_mutator_results: Vec::new(),
+            rejected_mutator_results: Vec::new(),
+            failed_mutations: BTreeMap::new(),
+        }
+    }
+
+    /// Randomly pick a node for the next mutation algorithm in the list and mutate the node.
+    /// Return the mutant if the mutant differs from the accepted and rejected mutants, or None
+    /// when the selector has run out of mutation algorithms.
+    ///
+    /// # Arguments
+    ///
+    /// * `language_object` - The language object with the mutators selected.
+    /// * `ast` - The AST to mutate.
+    /// * `mutable_nodes_table` - The number of mutable nodes in `ast` for each algorithm.
+    /// * `rng` - The random number generator.
+    /// * `permissions` - The permissions that control which nodes to mutate.
+    /// * `node_path_map` - The paths to the nodes of `ast`.
+    pub fn next_mutant(
+        &mut self,
+        language_object: &mut dyn MutableLanguage,
+        ast: &SuperAST,
+        mutable_nodes_table: &HashMap<MutationType, usize>,
+        rng: &mut Pcg64,
+        permissions: &Permissions,
+        node_path_map: &NodePathMap,
+    ) -> Result<Option<MutantCandidate>, MutagenyxError> {
+        while let Some(mutation_type) = self.mutation_kinds_todo.front().copied() {
+            if self.attempts >= ATTEMPTS_TO_GENERATE_A_MUTANT {
+                // We reached the limits of attempts on the last mutation, move on to the next.
+                self.next_kind();
+                continue;
+            }
+
+            // Randomly try to pick the node to mutate.
+            let number_of_mutable_nodes = mutable_nodes_table
+                .get(&mutation_type)
+                .copied()
+                .unwrap_or(0);
+            let index = if number_of_mutable_nodes > 1 {
+                (rng.next_u64() % number_of_mutable_nodes as u64) as usize
+            } else {
+                0
+            };
+
+            let mutate_ast_result = language_object.mutate_ast(
+                ast,
+                &mutation_type,
+                index,
+                rng,
+                permissions,
+                node_path_map,
+            )?;
+
+            // The mutator may claim a node but then fail to mutate the node.
+            let mutated_ast = match mutate_ast_result.ast {
+                Some(mutated_ast) => mutated_ast,
+                None => {
+                    *self.failed_mutations.entry(mutation_type).or_insert(0) += 1;
+                    self.attempts += 1;
+                    continue;
+                }
+            };
+
+            // Skip the mutations that repeat an earlier mutant.
+            if self
+                .observed_mutator_results
+                .contains(&mutate_ast_result.mutator_result)
+                || self
+                    .rejected_mutator_results
+                    .contains(&mutate_ast_result.mutator_result)
+            {
+                self.attempts += 1;
+                continue;
+            }
+
+            return Ok(Some(MutantCandidate {
+                mutation_type,
+                ast: mutated_ast,
+                mutator_result: mutate_ast_result.mutator_result,
+            }));
+        }
+
+        Ok(None)
+    }
+
+    /// Keep the mutant described by `mutator_result` and move on to the next mutation algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `mutator_result` - The details of the mutation returned by `next_mutant`.
+    pub fn accept(&mut self, mutator_result: MutatorResult) {
+        self.observed_mutator_results.push(mutator_result);
+        self.next_kind();
+    }
+
+    /// Turn down the mutant described by `mutator_result` and count the attempt against the
+    /// current mutation algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `mutator_result` - The details of the mutation returned by `next_mutant`.
+    pub fn reject(&mut self, mutator_result: MutatorResult) {
+        self.rejected_mutator_results.push(mutator_result);
+        self.attempts += 1;
+    }
+
+    /// Remove the mutation algorithm at the front of the list and reset the attempts.
+    fn next_kind(&mut self) {
+        self.mutation_kinds_todo.pop_front();
+        self.attempts = 0;
+    }
+}
@@ -2,9 +2,10 @@
 //! library.
 
 use crate::error::MutagenyxError;
+use crate::language::Language;
 use crate::operators::{
     arithmetic_operators, bitshift_operators, bitwise_operators, comparison_operators,
-    logical_operators, non_commutative_operators, prefix_operators,
+    compound_assignment_operators, logical_operators, non_commutative_operators, prefix_operators,
 };
 use std::collections::HashMap;
 use std::fmt;
@@ -99,9 +100,48 @@ pub enum GenericMutation {
     /// ```
     Assignment,
 
+    /// Replace the operator of a compound assignment expression with the simple assignment
+    /// operator or with another compound assignment operator.
+    ///
+    /// # Examples
+    ///
+    /// `total += amount;` might become `total = amount;`
+    ///
+    /// `total += amount;` might become `total -= amount;`
+    ///
+    /// # Operators
+    ///
+    /// This algorithm will replace the operators in the list (+=, -=, *=, /=, %=, <<=, >>=, &=,
+    /// |=, ^=) with `=` or with another operator from the same list.
+    AssignmentOperatorReplacement,
+
+    /// Replaces a boolean literal with the opposite boolean literal.
+    ///
+    /// # Examples
+    ///
+    /// `setPaused(true);` would become `setPaused(false);`
+    BooleanLiteralFlip,
+
     /// Randomly select a statement in the program and delete it.
     DeleteStatement,
 
+    /// Randomly select a statement in a block and insert a copy of the statement immediately
+    /// after the original.
+    ///
+    /// # Examples
+    ///
+    /// ```solidity
+    /// total += amount;
+    /// ```
+    ///
+    /// would become
+    ///
+    /// ```solidity
+    /// total += amount;
+    /// total += amount;
+    /// ```
+    DuplicateStatement,
+
     /// In an expression that contains a function call, randomly select one of the arguments
     /// of the function call, and replace the function call itself with the argument.
     ///
@@ -216,9 +256,222 @@ pub enum SolidityMutation {
     ///
     /// `let a := foo.delegatecall()` would become `let a := foo.call()`
     ElimDelegateCall,
+
+    /// Replaces the comparison operator in the argument expression of the Solidity function
+    /// `require` with the operator that yields the negated comparison.
+    ///
+    /// # Examples
+    ///
+    /// `require(a >= b);` would become `require(a < b);`
+    RequireComparison,
+
+    /// Changes the data location of a local `memory` struct variable initialized from
+    /// state-backed data to `storage`, so that writes to the local variable persist.
+    ///
+    /// # Examples
+    ///
+    /// `S memory s = structs[i];` would become `S storage s = structs[i];`
+    MemoryToStorage,
+
+    /// Changes the size of a fixed-size byte array type (`bytes1` through `bytes32`) to a
+    /// different size.
+    ///
+    /// # Examples
+    ///
+    /// `bytes32 a;` might become `bytes16 a;`
+    FixedBytesSize,
+
+    /// Changes the expression given for the `value` or `gas` option of a function call.
+    ///
+    /// # Examples
+    ///
+    /// `a.call{value: 0}("")` might become `a.call{value: 1 ether}("")`
+    CallOptions,
+
+    /// Swaps the data location of an array or `bytes` parameter of an external function between
+    /// `calldata` and `memory`.
+    ///
+    /// # Examples
+    ///
+    /// `function f(uint256[] calldata a) external` would become
+    /// `function f(uint256[] memory a) external`
+    SwapCalldataMemory,
+
+    /// Replaces the creation of a contract with `new` by a conversion of an address that is
+    /// already in scope to the contract type.
+    ///
+    /// # Examples
+    ///
+    /// `Token t = new Token(owner);` would become `Token t = Token(owner);`
+    NewToAddress,
+
+    /// Swaps two values of the same type that initialize the fields of a struct, either in a
+    /// struct constructor call or in a sequence of assignments to members of the same struct.
+    ///
+    /// # Examples
+    ///
+    /// `Order(buyer, seller, amount)` would become `Order(seller, buyer, amount)`
+    SwapStructFields,
+
+    /// Changes a state variable declared `constant` or `immutable` to use the other modifier or
+    /// removes the modifier.
+    ///
+    /// # Examples
+    ///
+    /// `uint256 public constant FEE = 30;` might become `uint256 public immutable FEE = 30;`
+    ConstantImmutable,
+
+    /// Replaces a read of the `length` member of an array with an integer constant.
+    ///
+    /// # Examples
+    ///
+    /// `i < values.length` might become `i < 1`
+    ArrayLength,
+
+    /// Replaces an access to a member of the `block`, `msg`, or `tx` global variables with an
+    /// access to a different global member of the same type.
+    ///
+    /// # Examples
+    ///
+    /// `block.timestamp` might become `block.number`
+    GlobalMemberAccess,
+
+    /// Swaps two modifiers applied to a function definition.
+    ///
+    /// # Examples
+    ///
+    /// `function withdraw() external nonReentrant onlyOwner` might become
+    /// `function withdraw() external onlyOwner nonReentrant`
+    SwapModifiers,
+
+    /// Rewrites a `for` loop as the equivalent `while` loop or a `while` loop as the equivalent
+    /// `for` loop.
+    ///
+    /// # Examples
+    ///
+    /// `while (i < n) { i++; }` might become `for (; i < n;) { i++; }`
+    LoopForm,
+
+    /// Adds or subtracts one byte or one 32-byte word to a number literal in an inline assembly
+    /// block.
+    ///
+    /// # Examples
+    ///
+    /// `mload(0x40)` might become `mload(0x60)`
+    AssemblyLiteral,
+
+    /// Rewrites an assignment of a conditional expression as an if/else statement or an
+    /// if/else statement that assigns to one target as an assignment of a conditional
+    /// expression.
+    ///
+    /// # Examples
+    ///
+    /// `fee = premium ? high : low;` might become
+    /// `if (premium) { fee = high; } else { fee = low; }`
+    TernaryIfElse,
+
+    /// Replaces the string literal message of a `require` call, a `revert` call, or a revert
+    /// statement with a different string.
+    ///
+    /// # Examples
+    ///
+    /// `require(amount > 0, "zero amount");` might become `require(amount > 0, "");`
+    RevertMessage,
+
+    /// Toggles the payability of a `fallback` function between `payable` and `nonpayable`.
+    /// A `receive` function must be payable, so the algorithm never mutates `receive` functions.
+    ///
+    /// # Examples
+    ///
+    /// `fallback() external payable {}` might become `fallback() external {}`
+    FallbackPayability,
+
+    /// Replaces a read of the Ether balance of an address with an integer constant.
+    ///
+    /// # Examples
+    ///
+    /// `require(address(this).balance >= amount);` might become `require(0 >= amount);`
+    BalanceConstant,
+
+    /// Replaces an argument of a base constructor call in a constructor definition with a
+    /// boundary value for the type of the argument.
+    ///
+    /// # Examples
+    ///
+    /// `constructor() Owned(msg.sender) {}` might become `constructor() Owned(address(0)) {}`
+    BaseConstructorArguments,
+
+    /// Swaps an access to `msg.sender` with an access to `tx.origin`.
+    ///
+    /// # Examples
+    ///
+    /// `require(msg.sender == owner);` would become `require(tx.origin == owner);`
+    SwapMsgContext,
+
+    /// Comments out a statement that resets a variable to its default value with `delete`.
+    ///
+    /// # Examples
+    ///
+    /// `delete balances[addr];` would become `// delete balances[addr];`
+    RemoveDelete,
+
+    /// Removes a `payable(...)` conversion of an address and leaves the converted expression.
+    ///
+    /// # Examples
+    ///
+    /// `payable(owner).transfer(fee);` would become `owner.transfer(fee);`
+    RemovePayableCast,
+
+    /// Swaps two members of an enum definition, which swaps the integer values of the members.
+    ///
+    /// # Examples
+    ///
+    /// `enum Status { Pending, Open, Closed }` could become `enum Status { Closed, Open, Pending }`
+    SwapEnumMembers,
+
+    /// Comments out a `continue` or `break` statement in a loop, or replaces `continue` with
+    /// `break` and `break` with `continue`.
+    ///
+    /// # Examples
+    ///
+    /// `if (skip[i]) { continue; }` could become `if (skip[i]) { break; }` or
+    /// `if (skip[i]) { // continue; }`
+    LoopControl,
+}
+
+/// The items in this enumeration represent Vyper language specific mutation algorithms.
+#[derive(Hash, Eq, PartialEq, PartialOrd, Ord, Clone, Copy, Debug)]
+pub enum VyperMutation {
+    /// Replaces the target argument of the Vyper low-level call functions `send`, `raw_call`,
+    /// and `create_from_blueprint` with a different address expression.
+    ///
+    /// # Examples
+    ///
+    /// `send(self.owner, amount)` might become `send(msg.sender, amount)`
+    LowLevelCallTarget,
+
+    /// Changes the `public`, `constant`, and `immutable` modifiers of a module-level variable
+    /// declaration.
+    ///
+    /// # Examples
+    ///
+    /// `FEE: public(constant(uint256)) = 30` might become `FEE: constant(uint256) = 30`
+    StateVariableModifiers,
+
+    /// Adds one to a bound, subtracts one from a bound, or swaps the bounds of the `range()`
+    /// call in a `for` loop.
+    ///
+    /// # Examples
+    ///
+    /// `for i in range(10):` might become `for i in range(11):`
+    RangeBound,
 }
 
 /// This enumeration collects all variations of mutation algorithms into a single enumeration.
+///
+/// The derived ordering of the enumeration is the priority of the algorithms when more than one
+/// mutator can mutate the same node (see [`crate::mutation_visitor`]), so add new algorithms at
+/// the end of the enumerations to keep the mutants of existing seeds.
 #[derive(Hash, Eq, PartialEq, PartialOrd, Ord, Clone, Copy, Debug)]
 pub enum MutationType {
     /// A generic mutation algorithm usable by all language instances
@@ -226,6 +479,14 @@ pub enum MutationType {
 
     /// Mutation algorithms specific to Solidity
     Solidity(SolidityMutation),
+
+    /// Mutation algorithms specific to Vyper
+    Vyper(VyperMutation),
+
+    /// A mutation algorithm implemented outside of the library by a mutator added with
+    /// [`crate::language_interface::MutableLanguage::add_custom_mutator`].  The string names the
+    /// algorithm.
+    Custom(&'static str),
 }
 
 /// Provide the ability to convert a string value into a [`MutationType`] enum.
@@ -240,7 +501,12 @@ impl FromStr for MutationType {
             "BitshiftBinaryOp" => Ok(MutationType::Generic(GenericMutation::BitshiftBinaryOp)),
             "ComparisonBinaryOp" => Ok(MutationType::Generic(GenericMutation::ComparisonBinaryOp)),
             "Assignment" => Ok(MutationType::Generic(GenericMutation::Assignment)),
+            "AssignmentOperatorReplacement" => Ok(MutationType::Generic(
+                GenericMutation::AssignmentOperatorReplacement,
+            )),
+            "BooleanLiteralFlip" => Ok(MutationType::Generic(GenericMutation::BooleanLiteralFlip)),
             "DeleteStatement" => Ok(MutationType::Generic(GenericMutation::DeleteStatement)),
+            "DuplicateStatement" => Ok(MutationType::Generic(GenericMutation::DuplicateStatement)),
             "FunctionCall" => Ok(MutationType::Generic(GenericMutation::FunctionCall)),
             "IfStatement" => Ok(MutationType::Generic(GenericMutation::IfStatement)),
             "Integer" => Ok(MutationType::Generic(GenericMutation::Integer)),
@@ -256,6 +522,43 @@ impl FromStr for MutationType {
             "Require" => Ok(MutationType::Solidity(SolidityMutation::Require)),
             "UncheckedBlock" => Ok(MutationType::Solidity(SolidityMutation::UncheckedBlock)),
             "ElimDelegateCall" => Ok(MutationType::Solidity(SolidityMutation::ElimDelegateCall)),
+            "RequireComparison" => Ok(MutationType::Solidity(SolidityMutation::RequireComparison)),
+            "MemoryToStorage" => Ok(MutationType::Solidity(SolidityMutation::MemoryToStorage)),
+            "FixedBytesSize" => Ok(MutationType::Solidity(SolidityMutation::FixedBytesSize)),
+            "CallOptions" => Ok(MutationType::Solidity(SolidityMutation::CallOptions)),
+            "SwapCalldataMemory" => {
+                Ok(MutationType::Solidity(SolidityMutation::SwapCalldataMemory))
+            }
+            "NewToAddress" => Ok(MutationType::Solidity(SolidityMutation::NewToAddress)),
+            "SwapStructFields" => Ok(MutationType::Solidity(SolidityMutation::SwapStructFields)),
+            "ConstantImmutable" => Ok(MutationType::Solidity(SolidityMutation::ConstantImmutable)),
+            "ArrayLength" => Ok(MutationType::Solidity(SolidityMutation::ArrayLength)),
+            "GlobalMemberAccess" => {
+                Ok(MutationType::Solidity(SolidityMutation::GlobalMemberAccess))
+            }
+            "SwapModifiers" => Ok(MutationType::Solidity(SolidityMutation::SwapModifiers)),
+            "LoopForm" => Ok(MutationType::Solidity(SolidityMutation::LoopForm)),
+            "AssemblyLiteral" => Ok(MutationType::Solidity(SolidityMutation::AssemblyLiteral)),
+            "TernaryIfElse" => Ok(MutationType::Solidity(SolidityMutation::TernaryIfElse)),
+            "RevertMessage" => Ok(MutationType::Solidity(SolidityMutation::RevertMessage)),
+            "FallbackPayability" => {
+                Ok(MutationType::Solidity(SolidityMutation::FallbackPayability))
+            }
+            "BalanceConstant" => Ok(MutationType::Solidity(SolidityMutation::BalanceConstant)),
+            "BaseConstructorArguments" => Ok(MutationType::Solidity(
+                SolidityMutation::BaseConstructorArguments,
+            )),
+            "SwapMsgContext" => Ok(MutationType::Solidity(SolidityMutation::SwapMsgContext)),
+            "RemoveDelete" => Ok(MutationType::Solidity(SolidityMutation::RemoveDelete)),
+            "RemovePayableCast" => Ok(MutationType::Solidity(SolidityMutation::RemovePayableCast)),
+            "SwapEnumMembers" => Ok(MutationType::Solidity(SolidityMutation::SwapEnumMembers)),
+            "LoopControl" => Ok(MutationType::Solidity(SolidityMutation::LoopControl)),
+
+            "LowLevelCallTarget" => Ok(MutationType::Vyper(VyperMutation::LowLevelCallTarget)),
+            "StateVariableModifiers" => {
+                Ok(MutationType::Vyper(VyperMutation::StateVariableModifiers))
+            }
+            "RangeBound" => Ok(MutationType::Vyper(VyperMutation::RangeBound)),
 
             _last => Err(MutagenyxError::MutationAlgorithmNotSupported(String::from(
                 _last,
@@ -274,7 +577,10 @@ impl fmt::Display for MutationType {
                 GenericMutation::BitshiftBinaryOp => "BitshiftBinaryOp",
                 GenericMutation::ComparisonBinaryOp => "ComparisonBinaryOp",
                 GenericMutation::Assignment => "Assignment",
+                GenericMutation::AssignmentOperatorReplacement => "AssignmentOperatorReplacement",
+                GenericMutation::BooleanLiteralFlip => "BooleanLiteralFlip",
                 GenericMutation::DeleteStatement => "DeleteStatement",
+                GenericMutation::DuplicateStatement => "DuplicateStatement",
                 GenericMutation::FunctionCall => "FunctionCall",
                 GenericMutation::IfStatement => "IfStatement",
                 GenericMutation::Integer => "Integer",
@@ -287,7 +593,36 @@ impl fmt::Display for MutationType {
                 SolidityMutation::Require => "Require",
                 SolidityMutation::UncheckedBlock => "UncheckedBlock",
                 SolidityMutation::ElimDelegateCall => "ElimDelegateCall",
+                SolidityMutation::RequireComparison => "RequireComparison",
+                SolidityMutation::MemoryToStorage => "MemoryToStorage",
+                SolidityMutation::FixedBytesSize => "FixedBytesSize",
+                SolidityMutation::CallOptions => "CallOptions",
+                SolidityMutation::SwapCalldataMemory => "SwapCalldataMemory",
+                SolidityMutation::NewToAddress => "NewToAddress",
+                SolidityMutation::SwapStructFields => "SwapStructFields",
+                SolidityMutation::ConstantImmutable => "ConstantImmutable",
+                SolidityMutation::ArrayLength => "ArrayLength",
+                SolidityMutation::GlobalMemberAccess => "GlobalMemberAccess",
+                SolidityMutation::SwapModifiers => "SwapModifiers",
+                SolidityMutation::LoopForm => "LoopForm",
+                SolidityMutation::AssemblyLiteral => "AssemblyLiteral",
+                SolidityMutation::TernaryIfElse => "TernaryIfElse",
+                SolidityMutation::RevertMessage => "RevertMessage",
+                SolidityMutation::FallbackPayability => "FallbackPayability",
+                SolidityMutation::BalanceConstant => "BalanceConstant",
+                SolidityMutation::BaseConstructorArguments => "BaseConstructorArguments",
+                SolidityMutation::SwapMsgContext => "SwapMsgContext",
+                SolidityMutation::RemoveDelete => "RemoveDelete",
+                SolidityMutation::RemovePayableCast => "RemovePayableCast",
+                SolidityMutation::SwapEnumMembers => "SwapEnumMembers",
+                SolidityMutation::LoopControl => "LoopControl",
+            },
+            MutationType::Vyper(v) => match v {
+                VyperMutation::LowLevelCallTarget => "LowLevelCallTarget",
+                VyperMutation::StateVariableModifiers => "StateVariableModifiers",
+                VyperMutation::RangeBound => "RangeBound",
             },
+            MutationType::Custom(name) => name,
         };
 
         write!(f, "{}", text)
@@ -313,6 +648,133 @@ pub fn get_all_mutation_algorithms() -> Vec<MutationType> {
     algorithms
 }
 
+impl MutationType {
+    /// Return true if the mutation algorithm can mutate programs written in `language`.
+    ///
+    /// # Arguments
+    ///
+    /// * `language` - The language of the programs to mutate.
+    pub fn supports_language(&self, language: Language) -> bool {
+        match self {
+            // The Vyper mutators do not implement compound assignment replacement yet.
+            MutationType::Generic(GenericMutation::AssignmentOperatorReplacement) => {
+                language == Language::Solidity
+            }
+            // Standalone Yul programs only support the binary operator mutations that have Yul
+            // built-in functions.
+            MutationType::Generic(g) => {
+                language != Language::Yul
+                    || matches!(
+                        g,
+                        GenericMutation::ArithmeticBinaryOp
+                            | GenericMutation::BitwiseBinaryOp
+                            | GenericMutation::BitshiftBinaryOp
+                            | GenericMutation::ComparisonBinaryOp
+                    )
+            }
+            MutationType::Solidity(_) => language == Language::Solidity,
+            MutationType::Vyper(_) => language == Language::Vyper,
+            // The custom mutator decides which nodes it can mutate.
+            MutationType::Custom(_) => true,
+        }
+    }
+
+    /// Return true if the mutation algorithm belongs to the curated default set of algorithms
+    /// for `language`.  The default set leaves out experimental algorithms and algorithms that
+    /// make large structural changes to a program.
+    ///
+    /// # Arguments
+    ///
+    /// * `language` - The language of the programs to mutate.
+    pub fn is_default_for_language(&self, language: Language) -> bool {
+        if !self.supports_language(language) {
+            return false;
+        }
+
+        match self {
+            MutationType::Generic(g) => !matches!(
+                g,
+                GenericMutation::DuplicateStatement | GenericMutation::SwapLines
+            ),
+            MutationType::Solidity(s) => match s {
+                SolidityMutation::Require
+                | SolidityMutation::UncheckedBlock
+                | SolidityMutation::ElimDelegateCall
+                | SolidityMutation::RequireComparison => true,
+                SolidityMutation::MemoryToStorage
+                | SolidityMutation::FixedBytesSize
+                | SolidityMutation::CallOptions
+                | SolidityMutation::SwapCalldataMemory
+                | SolidityMutation::NewToAddress
+                | SolidityMutation::SwapStructFields
+                | SolidityMutation::ConstantImmutable
+                | SolidityMutation::ArrayLength
+                | SolidityMutation::GlobalMemberAccess
+                | SolidityMutation::SwapModifiers
+                | SolidityMutation::LoopForm
+                | SolidityMutation::AssemblyLiteral
+                | SolidityMutation::TernaryIfElse
+                | SolidityMutation::RevertMessage
+                | SolidityMutation::FallbackPayability
+                | SolidityMutation::BalanceConstant
+                | SolidityMutation::BaseConstructorArguments
+                | SolidityMutation::SwapMsgContext
+                | SolidityMutation::RemoveDelete
+                | SolidityMutation::RemovePayableCast
+                | SolidityMutation::SwapEnumMembers
+                | SolidityMutation::LoopControl => false,
+            },
+            MutationType::Vyper(v) => match v {
+                VyperMutation::LowLevelCallTarget
+                | VyperMutation::StateVariableModifiers
+                | VyperMutation::RangeBound => false,
+            },
+            MutationType::Custom(_) => false,
+        }
+    }
+}
+
+/// An entry in the list of mutation algorithms for a language.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MutationTypeEntry {
+    /// The mutation algorithm.
+    pub mutation_type: MutationType,
+
+    /// True if the algorithm belongs to the default set of algorithms for the language.
+    pub default: bool,
+}
+
+/// Return the list of the mutation algorithms that can mutate programs written in `language`,
+/// sorted by algorithm name.  Each entry records whether the algorithm belongs to the default set
+/// of algorithms for the language.
+///
+/// # Arguments
+///
+/// * `language` - The language of the programs to mutate.
+pub fn all_mutation_types(language: Language) -> Vec<MutationTypeEntry> {
+    get_all_mutation_algorithms()
+        .into_iter()
+        .filter(|t| t.supports_language(language))
+        .map(|t| MutationTypeEntry {
+            mutation_type: t,
+            default: t.is_default_for_language(language),
+        })
+        .collect()
+}
+
+/// Return the default set of mutation algorithms for `language`.
+///
+/// # Arguments
+///
+/// * `language` - The language of the programs to mutate.
+pub fn default_mutation_types(language: Language) -> Vec<MutationType> {
+    all_mutation_types(language)
+        .into_iter()
+        .filter(|e| e.default)
+        .map(|e| e.mutation_type)
+        .collect()
+}
+
 /// Type that holds the description of a mutation algorithm.
 pub struct MutationAlgorithmDescription {
     /// The basic summary of the algorithm.
@@ -402,6 +864,31 @@ pub fn all_algorithm_descriptions() -> HashMap<MutationType, MutationAlgorithmDe
         },
     );
 
+    algorithm_map.insert(
+        MutationType::Generic(GenericMutation::AssignmentOperatorReplacement),
+        MutationAlgorithmDescription {
+            summary: "Replace the operator of a compound assignment expression with the simple \
+            assignment operator or with another compound assignment operator.",
+            extra_details: "This mutation algorithm only works for Solidity programs. The \
+            algorithm does not change the left hand side or the right hand side of the \
+            assignment.",
+            operators: compound_assignment_operators(),
+            examples: "a += b; might become a = b; or a -= b;",
+        },
+    );
+
+    algorithm_map.insert(
+        MutationType::Generic(GenericMutation::BooleanLiteralFlip),
+        MutationAlgorithmDescription {
+            summary: "Replace a boolean literal with the opposite boolean literal.",
+            extra_details: "The algorithm changes true to false and false to true wherever the \
+            literal appears, for example in a function call argument, a return statement, or an \
+            assignment.",
+            operators: vec![],
+            examples: "setPaused(true); would become setPaused(false);",
+        },
+    );
+
     algorithm_map.insert(
         MutationType::Generic(GenericMutation::DeleteStatement),
         MutationAlgorithmDescription {
@@ -409,12 +896,27 @@ pub fn all_algorithm_descriptions() -> HashMap<MutationType, MutationAlgorithmDe
             statement.",
             extra_details: "For languages that have variable declarations and return statements \
             the algorithm will not delete declarations or return statements in order to minimize \
-            compilation issues caused by the mutation.",
+            compilation issues caused by the mutation.  The algorithm also skips statements that \
+            follow a return, revert, break, or continue statement in the same block since the \
+            statements never run.",
             operators: vec![],
             examples: "",
         },
     );
 
+    algorithm_map.insert(
+        MutationType::Generic(GenericMutation::DuplicateStatement),
+        MutationAlgorithmDescription {
+            summary: "Randomly select a statement in a program block and insert a copy of the \
+            statement immediately after the original.",
+            extra_details: "The algorithm will not duplicate declarations or statements that \
+            end the flow of control (such as return or revert) in order to avoid generating \
+            mutants that do not compile.",
+            operators: vec![],
+            examples: "total += amount; would become total += amount; total += amount;",
+        },
+    );
+
     algorithm_map.insert(
         MutationType::Generic(GenericMutation::FunctionCall),
         MutationAlgorithmDescription {
@@ -460,7 +962,9 @@ pub fn all_algorithm_descriptions() -> HashMap<MutationType, MutationAlgorithmDe
             summary: "Randomly swap two arguments in a function call.",
             extra_details: "Find function calls in the program with two or more arguments, \
             randomly select two arguments, and swap them.  When possible, the mutation algorithm \
-            will select arguments with the same type to avoid compilation issues.",
+            will select arguments with the same type to avoid compilation issues.  The algorithm \
+            does not swap the arguments of Solidity calls with named arguments, such as \
+            f({a: 1, b: 2}), since the names bind the arguments to the parameters.",
             operators: vec![],
             examples: "a = foo(bar, bat, bug); might become a = foo(but, bat, bar);",
         },
@@ -535,5 +1039,406 @@ pub fn all_algorithm_descriptions() -> HashMap<MutationType, MutationAlgorithmDe
         },
     );
 
+    algorithm_map.insert(
+        MutationType::Solidity(SolidityMutation::RequireComparison),
+        MutationAlgorithmDescription {
+            summary: "Randomly select a use of the Solidity require() function whose argument \
+            is a comparison and replace the comparison operator with its negation.",
+            extra_details: "This mutation algorithm only works for Solidity programs. Unlike \
+            the Require algorithm, the argument expression is not wrapped in a logical negation.",
+            operators: comparison_operators(),
+            examples: "require(a >= b); would become require(a < b);",
+        },
+    );
+
+    algorithm_map.insert(
+        MutationType::Solidity(SolidityMutation::MemoryToStorage),
+        MutationAlgorithmDescription {
+            summary: "Randomly select a local struct variable declared with the memory data \
+            location and change the data location to storage.",
+            extra_details: "This mutation algorithm only works for Solidity programs. The \
+            algorithm only selects variables initialized from state-backed storage data, the \
+            only place where a storage pointer is legal.",
+            operators: vec![],
+            examples: "S memory s = structs[i]; would become S storage s = structs[i];",
+        },
+    );
+
+    algorithm_map.insert(
+        MutationType::Solidity(SolidityMutation::FixedBytesSize),
+        MutationAlgorithmDescription {
+            summary: "Randomly select a fixed-size byte array type (bytes1 through bytes32) \
+            and change the size of the type.",
+            extra_details: "This mutation algorithm only works for Solidity programs. Many of \
+            the generated mutants will not compile where the sizes of types must match, use \
+            mutant validation to filter those mutants.",
+            operators: vec![],
+            examples: "bytes32 a; might become bytes16 a;",
+        },
+    );
+
+    algorithm_map.insert(
+        MutationType::Solidity(SolidityMutation::CallOptions),
+        MutationAlgorithmDescription {
+            summary: "Randomly select a function call with value or gas options and replace \
+            the expression for one of the options with a different constant.",
+            extra_details: "This mutation algorithm only works for Solidity programs. The value \
+            option may become 0, 1 wei, or 1 ether and the gas option may become 0, 2300, or \
+            100000.",
+            operators: vec![],
+            examples: "a.call{value: 0}(\"\") might become a.call{value: 1 ether}(\"\")",
+        },
+    );
+
+    algorithm_map.insert(
+        MutationType::Solidity(SolidityMutation::SwapCalldataMemory),
+        MutationAlgorithmDescription {
+            summary: "Randomly select an array or bytes parameter of an external function and \
+            swap the data location of the parameter between calldata and memory.",
+            extra_details: "This mutation algorithm only works for Solidity programs. The \
+            algorithm only changes parameters of external functions, the only place where both \
+            data locations are legal.",
+            operators: vec![],
+            examples: "function f(uint256[] calldata a) external would become \
+            function f(uint256[] memory a) external",
+        },
+    );
+
+    algorithm_map.insert(
+        MutationType::Solidity(SolidityMutation::NewToAddress),
+        MutationAlgorithmDescription {
+            summary: "Randomly select a contract creation with new and replace the creation \
+            with a conversion of an existing address to the contract type.",
+            extra_details: "This mutation algorithm only works for Solidity programs. The \
+            replacement address is either an address argument of the contract creation or \
+            msg.sender.",
+            operators: vec![],
+            examples: "Token t = new Token(owner); would become Token t = Token(owner);",
+        },
+    );
+
+    algorithm_map.insert(
+        MutationType::Solidity(SolidityMutation::SwapStructFields),
+        MutationAlgorithmDescription {
+            summary: "Randomly select a struct constructor call or a sequence of assignments to \
+            members of the same struct and swap two values of the same type.",
+            extra_details: "This mutation algorithm only works for Solidity programs. The \
+            algorithm only swaps values whose types match so that the mutant still compiles.",
+            operators: vec![],
+            examples: "Order(buyer, seller, amount) would become Order(seller, buyer, amount)",
+        },
+    );
+
+    algorithm_map.insert(
+        MutationType::Solidity(SolidityMutation::ConstantImmutable),
+        MutationAlgorithmDescription {
+            summary: "Randomly select a state variable declared constant or immutable and \
+            change the declaration to use the other modifier or remove the modifier.",
+            extra_details: "This mutation algorithm only works for Solidity programs. Many of \
+            the generated mutants will not compile (constant variables require a compile-time \
+            value), use mutant validation to filter those mutants.",
+            operators: vec![],
+            examples: "uint256 public constant FEE = 30; might become \
+            uint256 public immutable FEE = 30;",
+        },
+    );
+
+    algorithm_map.insert(
+        MutationType::Solidity(SolidityMutation::ArrayLength),
+        MutationAlgorithmDescription {
+            summary: "Randomly select a read of the length of an array and replace the read \
+            with an integer constant.",
+            extra_details: "This mutation algorithm only works for Solidity programs. The \
+            constant is 0, 1, or a random value between 2 and 255.",
+            operators: vec![],
+            examples: "i < values.length might become i < 1",
+        },
+    );
+
+    algorithm_map.insert(
+        MutationType::Solidity(SolidityMutation::GlobalMemberAccess),
+        MutationAlgorithmDescription {
+            summary: "Randomly select an access to a member of the block, msg, or tx global \
+            variables and replace the access with a different global member of the same type.",
+            extra_details: "This mutation algorithm only works for Solidity programs. The \
+            algorithm compares the type of the access reported by the compiler to the type of \
+            each candidate member to avoid mutants that do not compile. Some members, such as \
+            block.prevrandao, only exist in recent versions of Solidity.",
+            operators: vec![],
+            examples: "block.timestamp might become block.number, msg.sender might become \
+            tx.origin",
+        },
+    );
+
+    algorithm_map.insert(
+        MutationType::Solidity(SolidityMutation::SwapModifiers),
+        MutationAlgorithmDescription {
+            summary: "Randomly select a function definition with two or more modifiers and swap \
+            two of the modifiers.",
+            extra_details: "This mutation algorithm only works for Solidity programs. The \
+            algorithm does not move base constructor calls in constructor definitions.",
+            operators: vec![],
+            examples: "function withdraw() external nonReentrant onlyOwner might become \
+            function withdraw() external onlyOwner nonReentrant",
+        },
+    );
+
+    algorithm_map.insert(
+        MutationType::Solidity(SolidityMutation::LoopForm),
+        MutationAlgorithmDescription {
+            summary: "Randomly select a for or while loop and rewrite the loop in the other \
+            loop form.",
+            extra_details: "This mutation algorithm only works for Solidity programs. The \
+            algorithm only rewrites a for loop as a while loop when the for loop has a condition \
+            and a loop expression and the body of the loop does not contain a continue \
+            statement. The algorithm wraps the initialization statement and the while loop in a \
+            block to keep the loop variables local to the loop.",
+            operators: vec![],
+            examples: "for (uint256 i = 0; i < n; i++) { ... } might become \
+            { uint256 i = 0; while (i < n) { ... i++; } }",
+        },
+    );
+
+    algorithm_map.insert(
+        MutationType::Solidity(SolidityMutation::AssemblyLiteral),
+        MutationAlgorithmDescription {
+            summary: "Randomly select a number literal in an inline assembly block and add or \
+            subtract one byte or one 32-byte word.",
+            extra_details: "This mutation algorithm only works for Solidity programs. Inline \
+            assembly often uses number literals as memory offsets and storage slots. The \
+            algorithm is not part of the default set of algorithms because the mutants can \
+            fail in ways that are hard to diagnose, use --validate-mutants or \
+            --validate-command to check the mutants.",
+            operators: vec![],
+            examples: "mload(0x40) might become mload(0x60), sstore(0, x) might become \
+            sstore(1, x)",
+        },
+    );
+
+    algorithm_map.insert(
+        MutationType::Solidity(SolidityMutation::TernaryIfElse),
+        MutationAlgorithmDescription {
+            summary: "Randomly select an assignment of a conditional expression or an if/else \
+            statement that assigns to one target and rewrite the statement in the other form.",
+            extra_details: "This mutation algorithm only works for Solidity programs. The \
+            algorithm only rewrites statements whose assignment target has no side effects. The \
+            algorithm only collapses an if/else statement into a conditional expression when \
+            both branches use the same assignment operator and both assigned values have the \
+            type of the target.",
+            operators: vec![],
+            examples: "fee = premium ? high : low; might become \
+            if (premium) { fee = high; } else { fee = low; }",
+        },
+    );
+
+    algorithm_map.insert(
+        MutationType::Solidity(SolidityMutation::RevertMessage),
+        MutationAlgorithmDescription {
+            summary: "Randomly select a string literal message of a require() call, a revert() \
+            call, or a revert statement and replace the message with a different string.",
+            extra_details: "This mutation algorithm only works for Solidity programs. The \
+            mutants compile, and only tests that check the exact revert message detect them.",
+            operators: vec![],
+            examples: "require(amount > 0, \"zero amount\"); might become \
+            require(amount > 0, \"\");",
+        },
+    );
+
+    algorithm_map.insert(
+        MutationType::Solidity(SolidityMutation::FallbackPayability),
+        MutationAlgorithmDescription {
+            summary: "Randomly select a fallback function and toggle its state mutability \
+            between payable and nonpayable.",
+            extra_details: "This mutation algorithm only works for Solidity programs. The \
+            algorithm skips receive functions because a receive function must be payable.",
+            operators: vec![],
+            examples: "fallback() external payable {} might become fallback() external {}",
+        },
+    );
+
+    algorithm_map.insert(
+        MutationType::Solidity(SolidityMutation::BalanceConstant),
+        MutationAlgorithmDescription {
+            summary: "Randomly select a read of the balance of an address and replace the read \
+            with an integer constant.",
+            extra_details: "This mutation algorithm only works for Solidity programs. The \
+            constant is 0, 1, or 10^18 (one ether in wei).",
+            operators: vec![],
+            examples: "address(this).balance >= amount might become 0 >= amount",
+        },
+    );
+
+    algorithm_map.insert(
+        MutationType::Solidity(SolidityMutation::BaseConstructorArguments),
+        MutationAlgorithmDescription {
+            summary: "Randomly select a constructor that calls a base constructor and replace \
+            one argument of the base constructor call with a boundary value.",
+            extra_details: "This mutation algorithm only works for Solidity programs. The \
+            boundary value is 0 or 1 for integer arguments, the other value for boolean \
+            arguments, and address(0) for address arguments. The algorithm does not change the \
+            arguments of modifiers.",
+            operators: vec![],
+            examples: "constructor() Owned(msg.sender) {} might become \
+            constructor() Owned(address(0)) {}",
+        },
+    );
+
+    algorithm_map.insert(
+        MutationType::Solidity(SolidityMutation::SwapMsgContext),
+        MutationAlgorithmDescription {
+            summary: "Randomly select an access to msg.sender or tx.origin and replace the \
+            access with the other global member.",
+            extra_details: "This mutation algorithm only works for Solidity programs. The \
+            algorithm only swaps members of the same type, so it does not change accesses to \
+            msg.value.",
+            operators: vec![],
+            examples: "require(msg.sender == owner); would become \
+            require(tx.origin == owner);",
+        },
+    );
+
+    algorithm_map.insert(
+        MutationType::Solidity(SolidityMutation::RemoveDelete),
+        MutationAlgorithmDescription {
+            summary: "Randomly select a statement that resets a variable with delete and \
+            comment out the statement.",
+            extra_details: "This mutation algorithm only works for Solidity programs. The \
+            algorithm only selects delete statements in a block of statements.",
+            operators: vec![],
+            examples: "delete balances[addr]; would become // delete balances[addr];",
+        },
+    );
+
+    algorithm_map.insert(
+        MutationType::Solidity(SolidityMutation::RemovePayableCast),
+        MutationAlgorithmDescription {
+            summary: "Randomly select a payable(...) conversion of an address and replace the \
+            conversion with the converted expression.",
+            extra_details: "This mutation algorithm only works for Solidity programs. A plain \
+            address cannot receive Ether with transfer() or send(), so the compiler rejects \
+            mutants that needed the conversion and accepts mutants where the conversion was \
+            unnecessary.",
+            operators: vec![],
+            examples: "payable(owner).transfer(fee); would become owner.transfer(fee);",
+        },
+    );
+
+    algorithm_map.insert(
+        MutationType::Solidity(SolidityMutation::SwapEnumMembers),
+        MutationAlgorithmDescription {
+            summary: "Randomly select an enum definition with at least two members and swap two \
+            members of the enum.",
+            extra_details: "This mutation algorithm only works for Solidity programs. The integer \
+            value of an enum member is the position of the member in the definition, so the \
+            mutants reveal code that depends on the numeric values of the members, such as \
+            conversions between enums and integers and comparisons of enum values.",
+            operators: vec![],
+            examples: "enum Status { Pending, Open, Closed } could become enum Status { Closed, \
+            Open, Pending }",
+        },
+    );
+
+    algorithm_map.insert(
+        MutationType::Solidity(SolidityMutation::LoopControl),
+        MutationAlgorithmDescription {
+            summary: "Randomly select a continue or break statement in the body of a for, while, \
+            or do-while loop and either comment out the statement or replace continue with break \
+            and break with continue.",
+            extra_details: "This mutation algorithm only works for Solidity programs. The \
+            algorithm only comments out the statements that appear in a block, since commenting \
+            out the whole body of a loop or an if statement would not compile.",
+            operators: vec![],
+            examples: "if (skip[i]) { continue; } could become if (skip[i]) { break; } or \
+            if (skip[i]) { // continue; }",
+        },
+    );
+
+    algorithm_map.insert(
+        MutationType::Vyper(VyperMutation::LowLevelCallTarget),
+        MutationAlgorithmDescription {
+            summary: "Randomly select a use of the Vyper send(), raw_call(), or \
+            create_from_blueprint() functions and replace the target argument with a \
+            different address expression.",
+            extra_details: "This mutation algorithm only works for Vyper programs. The \
+            replacement target is one of msg.sender, tx.origin, self, or empty(address).",
+            operators: vec![],
+            examples: "send(self.owner, amount) might become send(msg.sender, amount)",
+        },
+    );
+
+    algorithm_map.insert(
+        MutationType::Vyper(VyperMutation::StateVariableModifiers),
+        MutationAlgorithmDescription {
+            summary: "Randomly select a module-level variable declaration and change the \
+            public, constant, and immutable modifiers of the declaration.",
+            extra_details: "This mutation algorithm only works for Vyper programs. The \
+            algorithm only makes a variable constant when the declaration has a literal value \
+            and removes the value when the variable stops being constant. Uses of the variable \
+            in the rest of the program may not compile, use mutant validation to filter those \
+            mutants.",
+            operators: vec![],
+            examples: "FEE: public(constant(uint256)) = 30 might become \
+            FEE: constant(uint256) = 30",
+        },
+    );
+
+    algorithm_map.insert(
+        MutationType::Vyper(VyperMutation::RangeBound),
+        MutationAlgorithmDescription {
+            summary: "Randomly select a for loop that iterates over a range() call and add one \
+            to a bound, subtract one from a bound, or swap the bounds of a two-argument range.",
+            extra_details: "This mutation algorithm only works for Vyper programs. The \
+            algorithm keeps the bounds constant expressions and never makes a literal bound \
+            negative.",
+            operators: vec![],
+            examples: "for i in range(10): might become for i in range(11):",
+        },
+    );
+
     algorithm_map
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_mutation_types_for_language() {
+        let solidity_types = all_mutation_types(Language::Solidity);
+        assert!(solidity_types
+            .iter()
+            .all(|e| e.mutation_type.supports_language(Language::Solidity)));
+        assert!(solidity_types
+            .iter()
+            .any(|e| e.mutation_type == MutationType::Solidity(SolidityMutation::Require)));
+        assert!(!solidity_types
+            .iter()
+            .any(|e| matches!(e.mutation_type, MutationType::Vyper(_))));
+
+        let vyper_types = all_mutation_types(Language::Vyper);
+        assert!(!vyper_types
+            .iter()
+            .any(|e| matches!(e.mutation_type, MutationType::Solidity(_))));
+    }
+
+    #[test]
+    fn test_default_mutation_types() {
+        let defaults = default_mutation_types(Language::Solidity);
+        assert!(defaults.contains(&MutationType::Generic(GenericMutation::ArithmeticBinaryOp)));
+        assert!(defaults.contains(&MutationType::Solidity(SolidityMutation::Require)));
+        assert!(!defaults.contains(&MutationType::Generic(GenericMutation::DuplicateStatement)));
+        assert!(!defaults.contains(&MutationType::Vyper(VyperMutation::LowLevelCallTarget)));
+
+        let defaults = default_mutation_types(Language::Vyper);
+        assert!(defaults.contains(&MutationType::Generic(GenericMutation::IfStatement)));
+        assert!(!defaults.contains(&MutationType::Solidity(SolidityMutation::Require)));
+    }
+
+    #[test]
+    fn test_mutation_type_names_round_trip() {
+        for mutation_type in get_all_mutation_algorithms() {
+            let name = mutation_type.to_string();
+            assert_eq!(MutationType::from_str(&name).ok(), Some(mutation_type));
+        }
+    }
+}
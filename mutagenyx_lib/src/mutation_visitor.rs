@@ -1,6 +1,15 @@
 //! The `mutation_visitor` module provides one [`Visitor`] type for traversing an AST and counting
 //! mutable nodes and one [`VisitorMut`] type for traversing an AST and mutating nodes.
-
+//!
+//! More than one mutator may be able to mutate the same node, for example both the
+//! ComparisonBinaryOp and the SwapOperatorArguments mutators accept a `<` operation.  The
+//! visitors always consult the mutators of such a contested node in [`MutationType`] order, which
+//! is the order in which the enumerations declare the algorithms: the generic algorithms first,
+//! then the Solidity and the Vyper algorithms, and last the custom algorithms sorted by name.
+//! The order does not depend on the order in which the caller registers the mutators, so the
+//! same seed always selects the same mutator for a contested node.
+
+use crate::commenter::CommentDetector;
 use crate::id::Id;
 use crate::mutation::MutationType;
 use crate::mutator::*;
@@ -11,10 +20,14 @@ use crate::permit::*;
 use crate::visitor::*;
 use crate::MutagenyxError;
 use rand_pcg::*;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// Helper function that returns true if the permitter allows the traverser permission to visit `node`.
 ///
+/// The permitter denies permission to visit a node when it denies permission to visit any of the
+/// kinds of the node, such as the type of the node.  The traverser then skips the node and the
+/// children of the node.
+///
 /// # Arguments
 ///
 /// * `namer` - A [`Namer<AST>`] trait object that can convert an AST node to a name string.
@@ -25,6 +38,15 @@ fn has_permission_to_visit<AST>(
     permitter: &dyn Permit<AST>,
     node: &AST,
 ) -> bool {
+    let forbidden = namer.get_kinds(node).into_iter().any(|kind| {
+        permitter.has_permission_to(PermissionAction::Visit(PermissionScope::Type(
+            PermissionObject::Value(kind, Permission::Deny),
+        )))
+    });
+    if forbidden {
+        return false;
+    }
+
     if let Some(node_name) = namer.get_name(node) {
         permitter.has_permission_to(PermissionAction::Visit(PermissionScope::Name(
             PermissionObject::Value(node_name, Permission::Allow),
@@ -102,6 +124,9 @@ pub struct PathVisitor<'a, AST> {
 
     /// A [`Namer<AST>`] trait object that can convert an AST node to a name string.
     namer: Box<dyn Namer<AST> + 'a>,
+
+    /// A [`CommentDetector<AST>`] trait object that recognizes comment nodes.
+    comment_detector: Box<dyn CommentDetector<AST> + 'a>,
 }
 
 impl<'a, AST> PathVisitor<'a, AST> {
@@ -112,10 +137,12 @@ impl<'a, AST> PathVisitor<'a, AST> {
     /// * `permitter` - A [`Permit<AST>`] object that responds to permission queries.
     /// * `id_maker` - A [`Id<AST>`] object that can uniquely identify each node in the AST.
     /// * `namer` - A [`Namer<AST>`] object that can generate a name from an AST node.
+    /// * `comment_detector` - A [`CommentDetector<AST>`] object that recognizes comment nodes.
     pub fn new(
         permitter: Box<dyn Permit<AST> + 'a>,
         id_maker: Box<dyn Id<AST> + 'a>,
         namer: Box<dyn Namer<AST> + 'a>,
+        comment_detector: Box<dyn CommentDetector<AST> + 'a>,
     ) -> PathVisitor<'a, AST> {
         PathVisitor {
             permitter,
@@ -123,13 +150,17 @@ impl<'a, AST> PathVisitor<'a, AST> {
             path_map: NodePathMap::new(),
             current_path: NodePath::new(),
             namer,
+            comment_detector,
         }
     }
 }
 
 impl<'a, AST> Visitor<AST> for PathVisitor<'a, AST> {
     fn have_permission_to_visit(&self, node: &AST) -> bool {
-        has_permission_to_visit(self.namer.as_ref(), self.permitter.as_ref(), node)
+        // A comment node from an earlier mutation may hold a copy of a removed node, skip the
+        // comment and its children.
+        !self.comment_detector.is_comment(node)
+            && has_permission_to_visit(self.namer.as_ref(), self.permitter.as_ref(), node)
     }
 
     fn visit(&mut self, node: &AST) -> bool {
@@ -154,14 +185,45 @@ impl<'a, AST> Visitor<AST> for PathVisitor<'a, AST> {
     }
 }
 
+/// The function that returns the text that identifies a node in a log message.
+pub type NodeDescriber<'a, AST> = Box<dyn Fn(&AST) -> String + 'a>;
+
+/// The function that returns true for the nodes that a visitor must skip along with their
+/// children.
+pub type NodeExcluder<'a, AST> = Box<dyn Fn(&AST) -> bool + 'a>;
+
+/// The function that returns true for the nodes that a visitor may mutate.  Unlike a
+/// [`NodeExcluder`], the function does not keep the visitor from visiting the children of the
+/// other nodes.
+pub type NodeSelector<'a, AST> = Box<dyn Fn(&AST) -> bool + 'a>;
+
+/// The function that returns the source line of a node.
+pub type NodeLineFinder<'a, AST> = Box<dyn Fn(&AST) -> Option<usize> + 'a>;
+
+/// A node that one or more mutation algorithms can mutate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MutableNode {
+    /// The id of the node.
+    pub node_id: u64,
+
+    /// The line of the node in the source file, None if not known.
+    pub line: Option<usize>,
+
+    /// The mutation algorithms that can mutate the node, in [`MutationType`] order.
+    pub mutation_types: Vec<MutationType>,
+}
+
 /// The struct for traversing an AST and counting mutable nodes in the AST.  The type conforms/adopts
 /// the [`Visitor`] trait.
 pub struct MutableNodesCounter<'a, AST> {
     /// Table for counting the number of mutable nodes by [`MutationType`].
     pub counter_table: HashMap<MutationType, u64>,
 
-    /// A reference to the map of mutators by [`MutationType`].
-    mutator_map: &'a mut HashMap<MutationType, Box<dyn Mutator<AST>>>,
+    /// A reference to the map of mutators by [`MutationType`].  The map is ordered so that when
+    /// more than one mutator can mutate the same node, the visitor always consults the mutators
+    /// in [`MutationType`] order.  Mutators may consume random numbers when checking a node, so
+    /// a stable order keeps the traversal reproducible for a given seed.
+    mutator_map: &'a mut BTreeMap<MutationType, Box<dyn Mutator<AST>>>,
 
     /// A [`Permit`] trait object that responds to permission queries.
     permitter: Box<dyn Permit<AST> + 'a>,
@@ -172,9 +234,30 @@ pub struct MutableNodesCounter<'a, AST> {
     /// A [`Namer<AST>`] trait object that can convert an AST node to a name string.
     namer: Box<dyn Namer<AST> + 'a>,
 
+    /// A [`CommentDetector<AST>`] trait object that recognizes comment nodes.
+    comment_detector: Box<dyn CommentDetector<AST> + 'a>,
+
     /// True if the visitor is traversing the children of a mutable node and does not need to
     /// check the permission for each child.
     skip_mutation_permission_check: bool,
+
+    /// The function that describes a node in the trace of the mutable node selection, None if
+    /// the visitor does not trace the selection.
+    node_describer: Option<NodeDescriber<'a, AST>>,
+
+    /// The functions that find the id and the source line of a mutable node, None if the visitor
+    /// does not collect the mutable nodes.
+    node_locator: Option<(Box<dyn Id<AST> + 'a>, NodeLineFinder<'a, AST>)>,
+
+    /// The mutable nodes in traversal order, collected if the visitor has a node locator.
+    pub mutable_nodes: Vec<MutableNode>,
+
+    /// The function that selects the nodes that the visitor skips, None to visit every node.
+    node_excluder: Option<NodeExcluder<'a, AST>>,
+
+    /// The function that selects the nodes that the mutators may mutate, None to let the
+    /// mutators mutate every visited node.
+    node_selector: Option<NodeSelector<'a, AST>>,
 }
 
 impl<'a, AST> MutableNodesCounter<'a, AST> {
@@ -185,11 +268,13 @@ impl<'a, AST> MutableNodesCounter<'a, AST> {
     /// * `mutator_map` - A reference to an external map of mutators by [`MutationType`].
     /// * `permitter` - A [`Permit`] trait object that answers permission questions.
     /// * `namer` - A [`Namer<AST>`] object that can generate a name from an AST node.
+    /// * `comment_detector` - A [`CommentDetector<AST>`] object that recognizes comment nodes.
     pub fn new(
-        mutator_map: &'a mut HashMap<MutationType, Box<dyn Mutator<AST>>>,
+        mutator_map: &'a mut BTreeMap<MutationType, Box<dyn Mutator<AST>>>,
         permitter: Box<dyn Permit<AST> + 'a>,
         rng: &'a mut Pcg64,
         namer: Box<dyn Namer<AST> + 'a>,
+        comment_detector: Box<dyn CommentDetector<AST> + 'a>,
     ) -> MutableNodesCounter<'a, AST> {
         MutableNodesCounter {
             counter_table: HashMap::new(),
@@ -197,7 +282,74 @@ impl<'a, AST> MutableNodesCounter<'a, AST> {
             permitter,
             rng,
             namer,
+            comment_detector,
             skip_mutation_permission_check: false,
+            node_describer: None,
+            node_locator: None,
+            mutable_nodes: Vec::new(),
+            node_excluder: None,
+            node_selector: None,
+        }
+    }
+
+    /// Log (at debug level) each node that each mutator examines and the reason that the mutator
+    /// rejects the node.
+    ///
+    /// # Arguments
+    ///
+    /// * `node_describer` - The function that returns the id and type of a node for the log.
+    pub fn trace_selection(mut self, node_describer: NodeDescriber<'a, AST>) -> Self {
+        self.node_describer = Some(node_describer);
+        self
+    }
+
+    /// Collect each mutable node with an id in `mutable_nodes` along with the mutation types
+    /// that can mutate the node.
+    ///
+    /// # Arguments
+    ///
+    /// * `id_maker` - A [`Id<AST>`] object that returns the id of a node.
+    /// * `line_finder` - The function that returns the source line of a node.
+    pub fn collect_mutable_nodes(
+        mut self,
+        id_maker: Box<dyn Id<AST> + 'a>,
+        line_finder: NodeLineFinder<'a, AST>,
+    ) -> Self {
+        self.node_locator = Some((id_maker, line_finder));
+        self
+    }
+
+    /// Skip the nodes selected by `node_excluder` and their children, so that no mutator counts
+    /// them as mutable.
+    ///
+    /// # Arguments
+    ///
+    /// * `node_excluder` - The function that returns true for the nodes to skip.
+    pub fn exclude_nodes(mut self, node_excluder: NodeExcluder<'a, AST>) -> Self {
+        self.node_excluder = Some(node_excluder);
+        self
+    }
+
+    /// Only count the nodes selected by `node_selector` as mutable.  The visitor still visits
+    /// the children of the other nodes.
+    ///
+    /// # Arguments
+    ///
+    /// * `node_selector` - The function that returns true for the nodes the mutators may mutate.
+    pub fn select_nodes(mut self, node_selector: NodeSelector<'a, AST>) -> Self {
+        self.node_selector = Some(node_selector);
+        self
+    }
+
+    /// Return true if the node selector, if any, selects `node`.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The node to check.
+    fn is_selected(&self, node: &AST) -> bool {
+        match &self.node_selector {
+            Some(node_selector) => node_selector(node),
+            None => true,
         }
     }
 }
@@ -206,21 +358,58 @@ impl<'a, AST> Visitor<AST> for MutableNodesCounter<'a, AST> {
     fn on_enter(&mut self, _node: &AST) {}
 
     fn have_permission_to_visit(&self, node: &AST) -> bool {
-        has_permission_to_visit(self.namer.as_ref(), self.permitter.as_ref(), node)
+        // Comment nodes from an earlier mutation are not part of the program, so neither they
+        // nor any node copies they hold are candidates for mutation.
+        !self.comment_detector.is_comment(node)
+            && !self.node_excluder.as_ref().is_some_and(|e| e(node))
+            && has_permission_to_visit(self.namer.as_ref(), self.permitter.as_ref(), node)
     }
 
     fn visit(&mut self, node: &AST) -> bool {
-        if self.skip_mutation_permission_check
-            || has_permission_to_mutate(self.namer.as_ref(), self.permitter.as_ref(), node)
+        if (self.skip_mutation_permission_check
+            || has_permission_to_mutate(self.namer.as_ref(), self.permitter.as_ref(), node))
+            && self.is_selected(node)
         {
+            let mut mutation_types: Vec<MutationType> = Vec::new();
+
             for (key, mutator) in self.mutator_map.iter_mut() {
-                if mutator.is_mutable_node(node, self.rng) {
+                let is_mutable = mutator.is_mutable_node(node, self.rng);
+
+                if let Some(node_describer) = &self.node_describer {
+                    if is_mutable {
+                        log::debug!("{} accepted node {}", key, node_describer(node));
+                    } else {
+                        log::debug!(
+                            "{} rejected node {}: {}",
+                            key,
+                            node_describer(node),
+                            mutator
+                                .rejection_reason(node)
+                                .unwrap_or_else(|| String::from("not a mutable node"))
+                        );
+                    }
+                }
+
+                if is_mutable {
                     if self.counter_table.contains_key(key) {
                         let size = self.counter_table.get_mut(key).unwrap();
                         *size += 1;
                     } else {
                         self.counter_table.insert(*key, 1);
                     }
+                    mutation_types.push(*key);
+                }
+            }
+
+            if let Some((id_maker, line_finder)) = &self.node_locator {
+                if let Some(node_id) = id_maker.get_id(node) {
+                    if !mutation_types.is_empty() {
+                        self.mutable_nodes.push(MutableNode {
+                            node_id,
+                            line: line_finder(node),
+                            mutation_types,
+                        });
+                    }
                 }
             }
         }
@@ -281,14 +470,28 @@ pub struct MutationMaker<'a, AST> {
     /// A [`Namer<AST>`] trait object that can convert an AST node to a name string.
     namer: Box<dyn Namer<AST> + 'a>,
 
+    /// A [`CommentDetector<AST>`] trait object that recognizes comment nodes.
+    comment_detector: Box<dyn CommentDetector<AST> + 'a>,
+
     /// True if the visitor is mutating child nodes and can skip the node mutation permission check.
     skip_mutation_permission_check: bool,
 
     /// The cached results from the mutation.
     pub mutation_results: MutatorResult,
 
+    /// True if the mutator mutated the index(th) node.  The value stays false if the traversal
+    /// never reached the node or if the mutator failed to mutate the node.
+    pub mutation_made: bool,
+
     /// A cached error from the mutator.
     pub error: Option<MutagenyxError>,
+
+    /// The function that selects the nodes that the visitor skips, None to visit every node.
+    node_excluder: Option<NodeExcluder<'a, AST>>,
+
+    /// The function that selects the nodes that the mutators may mutate, None to let the
+    /// mutators mutate every visited node.
+    node_selector: Option<NodeSelector<'a, AST>>,
 }
 
 impl<'a, AST> MutationMaker<'a, AST> {
@@ -301,6 +504,7 @@ impl<'a, AST> MutationMaker<'a, AST> {
     /// * `index` - The index of the node in the AST to mutate.
     /// * `permitter` - A [`Permit`] trait object that answers permission questions.
     /// * `namer` - A [`Namer<AST>`] object that can generate a name from an AST node.
+    /// * `comment_detector` - A [`CommentDetector<AST>`] object that recognizes comment nodes.
     pub fn new(
         mutator: &'a mut dyn Mutator<AST>,
         rng: &'a mut Pcg64,
@@ -308,6 +512,7 @@ impl<'a, AST> MutationMaker<'a, AST> {
         permitter: Box<dyn Permit<AST> + 'a>,
         id_maker: Box<dyn Id<AST> + 'a>,
         namer: Box<dyn Namer<AST> + 'a>,
+        comment_detector: Box<dyn CommentDetector<AST> + 'a>,
     ) -> MutationMaker<'a, AST> {
         MutationMaker {
             mutator,
@@ -319,9 +524,47 @@ impl<'a, AST> MutationMaker<'a, AST> {
             mutated_node_id: 0_u64,
             mutator_comment: None,
             namer,
+            comment_detector,
             skip_mutation_permission_check: false,
             mutation_results: MutatorResult::new(),
+            mutation_made: false,
             error: None,
+            node_excluder: None,
+            node_selector: None,
+        }
+    }
+
+    /// Skip the nodes selected by `node_excluder` and their children.  The maker must skip the
+    /// same nodes as the [`MutableNodesCounter`] that counted the mutable nodes.
+    ///
+    /// # Arguments
+    ///
+    /// * `node_excluder` - The function that returns true for the nodes to skip.
+    pub fn exclude_nodes(mut self, node_excluder: NodeExcluder<'a, AST>) -> Self {
+        self.node_excluder = Some(node_excluder);
+        self
+    }
+
+    /// Only mutate the nodes selected by `node_selector`.  The maker must select the same nodes
+    /// as the [`MutableNodesCounter`] that counted the mutable nodes.
+    ///
+    /// # Arguments
+    ///
+    /// * `node_selector` - The function that returns true for the nodes the mutator may mutate.
+    pub fn select_nodes(mut self, node_selector: NodeSelector<'a, AST>) -> Self {
+        self.node_selector = Some(node_selector);
+        self
+    }
+
+    /// Return true if the node selector, if any, selects `node`.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The node to check.
+    fn is_selected(&self, node: &AST) -> bool {
+        match &self.node_selector {
+            Some(node_selector) => node_selector(node),
+            None => true,
         }
     }
 }
@@ -330,17 +573,22 @@ impl<'a, AST> VisitorMut<AST> for MutationMaker<'a, AST> {
     fn on_enter(&mut self, _node: &mut AST) {}
 
     fn have_permission_to_visit(&self, node: &AST) -> bool {
-        has_permission_to_visit(self.namer.as_ref(), self.permitter.as_ref(), node)
+        // Skip comment nodes so that the maker finds the same nodes as the counter.
+        !self.comment_detector.is_comment(node)
+            && !self.node_excluder.as_ref().is_some_and(|e| e(node))
+            && has_permission_to_visit(self.namer.as_ref(), self.permitter.as_ref(), node)
     }
 
     fn visit_mut(&mut self, node: &mut AST) -> bool {
         if (self.skip_mutation_permission_check
             || has_permission_to_mutate(self.namer.as_ref(), self.permitter.as_ref(), node))
+            && self.is_selected(node)
             && self.mutator.is_mutable_node(node, self.rng)
         {
             if self.current_index == self.index {
                 match self.mutator.mutate(node, self.rng) {
                     Ok(result) => {
+                        self.mutation_made = true;
                         self.mutation_results = result.clone();
                         if let Some(node_id) = result.mutated_node_id {
                             self.mutated_node_id = node_id;
@@ -379,3 +627,280 @@ impl<'a, AST> VisitorMut<AST> for MutationMaker<'a, AST> {
 
     fn on_exit(&mut self, _node: &mut AST) {}
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::ASTTraverser;
+    use crate::language::Language;
+    use crate::language_interface::LanguageInterface;
+    use crate::mutation::{GenericMutation, SolidityMutation};
+    use crate::super_ast::SuperAST;
+    use crate::test_fixtures::{all_permissions, solidity_ast_with_statements};
+    use rand::{RngCore, SeedableRng};
+    use serde_json::{json, Value};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    type SelectionLog = Rc<RefCell<Vec<(MutationType, u64)>>>;
+
+    /// Mutator that records the order in which the counter consults it along with the random
+    /// number it drew.
+    struct RecordingMutator {
+        mutation_type: MutationType,
+        log: SelectionLog,
+    }
+
+    impl Mutator<Value> for RecordingMutator {
+        fn is_mutable_node(&mut self, _node: &Value, rand: &mut Pcg64) -> bool {
+            self.log
+                .borrow_mut()
+                .push((self.mutation_type, rand.next_u64()));
+            true
+        }
+
+        fn mutate(
+            &mut self,
+            _node: &mut Value,
+            _rand: &mut Pcg64,
+        ) -> Result<MutatorResult, MutagenyxError> {
+            Ok(MutatorResult::new())
+        }
+
+        fn implements(&self) -> MutationType {
+            self.mutation_type
+        }
+    }
+
+    /// Mutator that claims every node but fails to mutate any of them.
+    struct FailingMutator {}
+
+    impl Mutator<Value> for FailingMutator {
+        fn is_mutable_node(&mut self, _node: &Value, _rand: &mut Pcg64) -> bool {
+            true
+        }
+
+        fn mutate(
+            &mut self,
+            _node: &mut Value,
+            _rand: &mut Pcg64,
+        ) -> Result<MutatorResult, MutagenyxError> {
+            Err(MutagenyxError::MalformedNode(
+                String::from("Literal"),
+                String::from("value"),
+            ))
+        }
+
+        fn implements(&self) -> MutationType {
+            MutationType::Generic(GenericMutation::Integer)
+        }
+    }
+
+    /// Mutator that claims every literal node.
+    struct LiteralMutator {}
+
+    impl Mutator<Value> for LiteralMutator {
+        fn is_mutable_node(&mut self, node: &Value, _rand: &mut Pcg64) -> bool {
+            node.get("nodeType") == Some(&json!("Literal"))
+        }
+
+        fn mutate(
+            &mut self,
+            _node: &mut Value,
+            _rand: &mut Pcg64,
+        ) -> Result<MutatorResult, MutagenyxError> {
+            Ok(MutatorResult::new())
+        }
+
+        fn implements(&self) -> MutationType {
+            MutationType::Generic(GenericMutation::Integer)
+        }
+    }
+
+    struct AllowAll {}
+
+    impl Permit<Value> for AllowAll {
+        fn has_permission_to(&self, _action: PermissionAction) -> bool {
+            true
+        }
+    }
+
+    struct NoId {}
+
+    impl Id<Value> for NoId {
+        fn get_id(&self, _node: &Value) -> Option<u64> {
+            None
+        }
+    }
+
+    struct NoName {}
+
+    impl Namer<Value> for NoName {
+        fn get_name(&self, _thing: &Value) -> Option<String> {
+            None
+        }
+    }
+
+    struct CommentNodeType {}
+
+    impl CommentDetector<Value> for CommentNodeType {
+        fn is_comment(&self, node: &Value) -> bool {
+            node.get("nodeType") == Some(&json!("Comment"))
+        }
+    }
+
+    fn count_with_insertion_order(types: &[MutationType]) -> Vec<(MutationType, u64)> {
+        let log: SelectionLog = Rc::new(RefCell::new(vec![]));
+        let mut mutator_map: BTreeMap<MutationType, Box<dyn Mutator<Value>>> = BTreeMap::new();
+        for t in types {
+            mutator_map.insert(
+                *t,
+                Box::new(RecordingMutator {
+                    mutation_type: *t,
+                    log: log.clone(),
+                }),
+            );
+        }
+
+        let mut rng = Pcg64::seed_from_u64(42);
+        let mut counter = MutableNodesCounter::new(
+            &mut mutator_map,
+            Box::new(AllowAll {}),
+            &mut rng,
+            Box::new(NoName {}),
+            Box::new(CommentNodeType {}),
+        );
+        counter.visit(&json!({"nodeType": "Literal"}));
+        assert_eq!(counter.counter_table.len(), types.len());
+
+        let result = log.borrow().clone();
+        result
+    }
+
+    #[test]
+    fn test_competing_mutators_consulted_in_stable_order() {
+        let mut types = vec![
+            MutationType::Solidity(SolidityMutation::Require),
+            MutationType::Generic(GenericMutation::UnaryOp),
+            MutationType::Generic(GenericMutation::ArithmeticBinaryOp),
+            MutationType::Generic(GenericMutation::Integer),
+        ];
+
+        let first = count_with_insertion_order(&types);
+        types.reverse();
+        let second = count_with_insertion_order(&types);
+        assert_eq!(first, second);
+
+        types.sort();
+        let order: Vec<MutationType> = first.iter().map(|(t, _)| *t).collect();
+        assert_eq!(order, types);
+    }
+
+    #[test]
+    fn test_mutation_maker_records_failed_mutation() {
+        let mut mutator = FailingMutator {};
+        let mut rng = Pcg64::seed_from_u64(42);
+        let mut maker = MutationMaker::new(
+            &mut mutator,
+            &mut rng,
+            0,
+            Box::new(AllowAll {}),
+            Box::new(NoId {}),
+            Box::new(NoName {}),
+            Box::new(CommentNodeType {}),
+        );
+
+        let mut node = json!({"nodeType": "Literal"});
+        assert!(maker.visit_mut(&mut node));
+        assert!(!maker.mutation_made);
+        assert!(maker.error.is_some());
+    }
+
+    #[test]
+    fn test_comment_nodes_are_not_mutable() {
+        let mut mutator_map: BTreeMap<MutationType, Box<dyn Mutator<Value>>> = BTreeMap::new();
+        mutator_map.insert(
+            MutationType::Generic(GenericMutation::Integer),
+            Box::new(LiteralMutator {}),
+        );
+
+        // The second statement is the comment that replaces a deleted statement.
+        let ast = json!({"nodeType": "Block", "statements": [
+            {"nodeType": "Literal"},
+            {"nodeType": "Comment", "value": {"nodeType": "Literal"}},
+            {"nodeType": "Comment", "text": "Integer Mutator: changed '1' to '2'"}
+        ]});
+
+        let mut rng = Pcg64::seed_from_u64(42);
+        let mut counter = MutableNodesCounter::new(
+            &mut mutator_map,
+            Box::new(AllowAll {}),
+            &mut rng,
+            Box::new(NoName {}),
+            Box::new(CommentNodeType {}),
+        );
+        ASTTraverser::traverse(&ast, &mut counter);
+
+        // Only the first literal, not the literal held by the comment.
+        assert_eq!(
+            counter.counter_table[&MutationType::Generic(GenericMutation::Integer)],
+            1
+        );
+    }
+
+    #[test]
+    fn test_contested_node_selection_is_stable() {
+        use rand::seq::SliceRandom;
+
+        let ast = SuperAST::Solidity(solidity_ast_with_statements(json!([{
+            "nodeType": "ExpressionStatement", "id": 60, "expression": {
+                "nodeType": "BinaryOperation", "id": 61, "operator": "<",
+                "leftExpression": {"nodeType": "Identifier", "id": 62, "name": "a"},
+                "rightExpression": {"nodeType": "Identifier", "id": 63, "name": "b"},
+                "typeDescriptions": {"typeIdentifier": "t_bool", "typeString": "bool"}
+            }
+        }])));
+
+        let mut types = vec![
+            MutationType::Generic(GenericMutation::SwapOperatorArguments),
+            MutationType::Generic(GenericMutation::ComparisonBinaryOp),
+        ];
+
+        let mut selections: Vec<(Vec<MutationType>, MutationType)> = vec![];
+        for _ in 0..2 {
+            let mut language_object =
+                LanguageInterface::get_language_object_for_language(&Language::Solidity).unwrap();
+            language_object
+                .select_mutators_for_mutation_types(&types)
+                .unwrap();
+
+            let mut rng = Pcg64::seed_from_u64(42);
+            let nodes = language_object
+                .list_mutable_nodes(&ast, &mut rng, &all_permissions(), None)
+                .unwrap();
+            let contested = nodes
+                .iter()
+                .find(|n| n.node_id == 61)
+                .unwrap()
+                .mutation_types
+                .clone();
+
+            // Select a mutator for the node the way the generators do.
+            let mut rng = Pcg64::seed_from_u64(7);
+            let selected = *contested.choose(&mut rng).unwrap();
+            selections.push((contested, selected));
+
+            // Register the mutators in the other order for the next run.
+            types.reverse();
+        }
+
+        assert_eq!(selections[0], selections[1]);
+        assert_eq!(
+            selections[0].0,
+            vec![
+                MutationType::Generic(GenericMutation::ComparisonBinaryOp),
+                MutationType::Generic(GenericMutation::SwapOperatorArguments),
+            ]
+        );
+    }
+}
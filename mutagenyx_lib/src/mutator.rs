@@ -45,6 +45,40 @@ pub trait Mutator<N> {
     fn get_comment_node(&self) -> Option<N> {
         None
     }
+
+    /// Return a one sentence description of the mutation that the mutator makes for people
+    /// reading the list of algorithms.  Every built-in mutator describes itself; the default
+    /// implementation returns an empty string.
+    fn description(&self) -> &'static str {
+        ""
+    }
+
+    /// Return an example of the mutations that the mutator makes, or an empty string if the
+    /// mutator has no example.
+    fn example(&self) -> &'static str {
+        ""
+    }
+
+    /// Return the reason that the mutator cannot mutate `node`, or None if the mutator does not
+    /// explain its decisions.  The visitors only ask for the reason when tracing the selection
+    /// of mutable nodes.  Only the binary and unary operator mutators explain their decisions;
+    /// the trace reports the other rejected nodes as not mutable.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The node that `is_mutable_node` rejected.
+    fn rejection_reason(&self, _node: &N) -> Option<String> {
+        None
+    }
+}
+
+/// The description and the example that a mutator supplies for a mutation algorithm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MutatorDescription {
+    /// The text of [`Mutator::description`].
+    pub description: &'static str,
+    /// The text of [`Mutator::example`].
+    pub example: &'static str,
 }
 
 /// Trait that describes the functionality need from the object that will create mutators on
@@ -5,4 +5,10 @@
 pub trait Namer<T> {
     /// Return a String representing the name of self.
     fn get_name(&self, thing: &T) -> Option<String>;
+
+    /// Return the kinds of `thing`, such as the type of an AST node.  Permissions with the
+    /// [`crate::permissions::PermissionScope::Type`] scope apply to these kinds.
+    fn get_kinds(&self, _thing: &T) -> Vec<String> {
+        Vec::new()
+    }
 }
@@ -1,8 +1,13 @@
 //! The `node_printer` module contains the [`NodePrinter<AST>`] and [`NodePrinterFactory<AST>`]
-//! traits.
+//! traits, [`OverridingNodePrinterFactory<AST>`], a factory that replaces the printers of
+//! selected node types in another factory, and [`pretty_print_node`], which prints a JSON AST
+//! with the printers of any factory.
 
+use crate::node_printer_helpers::traverse_sub_node_and_print;
 use crate::preferences::{PreferenceValue, Preferences};
 use crate::pretty_printer::PrettyPrinter;
+use serde_json::Value;
+use std::collections::HashMap;
 use std::io::Write;
 
 /// Trait that provides the functionality needed by objects that will print different nodes in an
@@ -82,6 +87,11 @@ pub trait NodePrinter<AST> {
 pub trait NodePrinterFactory<AST> {
     /// Get a node printer for `node`.
     ///
+    /// The pretty-printer requests a printer for every node in the AST, so printers should be
+    /// unit structs whenever possible: boxing a zero-sized printer does not allocate.  A printer
+    /// that depends on a factory setting should read the setting from the factory passed to the
+    /// [`NodePrinter<AST>`] functions rather than store a copy of the setting.
+    ///
     /// # Arguments
     /// * `node` - The node from the AST.
     fn printer_for(&self, node: &AST) -> Box<dyn NodePrinter<AST>>;
@@ -102,3 +112,110 @@ pub trait NodePrinterFactory<AST> {
     /// Get the settings object for the factory.
     fn get_settings(&self) -> &Preferences;
 }
+
+/// A function that returns the [`NodePrinter<AST>`] for a node.  The function receives the
+/// wrapped factory so that the function can return a printer that wraps the original printer.
+pub type NodePrinterOverride<AST> =
+    Box<dyn Fn(&AST, &dyn NodePrinterFactory<AST>) -> Box<dyn NodePrinter<AST>>>;
+
+/// A [`NodePrinterFactory<AST>`] that returns the printers of another factory except for the node
+/// types that have an override.
+///
+/// The pretty-printer passes the overriding factory to every printer, so the overrides also apply
+/// to the nodes that the printers of the wrapped factory print.
+///
+/// ```ignore
+/// let factory = OverridingNodePrinterFactory::new(
+///     Box::new(SolidityNodePrinterFactory::default()),
+///     |node| node.get("nodeType").and_then(|t| t.as_str()),
+/// )
+/// .with_override("Identifier", |_node, _inner| Box::new(AnnotatedIdentifierPrinter {}));
+///
+/// let mut printer = PrettyPrinter::new(4, 150);
+/// pretty_print_node(&mut printer, &mut std::io::stdout(), &factory, &ast);
+/// ```
+pub struct OverridingNodePrinterFactory<AST> {
+    /// The factory that provides the printers of the node types without an override.
+    inner: Box<dyn NodePrinterFactory<AST>>,
+
+    /// The function that returns the node type of a node.
+    node_type: fn(&AST) -> Option<&str>,
+
+    /// The override functions by node type.
+    overrides: HashMap<String, NodePrinterOverride<AST>>,
+}
+
+impl<AST> OverridingNodePrinterFactory<AST> {
+    /// Create a new factory that returns the printers of `inner`.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The factory that provides the printers of the node types without an override.
+    /// * `node_type` - The function that returns the node type of a node, such as the `nodeType`
+    ///   value of a Solidity node or the `ast_type` value of a Vyper node.
+    pub fn new(
+        inner: Box<dyn NodePrinterFactory<AST>>,
+        node_type: fn(&AST) -> Option<&str>,
+    ) -> OverridingNodePrinterFactory<AST> {
+        OverridingNodePrinterFactory {
+            inner,
+            node_type,
+            overrides: HashMap::new(),
+        }
+    }
+
+    /// Return the factory with `printer` providing the printers of the nodes of type `node_type`.
+    /// A later override of the same node type replaces the earlier override.
+    ///
+    /// # Arguments
+    ///
+    /// * `node_type` - The node type.
+    /// * `printer` - The function that returns the printer for a node of type `node_type`.
+    pub fn with_override<F>(
+        mut self,
+        node_type: &str,
+        printer: F,
+    ) -> OverridingNodePrinterFactory<AST>
+    where
+        F: Fn(&AST, &dyn NodePrinterFactory<AST>) -> Box<dyn NodePrinter<AST>> + 'static,
+    {
+        self.overrides
+            .insert(String::from(node_type), Box::new(printer));
+        self
+    }
+}
+
+impl<AST> NodePrinterFactory<AST> for OverridingNodePrinterFactory<AST> {
+    fn printer_for(&self, node: &AST) -> Box<dyn NodePrinter<AST>> {
+        match (self.node_type)(node).and_then(|t| self.overrides.get(t)) {
+            Some(printer) => printer(node, self.inner.as_ref()),
+            None => self.inner.printer_for(node),
+        }
+    }
+
+    fn get_settings(&self) -> &Preferences {
+        self.inner.get_settings()
+    }
+}
+
+/// Pretty-print `node`, a JSON AST or a node of a JSON AST, to `stream` with the printers of
+/// `factory`.  Use this function to print an AST with a factory other than the factory of the
+/// language, such as an [`OverridingNodePrinterFactory<AST>`] that wraps
+/// [`crate::solidity::SolidityNodePrinterFactory`].  A custom [`NodePrinter<AST>`] can also call
+/// the function to print the children of its node.
+///
+/// # Arguments
+///
+/// * `printer` - The [`PrettyPrinter`] that will write content to `stream`.
+/// * `stream` - The [`Write`] object that will receive formatted output.
+/// * `factory` - The [`NodePrinterFactory<AST>`] trait object that will generate node printers
+///   for the nodes of the AST.
+/// * `node` - The AST or AST node.
+pub fn pretty_print_node(
+    printer: &mut PrettyPrinter,
+    stream: &mut dyn Write,
+    factory: &dyn NodePrinterFactory<Value>,
+    node: &Value,
+) {
+    traverse_sub_node_and_print(printer, stream, factory, node);
+}
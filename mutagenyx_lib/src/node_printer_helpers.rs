@@ -1,13 +1,17 @@
 //! The `node_printer_helper` module contains common algorithms used while traversing an AST.
 
-use crate::ast::{ASTTraverser, SimpleAST};
+use crate::ast::SimpleAST;
 use crate::node_printer::NodePrinterFactory;
-use crate::pretty_print_visitor::PrettyPrintVisitor;
 use crate::pretty_printer::{write_space, write_token, PrettyPrinter};
 use std::io::Write;
 
 /// Helper function to traverse a child node
 ///
+/// Node printers call this function for nearly every child node, so the function drives the node
+/// printer for `node` directly rather than creating a [`crate::pretty_print_visitor::PrettyPrintVisitor<AST>`]
+/// (and its printer stack) for each call.  The order of the calls to the node printer matches
+/// the order used by the visitor.
+///
 /// # Arguments
 ///
 /// * `node` - The node in the AST to traverse.
@@ -20,8 +24,15 @@ pub fn traverse_sub_node_and_print<AST: SimpleAST<AST>>(
     factory: &dyn NodePrinterFactory<AST>,
     node: &AST,
 ) {
-    let mut visitor = PrettyPrintVisitor::<AST>::new(stream, printer, factory);
-    ASTTraverser::traverse(node, &mut visitor);
+    let mut node_printer = factory.printer_for(node);
+    node_printer.on_entry(printer, stream, factory, node);
+    node_printer.print_node(printer, stream, factory, node);
+    if node_printer.visit_children() {
+        for child in node.get_children() {
+            traverse_sub_node_and_print(printer, stream, factory, child.get_node());
+        }
+    }
+    node_printer.on_exit(printer, stream, factory, node);
 }
 
 /// Helper function to output the contents of an array of nodes
@@ -1,41 +1,224 @@
-//! The `solidity::operators` module provides helper functions to get vectors of various kinds of
-//! binary and unary expression operators.
+//! The `operators` module provides the [`OperatorRegistry`] that holds the groups of binary and
+//! unary expression operators used by the mutation algorithms, and helper functions to get
+//! vectors of the operators in the groups of the default registry.
+//!
+//! An application that mutates a language dialect with extra operators can select a registry
+//! with the operators for a language object:
+//!
+//! ```
+//! use mutagenyx_lib::language::Language;
+//! use mutagenyx_lib::language_interface::LanguageInterface;
+//! use mutagenyx_lib::operators::{OperatorRegistry, ARITHMETIC_OPERATORS};
+//!
+//! let mut language_object =
+//!     LanguageInterface::get_language_object_for_language(&Language::Solidity).unwrap();
+//! language_object.select_operator_registry(
+//!     OperatorRegistry::default()
+//!         .with_group(ARITHMETIC_OPERATORS, &["+", "-", "*", "/", "%", "**", "+%"]),
+//! );
+//! ```
+
+use std::collections::HashMap;
+
+/// The name of the group of arithmetic operators.
+pub static ARITHMETIC_OPERATORS: &str = "arithmetic";
+
+/// The name of the group of logical operators.
+pub static LOGICAL_OPERATORS: &str = "logical";
+
+/// The name of the group of bitwise operators.
+pub static BITWISE_OPERATORS: &str = "bitwise";
+
+/// The name of the group of bitshift operators.
+pub static BITSHIFT_OPERATORS: &str = "bitshift";
+
+/// The name of the group of comparison operators.
+pub static COMPARISON_OPERATORS: &str = "comparison";
+
+/// The name of the group of non-commutative operators.
+pub static NON_COMMUTATIVE_OPERATORS: &str = "non-commutative";
+
+/// The name of the group of prefix operators.
+pub static PREFIX_OPERATORS: &str = "prefix";
+
+/// The name of the group of postfix operators.
+pub static POSTFIX_OPERATORS: &str = "postfix";
+
+/// The name of the group of compound assignment operators.
+pub static COMPOUND_ASSIGNMENT_OPERATORS: &str = "compound-assignment";
+
+/// A map from operator group names to the operators in the group.  The mutator factories build
+/// the operator mutators from the groups in the registry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OperatorRegistry {
+    /// The operators in each group.
+    groups: HashMap<String, Vec<&'static str>>,
+}
+
+impl Default for OperatorRegistry {
+    fn default() -> Self {
+        OperatorRegistry {
+            groups: HashMap::new(),
+        }
+        .with_group(ARITHMETIC_OPERATORS, &["+", "-", "*", "/", "%", "**"])
+        .with_group(LOGICAL_OPERATORS, &["&&", "||"])
+        .with_group(BITWISE_OPERATORS, &["&", "|", "^"])
+        .with_group(BITSHIFT_OPERATORS, &["<<", ">>"])
+        .with_group(COMPARISON_OPERATORS, &["==", "!=", ">", "<", ">=", "<="])
+        .with_group(
+            NON_COMMUTATIVE_OPERATORS,
+            &["-", "/", "%", "**", ">", "<", "<=", ">=", "<<", ">>"],
+        )
+        .with_group(PREFIX_OPERATORS, &["++", "--", "~"])
+        .with_group(POSTFIX_OPERATORS, &["++", "--"])
+        .with_group(
+            COMPOUND_ASSIGNMENT_OPERATORS,
+            &["+=", "-=", "*=", "/=", "%=", "<<=", ">>=", "&=", "|=", "^="],
+        )
+    }
+}
+
+impl OperatorRegistry {
+    /// Return the registry with the group `name` set to `operators`.  The group replaces any
+    /// group with the same name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the group.
+    /// * `operators` - The operators in the group.
+    pub fn with_group(mut self, name: &str, operators: &[&'static str]) -> Self {
+        self.groups.insert(String::from(name), operators.to_vec());
+        self
+    }
+
+    /// Return the operators in the group `name`, or an empty vector if the registry does not
+    /// have the group.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the group.
+    pub fn operators(&self, name: &str) -> Vec<&'static str> {
+        self.groups.get(name).cloned().unwrap_or_default()
+    }
+}
 
 /// Return the [`Vec<&str>`] object containing the arithmetic operators.
 pub fn arithmetic_operators() -> Vec<&'static str> {
-    vec!["+", "-", "*", "/", "%", "**"]
+    OperatorRegistry::default().operators(ARITHMETIC_OPERATORS)
 }
 
 /// Return the [`Vec<&str>`] object containing the logical operators.
 pub fn logical_operators() -> Vec<&'static str> {
-    vec!["&&", "||"]
+    OperatorRegistry::default().operators(LOGICAL_OPERATORS)
 }
 
 /// Return the [`Vec<&str>`] object containing the bitwise operators.
 pub fn bitwise_operators() -> Vec<&'static str> {
-    vec!["&", "|", "^"]
+    OperatorRegistry::default().operators(BITWISE_OPERATORS)
 }
 
+/// Return the [`Vec<&str>`] object containing the bitshift operators.
 pub fn bitshift_operators() -> Vec<&'static str> {
-    vec!["<<", ">>"]
+    OperatorRegistry::default().operators(BITSHIFT_OPERATORS)
 }
 
 /// Return the [`Vec<&str>`] object containing the comparison operators.
 pub fn comparison_operators() -> Vec<&'static str> {
-    vec!["==", "!=", ">", "<", ">=", "<="]
+    OperatorRegistry::default().operators(COMPARISON_OPERATORS)
 }
 
-// Return the [`Vec<&str`] object containing the non-commutative operators.
+/// Return the [`Vec<&str>`] object containing the non-commutative operators.
 pub fn non_commutative_operators() -> Vec<&'static str> {
-    vec!["-", "/", "%", "**", ">", "<", "<=", ">=", "<<", ">>"]
+    OperatorRegistry::default().operators(NON_COMMUTATIVE_OPERATORS)
 }
 
 /// Return the [`Vec<&str>`] object containing the prefix operators.
 pub fn prefix_operators() -> Vec<&'static str> {
-    vec!["++", "--", "~"]
+    OperatorRegistry::default().operators(PREFIX_OPERATORS)
 }
 
 /// Return the [`Vec<&str>`] object containing the postfix operators.
 pub fn postfix_operators() -> Vec<&'static str> {
-    vec!["++", "--"]
+    OperatorRegistry::default().operators(POSTFIX_OPERATORS)
+}
+
+/// Return the [`Vec<&str>`] object containing the compound assignment operators.
+pub fn compound_assignment_operators() -> Vec<&'static str> {
+    OperatorRegistry::default().operators(COMPOUND_ASSIGNMENT_OPERATORS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language::Language;
+    use crate::language_interface::LanguageInterface;
+    use crate::mutation::{GenericMutation, MutationType};
+    use crate::mutator::MutatorFactory;
+    use crate::solidity::mutators::SolidityMutatorFactory;
+    use crate::super_ast::SuperAST;
+    use crate::test_fixtures::{all_permissions, solidity_ast_with_statements};
+    use rand::SeedableRng;
+    use rand_pcg::Pcg64;
+    use serde_json::json;
+
+    #[test]
+    fn test_custom_arithmetic_group() {
+        let registry = OperatorRegistry::default().with_group(ARITHMETIC_OPERATORS, &["+", "+%"]);
+        assert_eq!(registry.operators(LOGICAL_OPERATORS), vec!["&&", "||"]);
+
+        let factory = SolidityMutatorFactory::new(registry);
+        let mut mutator = factory
+            .mutator_for(&MutationType::Generic(GenericMutation::ArithmeticBinaryOp))
+            .unwrap();
+
+        let mut rng = Pcg64::seed_from_u64(1);
+        let node = |operator: &str| {
+            json!({
+                "nodeType": "BinaryOperation", "id": 3, "operator": operator,
+                "leftExpression": {"nodeType": "Identifier", "id": 1, "name": "a"},
+                "rightExpression": {"nodeType": "Identifier", "id": 2, "name": "b"}
+            })
+        };
+        assert!(mutator.is_mutable_node(&node("+%"), &mut rng));
+        assert!(!mutator.is_mutable_node(&node("-"), &mut rng));
+
+        // The only other operator in the group is the new operator.
+        let mut addition = node("+");
+        mutator.mutate(&mut addition, &mut rng).unwrap();
+        assert_eq!(addition["operator"], "+%");
+    }
+
+    #[test]
+    fn test_selected_registry_reaches_the_mutators() {
+        let ast = SuperAST::Solidity(solidity_ast_with_statements(json!([{
+            "nodeType": "ExpressionStatement", "id": 20, "expression": {
+                "nodeType": "BinaryOperation", "id": 3, "operator": "+%",
+                "leftExpression": {"nodeType": "Identifier", "id": 1, "name": "a"},
+                "rightExpression": {"nodeType": "Identifier", "id": 2, "name": "b"}
+            }
+        }])));
+        let mutation_type = MutationType::Generic(GenericMutation::ArithmeticBinaryOp);
+        let count = |registry: Option<OperatorRegistry>| {
+            let mut language_object =
+                LanguageInterface::get_language_object_for_language(&Language::Solidity).unwrap();
+            if let Some(registry) = registry {
+                language_object.select_operator_registry(registry);
+            }
+            language_object
+                .select_mutators_for_mutation_types(&[mutation_type])
+                .unwrap();
+            let mut rng = Pcg64::seed_from_u64(1);
+            language_object
+                .count_mutable_nodes(&ast, &mut rng, &all_permissions())
+                .unwrap()
+                .get(&mutation_type)
+                .copied()
+                .unwrap_or(0)
+        };
+
+        // Only the language object with the registry of the new operator mutates the node.
+        assert_eq!(count(None), 0);
+        let registry = OperatorRegistry::default().with_group(ARITHMETIC_OPERATORS, &["+", "+%"]);
+        assert_eq!(count(Some(registry)), 1);
+    }
 }
@@ -246,3 +246,191 @@ impl Default for Permissions {
         Self::new()
     }
 }
+
+/// The kind of the constructor definitions in an AST.  Forbid this kind with
+/// [`PermissionsBuilder::forbid_node_type`] to keep the mutators out of constructors.
+pub static CONSTRUCTOR_NODE_KIND: &str = "constructor";
+
+/// The kind of the modifier definitions in an AST.  Forbid this kind with
+/// [`PermissionsBuilder::forbid_node_type`] to keep the mutators out of modifiers.
+pub static MODIFIER_NODE_KIND: &str = "modifier";
+
+/// Builder for the [`Permissions`] object of a mutation run.
+///
+/// By default the permissions allow the mutators to visit and mutate every node.
+///
+/// ```rust
+/// use mutagenyx_lib::permissions::{PermissionsBuilder, CONSTRUCTOR_NODE_KIND};
+///
+/// let permissions = PermissionsBuilder::new()
+///     .only_functions(&[String::from("transfer")])
+///     .forbid_node_type(CONSTRUCTOR_NODE_KIND)
+///     .forbid_node_type("ImportDirective")
+///     .build();
+/// assert!(!permissions.is_empty());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PermissionsBuilder {
+    /// The names of the functions to mutate, empty to mutate every function.
+    functions: Vec<String>,
+
+    /// The node types or kinds that the mutators must not visit.
+    forbidden_node_types: Vec<String>,
+}
+
+impl PermissionsBuilder {
+    /// Create a new builder for permissions that allow visiting and mutating every node.
+    pub fn new() -> PermissionsBuilder {
+        PermissionsBuilder::default()
+    }
+
+    /// Only mutate the nodes in the functions named in `names`.  An empty list allows mutations
+    /// in every function.
+    ///
+    /// # Arguments
+    ///
+    /// * `names` - The names of the functions to mutate.
+    pub fn only_functions(mut self, names: &[String]) -> PermissionsBuilder {
+        self.functions.extend_from_slice(names);
+        self
+    }
+
+    /// Never visit, and so never mutate, the nodes of type `node_type` or the children of those
+    /// nodes.  The type is a node type of the AST, such as `ImportDirective`, or a kind that
+    /// covers several node types, such as [`CONSTRUCTOR_NODE_KIND`].
+    ///
+    /// # Arguments
+    ///
+    /// * `node_type` - The node type or kind to forbid.
+    pub fn forbid_node_type(mut self, node_type: &str) -> PermissionsBuilder {
+        self.forbidden_node_types.push(String::from(node_type));
+        self
+    }
+
+    /// Return the [`Permissions`] object.
+    pub fn build(&self) -> Permissions {
+        let mut permissions = Permissions::new();
+
+        // The visitors check the denied node types before the other visit permissions.
+        for node_type in &self.forbidden_node_types {
+            permissions.add_permission(PermissionAction::Visit(PermissionScope::Type(
+                PermissionObject::Value(node_type.clone(), Permission::Deny),
+            )));
+        }
+
+        if !self.functions.is_empty() {
+            for function_name in &self.functions {
+                // Give permission to mutate the node with the function name.
+                permissions.add_permission(PermissionAction::Mutate(PermissionScope::Name(
+                    PermissionObject::Value(function_name.clone(), Permission::Allow),
+                )));
+
+                // Also grant permission to mutate any children of this node.
+                permissions.add_permission(PermissionAction::Mutate(PermissionScope::Children(
+                    PermissionObject::Value(function_name.clone(), Permission::Allow),
+                )));
+            }
+        } else {
+            permissions.add_permission(PermissionAction::Mutate(PermissionScope::Any(
+                Permission::Allow,
+            )));
+        }
+
+        permissions.add_permission(PermissionAction::Visit(PermissionScope::Any(
+            Permission::Allow,
+        )));
+
+        permissions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language_interface::LanguageInterface;
+    use crate::mutation::{GenericMutation, MutationType};
+    use crate::super_ast::SuperAST;
+    use crate::test_fixtures::solidity_ast_with_statements;
+    use crate::Language;
+    use rand::SeedableRng;
+    use rand_pcg::Pcg64;
+    use serde_json::json;
+
+    #[test]
+    fn test_forbidden_constructor_is_not_mutated() {
+        let subtraction = |id: u64| {
+            json!({
+                "nodeType": "ExpressionStatement", "id": id, "expression": {
+                    "nodeType": "BinaryOperation", "id": id + 1, "operator": "-",
+                    "leftExpression": {"nodeType": "Identifier", "id": id + 2, "name": "a"},
+                    "rightExpression": {"nodeType": "Identifier", "id": id + 3, "name": "b"},
+                    "typeDescriptions": {"typeIdentifier": "t_uint256", "typeString": "uint256"}
+                }
+            })
+        };
+        let mut ast = solidity_ast_with_statements(json!([subtraction(60)]));
+        ast["nodes"][0]["nodes"]
+            .as_array_mut()
+            .unwrap()
+            .push(json!({
+                "nodeType": "FunctionDefinition", "id": 41, "name": "", "kind": "constructor",
+                "visibility": "public", "stateMutability": "nonpayable", "virtual": false,
+                "implemented": true, "modifiers": [],
+                "parameters": {"nodeType": "ParameterList", "id": 12, "parameters": []},
+                "returnParameters": {"nodeType": "ParameterList", "id": 13, "parameters": []},
+                "body": {"nodeType": "Block", "id": 31, "statements": [subtraction(70)]}
+            }));
+        let ast = SuperAST::Solidity(ast);
+
+        let mutation_type = MutationType::Generic(GenericMutation::ArithmeticBinaryOp);
+        let mut language_object =
+            LanguageInterface::get_language_object_for_language(&Language::Solidity).unwrap();
+        language_object
+            .select_mutators_for_mutation_types(&[mutation_type])
+            .unwrap();
+        let mut rng = Pcg64::seed_from_u64(0);
+
+        // Without the restriction both subtractions are mutable.
+        let permissions = LanguageInterface::permissions_builder().build();
+        let counts = language_object
+            .count_mutable_nodes(&ast, &mut rng, &permissions)
+            .unwrap();
+        assert_eq!(counts[&mutation_type], 2);
+
+        let permissions = LanguageInterface::permissions_builder()
+            .forbid_node_type(CONSTRUCTOR_NODE_KIND)
+            .build();
+        let counts = language_object
+            .count_mutable_nodes(&ast, &mut rng, &permissions)
+            .unwrap();
+        assert_eq!(counts[&mutation_type], 1);
+
+        // The only mutation changes the function and leaves the constructor alone.
+        let path_map = language_object
+            .calculate_node_paths(&ast, &permissions)
+            .unwrap();
+        let result = language_object
+            .mutate_ast(&ast, &mutation_type, 0, &mut rng, &permissions, &path_map)
+            .unwrap();
+        assert_eq!(result.mutator_result.mutated_node_id, Some(61));
+        let mutated = match result.ast {
+            Some(SuperAST::Solidity(a)) => a,
+            _ => panic!("expected a Solidity AST"),
+        };
+        let constructor = &mutated["nodes"][0]["nodes"][1];
+        assert_eq!(constructor["kind"], "constructor");
+        assert_eq!(
+            constructor["body"]["statements"][0]["expression"]["operator"],
+            "-"
+        );
+
+        // Forbidding a node type keeps the mutators away from every node of the type.
+        let permissions = LanguageInterface::permissions_builder()
+            .forbid_node_type("FunctionDefinition")
+            .build();
+        let counts = language_object
+            .count_mutable_nodes(&ast, &mut rng, &permissions)
+            .unwrap();
+        assert!(!counts.contains_key(&mutation_type));
+    }
+}
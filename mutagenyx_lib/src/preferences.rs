@@ -322,6 +322,19 @@ impl Preferences {
             .insert(String::from(key), PreferenceValue::Map(prefs.map));
     }
 
+    /// Overlay the key/value pairs of `other` onto the object.  The values in `other` replace
+    /// the values of the same keys in the object, and the object keeps the keys that `other`
+    /// does not have.  The function replaces map values as a whole, it does not merge them.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The preferences that override the values in the object.
+    pub fn merge(&mut self, other: &Preferences) {
+        for (key, value) in &other.map {
+            self.map.insert(key.clone(), value.clone());
+        }
+    }
+
     /// Return the number of preferences stored in the object.
     pub fn len(&self) -> usize {
         self.map.len()
@@ -434,6 +447,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_merge() {
+        let mut preferences = Preferences::new();
+        preferences.set_bool_for_key("semicolon", true);
+        preferences.set_int_for_key("width", 80);
+        preferences.set_string_for_key("style", "text");
+
+        let mut overrides = Preferences::new();
+        overrides.set_int_for_key("width", 120);
+        overrides.set_bool_for_key("wrap", false);
+
+        preferences.merge(&overrides);
+        assert_eq!(preferences.len(), 4);
+        assert_eq!(preferences.get_bool_for_key("semicolon"), Some(true));
+        assert_eq!(preferences.get_int_for_key("width"), Some(120));
+        assert_eq!(
+            preferences.get_string_for_key("style"),
+            Some(String::from("text"))
+        );
+        assert_eq!(preferences.get_bool_for_key("wrap"), Some(false));
+
+        // The overrides do not change.
+        assert_eq!(overrides.len(), 2);
+    }
+
     #[test]
     fn test_json_into_preference_value() {
         let ipvalue: Result<PreferenceValue, MutagenyxError> = Value::from(10).try_into();
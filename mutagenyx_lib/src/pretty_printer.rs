@@ -5,6 +5,27 @@ use crate::error::MutagenyxError;
 use crate::string::*;
 use std::io::Write;
 
+/// The delimiter that [`PrettyPrinter::write_string`] writes around string values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StringQuoteStyle {
+    /// Surround strings with `"` characters.
+    #[default]
+    Double,
+
+    /// Surround strings with `'` characters.
+    Single,
+}
+
+impl StringQuoteStyle {
+    /// Return the delimiter character of the style.
+    pub fn delimiter(&self) -> char {
+        match self {
+            StringQuoteStyle::Double => '"',
+            StringQuoteStyle::Single => '\'',
+        }
+    }
+}
+
 /// Object that encapsulates the behavior needed to write structured output to any object that
 /// implements the [`Write`] trait.
 pub struct PrettyPrinter {
@@ -15,6 +36,7 @@ pub struct PrettyPrinter {
     pub indent: usize,
     pub newline: String,
     pub max_indent: usize,
+    pub string_quote_style: StringQuoteStyle,
 }
 
 impl PrettyPrinter {
@@ -43,6 +65,7 @@ impl PrettyPrinter {
             indent: 0,
             newline: String::from(newline),
             max_indent: max_indent as usize,
+            string_quote_style: StringQuoteStyle::default(),
         }
     }
 
@@ -216,6 +239,35 @@ impl PrettyPrinter {
         Ok(())
     }
 
+    /// Write `s` to `stream` exactly as given.
+    ///
+    /// Unlike [`PrettyPrinter::write_token`], the printer never starts a new line before the
+    /// text, even when the text does not fit in the remaining page width, so the bytes of `s`
+    /// always follow the previous output directly.  Use `write_raw` when a line break would change
+    /// the meaning of the program, such as the quoted value that follows a `unicode` prefix.
+    /// Prefer `write_token` for everything else so that the printer can keep lines within the
+    /// page width.
+    ///
+    /// The printer still tracks the row and column of the output, including any newlines in `s`.
+    ///
+    /// # Arguments
+    ///
+    /// * `stream` - The [`Write`] object that receives the text.
+    /// * `s` - The string slice to write to `stream`.
+    pub fn write_raw(&mut self, stream: &mut dyn Write, s: &str) -> Result<(), MutagenyxError> {
+        if let Err(e) = write!(stream, "{s}") {
+            return Err(MutagenyxError::from(e));
+        }
+        match s.rfind('\n') {
+            Some(index) => {
+                self.row += s.matches('\n').count();
+                self.column = 1 + s[index + 1..].len();
+            }
+            None => self.column += s.len(),
+        }
+        Ok(())
+    }
+
     /// Write multiple copies of `token` to `stream`.
     ///
     /// # Arguments
@@ -235,8 +287,29 @@ impl PrettyPrinter {
         Ok(())
     }
 
+    /// Return `s` surrounded by the delimiters of [`PrettyPrinter::string_quote_style`].  The
+    /// function escapes each backslash and each delimiter character in `s` with a backslash and
+    /// leaves the other quote character alone.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The text of the string.
+    pub fn quote_string(&self, s: &str) -> String {
+        let delimiter = self.string_quote_style.delimiter();
+        let mut quoted = String::with_capacity(s.len() + 2);
+        quoted.push(delimiter);
+        for c in s.chars() {
+            if c == '\\' || c == delimiter {
+                quoted.push('\\');
+            }
+            quoted.push(c);
+        }
+        quoted.push(delimiter);
+        quoted
+    }
+
     /// Write a string value to the stream.  The function will emit the string surrounded by the
-    /// \" delimiters.
+    /// delimiters of [`PrettyPrinter::string_quote_style`] (\" by default).
     ///
     /// # Arguments
     ///
@@ -252,7 +325,7 @@ impl PrettyPrinter {
     ///
     /// "The quick brown dog..."
     pub fn write_string(&mut self, stream: &mut dyn Write, s: &str) -> Result<(), MutagenyxError> {
-        let composed_string = String::from("\"") + s + "\"";
+        let composed_string = self.quote_string(s);
         self.write_token(stream, &composed_string)?;
         Ok(())
     }
@@ -333,78 +406,69 @@ impl PrettyPrinter {
 
         let t = text.as_str();
 
-        if t.len() > (self.page_width - self.column) {
-            let space_left = self.page_width - self.column;
-            if space_left == 0 {
-                self.write_newline(stream)?;
-                self.write_indent(stream)?;
-                self.write_token(stream, next_line_text)?;
-                self.write_flowable_text(stream, t, next_line_text)?;
-            } else {
-                // Here we carefully examine the breakpoint of the text to see if we are breaking
-                // the text mid-word, or on a space boundary.  If we are breaking mid-word, then
-                // we back up the break point to the last space character and put the next full word
-                // on a newline.  This behavior prevents output flowable text from having wierd breaks
-                // in words.
-                let mut index_of_start_of_word: usize = 0;
-                let mut i: usize = 0;
-                let mut previous_char_was_space = false;
-                let bytes = t.as_bytes();
-
-                // First walk through the text that would fit in the remaining space and find any
-                // word boundaries.  We use a ' ' (space) character as the word boundary.
-                while i < space_left {
-                    if bytes[i] as char == ' ' {
-                        previous_char_was_space = true;
-                    } else {
-                        if previous_char_was_space {
-                            index_of_start_of_word = i;
-                        }
-                        previous_char_was_space = false;
-                    }
+        // The column may already be past the page width if a previous token was too long to fit.
+        let space_left = self.page_width.saturating_sub(self.column);
 
-                    i += 1;
-                }
+        if t.len() <= space_left {
+            self.write_basic_string(stream, t)?;
+            return Ok(());
+        }
 
-                let first_part: &str;
-                let rest: &str;
-
-                // Now, check the end of the text at the space_left boundary and see if it is a
-                // ' ' (space) character or a text character.  If it is a space character, then we
-                // can break the text exactly on the space_left boundary.  If not, we go back
-                // to the previous space and put the with the partial word on the next line.
-                //
-                // NOTE: The algorithm makes no attempt to correctly break text with spaces enclosed
-                // in quotation marks (either single or double).
-                if bytes[i] as char == ' ' {
-                    first_part = &t[..space_left];
-                    rest = if bytes[space_left] as char == ' ' {
-                        &t[space_left + 1..]
-                    } else {
-                        &t[space_left..]
-                    };
-                } else {
-                    first_part = &t[..index_of_start_of_word];
-                    rest = if bytes[index_of_start_of_word] as char == ' ' {
-                        &t[index_of_start_of_word + 1..]
-                    } else {
-                        &t[index_of_start_of_word..]
-                    };
-                }
+        // The column where the text continues after the printer breaks the line.
+        let next_line_column = 1 + self.indent_length() + next_line_text.len();
+
+        // Here we carefully examine the breakpoint of the text to see if we are breaking
+        // the text mid-word, or on a space boundary.  If we are breaking mid-word, then
+        // we back up the break point to the last space character and put the next full word
+        // on a newline.  This behavior prevents output flowable text from having wierd breaks
+        // in words.  We only break the text on ' ' (space) characters, which also guarantees
+        // that we never split a multi-byte character.
+        //
+        // NOTE: The algorithm makes no attempt to correctly break text with spaces enclosed
+        // in quotation marks (either single or double).
+        let bytes = t.as_bytes();
+        let mut break_point: Option<(usize, usize)> = None;
+        if space_left > 0 {
+            if bytes[space_left] == b' ' {
+                break_point = Some((space_left, space_left + 1));
+            } else if let Some(index) = bytes[..space_left].iter().rposition(|b| *b == b' ') {
+                break_point = Some((index + 1, index + 1));
+            }
+        }
 
-                if !first_part.is_empty() {
-                    self.write_basic_string(stream, first_part)?;
-                }
+        match break_point {
+            Some((end_of_first_part, start_of_rest)) if end_of_first_part > 0 => {
+                self.write_basic_string(stream, &t[..end_of_first_part])?;
                 self.write_newline(stream)?;
                 self.write_indent(stream)?;
                 self.write_token(stream, next_line_text)?;
 
+                let rest = &t[start_of_rest..];
                 if !rest.is_empty() {
                     self.write_flowable_text(stream, rest, next_line_text)?;
                 }
             }
-        } else {
-            self.write_basic_string(stream, t)?;
+            _ => {
+                if self.column > next_line_column {
+                    // The first word does not fit on this line, but a new line has more room.
+                    self.write_newline(stream)?;
+                    self.write_indent(stream)?;
+                    self.write_token(stream, next_line_text)?;
+                    self.write_flowable_text(stream, t, next_line_text)?;
+                } else {
+                    // The first word is longer than an entire line, write the word without
+                    // breaking it.
+                    let end_of_word = t.find(' ').unwrap_or(t.len());
+                    self.write_basic_string(stream, &t[..end_of_word])?;
+
+                    if end_of_word < t.len() {
+                        self.write_newline(stream)?;
+                        self.write_indent(stream)?;
+                        self.write_token(stream, next_line_text)?;
+                        self.write_flowable_text(stream, &t[end_of_word + 1..], next_line_text)?;
+                    }
+                }
+            }
         }
 
         Ok(())
@@ -503,6 +567,22 @@ pub fn write_token(printer: &mut PrettyPrinter, stream: &mut dyn Write, token: &
     }
 }
 
+/// Helper function to write `s` to `stream` exactly as given while suppressing any errors.  The
+/// function sends errors to the log.
+///
+/// The raw text has the same semantics as [`PrettyPrinter::write_raw`].
+///
+/// # Arguments
+///
+/// * `printer` - The pretty-printer that will write the text to the `stream`.
+/// * `stream` - The [`Write`] object that will receive the text.
+/// * `s` - The text to write to the stream.
+pub fn write_raw(printer: &mut PrettyPrinter, stream: &mut dyn Write, s: &str) {
+    if let Err(e) = printer.write_raw(stream, s) {
+        log::info!("Unable to write raw text: {e}");
+    }
+}
+
 /// Helper function to write multiple copies of `token` to `stream` while suppressing errors.  The
 /// function sends error messages to the log.
 ///
@@ -526,7 +606,8 @@ pub fn write_tokens(
 /// Helper function to write a string to `stream` while suppressing any errors.  The function
 /// sends errors to the log.
 ///
-/// The pretty-printer will output the string as "`s`".
+/// The pretty-printer will output the string as "`s`", or with the delimiters of
+/// [`PrettyPrinter::string_quote_style`].
 ///
 /// # Arguments
 ///
@@ -624,4 +705,95 @@ mod tests {
         printer.decrease_indent_by(10);
         assert_eq!(printer.indent_length(), 0);
     }
+
+    #[test]
+    fn test_write_raw_and_write_token_adjacent_punctuation() {
+        // write_token starts a new line when the token does not fit in the page width.
+        let mut printer = PrettyPrinter::new(4, 10);
+        let mut output: Vec<u8> = Vec::new();
+        printer.write_token(&mut output, "unicode").unwrap();
+        printer.write_token(&mut output, "\"é\"").unwrap();
+        printer.write_token(&mut output, ";").unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), "unicode\n\"é\";");
+
+        // write_raw keeps the text next to the previous output.
+        let mut printer = PrettyPrinter::new(4, 10);
+        let mut output: Vec<u8> = Vec::new();
+        printer.write_token(&mut output, "unicode").unwrap();
+        printer.write_raw(&mut output, "\"é\"").unwrap();
+        printer.write_raw(&mut output, ";").unwrap();
+        assert_eq!(String::from_utf8(output.clone()).unwrap(), "unicode\"é\";");
+        assert_eq!(printer.row, 1);
+        assert_eq!(printer.column, 13);
+
+        // write_raw tracks the newlines in the text.
+        printer.write_raw(&mut output, "a\nbc").unwrap();
+        assert_eq!(printer.row, 2);
+        assert_eq!(printer.column, 3);
+    }
+
+    #[test]
+    fn test_write_flowable_text_long_word() {
+        let mut printer = PrettyPrinter::new(4, 10);
+        let mut output: Vec<u8> = Vec::new();
+        let word = "a".repeat(100);
+        printer
+            .write_flowable_text(&mut output, &word, " * ")
+            .unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), word);
+    }
+
+    #[test]
+    fn test_write_flowable_text_unicode() {
+        let mut printer = PrettyPrinter::new(4, 10);
+        let mut output: Vec<u8> = Vec::new();
+        let text = "ééééé ééééé ééééé";
+        printer.write_flowable_text(&mut output, text, "").unwrap();
+        let result = String::from_utf8(output).unwrap();
+        assert_eq!(result.replace('\n', " "), text);
+    }
+
+    #[test]
+    fn test_write_flowable_text_past_page_width() {
+        let mut printer = PrettyPrinter::new(4, 10);
+        let mut output: Vec<u8> = Vec::new();
+        printer
+            .write_basic_string(&mut output, "0123456789abcdef")
+            .unwrap();
+        printer
+            .write_flowable_text(&mut output, "lorem ipsum", "")
+            .unwrap();
+        let result = String::from_utf8(output).unwrap();
+        assert!(result.starts_with("0123456789abcdef"));
+        assert!(result.contains("lorem"));
+        assert!(result.contains("ipsum"));
+    }
+
+    #[test]
+    fn test_write_string_quote_styles() {
+        let text = r#"say "hi" and 'bye'"#;
+
+        let mut printer = PrettyPrinter::new(4, 150);
+        let mut output: Vec<u8> = Vec::new();
+        printer.write_string(&mut output, text).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            r#""say \"hi\" and 'bye'""#
+        );
+
+        let mut printer = PrettyPrinter::new(4, 150);
+        printer.string_quote_style = StringQuoteStyle::Single;
+        let mut output: Vec<u8> = Vec::new();
+        printer.write_string(&mut output, text).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            r#"'say "hi" and \'bye\''"#
+        );
+        assert_eq!(printer.column, 1 + r#"'say "hi" and \'bye\''"#.len());
+
+        // The backslash of an escaped delimiter does not combine with a backslash in the text.
+        let printer = PrettyPrinter::new(4, 150);
+        assert_eq!(printer.quote_string(r#"C:\dir\"#), r#""C:\\dir\\""#);
+        assert_eq!(printer.quote_string(r#"\""#), r#""\\\"""#);
+    }
 }
@@ -9,9 +9,15 @@ use crate::language_interface::*;
 use crate::preferences::Preferences;
 use crate::MutagenyxError;
 use std::ffi::OsStr;
-use std::path::PathBuf;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Bytes, Read};
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
+/// The number of bytes at the start of a source file that
+/// [`Recognizer::recognize_language_only`] inspects.
+const SOURCE_PREFIX_LENGTH: u64 = 64 * 1024;
+
 /// Enumeration of possible file types that a [`Recognizer`] can identify.
 #[derive(PartialEq, Eq)]
 pub enum FileType {
@@ -92,8 +98,15 @@ impl<'a> Recognizer<'a> {
             Err(_) => return None,
         };
 
+        // Scan the file once for the keys that identify the language and leave the full parse
+        // of the AST until the caller loads the file for the recognized language.
+        let discriminators = match scan_json_discriminators_from_file_with_name(file_name) {
+            Ok(d) => d,
+            Err(_) => return None,
+        };
+
         for language_object in language_objects {
-            if language_object.file_is_language_ast_file(file_name) {
+            if language_object.discriminators_are_language_ast(&discriminators) {
                 return Some(RecognizeResult::new(
                     language_object.implements(),
                     FileType::AST,
@@ -146,4 +159,396 @@ impl<'a> Recognizer<'a> {
 
         Ok(recognized_details.unwrap())
     }
+
+    /// Return the language of the source, AST, or configuration file `file_name` without
+    /// compiling the file or parsing the whole AST, or None if the file does not look like a
+    /// file of a supported language.
+    ///
+    /// For a JSON file, the function reads the top level keys of the JSON object and stops as soon
+    /// as a key identifies the language, skipping over the nested nodes without building them.
+    /// For a source file, the function uses the extension of the file name or, failing that, a
+    /// few characteristic tokens in the start of the file.  The result is a guess meant for
+    /// triage: [`Recognizer::recognize_file`] remains the authority on whether Mutagenyx can
+    /// load the file.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_name` - The path to the file in the file system.
+    pub fn recognize_language_only(file_name: &str) -> Option<Language> {
+        let path = Path::new(file_name);
+        let mut scanner = JSONScanner::new(BufReader::new(File::open(path).ok()?));
+
+        scanner.skip_whitespace();
+        if scanner.peek() == Some(b'{') {
+            let is_config_file = path.extension() == Some(OsStr::new(CONFIG_FILE_EXTENSION));
+            if let Some(language) = scanner.scan_language(is_config_file) {
+                return Some(language);
+            }
+
+            // A Yul block also starts with a brace, but a Yul program is not a JSON object.
+            if scanner.read_whole_object || path.extension() == Some(OsStr::new("json")) {
+                return None;
+            }
+        }
+
+        if let Some(language) = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .and_then(Language::from_extension)
+        {
+            return Some(language);
+        }
+
+        let mut prefix = String::new();
+        File::open(path)
+            .ok()?
+            .take(SOURCE_PREFIX_LENGTH)
+            .read_to_string(&mut prefix)
+            .ok()?;
+        language_from_source_text(&prefix)
+    }
+}
+
+/// Return the language suggested by the tokens in the start of a source file, or None if the
+/// text does not have any of the tokens.
+///
+/// # Arguments
+///
+/// * `text` - The text at the start of the source file.
+fn language_from_source_text(text: &str) -> Option<Language> {
+    let lines: Vec<&str> = text.lines().map(|l| l.trim_start()).collect();
+    let line_starts_with = |tokens: &[&str]| {
+        lines
+            .iter()
+            .any(|l| tokens.iter().any(|token| l.starts_with(token)))
+    };
+
+    if line_starts_with(&["pragma solidity", "pragma abicoder", "pragma experimental"]) {
+        Some(Language::Solidity)
+    } else if line_starts_with(&[
+        "# @version",
+        "#pragma version",
+        "# pragma version",
+        "@external",
+        "@internal",
+        "@deploy",
+        "@view",
+    ]) {
+        Some(Language::Vyper)
+    } else if line_starts_with(&["contract ", "abstract contract ", "library "]) {
+        Some(Language::Solidity)
+    } else if text.trim_start().starts_with("object \"") || text.trim_start().starts_with('{') {
+        Some(Language::Yul)
+    } else {
+        None
+    }
+}
+
+/// A reader for the top level of a JSON object that does not build the values in the object.
+struct JSONScanner<R: BufRead> {
+    /// The bytes of the JSON text.
+    bytes: Bytes<R>,
+
+    /// The byte read ahead of the scan position, if any.
+    peeked: Option<u8>,
+
+    /// True if the scanner read a complete JSON object.
+    read_whole_object: bool,
+}
+
+impl<R: BufRead> JSONScanner<R> {
+    /// Create a new scanner that reads JSON text from `reader`.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The source of the JSON text.
+    fn new(reader: R) -> JSONScanner<R> {
+        JSONScanner {
+            bytes: reader.bytes(),
+            peeked: None,
+            read_whole_object: false,
+        }
+    }
+
+    /// Return the next byte without consuming the byte.
+    fn peek(&mut self) -> Option<u8> {
+        if self.peeked.is_none() {
+            self.peeked = self.bytes.next().and_then(|b| b.ok());
+        }
+        self.peeked
+    }
+
+    /// Consume and return the next byte.
+    fn next(&mut self) -> Option<u8> {
+        match self.peeked.take() {
+            Some(b) => Some(b),
+            None => self.bytes.next().and_then(|b| b.ok()),
+        }
+    }
+
+    /// Consume the whitespace in front of the next token.
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|b| b.is_ascii_whitespace()) {
+            self.next();
+        }
+    }
+
+    /// Consume a string whose opening quote the scanner already consumed.  Return the contents
+    /// of the string if `keep` is true.  The function keeps escape sequences as written since
+    /// the keys and values that identify a language do not contain escapes.
+    ///
+    /// # Arguments
+    ///
+    /// * `keep` - True to return the contents of the string.
+    fn read_string(&mut self, keep: bool) -> Option<String> {
+        let mut contents: Vec<u8> = Vec::new();
+        loop {
+            let b = self.next()?;
+            match b {
+                b'"' => break,
+                b'\\' => {
+                    let escaped = self.next()?;
+                    if keep {
+                        contents.push(b);
+                        contents.push(escaped);
+                    }
+                }
+                _ if keep => contents.push(b),
+                _ => {}
+            }
+        }
+        Some(String::from_utf8_lossy(&contents).into_owned())
+    }
+
+    /// Consume one JSON value, including any nested objects and arrays.  Return None if the text
+    /// ends in the middle of the value.
+    fn skip_value(&mut self) -> Option<()> {
+        let mut depth = 0_usize;
+        loop {
+            match self.peek()? {
+                b'"' => {
+                    self.next();
+                    self.read_string(false)?;
+                }
+                b'{' | b'[' => {
+                    self.next();
+                    depth += 1;
+                }
+                b'}' | b']' if depth > 0 => {
+                    self.next();
+                    depth -= 1;
+                }
+                b',' | b'}' | b']' if depth == 0 => return Some(()),
+                _ => {
+                    self.next();
+                }
+            }
+            if depth == 0 && self.peek_is_value_end() {
+                return Some(());
+            }
+        }
+    }
+
+    /// Return true if the next token ends a value in an object: a comma, a closing brace, or
+    /// the end of the text.
+    fn peek_is_value_end(&mut self) -> bool {
+        self.skip_whitespace();
+        matches!(self.peek(), None | Some(b',') | Some(b'}') | Some(b']'))
+    }
+
+    /// Read the top level keys of the JSON object and return the language that the keys
+    /// identify.  The function returns as soon as the keys identify the language, and records
+    /// whether it reached the end of the object without finding the language.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_config_file` - True if the file is a configuration file, which names the language
+    ///   of the files it lists with the `language` key.
+    fn scan_language(&mut self, is_config_file: bool) -> Option<Language> {
+        if self.next()? != b'{' {
+            return None;
+        }
+
+        let mut has_ast = false;
+        let mut has_contract_name = false;
+
+        loop {
+            self.skip_whitespace();
+            match self.next()? {
+                b'"' => {}
+                b'}' => {
+                    self.read_whole_object = true;
+                    return None;
+                }
+                _ => return None,
+            }
+            let key = self.read_string(true)?;
+            self.skip_whitespace();
+            if self.next()? != b':' {
+                return None;
+            }
+            self.skip_whitespace();
+
+            let wanted = key == "nodeType" || (is_config_file && key == LANGUAGE_KEY);
+            if wanted && self.peek() == Some(b'"') {
+                self.next();
+                let value = self.read_string(true)?;
+                match value.as_str() {
+                    "SourceUnit" if key == "nodeType" => return Some(Language::Solidity),
+                    "YulObject" | "YulCode" | "YulBlock" if key == "nodeType" => {
+                        return Some(Language::Yul)
+                    }
+                    _ if key == LANGUAGE_KEY => return Language::from_str(&value).ok(),
+                    _ => {}
+                }
+            } else {
+                has_ast |= key == "ast";
+                has_contract_name |= key == "contract_name";
+                self.skip_value()?;
+                if has_ast && has_contract_name && !is_config_file {
+                    return Some(Language::Vyper);
+                }
+            }
+
+            self.skip_whitespace();
+            match self.next()? {
+                b',' => continue,
+                b'}' => {
+                    self.read_whole_object = true;
+                    return None;
+                }
+                _ => return None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::io::Cursor;
+
+    /// A reader that counts the bytes that its callers read from the wrapped reader.
+    struct CountingReader<R: Read> {
+        /// The wrapped reader.
+        reader: R,
+
+        /// The number of bytes read so far.
+        count: usize,
+    }
+
+    impl<R: Read> Read for CountingReader<R> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let n = self.reader.read(buf)?;
+            self.count += n;
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn test_recognize_language_only() {
+        let directory = env::temp_dir().join("mutagenyx_recognize_language_only");
+        let _ = std::fs::remove_dir_all(&directory);
+        std::fs::create_dir_all(&directory).unwrap();
+        let write = |name: &str, text: &str| {
+            let path = directory.join(name);
+            std::fs::write(&path, text).unwrap();
+            String::from(path.to_str().unwrap())
+        };
+        let recognize = |file_name: &str| Recognizer::recognize_language_only(file_name);
+
+        let sample = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/data/Sample.sol");
+        assert_eq!(
+            recognize(sample.to_str().unwrap()),
+            Some(Language::Solidity)
+        );
+
+        let solidity_ast = write(
+            "token.json",
+            "{\"absolutePath\": \"a\\\"b.sol\", \"exportedSymbols\": {\"C\": [1, 2]}, \
+            \"id\": 3, \"nodeType\": \"SourceUnit\", \"nodes\": []}",
+        );
+        assert_eq!(recognize(&solidity_ast), Some(Language::Solidity));
+
+        let vyper_ast = write(
+            "vault.json",
+            "{\"ast\": {\"ast_type\": \"Module\", \"body\": [{\"value\": \"}\"}]}, \
+            \"contract_name\": \"vault.vy\"}",
+        );
+        assert_eq!(recognize(&vyper_ast), Some(Language::Vyper));
+
+        let yul_ast = write("object.json", "{\"nodeType\": \"YulObject\"}");
+        assert_eq!(recognize(&yul_ast), Some(Language::Yul));
+
+        let config = write(
+            "run.mgnx",
+            "{\"filenames\": [\"token.sol\"], \"language\": \"Vyper\"}",
+        );
+        assert_eq!(recognize(&config), Some(Language::Vyper));
+
+        let vyper_source = write(
+            "vault",
+            "# pragma version ^0.3.0\n\n@external\ndef f():\n    pass\n",
+        );
+        assert_eq!(recognize(&vyper_source), Some(Language::Vyper));
+        assert_eq!(
+            recognize(&write("vault.vy", "x: uint256\n")),
+            Some(Language::Vyper)
+        );
+        assert_eq!(
+            recognize(&write("code", "object \"A\" {\n    code {}\n}\n")),
+            Some(Language::Yul)
+        );
+
+        assert_eq!(
+            recognize(&write("block", "{\n    let x := add(1, 2)\n}\n")),
+            Some(Language::Yul)
+        );
+
+        assert_eq!(recognize(&write("notes.txt", "not a program\n")), None);
+        assert_eq!(recognize(&write("empty", "{}")), None);
+        assert_eq!(
+            recognize(&write("data.json", "{\"key\": [1, {\"a\": 2}]}")),
+            None
+        );
+        assert_eq!(recognize(&write("broken.json", "{\"nodeType\": ")), None);
+        assert_eq!(
+            recognize(directory.join("missing.sol").to_str().unwrap()),
+            None
+        );
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_recognize_language_only_stops_early_in_large_ast() {
+        // A Solidity AST of several megabytes: the compiler writes nodeType before nodes.
+        let node = "{\"nodeType\": \"ExpressionStatement\", \"id\": 1, \"src\": \"0:1:0\", \
+            \"expression\": {\"nodeType\": \"Identifier\", \"id\": 2, \"name\": \"a\"}}";
+        let nodes = vec![node; 50_000].join(", ");
+        let text = format!(
+            "{{\"absolutePath\": \"large.sol\", \"id\": 100, \"nodeType\": \"SourceUnit\", \
+            \"nodes\": [{}]}}",
+            nodes
+        );
+        let mut reader = CountingReader {
+            reader: Cursor::new(text.as_bytes()),
+            count: 0,
+        };
+
+        let mut scanner = JSONScanner::new(BufReader::new(&mut reader));
+        scanner.skip_whitespace();
+        assert_eq!(scanner.scan_language(false), Some(Language::Solidity));
+        assert!(!scanner.read_whole_object);
+        drop(scanner);
+
+        // The scanner reads no more than the first buffer of the text.
+        assert!(
+            reader.count <= 64 * 1024,
+            "scanner read {} of {} bytes",
+            reader.count,
+            text.len()
+        );
+    }
 }
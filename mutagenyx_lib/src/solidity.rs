@@ -4,7 +4,9 @@ pub mod ast;
 mod commenter;
 pub mod compiler_details;
 pub mod delegate;
-mod language_interface;
-mod mutators;
+pub(crate) mod language_interface;
+pub(crate) mod mutators;
 mod node_finder;
-mod pretty_printer;
+pub(crate) mod pretty_printer;
+
+pub use self::pretty_printer::SolidityNodePrinterFactory;
@@ -6,3 +6,7 @@ pub static INCLUDE_PATHS_KEY: &str = "include-paths";
 pub static ALLOW_PATHS_KEY: &str = "allow-paths";
 pub static REMAPPINGS_KEY: &str = "remappings";
 pub static STOP_AFTER_KEY: &str = "stop-after";
+
+/// When true, checking that a mutant compiles only asks the compiler for the AST, since the
+/// compiler cannot generate bytecode for abstract contracts.
+pub static COMPILE_ONLY_KEY: &str = "compile-only";
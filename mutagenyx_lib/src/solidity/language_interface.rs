@@ -1,20 +1,24 @@
 //! The `solidity::language_interface` module provides the implementation for the [`JSONLanguageDelegate`]
 //! trait and the function `get_solidity_sub_language_interface`.
 
+use crate::commenter::CommentDetector;
 use crate::compiler_details::*;
 use crate::error::MutagenyxError;
 use crate::id::Id;
 use crate::json::*;
 use crate::json_ast_id_maker::JSONIDMaker;
 use crate::json_ast_permitter::JSONPermitter;
+use crate::json_comment_helpers::JSONCommentDetector;
 use crate::json_comment_inserter::JSONCommentInserter;
 use crate::json_language_delegate::JSONLanguageDelegate;
 use crate::json_namer::JSONNamer;
+use crate::line_table::LineTable;
 use crate::mutation_visitor::NodePath;
 use crate::mutator::*;
 use crate::namer::Namer;
 use crate::node_printer::NodePrinterFactory;
-use crate::permissions::Permissions;
+use crate::operators::OperatorRegistry;
+use crate::permissions::{Permissions, CONSTRUCTOR_NODE_KIND, MODIFIER_NODE_KIND};
 use crate::permit::Permit;
 use crate::preferences::*;
 use crate::pretty_print_visitor::PrettyPrintVisitor;
@@ -22,20 +26,26 @@ use crate::pretty_printer::PrettyPrinter;
 use crate::solidity::ast::SolidityAST;
 use crate::solidity::commenter::SolidityCommenterFactory;
 use crate::solidity::compiler_details::{
-    ALLOW_PATHS_KEY, BASE_PATH_KEY, INCLUDE_PATHS_KEY, REMAPPINGS_KEY, STOP_AFTER_KEY,
+    ALLOW_PATHS_KEY, BASE_PATH_KEY, COMPILE_ONLY_KEY, INCLUDE_PATHS_KEY, REMAPPINGS_KEY,
+    STOP_AFTER_KEY,
 };
 use crate::solidity::mutators::SolidityMutatorFactory;
 use crate::solidity::node_finder::SolidityNodeFinderFactory;
 use crate::solidity::pretty_printer::SolidityNodePrinterFactory;
 use crate::super_ast::SuperAST;
-use crate::utility::shell_execute;
+use crate::utility::{
+    compiler_execute, compiler_execute_with_timeout, compiler_timeout, compiler_warnings,
+    compiler_warnings_are_errors,
+};
 use crate::visitor::Visitor;
 use crate::Language;
 use serde_json::Value;
 use std::env;
 use std::io::Write;
+use std::ops::RangeInclusive;
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::time::Duration;
 
 /// The type that implements [`JSONLanguageDelegate`].
 pub struct SolidityLanguageSubDelegate {
@@ -69,8 +79,8 @@ impl JSONLanguageDelegate for SolidityLanguageSubDelegate {
         Err(MutagenyxError::LanguageNotRecognized)
     }
 
-    fn get_mutator_factory(&self) -> Box<dyn MutatorFactory<Value>> {
-        Box::new(SolidityMutatorFactory {})
+    fn get_mutator_factory(&self, operators: &OperatorRegistry) -> Box<dyn MutatorFactory<Value>> {
+        Box::new(SolidityMutatorFactory::new(operators.clone()))
     }
 
     fn get_pretty_print_visitor<'a>(
@@ -99,20 +109,14 @@ impl JSONLanguageDelegate for SolidityLanguageSubDelegate {
         file_name: &str,
         prefs: &Preferences,
     ) -> Result<SuperAST, MutagenyxError> {
-        if let Ok(s) = file_is_source_file(file_name, prefs) {
-            let value = load_json_from_file_with_name(&s)?;
-            return <SolidityLanguageSubDelegate as JSONLanguageDelegate>::get_value_as_super_ast(
-                self, value,
-            );
-        }
-
-        Err(MutagenyxError::SourceDoesNotCompile(String::from(
-            file_name,
-        )))
+        let warnings_as_errors = compiler_warnings_are_errors(prefs, Language::Solidity);
+        let s = file_is_source_file(file_name, prefs, warnings_as_errors)?;
+        let value = load_json_from_file_with_name(&s)?;
+        <SolidityLanguageSubDelegate as JSONLanguageDelegate>::get_value_as_super_ast(self, value)
     }
 
     fn file_is_language_source_file(&self, file_name: &str, prefs: &Preferences) -> bool {
-        file_is_source_file(file_name, prefs).is_ok()
+        file_is_source_file(file_name, prefs, false).is_ok()
     }
 
     fn implements(&self) -> Language {
@@ -136,8 +140,12 @@ impl JSONLanguageDelegate for SolidityLanguageSubDelegate {
         Box::new(JSONPermitter::new(permissions))
     }
 
-    fn mutant_compiles(&self, file_name: &str, prefs: &Preferences) -> bool {
-        file_compiles(file_name, prefs)
+    fn check_mutant_compiles(
+        &self,
+        file_name: &str,
+        prefs: &Preferences,
+    ) -> Result<(), MutagenyxError> {
+        check_file_compiles(file_name, prefs)
     }
 
     fn get_node_id_maker(&self) -> Box<dyn Id<Value>> {
@@ -155,19 +163,77 @@ impl JSONLanguageDelegate for SolidityLanguageSubDelegate {
         json_comment_inserter.insert_comment_by_path(ast, comment_node, node_path)
     }
 
+    fn get_comment_text_key(&self) -> &str {
+        "text"
+    }
+
+    fn is_pragma_node(&self, node: &Value) -> bool {
+        node.get_str_for_key("nodeType") == Some("PragmaDirective")
+    }
+
+    fn get_source_line_for_node(&self, node: &Value, lines: Option<&LineTable>) -> Option<usize> {
+        // The src value has the form 'offset:length:source-index' where offset is a byte offset.
+        let src = node.get_str_for_key("src")?;
+        let offset: usize = src.split(':').next()?.parse().ok()?;
+        lines?.line_for_offset(offset)
+    }
+
+    fn get_source_lines_for_node(
+        &self,
+        node: &Value,
+        lines: Option<&LineTable>,
+    ) -> Option<RangeInclusive<usize>> {
+        let src = node.get_str_for_key("src")?;
+        let mut parts = src.split(':');
+        let offset: usize = parts.next()?.parse().ok()?;
+        let length: usize = parts.next()?.parse().ok()?;
+        let lines = lines?;
+        let first = lines.line_for_offset(offset)?;
+        let last = lines.line_for_offset(offset + length.saturating_sub(1))?;
+        Some(first..=last)
+    }
+
     fn get_namer(&self) -> Box<dyn Namer<Value>> {
-        Box::new(JSONNamer::new(|v| {
-            v.get_str_for_key("name").map(String::from)
+        Box::new(
+            JSONNamer::new(|v| v.get_str_for_key("name").map(String::from))
+                .with_kinds(solidity_node_kinds),
+        )
+    }
+
+    fn get_comment_detector(&self) -> Box<dyn CommentDetector<Value>> {
+        Box::new(JSONCommentDetector::new(|v| {
+            v.get_str_for_key("nodeType") == Some("Comment")
         }))
     }
 }
 
+/// Return the kinds of the Solidity AST `node` for permission checks: the node type, and
+/// [`CONSTRUCTOR_NODE_KIND`] or [`MODIFIER_NODE_KIND`] for constructor and modifier definitions.
+///
+/// # Arguments
+///
+/// * `node` - The Solidity AST node.
+fn solidity_node_kinds(node: &Value) -> Vec<String> {
+    let mut kinds: Vec<String> = Vec::new();
+    if let Some(node_type) = node.get_str_for_key("nodeType") {
+        kinds.push(String::from(node_type));
+        match node_type {
+            "FunctionDefinition" if node.get_str_for_key("kind") == Some("constructor") => {
+                kinds.push(String::from(CONSTRUCTOR_NODE_KIND))
+            }
+            "ModifierDefinition" => kinds.push(String::from(MODIFIER_NODE_KIND)),
+            _ => {}
+        }
+    }
+    kinds
+}
+
 /// Retrieve Solidity compiler flags from a [`Preferences`] object.
 ///
 /// # Arguments
 ///
 /// * `prefs` - The [`Preferences`] object.
-fn get_solidity_compiler_flags_from_preferences(prefs: &Preferences) -> Vec<String> {
+pub(crate) fn get_solidity_compiler_flags_from_preferences(prefs: &Preferences) -> Vec<String> {
     let mut args: Vec<String> = Vec::new();
 
     let language_key = format!["{}", Language::Solidity];
@@ -224,7 +290,7 @@ fn get_solidity_compiler_flags_from_preferences(prefs: &Preferences) -> Vec<Stri
 /// # Arguments
 ///
 /// * `prefs` - The [`Preferences`] containing the compiler path.
-fn get_solidity_compiler_from_preferences(prefs: &Preferences) -> String {
+pub(crate) fn get_solidity_compiler_from_preferences(prefs: &Preferences) -> String {
     let mut solidity_compiler = String::from("solc");
 
     let language_key = format!["{}", Language::Solidity];
@@ -245,7 +311,12 @@ fn get_solidity_compiler_from_preferences(prefs: &Preferences) -> String {
 ///
 /// * `file_name` - The path to the source file to compile.
 /// * `prefs` - The [`Preferences`] object that may contain a value for `solidity_compiler`.
-fn file_is_source_file(file_name: &str, prefs: &Preferences) -> Result<String, MutagenyxError> {
+/// * `warnings_as_errors` - True if the function should fail when the compiler reports warnings.
+fn file_is_source_file(
+    file_name: &str,
+    prefs: &Preferences,
+    warnings_as_errors: bool,
+) -> Result<String, MutagenyxError> {
     let file_path = PathBuf::from_str(file_name).unwrap();
     let base_name = file_path.file_name().unwrap().to_str().unwrap();
     let tmp_dir = env::temp_dir();
@@ -273,27 +344,30 @@ fn file_is_source_file(file_name: &str, prefs: &Preferences) -> Result<String, M
         full_compiler_args
     );
 
-    match shell_execute(&solidity_compiler, full_compiler_args) {
-        Ok(output) => {
-            if output.status.success() {
-                Ok(out_path)
-            } else {
-                let stdout_contents = core::str::from_utf8(output.stdout.as_slice()).unwrap();
-                let stderr_contents = core::str::from_utf8(output.stderr.as_slice()).unwrap();
-                log::debug!(
-                    "Compilation failed:\n\tstdout: {}\n\tstderr: {}",
-                    stdout_contents,
-                    stderr_contents
-                );
-                Err(MutagenyxError::SourceDoesNotCompile(String::from(
-                    file_name,
-                )))
-            }
-        }
-        Err(_e) => Err(MutagenyxError::SourceDoesNotCompile(String::from(
-            file_name,
-        ))),
+    let output = compiler_execute(&solidity_compiler, full_compiler_args, file_name)?;
+    let warnings = compiler_warnings(&output);
+    if warnings_as_errors && !warnings.is_empty() {
+        return Err(MutagenyxError::CompilerWarnings(
+            String::from(file_name),
+            warnings.join("; "),
+        ));
     }
+    Ok(out_path)
+}
+
+/// Return true if the compiler settings in `prefs` only ask for an AST when checking that a
+/// mutant compiles.
+///
+/// # Arguments
+///
+/// * `prefs` - The [`Preferences`] object containing compiler settings.
+fn compile_only_from_preferences(prefs: &Preferences) -> bool {
+    let language_key = format!["{}", Language::Solidity];
+    prefs
+        .get_preferences_for_key(&language_key)
+        .and_then(|p| p.get_preferences_for_key(COMPILER_KEY))
+        .and_then(|p| p.get_bool_for_key(COMPILE_ONLY_KEY))
+        .unwrap_or(false)
 }
 
 /// Checks to see if the contents of file located at `file_name` compiles.
@@ -302,9 +376,20 @@ fn file_is_source_file(file_name: &str, prefs: &Preferences) -> Result<String, M
 ///
 /// * `file_name` - The name of the file in the file system to compile.
 /// * `prefs` - The [`Preferences`] object containing compiler settings.
-fn file_compiles(file_name: &str, prefs: &Preferences) -> bool {
+///
+/// # Errors
+///
+/// The function returns the [`MutagenyxError::CompilerInvocation`] error of the first compiler
+/// invocation if the file does not compile.
+fn check_file_compiles(file_name: &str, prefs: &Preferences) -> Result<(), MutagenyxError> {
     let tmp_dir = env::temp_dir();
-    let args = vec!["--overwrite", "-o", tmp_dir.to_str().unwrap(), file_name];
+
+    // Abstract contracts compile but do not have bytecode, so only ask for the AST.
+    let mut args: Vec<&str> = Vec::new();
+    if compile_only_from_preferences(prefs) {
+        args.push("--ast-compact-json");
+    }
+    args.extend(["--overwrite", "-o", tmp_dir.to_str().unwrap(), file_name]);
 
     let solidity_compiler = get_solidity_compiler_from_preferences(prefs);
     let mut full_compiler_args: Vec<String> = get_solidity_compiler_flags_from_preferences(prefs);
@@ -313,26 +398,41 @@ fn file_compiles(file_name: &str, prefs: &Preferences) -> bool {
         full_compiler_args.push(String::from(*arg));
     }
 
-    if invoke_compiler(file_name, &solidity_compiler, &full_compiler_args) {
-        return true;
-    }
+    let timeout = compiler_timeout(prefs, Language::Solidity);
+    let error = match invoke_compiler(file_name, &solidity_compiler, &full_compiler_args, timeout) {
+        Ok(()) => return Ok(()),
+        Err(e @ MutagenyxError::CompilerTimeout { .. }) => return Err(e),
+        Err(e) => e,
+    };
 
     // Compilation failed, try one more time, this time we inject '--stop-after parsing' into the command
-    // line for the compiler.
+    // line for the compiler.  The first invocation reports more than the parser, so keep the error
+    // of the first invocation.
     full_compiler_args.push(String::from("--stop-after"));
     full_compiler_args.push(String::from("parsing"));
 
-    invoke_compiler(file_name, &solidity_compiler, &full_compiler_args)
+    invoke_compiler(file_name, &solidity_compiler, &full_compiler_args, timeout).map_err(|_| error)
 }
 
-/// Helper function for simple compiler invocations.  Returns true if the compilation succeeded.
+/// Helper function for simple compiler invocations.
 ///
 /// # Arguments
 ///
 /// * - `file_name` the name of the file to compile, used in the log message.
 /// * - `compiler` the path to the compiler.
 /// * - `args` array of compiler command-line flags as strings.
-fn invoke_compiler(file_name: &str, compiler: &str, args: &Vec<String>) -> bool {
+/// * - `timeout` the time limit of the compiler, None to wait until the compiler finishes.
+///
+/// # Errors
+///
+/// The function returns [`MutagenyxError::CompilerInvocation`] if the compilation fails and
+/// [`MutagenyxError::CompilerTimeout`] if the compiler runs for longer than `timeout`.
+fn invoke_compiler(
+    file_name: &str,
+    compiler: &str,
+    args: &[String],
+    timeout: Option<Duration>,
+) -> Result<(), MutagenyxError> {
     log::debug!(
         "Attempting to compile {} with Solidity compiler '{}' and args: {:?}",
         file_name,
@@ -340,21 +440,94 @@ fn invoke_compiler(file_name: &str, compiler: &str, args: &Vec<String>) -> bool
         args
     );
 
-    let compiler_args = args.clone();
-
-    match shell_execute(compiler, compiler_args) {
-        Ok(output) => {
-            if !output.status.success() {
-                let stdout_contents = core::str::from_utf8(output.stdout.as_slice()).unwrap();
-                let stderr_contents = core::str::from_utf8(output.stderr.as_slice()).unwrap();
-                log::debug!(
-                    "Compilation failed:\n\tstdout: {}\n\tstderr: {}",
-                    stdout_contents,
-                    stderr_contents
-                );
-            }
-            output.status.success()
+    compiler_execute_with_timeout(compiler, args.to_vec(), file_name, timeout)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::MutagenyxError;
+    use crate::json::JSONMutate;
+    use crate::language_interface::LanguageInterface;
+    use crate::mutation::MutationType;
+    use crate::mutator::Mutator;
+    use crate::mutator_result::MutatorResult;
+    use crate::super_ast::SuperAST;
+    use crate::test_fixtures::{all_permissions, solidity_ast_with_statements};
+    use crate::Language;
+    use rand::SeedableRng;
+    use rand_pcg::Pcg64;
+    use serde_json::{json, Value};
+
+    /// A mutator that rewrites the literals of any node, including the version literals of a
+    /// pragma directive.
+    struct LiteralsMutator {}
+
+    impl Mutator<Value> for LiteralsMutator {
+        fn is_mutable_node(&mut self, node: &Value, _rand: &mut Pcg64) -> bool {
+            node.get("literals").is_some()
         }
-        Err(_e) => false,
+
+        fn mutate(
+            &mut self,
+            node: &mut Value,
+            _rand: &mut Pcg64,
+        ) -> Result<MutatorResult, MutagenyxError> {
+            node.set_node_for_key("literals", json!(["solidity", "^", "0.4", ".0"]));
+            let mut mutator_result = MutatorResult::new();
+            mutator_result.mutation_type = Some(self.implements());
+            Ok(mutator_result)
+        }
+
+        fn implements(&self) -> MutationType {
+            MutationType::Custom("Literals")
+        }
+    }
+
+    #[test]
+    fn test_pragma_nodes_are_not_mutable() {
+        let mut ast = solidity_ast_with_statements(json!([]));
+        ast["nodes"].as_array_mut().unwrap().insert(
+            0,
+            json!({
+                "nodeType": "PragmaDirective", "id": 1,
+                "literals": ["solidity", "^", "0.8", ".0"]
+            }),
+        );
+        let ast = SuperAST::Solidity(ast);
+
+        let custom = MutationType::Custom("Literals");
+        let permissions = all_permissions();
+        let mut language_object =
+            LanguageInterface::get_language_object_for_language(&Language::Solidity).unwrap();
+        language_object.add_custom_mutator(Box::new(LiteralsMutator {}));
+
+        // The interface skips pragma directives by default.
+        let mut rng = Pcg64::seed_from_u64(0);
+        let counts = language_object
+            .count_mutable_nodes(&ast, &mut rng, &permissions)
+            .unwrap();
+        assert!(!counts.contains_key(&custom));
+        let nodes = language_object
+            .list_mutable_nodes(&ast, &mut rng, &permissions, None)
+            .unwrap();
+        assert!(nodes.is_empty());
+
+        // Turning off the guard lets the mutator reach the pragma.
+        language_object.exclude_pragma_nodes(false);
+        let counts = language_object
+            .count_mutable_nodes(&ast, &mut rng, &permissions)
+            .unwrap();
+        assert_eq!(counts[&custom], 1);
+
+        // The mutation maker skips the same nodes as the counter.
+        let path_map = language_object
+            .calculate_node_paths(&ast, &permissions)
+            .unwrap();
+        language_object.exclude_pragma_nodes(true);
+        let result = language_object
+            .mutate_ast(&ast, &custom, 0, &mut rng, &permissions, &path_map)
+            .unwrap();
+        assert!(result.ast.is_none());
     }
 }
@@ -12,13 +12,12 @@ use crate::solidity::ast::{SolidityAST, SolidityASTApi};
 use crate::solidity::pretty_printer::SolidityNodePrinterFactory;
 use crate::PrettyPrinter;
 use hex;
-use num::Integer;
+use num::{BigUint, Integer};
 use openssl::hash::{Hasher, MessageDigest};
 use rand::seq::SliceRandom;
 use rand::{Rng, RngCore};
 use rand_pcg::*;
-use serde_json::json;
-use std::collections::HashMap;
+use serde_json::{json, Value};
 use std::fmt;
 
 /// Return a new integer literal node representing an integer literal number.
@@ -51,6 +50,29 @@ fn new_integer_constant_node<I: Integer + fmt::Display>(
     new_json_node(&node_string)
 }
 
+/// Replace `node` with an integer constant literal that keeps the id of `node`, so the
+/// language interface can still find the mutated node to insert the mutation comment.
+///
+/// # Arguments
+///
+/// * `node` - The node to replace.
+/// * `value` - The value of the integer constant.
+fn replace_with_integer_constant<I: Integer + fmt::Display>(
+    node: &mut SolidityAST,
+    value: I,
+) -> Result<(), MutagenyxError> {
+    let mut new_node = match new_integer_constant_node(value) {
+        Ok(n) => n,
+        _ => return Err(MutagenyxError::UnableToGenerateNode("integer constant")),
+    };
+
+    if let Some(id) = node.get("id") {
+        new_node.set_node_for_key("id", id.clone());
+    }
+    *node = new_node;
+    Ok(())
+}
+
 /// Return a new boolean literal node.
 ///
 /// # Argument
@@ -281,6 +303,19 @@ impl Mutator<SolidityAST> for BinaryOpMutator {
         false
     }
 
+    fn rejection_reason(&self, node: &SolidityAST) -> Option<String> {
+        if node.get_str_for_key("nodeType") != Some("BinaryOperation") {
+            return Some(String::from("not a BinaryOperation node"));
+        }
+        match node.get_str_for_key("operator") {
+            Some(op) => Some(format!(
+                "operator '{op}' is not one of {:?}",
+                self.operators
+            )),
+            None => Some(String::from("the node does not have an operator")),
+        }
+    }
+
     fn mutate(
         &mut self,
         node: &mut SolidityAST,
@@ -347,6 +382,167 @@ impl Mutator<SolidityAST> for BinaryOpMutator {
         self.mutation_type
     }
 
+    fn description(&self) -> &'static str {
+        match self.mutation_type {
+            MutationType::Generic(GenericMutation::ArithmeticBinaryOp) => {
+                "Replace the arithmetic operator of a binary operation with another arithmetic \
+                    operator."
+            }
+            MutationType::Generic(GenericMutation::LogicalBinaryOp) => {
+                "Replace the logical operator of a binary operation with another logical operator."
+            }
+            MutationType::Generic(GenericMutation::BitwiseBinaryOp) => {
+                "Replace the bitwise operator of a binary operation with another bitwise operator."
+            }
+            MutationType::Generic(GenericMutation::BitshiftBinaryOp) => {
+                "Replace the bitshift operator of a binary operation with another bitshift \
+                    operator."
+            }
+            MutationType::Generic(GenericMutation::ComparisonBinaryOp) => {
+                "Replace the comparison operator of a binary operation with another comparison \
+                    operator."
+            }
+            _ => "",
+        }
+    }
+
+    fn example(&self) -> &'static str {
+        match self.mutation_type {
+            MutationType::Generic(GenericMutation::ArithmeticBinaryOp) => {
+                "a + b might become a - b, a * b might become a / b"
+            }
+            MutationType::Generic(GenericMutation::LogicalBinaryOp) => "a || b might become a && b",
+            MutationType::Generic(GenericMutation::BitwiseBinaryOp) => "a & b might become a | b",
+            MutationType::Generic(GenericMutation::BitshiftBinaryOp) => {
+                "a << b might become a >> b"
+            }
+            MutationType::Generic(GenericMutation::ComparisonBinaryOp) => {
+                "a < b might become a > b, a == b might become a <= b"
+            }
+            _ => "",
+        }
+    }
+
+    fn get_comment_node(&self) -> Option<SolidityAST> {
+        self.comment_node.clone()
+    }
+}
+
+/// Implements the compound assignment operator replacement algorithm.
+///
+/// For a given Assignment expression with a compound operator such as `+=`, the algorithm either
+/// replaces the operator with the simple assignment operator `=` or replaces the operator with
+/// another compound operator.  The algorithm does not change either side of the assignment.
+struct AssignmentOperatorMutator {
+    /// The compound assignment operators.
+    operators: Vec<&'static str>,
+
+    /// Information about the mutation.
+    comment_node: Option<SolidityAST>,
+}
+
+impl AssignmentOperatorMutator {
+    /// Create the new mutator.
+    ///
+    /// # Arguments
+    ///
+    /// * `operators` - The compound assignment operators.
+    pub fn new(operators: Vec<&'static str>) -> AssignmentOperatorMutator {
+        AssignmentOperatorMutator {
+            operators,
+            comment_node: None,
+        }
+    }
+}
+
+impl Mutator<SolidityAST> for AssignmentOperatorMutator {
+    fn is_mutable_node(&mut self, node: &SolidityAST, _rand: &mut Pcg64) -> bool {
+        if node.get_str_for_key("nodeType") == Some("Assignment") {
+            if let Some(op) = node.get_str_for_key("operator") {
+                return self.operators.contains(&op);
+            }
+        }
+        false
+    }
+
+    fn mutate(
+        &mut self,
+        node: &mut SolidityAST,
+        rand: &mut Pcg64,
+    ) -> Result<MutatorResult, MutagenyxError> {
+        // Remove any previous comment.
+        self.comment_node = None;
+
+        let mut mutation_result = MutatorResult::new();
+        mutation_result.mutation_type = Some(self.implements());
+
+        let mut hasher = Hasher::new(MessageDigest::sha256())?;
+
+        let original_operator = match node.get_str_for_key("operator") {
+            Some(o) => String::from(o),
+            None => {
+                return Err(MutagenyxError::MalformedNode(
+                    String::from("Assignment"),
+                    String::from("operator"),
+                ))
+            }
+        };
+
+        // Either drop the compound operation or pick a different compound operator.
+        let other_operators: Vec<&&str> = self
+            .operators
+            .iter()
+            .filter(|o| **o != original_operator)
+            .collect();
+        let chosen_operator = if other_operators.is_empty() || rand.gen_range(0, 2) == 0 {
+            "="
+        } else {
+            match other_operators.choose(rand) {
+                Some(o) => **o,
+                None => {
+                    return Err(MutagenyxError::RandomOperationFailure(
+                        "invalid operator choice",
+                    ))
+                }
+            }
+        };
+
+        hasher.update(chosen_operator.as_bytes())?;
+        let byte_array = hasher.finish()?;
+        mutation_result.random_behavior_hash = Some(hex::encode(byte_array));
+
+        node.set_str_for_key("operator", chosen_operator);
+
+        let comment_text = format!(
+            "{} Mutator: changed '{}' to '{}'",
+            self.implements(),
+            original_operator,
+            chosen_operator
+        );
+        if let Ok(comment_node) = new_comment_node(&comment_text) {
+            self.comment_node = Some(comment_node);
+        }
+
+        if let Some(id) = node.get_int_for_key("id") {
+            mutation_result.mutated_node_id = Some(id as u64);
+        }
+
+        Ok(mutation_result)
+    }
+
+    fn implements(&self) -> MutationType {
+        MutationType::Generic(GenericMutation::AssignmentOperatorReplacement)
+    }
+
+    fn description(&self) -> &'static str {
+        "Replace the operator of a compound assignment with the simple assignment operator or with \
+            another compound assignment operator."
+    }
+
+    fn example(&self) -> &'static str {
+        "a += b; might become a = b; or a -= b;"
+    }
+
     fn get_comment_node(&self) -> Option<SolidityAST> {
         self.comment_node.clone()
     }
@@ -366,10 +562,18 @@ struct UnaryOpMutator {
 
 impl UnaryOpMutator {
     /// Create a new unary expression mutator.
-    pub fn new() -> UnaryOpMutator {
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix_operators` - The operators usable as prefix operators.
+    /// * `postfix_operators` - The operators usable as postfix operators.
+    pub fn new(
+        prefix_operators: Vec<&'static str>,
+        postfix_operators: Vec<&'static str>,
+    ) -> UnaryOpMutator {
         UnaryOpMutator {
-            prefix_operators: prefix_operators(),
-            postfix_operators: postfix_operators(),
+            prefix_operators,
+            postfix_operators,
             comment_node: None,
         }
     }
@@ -409,6 +613,24 @@ impl Mutator<SolidityAST> for UnaryOpMutator {
         false
     }
 
+    fn rejection_reason(&self, node: &SolidityAST) -> Option<String> {
+        if node.get_str_for_key("nodeType") != Some("UnaryOperation") {
+            return Some(String::from("not a UnaryOperation node"));
+        }
+        let (position, operator_list) = match node.get_bool_for_key("prefix") {
+            Some(true) => ("prefix", &self.prefix_operators),
+            Some(false) => ("postfix", &self.postfix_operators),
+            None => return Some(String::from("the node does not have prefix information")),
+        };
+        match node.get_str_for_key("operator") {
+            Some(op) => Some(format!(
+                "{position} operator '{op}' is not one of {:?}",
+                operator_list
+            )),
+            None => Some(String::from("the node does not have an operator")),
+        }
+    }
+
     fn mutate(
         &mut self,
         node: &mut SolidityAST,
@@ -494,6 +716,14 @@ impl Mutator<SolidityAST> for UnaryOpMutator {
         MutationType::Generic(GenericMutation::UnaryOp)
     }
 
+    fn description(&self) -> &'static str {
+        "Replace the operator of a prefix or postfix unary operation with another unary operator."
+    }
+
+    fn example(&self) -> &'static str {
+        "++a; might become ~a;, a-- might become a++"
+    }
+
     fn get_comment_node(&self) -> Option<SolidityAST> {
         self.comment_node.clone()
     }
@@ -703,15 +933,129 @@ impl Mutator<SolidityAST> for AssignmentMutator {
         MutationType::Generic(GenericMutation::Assignment)
     }
 
+    fn description(&self) -> &'static str {
+        "Replace the right hand side of an assignment with a random value of the type of the left \
+            hand side."
+    }
+
+    fn example(&self) -> &'static str {
+        "a = b + 10; where a is of type uint256, might become a = 29494243244;"
+    }
+
     fn get_comment_node(&self) -> Option<SolidityAST> {
         self.comment_node.clone()
     }
 }
 
+/// Return the number of statements in `statements` up to and including the first statement
+/// that unconditionally ends the flow of control (`return`, `revert`, `break`, `continue`, or
+/// `throw`).  The statements after that statement never run.
+///
+/// # Arguments
+///
+/// * `statements` - The statements array of a Block.
+fn reachable_statement_count(statements: &[SolidityAST]) -> usize {
+    let is_terminator = |statement: &SolidityAST| match statement.get_str_for_key("nodeType") {
+        Some("Return")
+        | Some("RevertStatement")
+        | Some("Break")
+        | Some("Continue")
+        | Some("Throw") => true,
+        Some("ExpressionStatement") => {
+            statement.get_str_for_key("expression/nodeType") == Some("FunctionCall")
+                && statement.get_str_for_key("expression/expression/name") == Some("revert")
+        }
+        _ => false,
+    };
+
+    match statements.iter().position(is_terminator) {
+        Some(index) => index + 1,
+        None => statements.len(),
+    }
+}
+
+/// Return true if `statement` is an ExpressionStatement.
+///
+/// # Arguments
+///
+/// * `statement` - The statement node.
+fn is_expression_statement(statement: &SolidityAST) -> bool {
+    statement.get_str_for_key("nodeType") == Some("ExpressionStatement")
+}
+
+/// Return the indexes of the reachable statements of the Block `node` for which `is_candidate`
+/// returns true, or an empty list if `node` is not a Block.
+///
+/// # Arguments
+///
+/// * `node` - The Block node.
+/// * `is_candidate` - The function that selects the statements.
+fn reachable_statement_indexes(
+    node: &SolidityAST,
+    is_candidate: fn(&SolidityAST) -> bool,
+) -> Vec<usize> {
+    if node.get_str_for_key("nodeType") != Some("Block") {
+        return vec![];
+    }
+
+    match node.get_array_for_key("statements") {
+        Some(statements) => {
+            let reachable = reachable_statement_count(statements);
+            statements[..reachable]
+                .iter()
+                .enumerate()
+                .filter(|(_, s)| is_candidate(s))
+                .map(|(i, _)| i)
+                .collect()
+        }
+        None => vec![],
+    }
+}
+
+/// Replace a random statement of the Block `node`, chosen from the reachable statements for
+/// which `is_candidate` returns true, with a comment node that holds the statement.  Return the
+/// statement.  The comment node prints the statement with its semicolon, so the semicolon ends
+/// up in the comment with the rest of the statement.
+///
+/// # Arguments
+///
+/// * `node` - The Block node.
+/// * `is_candidate` - The function that selects the statements.
+/// * `rand` - The random number generator that chooses the statement.
+/// * `hasher` - The hasher that records the choice.
+fn comment_out_random_statement(
+    node: &mut SolidityAST,
+    is_candidate: fn(&SolidityAST) -> bool,
+    rand: &mut Pcg64,
+    hasher: &mut Hasher,
+) -> Result<SolidityAST, MutagenyxError> {
+    let malformed_block =
+        || MutagenyxError::MalformedNode(String::from("Block"), String::from("statements"));
+
+    let indexes = reachable_statement_indexes(node, is_candidate);
+    if indexes.is_empty() {
+        return Err(malformed_block());
+    }
+
+    let vector_index = (rand.next_u64() % indexes.len() as u64) as usize;
+    hasher.update(&vector_index.to_ne_bytes())?;
+
+    let statements = node
+        .get_array_for_key_mut("statements")
+        .ok_or_else(malformed_block)?;
+    let statement = statements.remove(indexes[vector_index]);
+    let comment = new_comment_node_from_node(statement.clone())
+        .map_err(|_| MutagenyxError::UnableToGenerateNode("comment node"))?;
+    statements.insert(indexes[vector_index], comment);
+
+    Ok(statement)
+}
+
 /// Type that implements the DeleteStatement algorithm.
 ///
 /// The algorithm chooses a random ExpressionStatement node in any Block and replaces that statement
-/// with a comment node.
+/// with a comment node.  The algorithm skips the statements after a statement that ends the flow
+/// of control since deleting unreachable code does not change the behavior of the program.
 struct DeleteStatementMutator {
     /// Information about the mutation.
     comment_node: Option<SolidityAST>,
@@ -726,24 +1070,141 @@ impl DeleteStatementMutator {
 
 impl Mutator<SolidityAST> for DeleteStatementMutator {
     fn is_mutable_node(&mut self, node: &SolidityAST, _rand: &mut Pcg64) -> bool {
-        // We are looking for a Block node that has at least one ExpressionStatement
-        // in the body of the function.
+        // We are looking for a Block node with more than one statement and at least one
+        // reachable ExpressionStatement.
+        node.get_array_for_key("statements")
+            .is_some_and(|statements| statements.len() > 1)
+            && !reachable_statement_indexes(node, is_expression_statement).is_empty()
+    }
+
+    fn mutate(
+        &mut self,
+        node: &mut SolidityAST,
+        rand: &mut Pcg64,
+    ) -> Result<MutatorResult, MutagenyxError> {
+        // Remove previously existing comment.
+        self.comment_node = None;
+
+        let mut mutation_result = MutatorResult::new();
+        mutation_result.mutation_type = Some(self.implements());
+
+        let mut hasher = Hasher::new(MessageDigest::sha256())?;
+
+        let statement =
+            comment_out_random_statement(node, is_expression_statement, rand, &mut hasher)?;
+        mutation_result.mutated_node_id = statement.get_int_for_key("id").map(|id| id as u64);
+
+        let comment_text = format!(
+            "DeleteStatement Mutator: deleted statement '{}'",
+            pretty_print_node(&statement)
+        );
+        if let Ok(comment_node) = new_comment_node(&comment_text) {
+            self.comment_node = Some(comment_node);
+        }
+
+        let byte_array = hasher.finish()?;
+        mutation_result.random_behavior_hash = Some(hex::encode(byte_array));
+
+        Ok(mutation_result)
+    }
+
+    fn implements(&self) -> MutationType {
+        MutationType::Generic(GenericMutation::DeleteStatement)
+    }
+
+    fn description(&self) -> &'static str {
+        "Comment out a randomly selected expression statement of a block."
+    }
+
+    fn example(&self) -> &'static str {
+        "total += amount; would become // total += amount;"
+    }
+
+    fn get_comment_node(&self) -> Option<SolidityAST> {
+        self.comment_node.clone()
+    }
+}
+
+/// Type that implements the DuplicateStatement algorithm.
+///
+/// The algorithm chooses a random statement in any Block and inserts a copy of that statement
+/// immediately after the original.  The algorithm does not duplicate variable declarations or
+/// statements that end the flow of control since the copies would not compile.
+struct DuplicateStatementMutator {
+    /// Information about the mutation.
+    comment_node: Option<SolidityAST>,
+
+    /// The statement node types that the mutator will not duplicate.
+    node_types_to_avoid: Vec<&'static str>,
+
+    /// The names of functions that end the flow of control when called as a statement.
+    terminating_functions: Vec<&'static str>,
+}
+
+impl DuplicateStatementMutator {
+    /// Create the new duplicate statement mutator.
+    pub fn new() -> DuplicateStatementMutator {
+        DuplicateStatementMutator {
+            comment_node: None,
+            node_types_to_avoid: vec![
+                "Break",
+                "Continue",
+                "Return",
+                "RevertStatement",
+                "Throw",
+                "VariableDeclarationStatement",
+            ],
+            terminating_functions: vec!["revert", "selfdestruct"],
+        }
+    }
+
+    /// Return true if the mutator can duplicate `statement`.
+    ///
+    /// # Arguments
+    ///
+    /// * `statement` - The statement node from the statements array of a Block.
+    fn can_duplicate(&self, statement: &SolidityAST) -> bool {
+        let node_type = match statement.get_str_for_key("nodeType") {
+            Some(t) => t,
+            None => return false,
+        };
+
+        if self.node_types_to_avoid.contains(&node_type) {
+            return false;
+        }
+
+        if node_type == "ExpressionStatement" {
+            if let Some(function_name) = statement.get_str_for_key("expression/expression/name") {
+                return !self.terminating_functions.contains(&function_name);
+            }
+        }
+
+        true
+    }
+
+    /// Return the indices of the statements in the Block `node` that the mutator can duplicate.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The Block node.
+    fn duplicable_statement_indices(&self, node: &SolidityAST) -> Vec<usize> {
+        match node.get_array_for_key("statements") {
+            Some(statements) => statements
+                .iter()
+                .enumerate()
+                .filter(|(_, s)| self.can_duplicate(s))
+                .map(|(i, _)| i)
+                .collect(),
+            None => vec![],
+        }
+    }
+}
+
+impl Mutator<SolidityAST> for DuplicateStatementMutator {
+    fn is_mutable_node(&mut self, node: &SolidityAST, _rand: &mut Pcg64) -> bool {
         if let Some(node_type) = node.get_str_for_key("nodeType") {
             if node_type == "Block" {
-                if let Some(statements_node) = node.get("statements") {
-                    if let Some(statements_array) = statements_node.as_array() {
-                        if statements_array.len() > 1 {
-                            // The array needs to have at least one ExpressionStatement.
-                            for value in statements_array {
-                                if let Some(value_type) = value.get_str_for_key("nodeType") {
-                                    if value_type == "ExpressionStatement" {
-                                        return true;
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
+                return !self.duplicable_statement_indices(node).is_empty();
             }
         }
         false
@@ -762,46 +1223,25 @@ impl Mutator<SolidityAST> for DeleteStatementMutator {
 
         let mut hasher = Hasher::new(MessageDigest::sha256())?;
 
-        // We have a Block node that has a statements array with at least one
-        // ExpressionStatement. Go through the statements and pick one randomly in order
-        // to delete the statement.
-
-        if let Some(mut statements_node) = node.take_value_for_key("statements") {
-            if let Some(statements_array) = statements_node.as_array_mut() {
-                let mut expression_statement_indexes: Vec<usize> = vec![];
-                for (index, value) in statements_array.iter().enumerate() {
-                    if value.is_object() {
-                        if let Some(value_type) = value.get_str_for_key("nodeType") {
-                            if value_type == "ExpressionStatement" {
-                                expression_statement_indexes.push(index);
-                            }
-                        }
-                    }
-                }
-
-                // Now pick a random index.
-                let vector_index =
-                    (rand.next_u64() % expression_statement_indexes.len() as u64) as usize;
-
-                hasher.update(&vector_index.to_ne_bytes())?;
-
-                let value = statements_array.remove(expression_statement_indexes[vector_index]);
+        let indices = self.duplicable_statement_indices(node);
+        if let Some(index) = indices.choose(rand) {
+            if let Some(statements_array) = node
+                .get_mut("statements")
+                .and_then(|statements| statements.as_array_mut())
+            {
+                hasher.update(&index.to_ne_bytes())?;
 
+                let value = statements_array[*index].clone();
                 let value_s = pretty_print_node(&value);
 
                 mutation_result.mutated_node_id = value.get_int_for_key("id").map(|id| id as u64);
 
-                let new_node = match new_comment_node_from_node(value) {
-                    Ok(node) => node,
-                    Err(_e) => return Err(MutagenyxError::UnableToGenerateNode("comment node")),
-                };
-
-                statements_array.insert(expression_statement_indexes[vector_index], new_node);
+                statements_array.insert(*index + 1, value);
 
-                node.set_node_for_key("statements", statements_node);
-
-                let comment_text =
-                    format!("DeleteStatement Mutator: deleted statement '{}'", value_s);
+                let comment_text = format!(
+                    "DuplicateStatement Mutator: duplicated statement '{}'",
+                    value_s
+                );
                 if let Ok(comment_node) = new_comment_node(&comment_text) {
                     self.comment_node = Some(comment_node);
                 }
@@ -820,35 +1260,20 @@ impl Mutator<SolidityAST> for DeleteStatementMutator {
     }
 
     fn implements(&self) -> MutationType {
-        MutationType::Generic(GenericMutation::DeleteStatement)
+        MutationType::Generic(GenericMutation::DuplicateStatement)
     }
 
-    fn get_comment_node(&self) -> Option<SolidityAST> {
-        self.comment_node.clone()
+    fn description(&self) -> &'static str {
+        "Insert a copy of a randomly selected statement of a block immediately after the \
+            statement."
     }
-}
 
-/// Helper struct for FunctionCall mutation algorithm.
-///
-/// The mutation algorithm keeps track of argument indexes and values with IndexedNode
-/// objects.
-struct IndexedNode {
-    /// The index of the node in the function call arguments array.
-    pub index: usize,
-
-    /// A copy of the argument found at `index`.
-    pub node: SolidityAST,
-}
+    fn example(&self) -> &'static str {
+        "total += amount; would become total += amount; total += amount;"
+    }
 
-impl IndexedNode {
-    /// Create a new IndexedNode object.
-    ///
-    /// # Arguments
-    ///
-    /// * `index` - The index of the node from the arguments array.
-    /// * `node` - A copy of the node from the arguments array.
-    fn new(index: usize, node: SolidityAST) -> IndexedNode {
-        IndexedNode { index, node }
+    fn get_comment_node(&self) -> Option<SolidityAST> {
+        self.comment_node.clone()
     }
 }
 
@@ -967,15 +1392,67 @@ impl Mutator<SolidityAST> for FunctionCallMutator {
         MutationType::Generic(GenericMutation::FunctionCall)
     }
 
+    fn description(&self) -> &'static str {
+        "Replace a function call with one of the arguments of the call."
+    }
+
+    fn example(&self) -> &'static str {
+        "a = foo(b, c); might become a = c;"
+    }
+
     fn get_comment_node(&self) -> Option<SolidityAST> {
         self.comment_node.clone()
     }
 }
 
+/// Return the indexes of the arguments of a function call grouped by the type of the argument.
+/// The function only returns the groups with at least two arguments, in the order of the first
+/// argument of each group, and returns no groups for calls with named arguments since the names,
+/// not the positions, bind those arguments to the parameters.
+///
+/// # Arguments
+///
+/// * `node` - The node in the AST.
+fn swappable_argument_groups(node: &SolidityAST) -> Vec<Vec<usize>> {
+    if node.get_str_for_key("nodeType") != Some("FunctionCall") {
+        return vec![];
+    }
+
+    if node
+        .get("names")
+        .and_then(|n| n.as_array())
+        .is_some_and(|n| !n.is_empty())
+    {
+        return vec![];
+    }
+
+    let arguments_array = match node.get("arguments").and_then(|a| a.as_array()) {
+        Some(a) => a,
+        None => return vec![],
+    };
+
+    let mut groups: Vec<(&str, Vec<usize>)> = vec![];
+    for (index, value) in arguments_array.iter().enumerate() {
+        if let Some(type_string) = value.get_str_for_key("typeDescriptions/typeString") {
+            match groups.iter_mut().find(|(t, _)| *t == type_string) {
+                Some((_, indexes)) => indexes.push(index),
+                None => groups.push((type_string, vec![index])),
+            }
+        }
+    }
+
+    groups
+        .into_iter()
+        .map(|(_, indexes)| indexes)
+        .filter(|indexes| indexes.len() >= 2)
+        .collect()
+}
+
 /// Implements the function call argument swap mutation algorithm.
 ///
 /// The mutator should identify function call expressions where the function call contains
-/// at least two arguments of the same type.  The mutator will swap the two arguments.
+/// at least two positional arguments of the same type.  The mutator will swap two of the
+/// arguments of the same type.
 struct SwapFunctionArgumentsMutator {
     /// Information about the mutation.
     comment_node: Option<SolidityAST>,
@@ -990,41 +1467,7 @@ impl SwapFunctionArgumentsMutator {
 
 impl Mutator<SolidityAST> for SwapFunctionArgumentsMutator {
     fn is_mutable_node(&mut self, node: &SolidityAST, _rand: &mut Pcg64) -> bool {
-        if let Some(node_type) = node.get_str_for_key("nodeType") {
-            if node_type == "FunctionCall" {
-                if let Some(arguments_node) = node.get("arguments") {
-                    if let Some(arguments_array) = arguments_node.as_array() {
-                        if arguments_array.len() > 1 {
-                            // Now check to see if we have two nodes of the same type.  If we have
-                            // two nodes of the same type then we can swap the arguments.
-                            let mut type_map: HashMap<&str, i32> = HashMap::new();
-                            for value in arguments_array {
-                                if let Some(type_descriptions_node) = value.get("typeDescriptions")
-                                {
-                                    if let Some(type_string) =
-                                        type_descriptions_node.get_str_for_key("typeString")
-                                    {
-                                        if type_map.contains_key(type_string) {
-                                            let counter = type_map.get_mut(type_string).unwrap();
-                                            *counter += 1;
-                                        } else {
-                                            type_map.insert(type_string, 1);
-                                        }
-                                    }
-                                }
-                            }
-
-                            for (_key, value) in type_map {
-                                if value >= 2 {
-                                    return true;
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-        }
-        false
+        !swappable_argument_groups(node).is_empty()
     }
 
     fn mutate(
@@ -1040,85 +1483,67 @@ impl Mutator<SolidityAST> for SwapFunctionArgumentsMutator {
 
         let mut hasher = Hasher::new(MessageDigest::sha256())?;
 
-        // We have the FunctionCall node with arguments of the same type. Iterate and find
-        // nodes of the same type so that we can swap them.
-        if let Some(mut arguments_node) = node.take_value_for_key("arguments") {
-            if let Some(arguments_array) = arguments_node.as_array_mut() {
-                let mut arg_map: HashMap<&str, Vec<IndexedNode>> = HashMap::new();
-                for (index, value) in arguments_array.iter().enumerate() {
-                    if let Some(type_descriptions_node) = value.get("typeDescriptions") {
-                        if let Some(type_string) =
-                            type_descriptions_node.get_str_for_key("typeString")
-                        {
-                            if arg_map.contains_key(type_string) {
-                                let list = arg_map.get_mut(type_string).unwrap();
-                                list.push(IndexedNode::new(index, value.clone()));
-                            } else {
-                                arg_map.insert(
-                                    type_string,
-                                    vec![IndexedNode::new(index, value.clone())],
-                                );
-                            }
-                        }
-                    }
-                }
-
-                // Attempt to randomly select a pair of args from the arg_map.  We do this by
-                // putting the keys in the map that have a list with two or more arguments into
-                // a list and then randomly indexing into the list to select a key.
-                let mut list_list: Vec<Vec<IndexedNode>> = vec![];
-                for (_key, value) in arg_map {
-                    if value.len() >= 2 {
-                        list_list.push(value);
-                    }
-                }
+        let groups = swappable_argument_groups(node);
+        if groups.is_empty() {
+            return Err(MutagenyxError::MalformedNode(
+                String::from("FunctionCall"),
+                String::from("arguments"),
+            ));
+        }
 
-                let list_index = (rand.next_u64() % list_list.len() as u64) as usize;
-                let node_list = &list_list[list_index];
-                let in1 = &node_list[0];
-                let in2 = &node_list[1];
+        // Randomly select a group of arguments of the same type and then two different
+        // arguments from the group.
+        let list_index = (rand.next_u64() % groups.len() as u64) as usize;
+        let indexes = &groups[list_index];
+        let first = (rand.next_u64() % indexes.len() as u64) as usize;
+        let mut second = (rand.next_u64() % (indexes.len() - 1) as u64) as usize;
+        if second >= first {
+            second += 1;
+        }
+        let (in1, in2) = (indexes[first], indexes[second]);
 
-                hasher.update(&list_index.to_ne_bytes())?;
+        hasher.update(&in1.to_ne_bytes())?;
+        hasher.update(&in2.to_ne_bytes())?;
 
-                // pretty print the node contents first before swapping. We need these strings
-                // for the comment node.
-                let in1_node_s = pretty_print_node(&in1.node);
-                let in2_node_s = pretty_print_node(&in2.node);
+        if let Some(arguments_array) = node.get_array_for_key_mut("arguments") {
+            // pretty print the node contents first before swapping. We need these strings
+            // for the comment node.
+            let in1_node_s = pretty_print_node(&arguments_array[in1]);
+            let in2_node_s = pretty_print_node(&arguments_array[in2]);
 
-                arguments_array.remove(in1.index);
-                arguments_array.insert(in1.index, in2.node.clone());
-                arguments_array.remove(in2.index);
-                arguments_array.insert(in2.index, in1.node.clone());
-                node.set_node_for_key("arguments", arguments_node);
+            arguments_array.swap(in1, in2);
 
-                let comment_text = format!(
-                    "SwapFunctionArguments Mutator: switched '{}' for '{}'",
-                    in1_node_s, in2_node_s
-                );
-                if let Ok(comment_node) = new_comment_node(&comment_text) {
-                    self.comment_node = Some(comment_node);
-                }
+            let comment_text = format!(
+                "SwapFunctionArguments Mutator: switched '{}' for '{}'",
+                in1_node_s, in2_node_s
+            );
+            if let Ok(comment_node) = new_comment_node(&comment_text) {
+                self.comment_node = Some(comment_node);
+            }
+        }
 
-                if let Some(id) = node.get_int_for_key("id") {
-                    mutation_result.mutated_node_id = Some(id as u64);
-                }
+        if let Some(id) = node.get_int_for_key("id") {
+            mutation_result.mutated_node_id = Some(id as u64);
+        }
 
-                let byte_array = hasher.finish()?;
-                mutation_result.random_behavior_hash = Some(hex::encode(byte_array));
+        let byte_array = hasher.finish()?;
+        mutation_result.random_behavior_hash = Some(hex::encode(byte_array));
 
-                return Ok(mutation_result);
-            }
-        }
-        Err(MutagenyxError::MalformedNode(
-            String::from("FunctionCall"),
-            String::from("arguments"),
-        ))
+        Ok(mutation_result)
     }
 
     fn implements(&self) -> MutationType {
         MutationType::Generic(GenericMutation::SwapFunctionArguments)
     }
 
+    fn description(&self) -> &'static str {
+        "Swap two arguments of the same type in a function call."
+    }
+
+    fn example(&self) -> &'static str {
+        "a = foo(bar, bat, bug); might become a = foo(bug, bat, bar);"
+    }
+
     fn get_comment_node(&self) -> Option<SolidityAST> {
         self.comment_node.clone()
     }
@@ -1261,6 +1686,15 @@ impl Mutator<SolidityAST> for IfStatementMutator {
         MutationType::Generic(GenericMutation::IfStatement)
     }
 
+    fn description(&self) -> &'static str {
+        "Replace the condition of an if statement with true, false, or the logical negation of the \
+            condition."
+    }
+
+    fn example(&self) -> &'static str {
+        "if (a > b) might become if (true), if (c == 10) might become if (!(c == 10))"
+    }
+
     fn get_comment_node(&self) -> Option<SolidityAST> {
         self.comment_node.clone()
     }
@@ -1381,6 +1815,114 @@ impl Mutator<SolidityAST> for IntegerMutator {
         MutationType::Generic(GenericMutation::Integer)
     }
 
+    fn description(&self) -> &'static str {
+        "Replace an integer literal with a random value."
+    }
+
+    fn example(&self) -> &'static str {
+        "a = 10; might become a = 11;, a = 9;, or a = 2932;"
+    }
+
+    fn get_comment_node(&self) -> Option<SolidityAST> {
+        self.comment_node.clone()
+    }
+}
+
+/// Return the value of `node` if the node is a boolean literal.
+///
+/// # Arguments
+///
+/// * `node` - The node in the AST.
+fn boolean_literal_value(node: &SolidityAST) -> Option<bool> {
+    if node.get_str_for_key("nodeType")? != "Literal" || node.get_str_for_key("kind")? != "bool" {
+        return None;
+    }
+
+    match node.get_str_for_key("value")? {
+        "true" => Some(true),
+        "false" => Some(false),
+        _ => None,
+    }
+}
+
+/// Implement the BooleanLiteralFlip mutation algorithm.
+///
+/// The algorithm replaces a `true` literal with `false` and a `false` literal with `true`.
+struct BooleanLiteralFlipMutator {
+    /// Information about the mutation.
+    comment_node: Option<SolidityAST>,
+}
+
+impl BooleanLiteralFlipMutator {
+    /// Create a new boolean literal flip mutator.
+    pub fn new() -> BooleanLiteralFlipMutator {
+        BooleanLiteralFlipMutator { comment_node: None }
+    }
+}
+
+impl Mutator<SolidityAST> for BooleanLiteralFlipMutator {
+    fn is_mutable_node(&mut self, node: &SolidityAST, _rand: &mut Pcg64) -> bool {
+        boolean_literal_value(node).is_some()
+    }
+
+    fn mutate(
+        &mut self,
+        node: &mut SolidityAST,
+        _rand: &mut Pcg64,
+    ) -> Result<MutatorResult, MutagenyxError> {
+        // Remove previous comment.
+        self.comment_node = None;
+
+        let mut mutation_result = MutatorResult::new();
+        mutation_result.mutation_type = Some(self.implements());
+
+        let mut hasher = Hasher::new(MessageDigest::sha256())?;
+
+        let value = match boolean_literal_value(node) {
+            Some(v) => v,
+            None => {
+                return Err(MutagenyxError::MalformedNode(
+                    String::from("Literal"),
+                    String::from("value"),
+                ))
+            }
+        };
+
+        // Keep the hex encoding of the literal in step with the new value.
+        let new_value = (!value).to_string();
+        node.set_str_for_key("value", &new_value);
+        node.set_str_for_key("hexValue", &hex::encode(new_value.as_bytes()));
+
+        hasher.update(new_value.as_bytes())?;
+
+        let comment_text = format!(
+            "BooleanLiteralFlip Mutator: changed '{}' to '{}'",
+            value, new_value
+        );
+        if let Ok(comment_node) = new_comment_node(&comment_text) {
+            self.comment_node = Some(comment_node);
+        }
+
+        mutation_result.mutated_node_id = node.get_int_for_key("id").map(|id| id as u64);
+
+        let byte_array = hasher.finish()?;
+        mutation_result.random_behavior_hash = Some(hex::encode(byte_array));
+
+        Ok(mutation_result)
+    }
+
+    fn implements(&self) -> MutationType {
+        MutationType::Generic(GenericMutation::BooleanLiteralFlip)
+    }
+
+    fn description(&self) -> &'static str {
+        "Replace a true or false literal with the opposite literal."
+    }
+
+    fn example(&self) -> &'static str {
+        "setPaused(true); would become setPaused(false);"
+    }
+
     fn get_comment_node(&self) -> Option<SolidityAST> {
         self.comment_node.clone()
     }
@@ -1400,9 +1942,13 @@ struct SwapOperatorArgumentsMutator {
 
 impl SwapOperatorArgumentsMutator {
     /// Create a new mutator.
-    fn new() -> SwapOperatorArgumentsMutator {
+    ///
+    /// # Arguments
+    ///
+    /// * `valid_operators` - The operators whose arguments the mutator can swap.
+    fn new(valid_operators: Vec<&'static str>) -> SwapOperatorArgumentsMutator {
         SwapOperatorArgumentsMutator {
-            valid_operators: non_commutative_operators(),
+            valid_operators,
             comment_node: None,
         }
     }
@@ -1479,6 +2025,14 @@ impl Mutator<SolidityAST> for SwapOperatorArgumentsMutator {
         MutationType::Generic(GenericMutation::SwapOperatorArguments)
     }
 
+    fn description(&self) -> &'static str {
+        "Swap the operands of a binary operation with a non-commutative operator."
+    }
+
+    fn example(&self) -> &'static str {
+        "thing = a - b; might become thing = b - a;, x = y << 5; might become x = 5 << y;"
+    }
+
     fn get_comment_node(&self) -> Option<SolidityAST> {
         self.comment_node.clone()
     }
@@ -1634,6 +2188,14 @@ impl Mutator<SolidityAST> for SwapLinesMutator {
         MutationType::Generic(GenericMutation::SwapLines)
     }
 
+    fn description(&self) -> &'static str {
+        "Swap two randomly selected statements in the body of a function."
+    }
+
+    fn example(&self) -> &'static str {
+        "a = foo - bar(); ... foo += 8; might become foo += 8; ... a = foo - bar();"
+    }
+
     fn get_comment_node(&self) -> Option<SolidityAST> {
         self.comment_node.clone()
     }
@@ -1818,6 +2380,14 @@ impl Mutator<SolidityAST> for SolidityRequireMutator {
         MutationType::Solidity(SolidityMutation::Require)
     }
 
+    fn description(&self) -> &'static str {
+        "Replace the condition of a require() call with the logical negation of the condition."
+    }
+
+    fn example(&self) -> &'static str {
+        "require(b > 10); would become require(!(b > 10));"
+    }
+
     fn get_comment_node(&self) -> Option<SolidityAST> {
         self.comment_node.clone()
     }
@@ -1960,6 +2530,14 @@ impl Mutator<SolidityAST> for SolidityUncheckedBlockMutator {
         MutationType::Solidity(SolidityMutation::UncheckedBlock)
     }
 
+    fn description(&self) -> &'static str {
+        "Wrap a randomly selected expression statement of a block in an unchecked block."
+    }
+
+    fn example(&self) -> &'static str {
+        "a = b + c; would become unchecked { a = b + c; }"
+    }
+
     fn get_comment_node(&self) -> Option<SolidityAST> {
         self.comment_node.clone()
     }
@@ -2023,62 +2601,4468 @@ impl Mutator<SolidityAST> for SolidityElimDelegateCallMutator {
         MutationType::Solidity(SolidityMutation::ElimDelegateCall)
     }
 
+    fn description(&self) -> &'static str {
+        "Replace a call to delegatecall() with a call to call()."
+    }
+
+    fn example(&self) -> &'static str {
+        "foo.delegatecall(data) would become foo.call(data)"
+    }
+
     fn get_comment_node(&self) -> Option<SolidityAST> {
         self.comment_node.clone()
     }
 }
 
-/// Implement the [`MutatorFactory<T>`] trait to have an interface for getting mutators for requested
-/// mutation algorithms.
-pub struct SolidityMutatorFactory {}
+/// Return the comparison operator that produces the logical negation of `operator`.
+///
+/// # Arguments
+///
+/// * `operator` - The string slice containing the comparison operator.
+fn negated_comparison_operator(operator: &str) -> Option<&'static str> {
+    match operator {
+        "==" => Some("!="),
+        "!=" => Some("=="),
+        "<" => Some(">="),
+        ">=" => Some("<"),
+        ">" => Some("<="),
+        "<=" => Some(">"),
+        _ => None,
+    }
+}
 
-impl MutatorFactory<SolidityAST> for SolidityMutatorFactory {
-    fn mutator_for(&self, mutation_type: &MutationType) -> Option<Box<dyn Mutator<SolidityAST>>> {
-        match mutation_type {
-            MutationType::Generic(t) => match t {
-                GenericMutation::ArithmeticBinaryOp => Some(Box::new(BinaryOpMutator::new(
-                    arithmetic_operators(),
-                    MutationType::Generic(GenericMutation::ArithmeticBinaryOp),
-                ))),
-                GenericMutation::LogicalBinaryOp => Some(Box::new(BinaryOpMutator::new(
-                    logical_operators(),
-                    MutationType::Generic(GenericMutation::LogicalBinaryOp),
-                ))),
-                GenericMutation::BitwiseBinaryOp => Some(Box::new(BinaryOpMutator::new(
-                    bitwise_operators(),
-                    MutationType::Generic(GenericMutation::BitwiseBinaryOp),
-                ))),
-                GenericMutation::BitshiftBinaryOp => Some(Box::new(BinaryOpMutator::new(
-                    bitshift_operators(),
-                    MutationType::Generic(GenericMutation::BitshiftBinaryOp),
-                ))),
-                GenericMutation::ComparisonBinaryOp => Some(Box::new(BinaryOpMutator::new(
-                    comparison_operators(),
-                    MutationType::Generic(GenericMutation::ComparisonBinaryOp),
-                ))),
-                GenericMutation::Assignment => Some(Box::new(AssignmentMutator::new())),
-                GenericMutation::DeleteStatement => Some(Box::new(DeleteStatementMutator::new())),
-                GenericMutation::FunctionCall => Some(Box::new(FunctionCallMutator::new())),
-                GenericMutation::SwapFunctionArguments => {
-                    Some(Box::new(SwapFunctionArgumentsMutator::new()))
-                }
-                GenericMutation::IfStatement => Some(Box::new(IfStatementMutator::new())),
-                GenericMutation::Integer => Some(Box::new(IntegerMutator::new())),
-                GenericMutation::SwapOperatorArguments => {
-                    Some(Box::new(SwapOperatorArgumentsMutator::new()))
-                }
-                GenericMutation::SwapLines => Some(Box::new(SwapLinesMutator::new())),
-                GenericMutation::UnaryOp => Some(Box::new(UnaryOpMutator::new())),
-            },
-            MutationType::Solidity(t) => match t {
-                SolidityMutation::Require => Some(Box::new(SolidityRequireMutator::new())),
-                SolidityMutation::UncheckedBlock => {
-                    Some(Box::new(SolidityUncheckedBlockMutator::new()))
-                }
-                SolidityMutation::ElimDelegateCall => {
-                    Some(Box::new(SolidityElimDelegateCallMutator::new()))
+/// Implements the Solidity require comparison mutation algorithm.
+///
+/// This mutator finds calls to the Solidity `require` function whose first argument is a
+/// comparison and replaces the comparison operator with the operator that negates the
+/// comparison.
+///
+/// # Example
+///
+/// ```solidity
+/// require(a >= b);
+/// ```
+///
+/// would become
+///
+/// ```solidity
+/// require(a < b);
+/// ```
+struct SolidityRequireComparisonMutator {
+    /// Information about the mutation.
+    comment_node: Option<SolidityAST>,
+}
+
+impl SolidityRequireComparisonMutator {
+    /// Create the new require comparison mutator.
+    pub fn new() -> SolidityRequireComparisonMutator {
+        SolidityRequireComparisonMutator { comment_node: None }
+    }
+}
+
+impl Mutator<SolidityAST> for SolidityRequireComparisonMutator {
+    fn is_mutable_node(&mut self, node: &SolidityAST, _rand: &mut Pcg64) -> bool {
+        if let Some(node_type) = node.get_str_for_key("nodeType") {
+            if node_type == "FunctionCall" {
+                if let Some(expression) = node.get("expression") {
+                    if expression.get_str_for_key("name") == Some("require") {
+                        if let Some(arguments) = node.get_array_for_key("arguments") {
+                            if let Some(arg) = arguments.first() {
+                                if arg.get_str_for_key("nodeType") == Some("BinaryOperation") {
+                                    if let Some(operator) = arg.get_str_for_key("operator") {
+                                        return negated_comparison_operator(operator).is_some();
+                                    }
+                                }
+                            }
+                        }
+                    }
                 }
-            },
+            }
+        }
+        false
+    }
+
+    fn mutate(
+        &mut self,
+        node: &mut SolidityAST,
+        _rand: &mut Pcg64,
+    ) -> Result<MutatorResult, MutagenyxError> {
+        // Remove previous comment
+        self.comment_node = None;
+
+        let mut mutation_result = MutatorResult::new();
+        mutation_result.mutation_type = Some(self.implements());
+
+        let mut hasher = Hasher::new(MessageDigest::sha256())?;
+
+        let node_id = node.get_int_for_key("id");
+
+        if let Some(arg) = node.pointer_mut("/arguments/0") {
+            let original_arg_s = pretty_print_node(arg);
+
+            if let Some(operator) = arg.get_str_for_key("operator") {
+                if let Some(new_operator) = negated_comparison_operator(operator) {
+                    arg.set_str_for_key("operator", new_operator);
+
+                    let new_arg_s = pretty_print_node(arg);
+                    hasher.update(new_arg_s.as_bytes())?;
+
+                    let comment_text = format!(
+                        "RequireComparison Mutator: Changing '{}' to '{}'",
+                        original_arg_s, new_arg_s
+                    );
+                    if let Ok(comment_node) = new_comment_node(&comment_text) {
+                        self.comment_node = Some(comment_node);
+                    }
+
+                    mutation_result.mutated_node_id = node_id.map(|id| id as u64);
+
+                    let byte_array = hasher.finish()?;
+                    mutation_result.random_behavior_hash = Some(hex::encode(byte_array));
+
+                    return Ok(mutation_result);
+                }
+            }
+        }
+
+        Err(MutagenyxError::MalformedNode(
+            String::from("FunctionCall"),
+            String::from("arguments"),
+        ))
+    }
+
+    fn implements(&self) -> MutationType {
+        MutationType::Solidity(SolidityMutation::RequireComparison)
+    }
+
+    fn description(&self) -> &'static str {
+        "Replace the comparison operator in the condition of a require() call with the negated \
+            operator."
+    }
+
+    fn example(&self) -> &'static str {
+        "require(a >= b); would become require(a < b);"
+    }
+
+    fn get_comment_node(&self) -> Option<SolidityAST> {
+        self.comment_node.clone()
+    }
+}
+
+/// Implements the memory-to-storage mutation algorithm for Solidity programs.
+///
+/// The algorithm finds VariableDeclarationStatement nodes that declare a single local struct
+/// variable in `memory` and initialize the variable from state-backed data (the initial value
+/// has a `storage` type).  The algorithm changes the data location of the variable to `storage`
+/// so the local variable becomes a pointer into storage instead of a copy.
+///
+/// # Example
+///
+/// ```solidity
+/// S memory s = structs[i];
+/// ```
+///
+/// would become
+///
+/// ```solidity
+/// S storage s = structs[i];
+/// ```
+struct SolidityMemoryToStorageMutator {
+    /// Information about the mutation.
+    comment_node: Option<SolidityAST>,
+}
+
+impl SolidityMemoryToStorageMutator {
+    /// Create a new memory-to-storage mutator.
+    pub fn new() -> SolidityMemoryToStorageMutator {
+        SolidityMemoryToStorageMutator { comment_node: None }
+    }
+}
+
+impl Mutator<SolidityAST> for SolidityMemoryToStorageMutator {
+    fn is_mutable_node(&mut self, node: &SolidityAST, _rand: &mut Pcg64) -> bool {
+        if let Some(node_type) = node.get_str_for_key("nodeType") {
+            if node_type == "VariableDeclarationStatement" {
+                // A storage pointer must refer to state-backed data.
+                let initialized_from_storage = node
+                    .get("initialValue")
+                    .and_then(|v| v.get_str_for_key("typeDescriptions/typeString"))
+                    .map_or_else(
+                        || false,
+                        |t| t.ends_with(" storage ref") || t.ends_with(" storage pointer"),
+                    );
+
+                if !initialized_from_storage {
+                    return false;
+                }
+
+                if let Some(declarations) = node.get_array_for_key("declarations") {
+                    if declarations.len() == 1 {
+                        let declaration = &declarations[0];
+                        let is_memory = declaration
+                            .get_str_for_key("storageLocation")
+                            .map_or_else(|| false, |l| l == "memory");
+                        let is_struct = declaration
+                            .get_str_for_key("typeDescriptions/typeString")
+                            .map_or_else(|| false, |t| t.starts_with("struct "));
+                        return is_memory && is_struct;
+                    }
+                }
+            }
+        }
+        false
+    }
+
+    fn mutate(
+        &mut self,
+        node: &mut SolidityAST,
+        _rand: &mut Pcg64,
+    ) -> Result<MutatorResult, MutagenyxError> {
+        // Remove previous comment
+        self.comment_node = None;
+
+        let mut mutation_result = MutatorResult::new();
+        mutation_result.mutation_type = Some(self.implements());
+
+        let mut hasher = Hasher::new(MessageDigest::sha256())?;
+
+        let original_node_s = pretty_print_node(node);
+
+        if let Some(declaration) = node.pointer_mut("/declarations/0") {
+            declaration.set_str_for_key("storageLocation", "storage");
+
+            // Keep the type information consistent with the new data location.
+            if let Some(type_string) = declaration.get_str_for_key("typeDescriptions/typeString") {
+                let new_type_string = type_string.replace(" memory", " storage");
+                declaration.set_str_for_key("typeDescriptions/typeString", &new_type_string);
+            }
+            if let Some(type_identifier) =
+                declaration.get_str_for_key("typeDescriptions/typeIdentifier")
+            {
+                let new_type_identifier = type_identifier.replace("_memory_ptr", "_storage_ptr");
+                declaration
+                    .set_str_for_key("typeDescriptions/typeIdentifier", &new_type_identifier);
+            }
+
+            let new_node_s = pretty_print_node(node);
+            hasher.update(new_node_s.as_bytes())?;
+
+            let comment_text = format!(
+                "MemoryToStorage Mutator: Changing '{}' to '{}'",
+                original_node_s, new_node_s
+            );
+            if let Ok(comment_node) = new_comment_node(&comment_text) {
+                self.comment_node = Some(comment_node);
+            }
+
+            mutation_result.mutated_node_id = node.get_int_for_key("id").map(|id| id as u64);
+
+            let byte_array = hasher.finish()?;
+            mutation_result.random_behavior_hash = Some(hex::encode(byte_array));
+
+            return Ok(mutation_result);
+        }
+
+        Err(MutagenyxError::MalformedNode(
+            String::from("VariableDeclarationStatement"),
+            String::from("declarations"),
+        ))
+    }
+
+    fn implements(&self) -> MutationType {
+        MutationType::Solidity(SolidityMutation::MemoryToStorage)
+    }
+
+    fn description(&self) -> &'static str {
+        "Change the data location of a local struct variable from memory to storage."
+    }
+
+    fn example(&self) -> &'static str {
+        "S memory s = structs[i]; would become S storage s = structs[i];"
+    }
+
+    fn get_comment_node(&self) -> Option<SolidityAST> {
+        self.comment_node.clone()
+    }
+}
+
+/// Return the size of the fixed-size byte array type named by `name` or None if `name` does not
+/// name a fixed-size byte array type.
+///
+/// # Arguments
+///
+/// * `name` - The string slice containing the type name (ie `bytes32`).
+fn fixed_bytes_size(name: &str) -> Option<u32> {
+    let size_str = name.strip_prefix("bytes")?;
+    if size_str.starts_with('0') {
+        return None;
+    }
+    match size_str.parse::<u32>() {
+        Ok(size) if (1..=32).contains(&size) => Some(size),
+        _ => None,
+    }
+}
+
+/// Implements the fixed bytes size mutation algorithm for Solidity programs.
+///
+/// The algorithm finds ElementaryTypeName nodes that name a fixed-size byte array type
+/// (`bytes1` through `bytes32`) and replaces the size of the type with a different random
+/// size.
+struct SolidityFixedBytesSizeMutator {
+    /// Information about the mutation.
+    comment_node: Option<SolidityAST>,
+}
+
+impl SolidityFixedBytesSizeMutator {
+    /// Create a new fixed bytes size mutator.
+    pub fn new() -> SolidityFixedBytesSizeMutator {
+        SolidityFixedBytesSizeMutator { comment_node: None }
+    }
+}
+
+impl Mutator<SolidityAST> for SolidityFixedBytesSizeMutator {
+    fn is_mutable_node(&mut self, node: &SolidityAST, _rand: &mut Pcg64) -> bool {
+        if let Some(node_type) = node.get_str_for_key("nodeType") {
+            if node_type == "ElementaryTypeName" {
+                if let Some(name) = node.get_str_for_key("name") {
+                    return fixed_bytes_size(name).is_some();
+                }
+            }
+        }
+        false
+    }
+
+    fn mutate(
+        &mut self,
+        node: &mut SolidityAST,
+        rand: &mut Pcg64,
+    ) -> Result<MutatorResult, MutagenyxError> {
+        // Remove previous comment
+        self.comment_node = None;
+
+        let mut mutation_result = MutatorResult::new();
+        mutation_result.mutation_type = Some(self.implements());
+
+        let mut hasher = Hasher::new(MessageDigest::sha256())?;
+
+        if let Some(name) = node.get_str_for_key("name") {
+            if let Some(size) = fixed_bytes_size(name) {
+                let original_name = String::from(name);
+
+                let mut new_size: u32 = rand.gen_range(1, 33);
+                while new_size == size {
+                    new_size = rand.gen_range(1, 33);
+                }
+
+                hasher.update(&new_size.to_ne_bytes())?;
+
+                let new_name = format!("bytes{new_size}");
+                node.set_str_for_key("name", &new_name);
+
+                // Keep the type information consistent with the new name.
+                if node
+                    .get_str_for_key("typeDescriptions/typeString")
+                    .is_some()
+                {
+                    node.set_str_for_key("typeDescriptions/typeString", &new_name);
+                    node.set_str_for_key(
+                        "typeDescriptions/typeIdentifier",
+                        &format!("t_bytes{new_size}"),
+                    );
+                }
+
+                let comment_text = format!(
+                    "FixedBytesSize Mutator: Changing '{}' to '{}'",
+                    original_name, new_name
+                );
+                if let Ok(comment_node) = new_comment_node(&comment_text) {
+                    self.comment_node = Some(comment_node);
+                }
+
+                mutation_result.mutated_node_id = node.get_int_for_key("id").map(|id| id as u64);
+
+                let byte_array = hasher.finish()?;
+                mutation_result.random_behavior_hash = Some(hex::encode(byte_array));
+
+                return Ok(mutation_result);
+            }
+        }
+
+        Err(MutagenyxError::MalformedNode(
+            String::from("ElementaryTypeName"),
+            String::from("name"),
+        ))
+    }
+
+    fn implements(&self) -> MutationType {
+        MutationType::Solidity(SolidityMutation::FixedBytesSize)
+    }
+
+    fn description(&self) -> &'static str {
+        "Change the size of a fixed-size byte array type."
+    }
+
+    fn example(&self) -> &'static str {
+        "bytes32 a; might become bytes16 a;"
+    }
+
+    fn get_comment_node(&self) -> Option<SolidityAST> {
+        self.comment_node.clone()
+    }
+}
+
+/// Implements the call options mutation algorithm for Solidity programs.
+///
+/// The algorithm finds FunctionCallOptions nodes that contain a `value` or `gas` option and
+/// replaces the expression for one of those options with a different constant.
+///
+/// # Example
+///
+/// ```solidity
+/// a.call{value: 0}("");
+/// ```
+///
+/// might become
+///
+/// ```solidity
+/// a.call{value: 1 ether}("");
+/// ```
+struct SolidityCallOptionsMutator {
+    /// Information about the mutation.
+    comment_node: Option<SolidityAST>,
+
+    /// The replacement constants (value, sub-denomination) for the `value` option.
+    value_options: Vec<(u64, Option<&'static str>)>,
+
+    /// The replacement constants for the `gas` option.
+    gas_options: Vec<(u64, Option<&'static str>)>,
+}
+
+impl SolidityCallOptionsMutator {
+    /// Create a new call options mutator.
+    pub fn new() -> SolidityCallOptionsMutator {
+        SolidityCallOptionsMutator {
+            comment_node: None,
+            value_options: vec![(0, None), (1, Some("wei")), (1, Some("ether"))],
+            gas_options: vec![(0, None), (2300, None), (100000, None)],
+        }
+    }
+
+    /// Return the list of replacement nodes for the option named `name` that differ from
+    /// `option`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the call option.
+    /// * `option` - The current expression for the call option.
+    fn replacements_for(&self, name: &str, option: &SolidityAST) -> Vec<SolidityAST> {
+        let candidates = match name {
+            "value" => &self.value_options,
+            "gas" => &self.gas_options,
+            _ => return vec![],
+        };
+
+        let option_s = pretty_print_node(option);
+        candidates
+            .iter()
+            .filter_map(|(value, sub_denomination)| {
+                let mut node = new_integer_constant_node(*value).ok()?;
+                if let Some(sub_denomination) = sub_denomination {
+                    node.set_str_for_key("subdenomination", sub_denomination);
+                }
+                Some(node)
+            })
+            .filter(|n| pretty_print_node(n) != option_s)
+            .collect()
+    }
+
+    /// Return the list of indices of the options in `node` that the mutator can replace.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The FunctionCallOptions node.
+    fn mutable_option_indices(&self, node: &SolidityAST) -> Vec<usize> {
+        let mut indices: Vec<usize> = vec![];
+        if let Some(names) = node.get_array_for_key("names") {
+            if let Some(options) = node.get_array_for_key("options") {
+                for (i, (name, option)) in names.iter().zip(options.iter()).enumerate() {
+                    if let Some(name) = name.as_str() {
+                        if !self.replacements_for(name, option).is_empty() {
+                            indices.push(i);
+                        }
+                    }
+                }
+            }
+        }
+        indices
+    }
+}
+
+impl Mutator<SolidityAST> for SolidityCallOptionsMutator {
+    fn is_mutable_node(&mut self, node: &SolidityAST, _rand: &mut Pcg64) -> bool {
+        if let Some(node_type) = node.get_str_for_key("nodeType") {
+            if node_type == "FunctionCallOptions" {
+                return !self.mutable_option_indices(node).is_empty();
+            }
+        }
+        false
+    }
+
+    fn mutate(
+        &mut self,
+        node: &mut SolidityAST,
+        rand: &mut Pcg64,
+    ) -> Result<MutatorResult, MutagenyxError> {
+        // Remove previous comment
+        self.comment_node = None;
+
+        let mut mutation_result = MutatorResult::new();
+        mutation_result.mutation_type = Some(self.implements());
+
+        let mut hasher = Hasher::new(MessageDigest::sha256())?;
+
+        let indices = self.mutable_option_indices(node);
+        if let Some(index) = indices.choose(rand) {
+            let name = node
+                .get_array_for_key("names")
+                .and_then(|a| a[*index].as_str())
+                .map(String::from);
+            let option = node.get_array_for_key("options").map(|a| a[*index].clone());
+
+            if let (Some(name), Some(option)) = (name, option) {
+                let replacements = self.replacements_for(&name, &option);
+                if let Some(replacement) = replacements.choose(rand) {
+                    let option_s = pretty_print_node(&option);
+                    let replacement_s = pretty_print_node(replacement);
+
+                    hasher.update(&index.to_ne_bytes())?;
+                    hasher.update(replacement_s.as_bytes())?;
+
+                    node.set_node_for_key_at_index("options", *index, replacement.clone());
+
+                    let comment_text = format!(
+                        "CallOptions Mutator: Changing '{}: {}' to '{}: {}'",
+                        name, option_s, name, replacement_s
+                    );
+                    if let Ok(comment_node) = new_comment_node(&comment_text) {
+                        self.comment_node = Some(comment_node);
+                    }
+
+                    mutation_result.mutated_node_id =
+                        node.get_int_for_key("id").map(|id| id as u64);
+
+                    let byte_array = hasher.finish()?;
+                    mutation_result.random_behavior_hash = Some(hex::encode(byte_array));
+
+                    return Ok(mutation_result);
+                }
+            }
+        }
+
+        Err(MutagenyxError::MalformedNode(
+            String::from("FunctionCallOptions"),
+            String::from("options"),
+        ))
+    }
+
+    fn implements(&self) -> MutationType {
+        MutationType::Solidity(SolidityMutation::CallOptions)
+    }
+
+    fn description(&self) -> &'static str {
+        "Replace the value or gas option of a function call with a different constant."
+    }
+
+    fn example(&self) -> &'static str {
+        "a.call{value: 0}(\"\") might become a.call{value: 1 ether}(\"\")"
+    }
+
+    fn get_comment_node(&self) -> Option<SolidityAST> {
+        self.comment_node.clone()
+    }
+}
+
+/// Return the data location that the swap calldata/memory mutation algorithm should use in place
+/// of `location` or None if the algorithm does not change `location`.
+///
+/// # Arguments
+///
+/// * `location` - The string slice containing the data location (ie `calldata`).
+fn swapped_data_location(location: &str) -> Option<&'static str> {
+    match location {
+        "calldata" => Some("memory"),
+        "memory" => Some("calldata"),
+        _ => None,
+    }
+}
+
+/// Implements the swap calldata/memory mutation algorithm for Solidity programs.
+///
+/// The algorithm finds external FunctionDefinition nodes with reference type parameters stored in
+/// `calldata` or `memory` and swaps the data location of one of those parameters.  External
+/// function parameters are the only place where both data locations are legal.
+///
+/// # Example
+///
+/// ```solidity
+/// function f(uint256[] calldata a) external {}
+/// ```
+///
+/// would become
+///
+/// ```solidity
+/// function f(uint256[] memory a) external {}
+/// ```
+struct SolidityCalldataMemoryMutator {
+    /// Information about the mutation.
+    comment_node: Option<SolidityAST>,
+}
+
+impl SolidityCalldataMemoryMutator {
+    /// Create a new calldata/memory mutator.
+    pub fn new() -> SolidityCalldataMemoryMutator {
+        SolidityCalldataMemoryMutator { comment_node: None }
+    }
+
+    /// Return the list of indices of the parameters of the function definition in `node` whose
+    /// data location the mutator can swap.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The FunctionDefinition node.
+    fn mutable_parameter_indices(&self, node: &SolidityAST) -> Vec<usize> {
+        let mut indices: Vec<usize> = vec![];
+        if let Some(parameters) = node.get_array_for_key("parameters/parameters") {
+            for (i, parameter) in parameters.iter().enumerate() {
+                let swappable = parameter
+                    .get_str_for_key("storageLocation")
+                    .and_then(swapped_data_location)
+                    .is_some();
+                if swappable {
+                    indices.push(i);
+                }
+            }
+        }
+        indices
+    }
+}
+
+impl Mutator<SolidityAST> for SolidityCalldataMemoryMutator {
+    fn is_mutable_node(&mut self, node: &SolidityAST, _rand: &mut Pcg64) -> bool {
+        if let Some(node_type) = node.get_str_for_key("nodeType") {
+            if node_type == "FunctionDefinition" {
+                let is_external = node
+                    .get_str_for_key("visibility")
+                    .map_or_else(|| false, |v| v == "external");
+                return is_external && !self.mutable_parameter_indices(node).is_empty();
+            }
+        }
+        false
+    }
+
+    fn mutate(
+        &mut self,
+        node: &mut SolidityAST,
+        rand: &mut Pcg64,
+    ) -> Result<MutatorResult, MutagenyxError> {
+        // Remove previous comment
+        self.comment_node = None;
+
+        let mut mutation_result = MutatorResult::new();
+        mutation_result.mutation_type = Some(self.implements());
+
+        let mut hasher = Hasher::new(MessageDigest::sha256())?;
+
+        let indices = self.mutable_parameter_indices(node);
+        if let Some(index) = indices.choose(rand) {
+            let pointer = format!("/parameters/parameters/{}", index);
+            if let Some(parameter) = node.pointer_mut(&pointer) {
+                let original_parameter_s = pretty_print_node(parameter);
+
+                let locations = parameter
+                    .get_str_for_key("storageLocation")
+                    .and_then(|l| Some((String::from(l), swapped_data_location(l)?)));
+
+                if let Some((old_location, new_location)) = locations {
+                    parameter.set_str_for_key("storageLocation", new_location);
+
+                    // Keep the type information consistent with the new data location.
+                    if let Some(type_string) =
+                        parameter.get_str_for_key("typeDescriptions/typeString")
+                    {
+                        let new_type_string = type_string
+                            .replace(&format!(" {}", old_location), &format!(" {}", new_location));
+                        parameter.set_str_for_key("typeDescriptions/typeString", &new_type_string);
+                    }
+                    if let Some(type_identifier) =
+                        parameter.get_str_for_key("typeDescriptions/typeIdentifier")
+                    {
+                        let new_type_identifier = type_identifier.replace(
+                            &format!("_{}_ptr", old_location),
+                            &format!("_{}_ptr", new_location),
+                        );
+                        parameter.set_str_for_key(
+                            "typeDescriptions/typeIdentifier",
+                            &new_type_identifier,
+                        );
+                    }
+
+                    let new_parameter_s = pretty_print_node(parameter);
+
+                    hasher.update(&index.to_ne_bytes())?;
+                    hasher.update(new_parameter_s.as_bytes())?;
+
+                    let comment_text = format!(
+                        "SwapCalldataMemory Mutator: Changing '{}' to '{}'",
+                        original_parameter_s, new_parameter_s
+                    );
+                    if let Ok(comment_node) = new_comment_node(&comment_text) {
+                        self.comment_node = Some(comment_node);
+                    }
+
+                    mutation_result.mutated_node_id =
+                        node.get_int_for_key("id").map(|id| id as u64);
+
+                    let byte_array = hasher.finish()?;
+                    mutation_result.random_behavior_hash = Some(hex::encode(byte_array));
+
+                    return Ok(mutation_result);
+                }
+            }
+        }
+
+        Err(MutagenyxError::MalformedNode(
+            String::from("FunctionDefinition"),
+            String::from("parameters"),
+        ))
+    }
+
+    fn implements(&self) -> MutationType {
+        MutationType::Solidity(SolidityMutation::SwapCalldataMemory)
+    }
+
+    fn description(&self) -> &'static str {
+        "Swap the data location of an array or bytes parameter of an external function between \
+            calldata and memory."
+    }
+
+    fn example(&self) -> &'static str {
+        "function f(uint256[] calldata a) external would become function f(uint256[] memory a) \
+            external"
+    }
+
+    fn get_comment_node(&self) -> Option<SolidityAST> {
+        self.comment_node.clone()
+    }
+}
+
+/// Implements the new-to-address mutation algorithm for Solidity programs.
+///
+/// The algorithm finds FunctionCall nodes that create a contract with a NewExpression and replaces
+/// the contract creation with a conversion of an address to the contract type.  The replacement
+/// address is either an address typed argument of the contract creation or `msg.sender`, both of
+/// which are always in scope at the point of the creation.
+///
+/// # Example
+///
+/// ```solidity
+/// Token t = new Token(owner);
+/// ```
+///
+/// would become
+///
+/// ```solidity
+/// Token t = Token(owner);
+/// ```
+struct SolidityNewToAddressMutator {
+    /// Information about the mutation.
+    comment_node: Option<SolidityAST>,
+}
+
+impl SolidityNewToAddressMutator {
+    /// Create a new new-to-address mutator.
+    pub fn new() -> SolidityNewToAddressMutator {
+        SolidityNewToAddressMutator { comment_node: None }
+    }
+
+    /// Return the NewExpression node that creates a contract in the FunctionCall `node` or None
+    /// if `node` does not create a contract.  The function looks through call options such as
+    /// `new Foo{salt: s}()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The FunctionCall node.
+    fn contract_creation(node: &SolidityAST) -> Option<&SolidityAST> {
+        let mut expression = node.get("expression")?;
+        if expression.get_str_for_key("nodeType")? == "FunctionCallOptions" {
+            expression = expression.get("expression")?;
+        }
+
+        if expression.get_str_for_key("nodeType")? != "NewExpression" {
+            return None;
+        }
+
+        // Only contracts can be converted from an address, `new bytes(n)` and
+        // `new uint256[](n)` do not qualify.
+        let type_name = expression.get("typeName")?;
+        if type_name.get_str_for_key("nodeType")? != "UserDefinedTypeName" {
+            return None;
+        }
+
+        Some(expression)
+    }
+
+    /// Return the list of address expressions that can replace the contract creation in `node`.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The FunctionCall node.
+    fn replacement_addresses(node: &SolidityAST) -> Vec<SolidityAST> {
+        let mut addresses: Vec<SolidityAST> = vec![];
+
+        if let Some(arguments) = node.get_array_for_key("arguments") {
+            for argument in arguments {
+                let is_address = argument
+                    .get_str_for_key("typeDescriptions/typeString")
+                    .map_or_else(|| false, |t| t == "address" || t == "address payable");
+                if is_address {
+                    addresses.push(argument.clone());
+                }
+            }
+        }
+
+        addresses.push(json!({
+            "id": 9999993,
+            "nodeType": "MemberAccess",
+            "memberName": "sender",
+            "expression": {
+                "id": 9999992,
+                "nodeType": "Identifier",
+                "name": "msg",
+                "typeDescriptions": {
+                    "typeIdentifier": "t_magic_message",
+                    "typeString": "msg"
+                }
+            },
+            "typeDescriptions": {
+                "typeIdentifier": "t_address",
+                "typeString": "address"
+            }
+        }));
+
+        addresses
+    }
+}
+
+impl Mutator<SolidityAST> for SolidityNewToAddressMutator {
+    fn is_mutable_node(&mut self, node: &SolidityAST, _rand: &mut Pcg64) -> bool {
+        if let Some(node_type) = node.get_str_for_key("nodeType") {
+            if node_type == "FunctionCall" {
+                return SolidityNewToAddressMutator::contract_creation(node).is_some();
+            }
+        }
+        false
+    }
+
+    fn mutate(
+        &mut self,
+        node: &mut SolidityAST,
+        rand: &mut Pcg64,
+    ) -> Result<MutatorResult, MutagenyxError> {
+        // Remove previous comment
+        self.comment_node = None;
+
+        let mut mutation_result = MutatorResult::new();
+        mutation_result.mutation_type = Some(self.implements());
+
+        let mut hasher = Hasher::new(MessageDigest::sha256())?;
+
+        let contract_name = SolidityNewToAddressMutator::contract_creation(node)
+            .and_then(|e| e.get("typeName"))
+            .map(pretty_print_node);
+
+        if let Some(contract_name) = contract_name {
+            let addresses = SolidityNewToAddressMutator::replacement_addresses(node);
+            if let Some(address) = addresses.choose(rand) {
+                let original_node_s = pretty_print_node(node);
+
+                node.set_str_for_key("kind", "typeConversion");
+                node.set_node_for_key(
+                    "expression",
+                    json!({
+                        "id": 9999991,
+                        "nodeType": "Identifier",
+                        "name": contract_name,
+                        "typeDescriptions": {
+                            "typeString": format!("type(contract {})", contract_name)
+                        }
+                    }),
+                );
+                node.set_node_for_key("arguments", json!([address]));
+                node.set_node_for_key("names", json!([]));
+
+                let new_node_s = pretty_print_node(node);
+                hasher.update(new_node_s.as_bytes())?;
+
+                let comment_text = format!(
+                    "NewToAddress Mutator: Changing '{}' to '{}'",
+                    original_node_s, new_node_s
+                );
+                if let Ok(comment_node) = new_comment_node(&comment_text) {
+                    self.comment_node = Some(comment_node);
+                }
+
+                mutation_result.mutated_node_id = node.get_int_for_key("id").map(|id| id as u64);
+
+                let byte_array = hasher.finish()?;
+                mutation_result.random_behavior_hash = Some(hex::encode(byte_array));
+
+                return Ok(mutation_result);
+            }
+        }
+
+        Err(MutagenyxError::MalformedNode(
+            String::from("FunctionCall"),
+            String::from("expression"),
+        ))
+    }
+
+    fn implements(&self) -> MutationType {
+        MutationType::Solidity(SolidityMutation::NewToAddress)
+    }
+
+    fn description(&self) -> &'static str {
+        "Replace a contract creation with new by a conversion of an existing address to the \
+            contract type."
+    }
+
+    fn example(&self) -> &'static str {
+        "Token t = new Token(owner); would become Token t = Token(owner);"
+    }
+
+    fn get_comment_node(&self) -> Option<SolidityAST> {
+        self.comment_node.clone()
+    }
+}
+
+/// Return the pretty-printed struct expression, the member type, and the pretty-printed value of
+/// an assignment statement of the form `s.member = value;` where `s` is a struct, or None if
+/// `statement` does not assign to a struct member.
+///
+/// # Arguments
+///
+/// * `statement` - The statement node from the statements array of a Block.
+fn struct_member_assignment(statement: &SolidityAST) -> Option<(String, String, String)> {
+    if statement.get_str_for_key("nodeType")? != "ExpressionStatement" {
+        return None;
+    }
+
+    let assignment = statement.get("expression")?;
+    if assignment.get_str_for_key("nodeType")? != "Assignment"
+        || assignment.get_str_for_key("operator")? != "="
+    {
+        return None;
+    }
+
+    let left_hand_side = assignment.get("leftHandSide")?;
+    if left_hand_side.get_str_for_key("nodeType")? != "MemberAccess" {
+        return None;
+    }
+
+    let base = left_hand_side.get("expression")?;
+    if !base
+        .get_str_for_key("typeDescriptions/typeString")?
+        .starts_with("struct ")
+    {
+        return None;
+    }
+
+    let member_type = left_hand_side.get_str_for_key("typeDescriptions/typeString")?;
+
+    Some((
+        pretty_print_node(base),
+        String::from(member_type),
+        pretty_print_node(assignment.get("rightHandSide")?),
+    ))
+}
+
+/// Implements the swap struct fields mutation algorithm for Solidity programs.
+///
+/// The algorithm finds struct constructor calls and Blocks that contain a sequence of assignments
+/// to members of the same struct.  The algorithm then swaps two values of the same type so that
+/// the values initialize the wrong fields of the struct.
+///
+/// # Example
+///
+/// ```solidity
+/// Order memory o = Order(buyer, seller, amount);
+/// ```
+///
+/// would become
+///
+/// ```solidity
+/// Order memory o = Order(seller, buyer, amount);
+/// ```
+struct SoliditySwapStructFieldsMutator {
+    /// Information about the mutation.
+    comment_node: Option<SolidityAST>,
+}
+
+impl SoliditySwapStructFieldsMutator {
+    /// Create a new swap struct fields mutator.
+    pub fn new() -> SoliditySwapStructFieldsMutator {
+        SoliditySwapStructFieldsMutator { comment_node: None }
+    }
+
+    /// Return the list of pairs of indices of the values in `node` that the mutator can swap.  For
+    /// a struct constructor call the indices refer to the arguments of the call and for a Block
+    /// the indices refer to the statements of the block.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The FunctionCall or Block node.
+    fn swappable_pairs(&self, node: &SolidityAST) -> Vec<(usize, usize)> {
+        // Each candidate records the values that must match (the type of the value and for
+        // member assignments the struct) and the pretty-printed value.
+        let candidates: Vec<Option<(String, String)>> = match node.get_str_for_key("nodeType") {
+            Some("FunctionCall")
+                if node.get_str_for_key("kind") == Some("structConstructorCall") =>
+            {
+                match node.get_array_for_key("arguments") {
+                    Some(arguments) => arguments
+                        .iter()
+                        .map(|a| {
+                            let type_string = a.get_str_for_key("typeDescriptions/typeString")?;
+                            Some((String::from(type_string), pretty_print_node(a)))
+                        })
+                        .collect(),
+                    None => vec![],
+                }
+            }
+            Some("Block") => match node.get_array_for_key("statements") {
+                Some(statements) => statements
+                    .iter()
+                    .map(|s| {
+                        let (base, member_type, value) = struct_member_assignment(s)?;
+                        Some((format!("{} {}", base, member_type), value))
+                    })
+                    .collect(),
+                None => vec![],
+            },
+            _ => vec![],
+        };
+
+        let mut pairs: Vec<(usize, usize)> = vec![];
+        for (i, first) in candidates.iter().enumerate() {
+            if let Some((first_key, first_value)) = first {
+                for (j, second) in candidates.iter().enumerate().skip(i + 1) {
+                    if let Some((second_key, second_value)) = second {
+                        // Swapping identical values would not change the program.
+                        if first_key == second_key && first_value != second_value {
+                            pairs.push((i, j));
+                        }
+                    }
+                }
+            }
+        }
+        pairs
+    }
+}
+
+impl Mutator<SolidityAST> for SoliditySwapStructFieldsMutator {
+    fn is_mutable_node(&mut self, node: &SolidityAST, _rand: &mut Pcg64) -> bool {
+        !self.swappable_pairs(node).is_empty()
+    }
+
+    fn mutate(
+        &mut self,
+        node: &mut SolidityAST,
+        rand: &mut Pcg64,
+    ) -> Result<MutatorResult, MutagenyxError> {
+        // Remove previous comment
+        self.comment_node = None;
+
+        let mut mutation_result = MutatorResult::new();
+        mutation_result.mutation_type = Some(self.implements());
+
+        let mut hasher = Hasher::new(MessageDigest::sha256())?;
+
+        let node_type = String::from(node.get_str_for_key("nodeType").unwrap_or("Block"));
+
+        let pairs = self.swappable_pairs(node);
+        if let Some((first_index, second_index)) = pairs.choose(rand) {
+            let (first_pointer, second_pointer) = if node_type == "Block" {
+                (
+                    format!("/statements/{}/expression/rightHandSide", first_index),
+                    format!("/statements/{}/expression/rightHandSide", second_index),
+                )
+            } else {
+                (
+                    format!("/arguments/{}", first_index),
+                    format!("/arguments/{}", second_index),
+                )
+            };
+
+            let first = node.pointer(&first_pointer).cloned();
+            let second = node.pointer(&second_pointer).cloned();
+
+            if let (Some(first), Some(second)) = (first, second) {
+                let first_s = pretty_print_node(&first);
+                let second_s = pretty_print_node(&second);
+
+                hasher.update(&first_index.to_ne_bytes())?;
+                hasher.update(&second_index.to_ne_bytes())?;
+
+                if let Some(value) = node.pointer_mut(&first_pointer) {
+                    *value = second;
+                }
+                if let Some(value) = node.pointer_mut(&second_pointer) {
+                    *value = first;
+                }
+
+                let comment_text = format!(
+                    "SwapStructFields Mutator: swapped '{}' with '{}'",
+                    first_s, second_s
+                );
+                if let Ok(comment_node) = new_comment_node(&comment_text) {
+                    self.comment_node = Some(comment_node);
+                }
+
+                mutation_result.mutated_node_id = node.get_int_for_key("id").map(|id| id as u64);
+
+                let byte_array = hasher.finish()?;
+                mutation_result.random_behavior_hash = Some(hex::encode(byte_array));
+
+                return Ok(mutation_result);
+            }
+        }
+
+        let key = if node_type == "Block" {
+            "statements"
+        } else {
+            "arguments"
+        };
+        Err(MutagenyxError::MalformedNode(node_type, String::from(key)))
+    }
+
+    fn implements(&self) -> MutationType {
+        MutationType::Solidity(SolidityMutation::SwapStructFields)
+    }
+
+    fn description(&self) -> &'static str {
+        "Swap two values of the same type in a struct constructor call or in a sequence of \
+            assignments to struct members."
+    }
+
+    fn example(&self) -> &'static str {
+        "Order(buyer, seller, amount) would become Order(seller, buyer, amount)"
+    }
+
+    fn get_comment_node(&self) -> Option<SolidityAST> {
+        self.comment_node.clone()
+    }
+}
+
+/// Return the text of the mutability of a state variable declaration (`constant`, `immutable`,
+/// or `mutable`) if the declaration is a state variable.
+///
+/// # Arguments
+///
+/// * `node` - The VariableDeclaration node.
+fn state_variable_mutability(node: &SolidityAST) -> Option<String> {
+    if node.get_str_for_key("nodeType")? != "VariableDeclaration"
+        || !node.get_bool_for_key("stateVariable")?
+    {
+        return None;
+    }
+
+    if node.get_bool_for_key("constant").unwrap_or(false) {
+        return Some(String::from("constant"));
+    }
+
+    Some(String::from(
+        node.get_str_for_key("mutability").unwrap_or("mutable"),
+    ))
+}
+
+/// Implements the constant/immutable mutation algorithm for Solidity programs.
+///
+/// The algorithm finds state variables declared `constant` or `immutable` and changes the
+/// declaration to use the other modifier or removes the modifier altogether.  Many of the mutants
+/// will not compile (a `constant` variable requires a compile-time value and an `immutable`
+/// variable may only be assigned in the constructor), so the algorithm relies on mutant
+/// validation to filter those mutants.
+///
+/// # Example
+///
+/// ```solidity
+/// uint256 public constant FEE = 30;
+/// ```
+///
+/// might become
+///
+/// ```solidity
+/// uint256 public immutable FEE = 30;
+/// ```
+struct SolidityConstantImmutableMutator {
+    /// Information about the mutation.
+    comment_node: Option<SolidityAST>,
+}
+
+impl SolidityConstantImmutableMutator {
+    /// Create a new constant/immutable mutator.
+    pub fn new() -> SolidityConstantImmutableMutator {
+        SolidityConstantImmutableMutator { comment_node: None }
+    }
+}
+
+impl Mutator<SolidityAST> for SolidityConstantImmutableMutator {
+    fn is_mutable_node(&mut self, node: &SolidityAST, _rand: &mut Pcg64) -> bool {
+        state_variable_mutability(node).map_or_else(
+            || false,
+            |mutability| mutability == "constant" || mutability == "immutable",
+        )
+    }
+
+    fn mutate(
+        &mut self,
+        node: &mut SolidityAST,
+        rand: &mut Pcg64,
+    ) -> Result<MutatorResult, MutagenyxError> {
+        // Remove previous comment
+        self.comment_node = None;
+
+        let mut mutation_result = MutatorResult::new();
+        mutation_result.mutation_type = Some(self.implements());
+
+        let mut hasher = Hasher::new(MessageDigest::sha256())?;
+
+        if let Some(mutability) = state_variable_mutability(node) {
+            let alternatives: Vec<&str> = ["constant", "immutable", "mutable"]
+                .into_iter()
+                .filter(|m| *m != mutability)
+                .collect();
+
+            if let Some(new_mutability) = alternatives.choose(rand) {
+                let original_node_s = pretty_print_node(node);
+
+                node.set_node_for_key("constant", json!(*new_mutability == "constant"));
+                node.set_str_for_key("mutability", new_mutability);
+
+                let new_node_s = pretty_print_node(node);
+
+                hasher.update(new_mutability.as_bytes())?;
+
+                let comment_text = format!(
+                    "ConstantImmutable Mutator: Changing '{}' to '{}'",
+                    original_node_s, new_node_s
+                );
+                if let Ok(comment_node) = new_comment_node(&comment_text) {
+                    self.comment_node = Some(comment_node);
+                }
+
+                mutation_result.mutated_node_id = node.get_int_for_key("id").map(|id| id as u64);
+
+                let byte_array = hasher.finish()?;
+                mutation_result.random_behavior_hash = Some(hex::encode(byte_array));
+
+                return Ok(mutation_result);
+            }
+        }
+
+        Err(MutagenyxError::MalformedNode(
+            String::from("VariableDeclaration"),
+            String::from("mutability"),
+        ))
+    }
+
+    fn implements(&self) -> MutationType {
+        MutationType::Solidity(SolidityMutation::ConstantImmutable)
+    }
+
+    fn description(&self) -> &'static str {
+        "Change a constant state variable to immutable or a plain variable, or an immutable state \
+            variable to constant or a plain variable."
+    }
+
+    fn example(&self) -> &'static str {
+        "uint256 public constant FEE = 30; might become uint256 public immutable FEE = 30;"
+    }
+
+    fn get_comment_node(&self) -> Option<SolidityAST> {
+        self.comment_node.clone()
+    }
+}
+
+/// Implements the array length mutation algorithm for Solidity programs.
+///
+/// The algorithm finds reads of the `length` member of an array (or `bytes`) value and replaces
+/// the read with an integer constant: 0, 1, or a random value between 2 and 255.
+///
+/// # Example
+///
+/// ```solidity
+/// for (uint256 i = 0; i < values.length; i++) {}
+/// ```
+///
+/// might become
+///
+/// ```solidity
+/// for (uint256 i = 0; i < 1; i++) {}
+/// ```
+struct SolidityArrayLengthMutator {
+    /// Information about the mutation.
+    comment_node: Option<SolidityAST>,
+}
+
+impl SolidityArrayLengthMutator {
+    /// Create a new array length mutator.
+    pub fn new() -> SolidityArrayLengthMutator {
+        SolidityArrayLengthMutator { comment_node: None }
+    }
+}
+
+impl Mutator<SolidityAST> for SolidityArrayLengthMutator {
+    fn is_mutable_node(&mut self, node: &SolidityAST, _rand: &mut Pcg64) -> bool {
+        if let Some(node_type) = node.get_str_for_key("nodeType") {
+            if node_type == "MemberAccess" {
+                let is_length = node
+                    .get_str_for_key("memberName")
+                    .map_or_else(|| false, |n| n == "length");
+                let is_integer = node
+                    .get_str_for_key("typeDescriptions/typeString")
+                    .map_or_else(|| false, |t| t.starts_with("uint"));
+                // Old versions of Solidity allow assignments to the length of a storage array.
+                let is_lvalue = node.get_bool_for_key("lValueRequested").unwrap_or(false);
+                return is_length && is_integer && !is_lvalue;
+            }
+        }
+        false
+    }
+
+    fn mutate(
+        &mut self,
+        node: &mut SolidityAST,
+        rand: &mut Pcg64,
+    ) -> Result<MutatorResult, MutagenyxError> {
+        // Remove previous comment
+        self.comment_node = None;
+
+        let mut mutation_result = MutatorResult::new();
+        mutation_result.mutation_type = Some(self.implements());
+
+        let mut hasher = Hasher::new(MessageDigest::sha256())?;
+
+        let original_node_s = pretty_print_node(node);
+        let id = node.get_int_for_key("id");
+
+        let replacement_value: u32 = match rand.gen_range(0, 3) {
+            0 => 0,
+            1 => 1,
+            _ => rand.gen_range(2, 256),
+        };
+
+        hasher.update(&replacement_value.to_ne_bytes())?;
+
+        replace_with_integer_constant(node, replacement_value)?;
+
+        let comment_text = format!(
+            "ArrayLength Mutator: Replaced '{}' with '{}'",
+            original_node_s, replacement_value
+        );
+        if let Ok(comment_node) = new_comment_node(&comment_text) {
+            self.comment_node = Some(comment_node);
+        }
+
+        // The literal replaces the member access, so report the id of the original node.
+        mutation_result.mutated_node_id = id.map(|id| id as u64);
+
+        let byte_array = hasher.finish()?;
+        mutation_result.random_behavior_hash = Some(hex::encode(byte_array));
+
+        Ok(mutation_result)
+    }
+
+    fn implements(&self) -> MutationType {
+        MutationType::Solidity(SolidityMutation::ArrayLength)
+    }
+
+    fn description(&self) -> &'static str {
+        "Replace a read of the length of an array with an integer constant."
+    }
+
+    fn example(&self) -> &'static str {
+        "i < values.length might become i < 1"
+    }
+
+    fn get_comment_node(&self) -> Option<SolidityAST> {
+        self.comment_node.clone()
+    }
+}
+
+/// The members of the Solidity `block`, `msg`, and `tx` global variables that the global member
+/// access mutator can exchange, along with the type of each member.
+const SOLIDITY_GLOBAL_MEMBERS: [(&str, &str, &str); 11] = [
+    ("block", "chainid", "uint256"),
+    ("block", "coinbase", "address payable"),
+    ("block", "difficulty", "uint256"),
+    ("block", "gaslimit", "uint256"),
+    ("block", "number", "uint256"),
+    ("block", "prevrandao", "uint256"),
+    ("block", "timestamp", "uint256"),
+    ("msg", "sender", "address"),
+    ("msg", "value", "uint256"),
+    ("tx", "gasprice", "uint256"),
+    ("tx", "origin", "address"),
+];
+
+/// Return the type identifier the Solidity compiler uses for the global variable `base`.
+///
+/// # Arguments
+///
+/// * `base` - The name of the global variable (`block`, `msg`, or `tx`).
+fn global_type_identifier(base: &str) -> &'static str {
+    match base {
+        "block" => "t_magic_block",
+        "msg" => "t_magic_message",
+        _ => "t_magic_transaction",
+    }
+}
+
+/// Implements the global member access mutation algorithm for Solidity programs.
+///
+/// The algorithm finds accesses to members of the `block`, `msg`, and `tx` global variables and
+/// replaces the access with an access to a different global member that has the same type.
+///
+/// # Example
+///
+/// ```solidity
+/// require(block.timestamp > deadline);
+/// ```
+///
+/// might become
+///
+/// ```solidity
+/// require(block.number > deadline);
+/// ```
+struct SolidityGlobalMemberAccessMutator {
+    /// Information about the mutation.
+    comment_node: Option<SolidityAST>,
+}
+
+impl SolidityGlobalMemberAccessMutator {
+    /// Create a new global member access mutator.
+    pub fn new() -> SolidityGlobalMemberAccessMutator {
+        SolidityGlobalMemberAccessMutator { comment_node: None }
+    }
+
+    /// Return the global members, other than the member accessed in `node`, that have the same
+    /// type as `node`.  The function returns an empty list if `node` is not an access to a
+    /// member of a global variable.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The MemberAccess node.
+    fn alternate_members_for(&self, node: &SolidityAST) -> Vec<(&'static str, &'static str)> {
+        if node.get_str_for_key("nodeType") != Some("MemberAccess")
+            || node.get_str_for_key("expression/nodeType") != Some("Identifier")
+        {
+            return vec![];
+        }
+
+        let (base, member, type_string) = match (
+            node.get_str_for_key("expression/name"),
+            node.get_str_for_key("memberName"),
+            node.get_str_for_key("typeDescriptions/typeString"),
+        ) {
+            (Some(b), Some(m), Some(t)) => (b, m, t),
+            _ => return vec![],
+        };
+
+        if !SOLIDITY_GLOBAL_MEMBERS
+            .iter()
+            .any(|(b, m, _)| *b == base && *m == member)
+        {
+            return vec![];
+        }
+
+        SOLIDITY_GLOBAL_MEMBERS
+            .iter()
+            .filter(|(b, m, t)| *t == type_string && !(*b == base && *m == member))
+            .map(|(b, m, _)| (*b, *m))
+            .collect()
+    }
+}
+
+impl Mutator<SolidityAST> for SolidityGlobalMemberAccessMutator {
+    fn is_mutable_node(&mut self, node: &SolidityAST, _rand: &mut Pcg64) -> bool {
+        !self.alternate_members_for(node).is_empty()
+    }
+
+    fn mutate(
+        &mut self,
+        node: &mut SolidityAST,
+        rand: &mut Pcg64,
+    ) -> Result<MutatorResult, MutagenyxError> {
+        // Remove previous comment
+        self.comment_node = None;
+
+        let mut mutation_result = MutatorResult::new();
+        mutation_result.mutation_type = Some(self.implements());
+
+        let mut hasher = Hasher::new(MessageDigest::sha256())?;
+
+        let alternates = self.alternate_members_for(node);
+
+        if let Some((base, member)) = alternates.choose(rand) {
+            let original_node_s = pretty_print_node(node);
+
+            node.set_str_for_key("expression/name", base);
+            node.set_str_for_key("memberName", member);
+
+            // Keep the type of the global variable consistent with the new name.
+            if let Some(type_descriptions) = node.pointer_mut("/expression/typeDescriptions") {
+                type_descriptions.set_str_for_key("typeString", base);
+                type_descriptions.set_str_for_key("typeIdentifier", global_type_identifier(base));
+            }
+
+            let new_node_s = pretty_print_node(node);
+            hasher.update(new_node_s.as_bytes())?;
+
+            let comment_text = format!(
+                "GlobalMemberAccess Mutator: Replaced '{}' with '{}'",
+                original_node_s, new_node_s
+            );
+            if let Ok(comment_node) = new_comment_node(&comment_text) {
+                self.comment_node = Some(comment_node);
+            }
+
+            if let Some(id) = node.get_int_for_key("id") {
+                mutation_result.mutated_node_id = Some(id as u64);
+            }
+
+            let byte_array = hasher.finish()?;
+            mutation_result.random_behavior_hash = Some(hex::encode(byte_array));
+
+            return Ok(mutation_result);
+        }
+
+        Err(MutagenyxError::MalformedNode(
+            String::from("MemberAccess"),
+            String::from("memberName"),
+        ))
+    }
+
+    fn implements(&self) -> MutationType {
+        MutationType::Solidity(SolidityMutation::GlobalMemberAccess)
+    }
+
+    fn description(&self) -> &'static str {
+        "Replace an access to a member of block, msg, or tx with another global member of the same \
+            type."
+    }
+
+    fn example(&self) -> &'static str {
+        "block.timestamp might become block.number, msg.sender might become tx.origin"
+    }
+
+    fn get_comment_node(&self) -> Option<SolidityAST> {
+        self.comment_node.clone()
+    }
+}
+
+/// Implements the swap modifiers mutation algorithm for Solidity programs.
+///
+/// The algorithm finds function definitions that apply two or more modifiers and swaps two of
+/// the modifiers so that the function applies the modifiers in a different order.  The algorithm
+/// leaves base constructor calls in constructor definitions alone.
+///
+/// # Example
+///
+/// ```solidity
+/// function withdraw() external nonReentrant onlyOwner {}
+/// ```
+///
+/// would become
+///
+/// ```solidity
+/// function withdraw() external onlyOwner nonReentrant {}
+/// ```
+struct SoliditySwapModifiersMutator {
+    /// Information about the mutation.
+    comment_node: Option<SolidityAST>,
+}
+
+impl SoliditySwapModifiersMutator {
+    /// Create a new swap modifiers mutator.
+    pub fn new() -> SoliditySwapModifiersMutator {
+        SoliditySwapModifiersMutator { comment_node: None }
+    }
+
+    /// Return the pairs of indices in the `modifiers` array of `node` that the mutator can swap
+    /// to change the order of the modifiers.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The FunctionDefinition node.
+    fn swappable_pairs(&self, node: &SolidityAST) -> Vec<(usize, usize)> {
+        if node.get_str_for_key("nodeType") != Some("FunctionDefinition") {
+            return vec![];
+        }
+
+        let modifiers = match node.get_array_for_key("modifiers") {
+            Some(m) => m,
+            None => return vec![],
+        };
+
+        // Older compilers do not record the kind of the invocation, in that case the entry is a
+        // modifier unless the function is a constructor.
+        let is_constructor = node.get_str_for_key("kind") == Some("constructor");
+        let invocations: Vec<(usize, String)> = modifiers
+            .iter()
+            .enumerate()
+            .filter(|(_, m)| match m.get_str_for_key("kind") {
+                Some(kind) => kind == "modifierInvocation",
+                None => !is_constructor,
+            })
+            .map(|(i, m)| (i, pretty_print_node(m)))
+            .collect();
+
+        let mut pairs: Vec<(usize, usize)> = vec![];
+        for (i, (first_index, first_s)) in invocations.iter().enumerate() {
+            for (second_index, second_s) in &invocations[i + 1..] {
+                if first_s != second_s {
+                    pairs.push((*first_index, *second_index));
+                }
+            }
+        }
+        pairs
+    }
+}
+
+impl Mutator<SolidityAST> for SoliditySwapModifiersMutator {
+    fn is_mutable_node(&mut self, node: &SolidityAST, _rand: &mut Pcg64) -> bool {
+        !self.swappable_pairs(node).is_empty()
+    }
+
+    fn mutate(
+        &mut self,
+        node: &mut SolidityAST,
+        rand: &mut Pcg64,
+    ) -> Result<MutatorResult, MutagenyxError> {
+        // Remove previous comment
+        self.comment_node = None;
+
+        let mut mutation_result = MutatorResult::new();
+        mutation_result.mutation_type = Some(self.implements());
+
+        let mut hasher = Hasher::new(MessageDigest::sha256())?;
+
+        let pairs = self.swappable_pairs(node);
+        if let Some((first_index, second_index)) = pairs.choose(rand) {
+            if let Some(modifiers) = node.get_array_for_key_mut("modifiers") {
+                let first_s = pretty_print_node(&modifiers[*first_index]);
+                let second_s = pretty_print_node(&modifiers[*second_index]);
+
+                hasher.update(&first_index.to_ne_bytes())?;
+                hasher.update(&second_index.to_ne_bytes())?;
+
+                modifiers.swap(*first_index, *second_index);
+
+                let comment_text = format!(
+                    "SwapModifiers Mutator: swapped '{}' with '{}'",
+                    first_s, second_s
+                );
+                if let Ok(comment_node) = new_comment_node(&comment_text) {
+                    self.comment_node = Some(comment_node);
+                }
+
+                mutation_result.mutated_node_id = node.get_int_for_key("id").map(|id| id as u64);
+
+                let byte_array = hasher.finish()?;
+                mutation_result.random_behavior_hash = Some(hex::encode(byte_array));
+
+                return Ok(mutation_result);
+            }
+        }
+
+        Err(MutagenyxError::MalformedNode(
+            String::from("FunctionDefinition"),
+            String::from("modifiers"),
+        ))
+    }
+
+    fn implements(&self) -> MutationType {
+        MutationType::Solidity(SolidityMutation::SwapModifiers)
+    }
+
+    fn description(&self) -> &'static str {
+        "Swap two modifiers of a function definition."
+    }
+
+    fn example(&self) -> &'static str {
+        "function withdraw() external nonReentrant onlyOwner might become function withdraw() \
+            external onlyOwner nonReentrant"
+    }
+
+    fn get_comment_node(&self) -> Option<SolidityAST> {
+        self.comment_node.clone()
+    }
+}
+
+/// Return true if `node` contains a `continue` statement that applies to the loop that
+/// contains `node`.  The function ignores `continue` statements inside nested loops.
+///
+/// # Arguments
+///
+/// * `node` - The node to search, usually the body of a loop.
+fn contains_loop_continue(node: &SolidityAST) -> bool {
+    match node {
+        Value::Object(map) => match map.get("nodeType").and_then(|n| n.as_str()) {
+            Some("Continue") => true,
+            Some("ForStatement") | Some("WhileStatement") | Some("DoWhileStatement") => false,
+            _ => map.values().any(contains_loop_continue),
+        },
+        Value::Array(array) => array.iter().any(contains_loop_continue),
+        _ => false,
+    }
+}
+
+/// Implements the loop form mutation algorithm for Solidity programs.
+///
+/// The algorithm rewrites a `while` loop as the equivalent `for` loop and rewrites a `for` loop
+/// that has a condition and a loop expression as the equivalent `while` loop.  The algorithm
+/// does not rewrite a `for` loop whose body contains a `continue` statement because `continue`
+/// would skip the loop expression in the `while` form.
+///
+/// # Example
+///
+/// ```solidity
+/// for (uint256 i = 0; i < n; i++) {
+///     total += i;
+/// }
+/// ```
+///
+/// would become
+///
+/// ```solidity
+/// {
+///     uint256 i = 0;
+///     while (i < n) {
+///         total += i;
+///         i++;
+///     }
+/// }
+/// ```
+struct SolidityLoopFormMutator {
+    /// Information about the mutation.
+    comment_node: Option<SolidityAST>,
+}
+
+impl SolidityLoopFormMutator {
+    /// Create a new loop form mutator.
+    pub fn new() -> SolidityLoopFormMutator {
+        SolidityLoopFormMutator { comment_node: None }
+    }
+
+    /// Return the `while` loop form of the ForStatement `node`.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The ForStatement node.
+    fn while_form(&self, node: &SolidityAST) -> Option<SolidityAST> {
+        let id = node.get_int_for_key("id")?;
+        let condition = node.get("condition").filter(|n| !n.is_null())?;
+        let loop_expression = node.get("loopExpression").filter(|n| !n.is_null())?;
+        let body = node.get("body")?;
+
+        // The loop expression runs at the end of each iteration, so it becomes the last
+        // statement of the body.
+        let mut body = if body.get_str_for_key("nodeType") == Some("Block") {
+            body.clone()
+        } else {
+            json!({"id": 9999994, "nodeType": "Block", "statements": [body]})
+        };
+        body.get_array_for_key_mut("statements")?
+            .push(loop_expression.clone());
+
+        match node
+            .get("initializationExpression")
+            .filter(|n| !n.is_null())
+        {
+            // Keep the variables declared by the initialization statement local to the loop by
+            // wrapping the initialization and the loop in a block.
+            Some(initialization) => Some(json!({
+                "id": id,
+                "nodeType": "Block",
+                "statements": [
+                    initialization,
+                    {
+                        "id": 9999994,
+                        "nodeType": "WhileStatement",
+                        "condition": condition,
+                        "body": body
+                    }
+                ]
+            })),
+            None => Some(json!({
+                "id": id,
+                "nodeType": "WhileStatement",
+                "condition": condition,
+                "body": body
+            })),
+        }
+    }
+
+    /// Return the `for` loop form of the WhileStatement `node`.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The WhileStatement node.
+    fn for_form(&self, node: &SolidityAST) -> Option<SolidityAST> {
+        Some(json!({
+            "id": node.get_int_for_key("id")?,
+            "nodeType": "ForStatement",
+            "initializationExpression": null,
+            "condition": node.get("condition")?,
+            "loopExpression": null,
+            "body": node.get("body")?
+        }))
+    }
+}
+
+impl Mutator<SolidityAST> for SolidityLoopFormMutator {
+    fn is_mutable_node(&mut self, node: &SolidityAST, _rand: &mut Pcg64) -> bool {
+        match node.get_str_for_key("nodeType") {
+            Some("WhileStatement") => self.for_form(node).is_some(),
+            Some("ForStatement") => match node.get("body") {
+                Some(body) => !contains_loop_continue(body) && self.while_form(node).is_some(),
+                None => false,
+            },
+            _ => false,
+        }
+    }
+
+    fn mutate(
+        &mut self,
+        node: &mut SolidityAST,
+        _rand: &mut Pcg64,
+    ) -> Result<MutatorResult, MutagenyxError> {
+        // Remove previous comment
+        self.comment_node = None;
+
+        let mut mutation_result = MutatorResult::new();
+        mutation_result.mutation_type = Some(self.implements());
+
+        let node_type = node
+            .get_str_for_key("nodeType")
+            .unwrap_or_default()
+            .to_string();
+        let (new_node, comment_text) = match node_type.as_str() {
+            "ForStatement" => (
+                self.while_form(node),
+                "LoopForm Mutator: rewrote 'for' loop as a 'while' loop",
+            ),
+            _ => (
+                self.for_form(node),
+                "LoopForm Mutator: rewrote 'while' loop as a 'for' loop",
+            ),
+        };
+
+        match new_node {
+            Some(new_node) => {
+                mutation_result.mutated_node_id = node.get_int_for_key("id").map(|id| id as u64);
+                *node = new_node;
+
+                if let Ok(comment_node) = new_comment_node(comment_text) {
+                    self.comment_node = Some(comment_node);
+                }
+
+                Ok(mutation_result)
+            }
+            None => Err(MutagenyxError::MalformedNode(
+                node_type,
+                String::from("condition"),
+            )),
+        }
+    }
+
+    fn implements(&self) -> MutationType {
+        MutationType::Solidity(SolidityMutation::LoopForm)
+    }
+
+    fn description(&self) -> &'static str {
+        "Rewrite a for loop as a while loop, or a while loop as a for loop."
+    }
+
+    fn example(&self) -> &'static str {
+        "for (uint256 i = 0; i < n; i++) { ... } might become { uint256 i = 0; while (i < n) { ... \
+            i++; } }"
+    }
+
+    fn get_comment_node(&self) -> Option<SolidityAST> {
+        self.comment_node.clone()
+    }
+}
+
+/// The amounts by which the assembly literal mutation algorithm moves a number: one byte and
+/// one 32-byte word.
+static ASSEMBLY_LITERAL_DELTAS: [u32; 2] = [1, 32];
+
+/// Collect the JSON pointers (relative to the node passed to the first call) of the YulLiteral
+/// number nodes in `node`.
+///
+/// # Arguments
+///
+/// * `node` - The node to search.
+/// * `pointer` - The JSON pointer of `node`.
+/// * `pointers` - The vector that receives the JSON pointers of the literals.
+fn collect_yul_number_literals(node: &SolidityAST, pointer: String, pointers: &mut Vec<String>) {
+    match node {
+        Value::Object(map) => {
+            if node.get_str_for_key("nodeType") == Some("YulLiteral") {
+                if node.get_str_for_key("kind") == Some("number")
+                    && node
+                        .get_str_for_key("value")
+                        .and_then(parse_yul_number)
+                        .is_some()
+                {
+                    pointers.push(pointer);
+                }
+                return;
+            }
+            for (key, value) in map {
+                collect_yul_number_literals(value, format!("{}/{}", pointer, key), pointers);
+            }
+        }
+        Value::Array(array) => {
+            for (i, value) in array.iter().enumerate() {
+                collect_yul_number_literals(value, format!("{}/{}", pointer, i), pointers);
+            }
+        }
+        _ => (),
+    }
+}
+
+/// Return the value of the Yul number literal `text` and true if the literal is a hexadecimal
+/// number.
+///
+/// # Arguments
+///
+/// * `text` - The text of the literal.
+fn parse_yul_number(text: &str) -> Option<(BigUint, bool)> {
+    match text.strip_prefix("0x") {
+        Some(digits) => BigUint::parse_bytes(digits.as_bytes(), 16).map(|v| (v, true)),
+        None => BigUint::parse_bytes(text.as_bytes(), 10).map(|v| (v, false)),
+    }
+}
+
+/// Implements the assembly literal mutation algorithm for Solidity programs.
+///
+/// The algorithm selects a number literal in an inline assembly block and moves the number by
+/// one byte or one 32-byte word in either direction.  Assembly code often uses these numbers as
+/// memory offsets and storage slots.
+///
+/// # Example
+///
+/// ```solidity
+/// assembly {
+///     let ptr := mload(0x40)
+/// }
+/// ```
+///
+/// might become
+///
+/// ```solidity
+/// assembly {
+///     let ptr := mload(0x60)
+/// }
+/// ```
+struct SolidityAssemblyLiteralMutator {
+    /// Information about the mutation.
+    comment_node: Option<SolidityAST>,
+}
+
+impl SolidityAssemblyLiteralMutator {
+    /// Create a new assembly literal mutator.
+    pub fn new() -> SolidityAssemblyLiteralMutator {
+        SolidityAssemblyLiteralMutator { comment_node: None }
+    }
+
+    /// Return the JSON pointers of the number literals in the InlineAssembly `node`.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The InlineAssembly node.
+    fn number_literals(&self, node: &SolidityAST) -> Vec<String> {
+        let mut pointers: Vec<String> = vec![];
+        if node.get_str_for_key("nodeType") == Some("InlineAssembly") {
+            // Yul nodes do not have ids, so the algorithm mutates the literal through the
+            // InlineAssembly node.
+            if let Some(yul_block) = node.get("AST") {
+                collect_yul_number_literals(yul_block, String::from("/AST"), &mut pointers);
+            }
+        }
+        pointers
+    }
+}
+
+impl Mutator<SolidityAST> for SolidityAssemblyLiteralMutator {
+    fn is_mutable_node(&mut self, node: &SolidityAST, _rand: &mut Pcg64) -> bool {
+        !self.number_literals(node).is_empty()
+    }
+
+    fn mutate(
+        &mut self,
+        node: &mut SolidityAST,
+        rand: &mut Pcg64,
+    ) -> Result<MutatorResult, MutagenyxError> {
+        // Remove previous comment
+        self.comment_node = None;
+
+        let mut mutation_result = MutatorResult::new();
+        mutation_result.mutation_type = Some(self.implements());
+
+        let mut hasher = Hasher::new(MessageDigest::sha256())?;
+
+        let pointers = self.number_literals(node);
+        if let Some(pointer) = pointers.choose(rand) {
+            if let Some(literal) = node.pointer_mut(pointer) {
+                if let Some((value, is_hex)) =
+                    literal.get_str_for_key("value").and_then(parse_yul_number)
+                {
+                    // Do not move a number below zero.
+                    let mut changes: Vec<(u32, bool)> = vec![];
+                    for delta in ASSEMBLY_LITERAL_DELTAS {
+                        changes.push((delta, true));
+                        if value >= BigUint::from(delta) {
+                            changes.push((delta, false));
+                        }
+                    }
+
+                    if let Some((delta, increase)) = changes.choose(rand) {
+                        hasher.update(pointer.as_bytes())?;
+                        hasher.update(&delta.to_ne_bytes())?;
+                        hasher.update(&[*increase as u8])?;
+
+                        let new_value = if *increase {
+                            &value + BigUint::from(*delta)
+                        } else {
+                            &value - BigUint::from(*delta)
+                        };
+                        let new_value_s = if is_hex {
+                            format!("0x{:x}", new_value)
+                        } else {
+                            new_value.to_string()
+                        };
+                        let original_value_s =
+                            String::from(literal.get_str_for_key("value").unwrap_or_default());
+                        literal.set_str_for_key("value", &new_value_s);
+
+                        let comment_text = format!(
+                            "AssemblyLiteral Mutator: changed '{}' to '{}'",
+                            original_value_s, new_value_s
+                        );
+                        if let Ok(comment_node) = new_comment_node(&comment_text) {
+                            self.comment_node = Some(comment_node);
+                        }
+
+                        mutation_result.mutated_node_id =
+                            node.get_int_for_key("id").map(|id| id as u64);
+
+                        let byte_array = hasher.finish()?;
+                        mutation_result.random_behavior_hash = Some(hex::encode(byte_array));
+
+                        return Ok(mutation_result);
+                    }
+                }
+            }
+        }
+
+        Err(MutagenyxError::MalformedNode(
+            String::from("InlineAssembly"),
+            String::from("AST"),
+        ))
+    }
+
+    fn implements(&self) -> MutationType {
+        MutationType::Solidity(SolidityMutation::AssemblyLiteral)
+    }
+
+    fn description(&self) -> &'static str {
+        "Add or subtract one byte or one 32-byte word to a number literal in an inline assembly \
+            block."
+    }
+
+    fn example(&self) -> &'static str {
+        "mload(0x40) might become mload(0x60), sstore(0, x) might become sstore(1, x)"
+    }
+
+    fn get_comment_node(&self) -> Option<SolidityAST> {
+        self.comment_node.clone()
+    }
+}
+
+/// Return true if evaluating the assignment target `node` has no side effects, so that the
+/// target can appear in both branches of an if/else statement.
+///
+/// # Arguments
+///
+/// * `node` - The left hand side of an Assignment node.
+fn is_simple_assignment_target(node: &SolidityAST) -> bool {
+    match node.get_str_for_key("nodeType") {
+        Some("Identifier") => true,
+        Some("MemberAccess") => node
+            .get("expression")
+            .is_some_and(is_simple_assignment_target),
+        Some("IndexAccess") => {
+            node.get("baseExpression")
+                .is_some_and(is_simple_assignment_target)
+                && node.get("indexExpression").is_some_and(|index| {
+                    index.get_str_for_key("nodeType") == Some("Literal")
+                        || is_simple_assignment_target(index)
+                })
+        }
+        _ => false,
+    }
+}
+
+/// Return the Assignment node of the statement `node` if the statement only assigns a value to
+/// a simple target.  The function looks inside a Block with a single statement.
+///
+/// # Arguments
+///
+/// * `node` - The statement node.
+fn simple_assignment(node: &SolidityAST) -> Option<&SolidityAST> {
+    match node.get_str_for_key("nodeType")? {
+        "Block" => match node.get_array_for_key("statements")?.as_slice() {
+            [statement] => simple_assignment(statement),
+            _ => None,
+        },
+        "ExpressionStatement" => {
+            let assignment = node.get("expression")?;
+            if assignment.get_str_for_key("nodeType") == Some("Assignment")
+                && assignment
+                    .get("leftHandSide")
+                    .is_some_and(is_simple_assignment_target)
+            {
+                Some(assignment)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Return the type string of the expression `node`.
+///
+/// # Arguments
+///
+/// * `node` - The expression node.
+fn expression_type_string(node: &SolidityAST) -> Option<&str> {
+    node.pointer("/typeDescriptions/typeString")
+        .and_then(|t| t.as_str())
+}
+
+/// Implements the ternary/if-else mutation algorithm for Solidity programs.
+///
+/// The algorithm rewrites an assignment of a conditional (ternary) expression as an if/else
+/// statement with an assignment in each branch.  The algorithm also rewrites an if/else
+/// statement whose branches only assign to the same target with the same operator as an
+/// assignment of a conditional expression.  The algorithm only collapses an if/else statement
+/// when both assigned values have the type of the target, so that the conditional expression
+/// has a valid type.
+///
+/// # Example
+///
+/// ```solidity
+/// fee = premium ? high : low;
+/// ```
+///
+/// would become
+///
+/// ```solidity
+/// if (premium) {
+///     fee = high;
+/// } else {
+///     fee = low;
+/// }
+/// ```
+struct SolidityTernaryIfElseMutator {
+    /// Information about the mutation.
+    comment_node: Option<SolidityAST>,
+}
+
+impl SolidityTernaryIfElseMutator {
+    /// Create a new ternary/if-else mutator.
+    pub fn new() -> SolidityTernaryIfElseMutator {
+        SolidityTernaryIfElseMutator { comment_node: None }
+    }
+
+    /// Return the if/else statement form of the ExpressionStatement `node` that assigns a
+    /// conditional expression.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The ExpressionStatement node.
+    fn if_else_form(&self, node: &SolidityAST) -> Option<SolidityAST> {
+        let assignment = simple_assignment(node)?;
+        let conditional = assignment.get("rightHandSide")?;
+        if conditional.get_str_for_key("nodeType") != Some("Conditional") {
+            return None;
+        }
+
+        let branch = |value: &SolidityAST| {
+            let mut branch_assignment = assignment.clone();
+            branch_assignment.set_node_for_key("rightHandSide", value.clone());
+            json!({
+                "id": 9999994,
+                "nodeType": "Block",
+                "statements": [{
+                    "id": 9999994,
+                    "nodeType": "ExpressionStatement",
+                    "expression": branch_assignment
+                }]
+            })
+        };
+
+        Some(json!({
+            "id": node.get_int_for_key("id")?,
+            "nodeType": "IfStatement",
+            "condition": conditional.get("condition")?,
+            "trueBody": branch(conditional.get("trueExpression")?),
+            "falseBody": branch(conditional.get("falseExpression")?)
+        }))
+    }
+
+    /// Return the conditional expression form of the IfStatement `node`.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The IfStatement node.
+    fn ternary_form(&self, node: &SolidityAST) -> Option<SolidityAST> {
+        let true_assignment = simple_assignment(node.get("trueBody")?)?;
+        let false_assignment = simple_assignment(node.get("falseBody").filter(|n| !n.is_null())?)?;
+
+        if true_assignment.get_str_for_key("operator")?
+            != false_assignment.get_str_for_key("operator")?
+        {
+            return None;
+        }
+
+        let target = true_assignment.get("leftHandSide")?;
+        if pretty_print_node(target) != pretty_print_node(false_assignment.get("leftHandSide")?) {
+            return None;
+        }
+
+        let true_value = true_assignment.get("rightHandSide")?;
+        let false_value = false_assignment.get("rightHandSide")?;
+        let target_type = expression_type_string(target)?;
+        if expression_type_string(true_value)? != target_type
+            || expression_type_string(false_value)? != target_type
+        {
+            return None;
+        }
+
+        let mut assignment = true_assignment.clone();
+        assignment.set_node_for_key(
+            "rightHandSide",
+            json!({
+                "id": 9999994,
+                "nodeType": "Conditional",
+                "condition": node.get("condition")?,
+                "trueExpression": true_value,
+                "falseExpression": false_value,
+                "typeDescriptions": target.get("typeDescriptions")?
+            }),
+        );
+
+        Some(json!({
+            "id": node.get_int_for_key("id")?,
+            "nodeType": "ExpressionStatement",
+            "expression": assignment
+        }))
+    }
+
+    /// Return the rewritten form of `node` and the text that describes the rewrite.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The ExpressionStatement or IfStatement node.
+    fn rewritten_form(&self, node: &SolidityAST) -> Option<(SolidityAST, &'static str)> {
+        match node.get_str_for_key("nodeType")? {
+            "ExpressionStatement" => self
+                .if_else_form(node)
+                .map(|n| (n, "rewrote conditional expression as if/else statement")),
+            "IfStatement" => self
+                .ternary_form(node)
+                .map(|n| (n, "rewrote if/else statement as conditional expression")),
+            _ => None,
+        }
+    }
+}
+
+impl Mutator<SolidityAST> for SolidityTernaryIfElseMutator {
+    fn is_mutable_node(&mut self, node: &SolidityAST, _rand: &mut Pcg64) -> bool {
+        self.rewritten_form(node).is_some()
+    }
+
+    fn mutate(
+        &mut self,
+        node: &mut SolidityAST,
+        _rand: &mut Pcg64,
+    ) -> Result<MutatorResult, MutagenyxError> {
+        // Remove previous comment
+        self.comment_node = None;
+
+        let mut mutation_result = MutatorResult::new();
+        mutation_result.mutation_type = Some(self.implements());
+
+        if let Some((new_node, description)) = self.rewritten_form(node) {
+            let comment_text = format!(
+                "TernaryIfElse Mutator: {} '{}'",
+                description,
+                pretty_print_node(node)
+            );
+            if let Ok(comment_node) = new_comment_node(&comment_text) {
+                self.comment_node = Some(comment_node);
+            }
+
+            mutation_result.mutated_node_id = node.get_int_for_key("id").map(|id| id as u64);
+            *node = new_node;
+
+            return Ok(mutation_result);
+        }
+
+        Err(MutagenyxError::MalformedNode(
+            String::from(node.get_str_for_key("nodeType").unwrap_or("Statement")),
+            String::from("Assignment"),
+        ))
+    }
+
+    fn implements(&self) -> MutationType {
+        MutationType::Solidity(SolidityMutation::TernaryIfElse)
+    }
+
+    fn description(&self) -> &'static str {
+        "Rewrite the assignment of a conditional expression as an if/else statement, or an if/else \
+            statement that assigns to one target as the assignment of a conditional expression."
+    }
+
+    fn example(&self) -> &'static str {
+        "fee = premium ? high : low; might become if (premium) { fee = high; } else { fee = low; }"
+    }
+
+    fn get_comment_node(&self) -> Option<SolidityAST> {
+        self.comment_node.clone()
+    }
+}
+
+/// The strings that the [`SolidityRevertMessageMutator`] uses to replace revert messages.
+const REVERT_MESSAGE_REPLACEMENTS: [&str; 2] = ["", "mutated revert reason"];
+
+/// Return the JSON pointers (relative to `node`) of the string literal revert messages in
+/// `node`.  The function recognizes the message of `require(condition, "message")`, the message
+/// of `revert("message")`, and the string arguments of a custom error in a revert statement.
+///
+/// # Arguments
+///
+/// * `node` - The AST node.
+fn revert_message_pointers(node: &SolidityAST) -> Vec<String> {
+    let (arguments_key, message_indices): (&str, Vec<usize>) =
+        match node.get_str_for_key("nodeType") {
+            Some("FunctionCall") => match node.get_str_for_key("expression/name") {
+                Some("require") => ("arguments", vec![1]),
+                Some("revert") => ("arguments", vec![0]),
+                _ => return vec![],
+            },
+            Some("RevertStatement") => {
+                let count = node
+                    .get_array_for_key("errorCall/arguments")
+                    .map_or(0, |a| a.len());
+                ("errorCall/arguments", (0..count).collect())
+            }
+            _ => return vec![],
+        };
+
+    let arguments = match node.get_array_for_key(arguments_key) {
+        Some(a) => a,
+        None => return vec![],
+    };
+
+    message_indices
+        .into_iter()
+        .filter(|i| {
+            arguments.get(*i).is_some_and(|a| {
+                a.get_str_for_key("nodeType") == Some("Literal")
+                    && a.get_str_for_key("kind") == Some("string")
+            })
+        })
+        .map(|i| format!("/{}/{}", arguments_key, i))
+        .collect()
+}
+
+/// Implement the RevertMessage mutation algorithm.
+///
+/// The algorithm selects a string literal revert message in a call to `require` or `revert`,
+/// or in a revert statement, and replaces the message with a different string.  Tests that
+/// check the exact revert message of a failure should fail for the mutant.
+///
+/// # Example
+///
+/// ```solidity
+/// require(amount > 0, "zero amount");
+/// ```
+///
+/// might become
+///
+/// ```solidity
+/// require(amount > 0, "");
+/// ```
+struct SolidityRevertMessageMutator {
+    /// Information about the mutation.
+    comment_node: Option<SolidityAST>,
+}
+
+impl SolidityRevertMessageMutator {
+    /// Create a new revert message mutator.
+    pub fn new() -> SolidityRevertMessageMutator {
+        SolidityRevertMessageMutator { comment_node: None }
+    }
+}
+
+impl Mutator<SolidityAST> for SolidityRevertMessageMutator {
+    fn is_mutable_node(&mut self, node: &SolidityAST, _rand: &mut Pcg64) -> bool {
+        !revert_message_pointers(node).is_empty()
+    }
+
+    fn mutate(
+        &mut self,
+        node: &mut SolidityAST,
+        rand: &mut Pcg64,
+    ) -> Result<MutatorResult, MutagenyxError> {
+        // Remove previous comment
+        self.comment_node = None;
+
+        let mut mutation_result = MutatorResult::new();
+        mutation_result.mutation_type = Some(self.implements());
+
+        let mut hasher = Hasher::new(MessageDigest::sha256())?;
+
+        let malformed_node_err = MutagenyxError::MalformedNode(
+            String::from(node.get_str_for_key("nodeType").unwrap_or("FunctionCall")),
+            String::from("arguments"),
+        );
+
+        let pointers = revert_message_pointers(node);
+        let pointer = match pointers.choose(rand) {
+            Some(p) => p.clone(),
+            None => return Err(malformed_node_err),
+        };
+
+        let original_node_s = pretty_print_node(node);
+
+        let message_node = match node.pointer_mut(&pointer) {
+            Some(m) => m,
+            None => return Err(malformed_node_err),
+        };
+        let original_message = String::from(message_node.get_str_for_key("value").unwrap_or(""));
+        let replacements: Vec<&str> = REVERT_MESSAGE_REPLACEMENTS
+            .into_iter()
+            .filter(|r| *r != original_message)
+            .collect();
+        let replacement = match replacements.choose(rand) {
+            Some(r) => *r,
+            None => return Err(malformed_node_err),
+        };
+
+        message_node.set_str_for_key("value", replacement);
+        message_node.set_str_for_key("hexValue", &hex::encode(replacement));
+        message_node.set_node_for_key(
+            "typeDescriptions",
+            json!({
+                "typeIdentifier": format!("t_stringliteral_{}", hex::encode(replacement)),
+                "typeString": format!("literal_string \"{}\"", replacement)
+            }),
+        );
+
+        hasher.update(pointer.as_bytes())?;
+        hasher.update(replacement.as_bytes())?;
+
+        let comment_text = format!(
+            "RevertMessage Mutator: Changing '{}' to '{}'",
+            original_node_s,
+            pretty_print_node(node)
+        );
+        if let Ok(comment_node) = new_comment_node(&comment_text) {
+            self.comment_node = Some(comment_node);
+        }
+
+        mutation_result.mutated_node_id = node.get_int_for_key("id").map(|id| id as u64);
+
+        let byte_array = hasher.finish()?;
+        mutation_result.random_behavior_hash = Some(hex::encode(byte_array));
+
+        Ok(mutation_result)
+    }
+
+    fn implements(&self) -> MutationType {
+        MutationType::Solidity(SolidityMutation::RevertMessage)
+    }
+
+    fn description(&self) -> &'static str {
+        "Replace the message of a require() call, a revert() call, or a revert statement with a \
+            different string."
+    }
+
+    fn example(&self) -> &'static str {
+        "require(amount > 0, \"zero amount\"); might become require(amount > 0, \"\");"
+    }
+
+    fn get_comment_node(&self) -> Option<SolidityAST> {
+        self.comment_node.clone()
+    }
+}
+
+/// Return the state mutability that the FallbackPayability mutation algorithm would give to
+/// `node`, or None if the algorithm cannot mutate the node.
+///
+/// Only `fallback` functions qualify.  A `receive` function must be payable, so removing its
+/// payability would produce a mutant that does not compile.
+///
+/// # Arguments
+///
+/// * `node` - The node in the AST.
+fn toggled_fallback_mutability(node: &SolidityAST) -> Option<&'static str> {
+    if node.get_str_for_key("nodeType")? != "FunctionDefinition"
+        || node.get_str_for_key("kind")? != "fallback"
+    {
+        return None;
+    }
+
+    match node.get_str_for_key("stateMutability")? {
+        "payable" => Some("nonpayable"),
+        "nonpayable" => Some("payable"),
+        _ => None,
+    }
+}
+
+/// Implement the FallbackPayability mutation algorithm.
+///
+/// The algorithm toggles the state mutability of a fallback function between payable and
+/// nonpayable so that the mutant changes whether the contract accepts Ether sent with calls to
+/// functions that do not exist.
+struct SolidityFallbackPayabilityMutator {
+    /// Information about the mutation.
+    comment_node: Option<SolidityAST>,
+}
+
+impl SolidityFallbackPayabilityMutator {
+    /// Create a new fallback payability mutator.
+    pub fn new() -> SolidityFallbackPayabilityMutator {
+        SolidityFallbackPayabilityMutator { comment_node: None }
+    }
+}
+
+impl Mutator<SolidityAST> for SolidityFallbackPayabilityMutator {
+    fn is_mutable_node(&mut self, node: &SolidityAST, _rand: &mut Pcg64) -> bool {
+        toggled_fallback_mutability(node).is_some()
+    }
+
+    fn mutate(
+        &mut self,
+        node: &mut SolidityAST,
+        _rand: &mut Pcg64,
+    ) -> Result<MutatorResult, MutagenyxError> {
+        // Remove previous comment
+        self.comment_node = None;
+
+        let mut mutation_result = MutatorResult::new();
+        mutation_result.mutation_type = Some(self.implements());
+
+        let mut hasher = Hasher::new(MessageDigest::sha256())?;
+
+        let new_mutability = match toggled_fallback_mutability(node) {
+            Some(m) => m,
+            None => {
+                return Err(MutagenyxError::MalformedNode(
+                    String::from(
+                        node.get_str_for_key("nodeType")
+                            .unwrap_or("FunctionDefinition"),
+                    ),
+                    String::from("stateMutability"),
+                ))
+            }
+        };
+        let old_mutability = String::from(node.get_str_for_key("stateMutability").unwrap_or(""));
+
+        node.set_str_for_key("stateMutability", new_mutability);
+
+        hasher.update(new_mutability.as_bytes())?;
+
+        let comment_text = format!(
+            "FallbackPayability Mutator: Changing fallback function from '{}' to '{}'",
+            old_mutability, new_mutability
+        );
+        if let Ok(comment_node) = new_comment_node(&comment_text) {
+            self.comment_node = Some(comment_node);
+        }
+
+        mutation_result.mutated_node_id = node.get_int_for_key("id").map(|id| id as u64);
+
+        let byte_array = hasher.finish()?;
+        mutation_result.random_behavior_hash = Some(hex::encode(byte_array));
+
+        Ok(mutation_result)
+    }
+
+    fn implements(&self) -> MutationType {
+        MutationType::Solidity(SolidityMutation::FallbackPayability)
+    }
+
+    fn description(&self) -> &'static str {
+        "Toggle the state mutability of a fallback function between payable and nonpayable."
+    }
+
+    fn example(&self) -> &'static str {
+        "fallback() external payable {} might become fallback() external {}"
+    }
+
+    fn get_comment_node(&self) -> Option<SolidityAST> {
+        self.comment_node.clone()
+    }
+}
+
+/// The constants that the [`SolidityBalanceConstantMutator`] uses to replace balance reads: no
+/// Ether, one wei, and one ether.
+static BALANCE_CONSTANT_REPLACEMENTS: [u128; 3] = [0, 1, 1_000_000_000_000_000_000];
+
+/// Implement the BalanceConstant mutation algorithm.
+///
+/// The algorithm replaces a read of the balance of an address with an integer constant so that
+/// the mutant checks whether tests exercise the balance dependent logic of the contract.
+///
+/// ```solidity
+/// require(address(this).balance >= amount);
+/// ```
+///
+/// might become
+///
+/// ```solidity
+/// require(0 >= amount);
+/// ```
+struct SolidityBalanceConstantMutator {
+    /// Information about the mutation.
+    comment_node: Option<SolidityAST>,
+}
+
+impl SolidityBalanceConstantMutator {
+    /// Create a new balance constant mutator.
+    pub fn new() -> SolidityBalanceConstantMutator {
+        SolidityBalanceConstantMutator { comment_node: None }
+    }
+}
+
+impl Mutator<SolidityAST> for SolidityBalanceConstantMutator {
+    fn is_mutable_node(&mut self, node: &SolidityAST, _rand: &mut Pcg64) -> bool {
+        if let Some(node_type) = node.get_str_for_key("nodeType") {
+            if node_type == "MemberAccess" {
+                let is_balance = node
+                    .get_str_for_key("memberName")
+                    .map_or_else(|| false, |n| n == "balance");
+                let is_integer = node
+                    .get_str_for_key("typeDescriptions/typeString")
+                    .map_or_else(|| false, |t| t.starts_with("uint"));
+                // A struct may have a member named balance, so make sure the expression is an
+                // address.
+                let is_address = node
+                    .get_str_for_key("expression/typeDescriptions/typeString")
+                    .map_or_else(|| false, |t| t.starts_with("address"));
+                return is_balance && is_integer && is_address;
+            }
+        }
+        false
+    }
+
+    fn mutate(
+        &mut self,
+        node: &mut SolidityAST,
+        rand: &mut Pcg64,
+    ) -> Result<MutatorResult, MutagenyxError> {
+        // Remove previous comment
+        self.comment_node = None;
+
+        let mut mutation_result = MutatorResult::new();
+        mutation_result.mutation_type = Some(self.implements());
+
+        let mut hasher = Hasher::new(MessageDigest::sha256())?;
+
+        let original_node_s = pretty_print_node(node);
+        let id = node.get_int_for_key("id");
+
+        let replacement_value = match BALANCE_CONSTANT_REPLACEMENTS.choose(rand) {
+            Some(v) => *v,
+            None => return Err(MutagenyxError::UnableToGenerateNode("integer constant")),
+        };
+
+        hasher.update(&replacement_value.to_ne_bytes())?;
+
+        replace_with_integer_constant(node, replacement_value)?;
+
+        let comment_text = format!(
+            "BalanceConstant Mutator: Replaced '{}' with '{}'",
+            original_node_s, replacement_value
+        );
+        if let Ok(comment_node) = new_comment_node(&comment_text) {
+            self.comment_node = Some(comment_node);
+        }
+
+        // The literal replaces the member access, so report the id of the original node.
+        mutation_result.mutated_node_id = id.map(|id| id as u64);
+
+        let byte_array = hasher.finish()?;
+        mutation_result.random_behavior_hash = Some(hex::encode(byte_array));
+
+        Ok(mutation_result)
+    }
+
+    fn implements(&self) -> MutationType {
+        MutationType::Solidity(SolidityMutation::BalanceConstant)
+    }
+
+    fn description(&self) -> &'static str {
+        "Replace a read of the balance of an address with an integer constant."
+    }
+
+    fn example(&self) -> &'static str {
+        "address(this).balance >= amount might become 0 >= amount"
+    }
+
+    fn get_comment_node(&self) -> Option<SolidityAST> {
+        self.comment_node.clone()
+    }
+}
+
+/// Return true if the entry `invocation` of the `modifiers` array of the constructor `node` calls
+/// a base constructor instead of applying a modifier.
+///
+/// Older compilers do not record the kind of the invocation, in that case the function treats
+/// the entries of a constructor as base constructor calls, the same as the SwapModifiers
+/// mutation algorithm.
+///
+/// # Arguments
+///
+/// * `node` - The FunctionDefinition node.
+/// * `invocation` - The entry of the `modifiers` array of `node`.
+fn is_base_constructor_call(node: &SolidityAST, invocation: &SolidityAST) -> bool {
+    if node.get_str_for_key("kind") != Some("constructor") {
+        return false;
+    }
+
+    match invocation.get_str_for_key("kind") {
+        Some(kind) => kind == "baseConstructorSpecifier",
+        None => true,
+    }
+}
+
+/// Return the boundary values that can replace the base constructor argument `argument`.  The
+/// function only replaces arguments with integer, boolean, or address types and never returns
+/// a replacement equal to the argument.
+///
+/// # Arguments
+///
+/// * `argument` - The argument expression of the base constructor call.
+fn base_constructor_argument_replacements(argument: &SolidityAST) -> Vec<SolidityAST> {
+    let type_string = match argument.get_str_for_key("typeDescriptions/typeString") {
+        Some(t) => t,
+        None => return vec![],
+    };
+    let literal_value = match argument.get_str_for_key("nodeType") {
+        Some("Literal") => argument.get_str_for_key("value"),
+        _ => None,
+    };
+
+    // Integer literals have the type `int_const <value>`.
+    let is_integer = type_string.starts_with("int_const")
+        || type_string
+            .trim_start_matches('u')
+            .strip_prefix("int")
+            .map_or_else(|| false, |bits| bits.chars().all(|c| c.is_ascii_digit()));
+
+    if is_integer {
+        [0u8, 1u8]
+            .iter()
+            .filter(|v| literal_value != Some(v.to_string().as_str()))
+            .filter_map(|v| new_integer_constant_node(*v).ok())
+            .collect()
+    } else if type_string == "bool" {
+        match literal_value {
+            Some(v) => new_boolean_literal_node(v != "true").into_iter().collect(),
+            None => [false, true]
+                .iter()
+                .filter_map(|v| new_boolean_literal_node(*v).ok())
+                .collect(),
+        }
+    } else if type_string == "address" || type_string == "address payable" {
+        let zero_address = json!({
+            "id": 9999990,
+            "nodeType": "FunctionCall",
+            "kind": "typeConversion",
+            "expression": {
+                "id": 9999989,
+                "nodeType": "ElementaryTypeNameExpression",
+                "isPure": true,
+                "typeName": {
+                    "id": 9999988,
+                    "nodeType": "ElementaryTypeName",
+                    "name": "address"
+                }
+            },
+            "arguments": [new_integer_constant_node(0u8).unwrap_or(Value::Null)],
+            "names": [],
+            "typeDescriptions": {
+                "typeIdentifier": "t_address",
+                "typeString": "address"
+            }
+        });
+        if pretty_print_node(argument) == pretty_print_node(&zero_address) {
+            vec![]
+        } else {
+            vec![zero_address]
+        }
+    } else {
+        vec![]
+    }
+}
+
+/// Implement the BaseConstructorArguments mutation algorithm.
+///
+/// The algorithm finds constructors that call base constructors, for example
+/// `constructor() Owned(msg.sender)`, and replaces one argument of a base constructor call with
+/// a boundary value for the type of the argument: 0 or 1 for integers, the other value for
+/// booleans, and `address(0)` for addresses.  The algorithm leaves the arguments of modifiers
+/// alone.
+///
+/// # Example
+///
+/// ```solidity
+/// constructor() Owned(msg.sender) {}
+/// ```
+///
+/// would become
+///
+/// ```solidity
+/// constructor() Owned(address(0)) {}
+/// ```
+struct SolidityBaseConstructorArgumentsMutator {
+    /// Information about the mutation.
+    comment_node: Option<SolidityAST>,
+}
+
+impl SolidityBaseConstructorArgumentsMutator {
+    /// Create a new base constructor arguments mutator.
+    pub fn new() -> SolidityBaseConstructorArgumentsMutator {
+        SolidityBaseConstructorArgumentsMutator { comment_node: None }
+    }
+
+    /// Return the index in the `modifiers` array, the index of the argument, and the replacement
+    /// value for each base constructor argument that the mutator can replace in `node`.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The FunctionDefinition node.
+    fn replacements(node: &SolidityAST) -> Vec<(usize, usize, SolidityAST)> {
+        if node.get_str_for_key("nodeType") != Some("FunctionDefinition") {
+            return vec![];
+        }
+
+        let modifiers = match node.get_array_for_key("modifiers") {
+            Some(m) => m,
+            None => return vec![],
+        };
+
+        let mut replacements: Vec<(usize, usize, SolidityAST)> = vec![];
+        for (invocation_index, invocation) in modifiers.iter().enumerate() {
+            if !is_base_constructor_call(node, invocation) {
+                continue;
+            }
+
+            if let Some(arguments) = invocation.get_array_for_key("arguments") {
+                for (argument_index, argument) in arguments.iter().enumerate() {
+                    for replacement in base_constructor_argument_replacements(argument) {
+                        replacements.push((invocation_index, argument_index, replacement));
+                    }
+                }
+            }
+        }
+        replacements
+    }
+}
+
+impl Mutator<SolidityAST> for SolidityBaseConstructorArgumentsMutator {
+    fn is_mutable_node(&mut self, node: &SolidityAST, _rand: &mut Pcg64) -> bool {
+        !SolidityBaseConstructorArgumentsMutator::replacements(node).is_empty()
+    }
+
+    fn mutate(
+        &mut self,
+        node: &mut SolidityAST,
+        rand: &mut Pcg64,
+    ) -> Result<MutatorResult, MutagenyxError> {
+        // Remove previous comment
+        self.comment_node = None;
+
+        let mut mutation_result = MutatorResult::new();
+        mutation_result.mutation_type = Some(self.implements());
+
+        let mut hasher = Hasher::new(MessageDigest::sha256())?;
+
+        let replacements = SolidityBaseConstructorArgumentsMutator::replacements(node);
+        if let Some((invocation_index, argument_index, replacement)) = replacements.choose(rand) {
+            if let Some(invocation) = node
+                .get_array_for_key_mut("modifiers")
+                .and_then(|m| m.get_mut(*invocation_index))
+            {
+                let original_invocation_s = pretty_print_node(invocation);
+
+                if let Some(argument) = invocation
+                    .get_array_for_key_mut("arguments")
+                    .and_then(|a| a.get_mut(*argument_index))
+                {
+                    *argument = replacement.clone();
+                }
+
+                let new_invocation_s = pretty_print_node(invocation);
+                hasher.update(new_invocation_s.as_bytes())?;
+
+                let comment_text = format!(
+                    "BaseConstructorArguments Mutator: Changing '{}' to '{}'",
+                    original_invocation_s, new_invocation_s
+                );
+                if let Ok(comment_node) = new_comment_node(&comment_text) {
+                    self.comment_node = Some(comment_node);
+                }
+
+                mutation_result.mutated_node_id = node.get_int_for_key("id").map(|id| id as u64);
+
+                let byte_array = hasher.finish()?;
+                mutation_result.random_behavior_hash = Some(hex::encode(byte_array));
+
+                return Ok(mutation_result);
+            }
+        }
+
+        Err(MutagenyxError::MalformedNode(
+            String::from("FunctionDefinition"),
+            String::from("modifiers"),
+        ))
+    }
+
+    fn implements(&self) -> MutationType {
+        MutationType::Solidity(SolidityMutation::BaseConstructorArguments)
+    }
+
+    fn description(&self) -> &'static str {
+        "Replace an argument of a base constructor call in a constructor with a boundary value of \
+            the argument type."
+    }
+
+    fn example(&self) -> &'static str {
+        "constructor() Owned(msg.sender) {} might become constructor() Owned(address(0)) {}"
+    }
+
+    fn get_comment_node(&self) -> Option<SolidityAST> {
+        self.comment_node.clone()
+    }
+}
+
+/// The name of a global variable, the `referencedDeclaration` of the variable, the name of a
+/// member of the variable, and the type of the member.
+type MsgContextMember = (&'static str, i64, &'static str, &'static str);
+
+/// The members of the `msg` and `tx` global variables that the SwapMsgContext mutation algorithm
+/// swaps.  The algorithm only swaps members of the same type, so `msg.value` has no replacement.
+static MSG_CONTEXT_MEMBERS: [MsgContextMember; 3] = [
+    ("msg", -15, "sender", "address"),
+    ("tx", -26, "origin", "address"),
+    ("msg", -15, "value", "uint256"),
+];
+
+/// Implements the SwapMsgContext mutation algorithm for Solidity programs.
+///
+/// The algorithm replaces an access to `msg.sender` with an access to `tx.origin` and the other
+/// way around, the confusion behind many access control bugs.
+///
+/// # Example
+///
+/// ```solidity
+/// require(msg.sender == owner);
+/// ```
+///
+/// would become
+///
+/// ```solidity
+/// require(tx.origin == owner);
+/// ```
+struct SoliditySwapMsgContextMutator {
+    /// Information about the mutation.
+    comment_node: Option<SolidityAST>,
+}
+
+impl SoliditySwapMsgContextMutator {
+    /// Create a new swap message context mutator.
+    pub fn new() -> SoliditySwapMsgContextMutator {
+        SoliditySwapMsgContextMutator { comment_node: None }
+    }
+
+    /// Return the entries of [`MSG_CONTEXT_MEMBERS`] that can replace the member accessed in
+    /// `node`.  The function returns an empty list if `node` does not access a member in
+    /// [`MSG_CONTEXT_MEMBERS`].
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The MemberAccess node.
+    fn alternate_members_for(node: &SolidityAST) -> Vec<&'static MsgContextMember> {
+        if node.get_str_for_key("nodeType") != Some("MemberAccess")
+            || node.get_str_for_key("expression/nodeType") != Some("Identifier")
+        {
+            return vec![];
+        }
+
+        let (base, member) = match (
+            node.get_str_for_key("expression/name"),
+            node.get_str_for_key("memberName"),
+        ) {
+            (Some(b), Some(m)) => (b, m),
+            _ => return vec![],
+        };
+
+        let member_type = match MSG_CONTEXT_MEMBERS
+            .iter()
+            .find(|(b, _, m, _)| *b == base && *m == member)
+        {
+            Some((_, _, _, t)) => *t,
+            None => return vec![],
+        };
+
+        MSG_CONTEXT_MEMBERS
+            .iter()
+            .filter(|(b, _, m, t)| *t == member_type && !(*b == base && *m == member))
+            .collect()
+    }
+}
+
+impl Mutator<SolidityAST> for SoliditySwapMsgContextMutator {
+    fn is_mutable_node(&mut self, node: &SolidityAST, _rand: &mut Pcg64) -> bool {
+        !SoliditySwapMsgContextMutator::alternate_members_for(node).is_empty()
+    }
+
+    fn mutate(
+        &mut self,
+        node: &mut SolidityAST,
+        rand: &mut Pcg64,
+    ) -> Result<MutatorResult, MutagenyxError> {
+        // Remove previous comment
+        self.comment_node = None;
+
+        let mut mutation_result = MutatorResult::new();
+        mutation_result.mutation_type = Some(self.implements());
+
+        let mut hasher = Hasher::new(MessageDigest::sha256())?;
+
+        let alternates = SoliditySwapMsgContextMutator::alternate_members_for(node);
+
+        if let Some((base, declaration, member, _)) = alternates.choose(rand) {
+            let original_node_s = pretty_print_node(node);
+
+            node.set_str_for_key("memberName", member);
+
+            // Keep the declaration and the type of the global variable consistent with the new
+            // name.  The member has the same type as the original member.
+            if let Some(expression) = node.get_mut("expression") {
+                expression.set_str_for_key("name", base);
+                if expression.contains_key("referencedDeclaration") {
+                    expression.set_node_for_key("referencedDeclaration", json!(declaration));
+                }
+                if let Some(type_descriptions) = expression.get_mut("typeDescriptions") {
+                    type_descriptions.set_str_for_key("typeString", base);
+                    type_descriptions
+                        .set_str_for_key("typeIdentifier", global_type_identifier(base));
+                }
+            }
+
+            let new_node_s = pretty_print_node(node);
+            hasher.update(new_node_s.as_bytes())?;
+
+            let comment_text = format!(
+                "SwapMsgContext Mutator: Replaced '{}' with '{}'",
+                original_node_s, new_node_s
+            );
+            if let Ok(comment_node) = new_comment_node(&comment_text) {
+                self.comment_node = Some(comment_node);
+            }
+
+            if let Some(id) = node.get_int_for_key("id") {
+                mutation_result.mutated_node_id = Some(id as u64);
+            }
+
+            let byte_array = hasher.finish()?;
+            mutation_result.random_behavior_hash = Some(hex::encode(byte_array));
+
+            return Ok(mutation_result);
+        }
+
+        Err(MutagenyxError::MalformedNode(
+            String::from("MemberAccess"),
+            String::from("memberName"),
+        ))
+    }
+
+    fn implements(&self) -> MutationType {
+        MutationType::Solidity(SolidityMutation::SwapMsgContext)
+    }
+
+    fn description(&self) -> &'static str {
+        "Replace msg.sender with tx.origin, or tx.origin with msg.sender."
+    }
+
+    fn example(&self) -> &'static str {
+        "require(msg.sender == owner); would become require(tx.origin == owner);"
+    }
+
+    fn get_comment_node(&self) -> Option<SolidityAST> {
+        self.comment_node.clone()
+    }
+}
+
+/// Return true if `node` is an ExpressionStatement whose expression is a `delete` operation.
+///
+/// # Arguments
+///
+/// * `node` - The statement node.
+fn is_delete_statement(node: &SolidityAST) -> bool {
+    node.get_str_for_key("nodeType") == Some("ExpressionStatement")
+        && node.get_str_for_key("expression/nodeType") == Some("UnaryOperation")
+        && node.get_str_for_key("expression/operator") == Some("delete")
+}
+
+/// Implements the RemoveDelete mutation algorithm for Solidity programs.
+///
+/// The algorithm chooses a `delete` statement in any Block and replaces the statement with a
+/// comment node that contains the statement, so the variable keeps its value.  The algorithm
+/// only selects statements in a Block since commenting out the body of an `if` or a loop
+/// without braces would make the next statement the body.
+///
+/// # Example
+///
+/// ```solidity
+/// delete balances[addr];
+/// ```
+///
+/// would become
+///
+/// ```solidity
+/// // delete balances[addr];
+/// ```
+struct SolidityRemoveDeleteMutator {
+    /// Information about the mutation.
+    comment_node: Option<SolidityAST>,
+}
+
+impl SolidityRemoveDeleteMutator {
+    /// Create a new remove delete mutator.
+    pub fn new() -> SolidityRemoveDeleteMutator {
+        SolidityRemoveDeleteMutator { comment_node: None }
+    }
+}
+
+impl Mutator<SolidityAST> for SolidityRemoveDeleteMutator {
+    fn is_mutable_node(&mut self, node: &SolidityAST, _rand: &mut Pcg64) -> bool {
+        !reachable_statement_indexes(node, is_delete_statement).is_empty()
+    }
+
+    fn mutate(
+        &mut self,
+        node: &mut SolidityAST,
+        rand: &mut Pcg64,
+    ) -> Result<MutatorResult, MutagenyxError> {
+        // Remove previous comment
+        self.comment_node = None;
+
+        let mut mutation_result = MutatorResult::new();
+        mutation_result.mutation_type = Some(self.implements());
+
+        let mut hasher = Hasher::new(MessageDigest::sha256())?;
+
+        let statement = comment_out_random_statement(node, is_delete_statement, rand, &mut hasher)?;
+        mutation_result.mutated_node_id = statement
+            .get_int_for_key("expression/id")
+            .map(|id| id as u64);
+
+        let comment_text = format!(
+            "RemoveDelete Mutator: commented out statement '{}'",
+            pretty_print_node(&statement)
+        );
+        if let Ok(comment_node) = new_comment_node(&comment_text) {
+            self.comment_node = Some(comment_node);
+        }
+
+        let byte_array = hasher.finish()?;
+        mutation_result.random_behavior_hash = Some(hex::encode(byte_array));
+
+        Ok(mutation_result)
+    }
+
+    fn implements(&self) -> MutationType {
+        MutationType::Solidity(SolidityMutation::RemoveDelete)
+    }
+
+    fn description(&self) -> &'static str {
+        "Comment out a statement that resets a variable with delete."
+    }
+
+    fn example(&self) -> &'static str {
+        "delete balances[addr]; would become // delete balances[addr];"
+    }
+
+    fn get_comment_node(&self) -> Option<SolidityAST> {
+        self.comment_node.clone()
+    }
+}
+
+/// Return true if `node` is a `payable(...)` conversion of an address.
+///
+/// # Arguments
+///
+/// * `node` - The node in the AST.
+fn is_payable_cast(node: &SolidityAST) -> bool {
+    node.get_str_for_key("nodeType") == Some("FunctionCall")
+        && node.get_str_for_key("kind") == Some("typeConversion")
+        && node.get_str_for_key("expression/nodeType") == Some("ElementaryTypeNameExpression")
+        && node.get_str_for_key("expression/typeName/stateMutability") == Some("payable")
+        && node
+            .get("arguments")
+            .and_then(|a| a.as_array())
+            .is_some_and(|a| a.len() == 1)
+}
+
+/// Implements the RemovePayableCast mutation algorithm for Solidity programs.
+///
+/// The algorithm replaces a `payable(...)` conversion with the converted expression, so that the
+/// mutant checks whether the program needs the conversion to send Ether to the address.
+///
+/// ```solidity
+/// payable(owner).transfer(fee);
+/// ```
+///
+/// would become
+///
+/// ```solidity
+/// owner.transfer(fee);
+/// ```
+///
+/// The replacement keeps the type descriptions of the converted expression, which describe the
+/// expression correctly, but the type descriptions of the enclosing nodes (such as the member
+/// access of `transfer`) still describe an `address payable`.  The type descriptions of a mutant
+/// are therefore approximate until the compiler checks the mutant.
+struct SolidityRemovePayableCastMutator {
+    /// Information about the mutation.
+    comment_node: Option<SolidityAST>,
+}
+
+impl SolidityRemovePayableCastMutator {
+    /// Create a new remove payable cast mutator.
+    pub fn new() -> SolidityRemovePayableCastMutator {
+        SolidityRemovePayableCastMutator { comment_node: None }
+    }
+}
+
+impl Mutator<SolidityAST> for SolidityRemovePayableCastMutator {
+    fn is_mutable_node(&mut self, node: &SolidityAST, _rand: &mut Pcg64) -> bool {
+        is_payable_cast(node)
+    }
+
+    fn mutate(
+        &mut self,
+        node: &mut SolidityAST,
+        _rand: &mut Pcg64,
+    ) -> Result<MutatorResult, MutagenyxError> {
+        // Remove previous comment
+        self.comment_node = None;
+
+        let mut mutation_result = MutatorResult::new();
+        mutation_result.mutation_type = Some(self.implements());
+
+        let mut hasher = Hasher::new(MessageDigest::sha256())?;
+
+        let mut argument = match node.get("arguments").and_then(|a| a.as_array()) {
+            Some(arguments) if is_payable_cast(node) => arguments[0].clone(),
+            _ => {
+                return Err(MutagenyxError::MalformedNode(
+                    String::from("FunctionCall"),
+                    String::from("arguments"),
+                ))
+            }
+        };
+
+        let original_node_s = pretty_print_node(node);
+        mutation_result.mutated_node_id = node.get_int_for_key("id").map(|id| id as u64);
+
+        // The argument takes the place of the conversion, so give the argument the id of the
+        // conversion.  The language interface finds the node by id to insert the comment.
+        if let Some(id) = node.get("id") {
+            argument.set_node_for_key("id", id.clone());
+        }
+        *node = argument;
+        let new_node_s = pretty_print_node(node);
+
+        hasher.update(new_node_s.as_bytes())?;
+
+        let comment_text = format!(
+            "RemovePayableCast Mutator: replaced '{}' with '{}'",
+            original_node_s, new_node_s
+        );
+        if let Ok(comment_node) = new_comment_node(&comment_text) {
+            self.comment_node = Some(comment_node);
+        }
+
+        let byte_array = hasher.finish()?;
+        mutation_result.random_behavior_hash = Some(hex::encode(byte_array));
+
+        Ok(mutation_result)
+    }
+
+    fn implements(&self) -> MutationType {
+        MutationType::Solidity(SolidityMutation::RemovePayableCast)
+    }
+
+    fn description(&self) -> &'static str {
+        "Replace a payable(...) conversion of an address with the converted expression."
+    }
+
+    fn example(&self) -> &'static str {
+        "payable(owner).transfer(fee); would become owner.transfer(fee);"
+    }
+
+    fn get_comment_node(&self) -> Option<SolidityAST> {
+        self.comment_node.clone()
+    }
+}
+
+/// Implements the SwapEnumMembers mutation algorithm for Solidity programs.
+///
+/// The algorithm swaps two members of an `EnumDefinition` node and leaves the `EnumValue` node of
+/// each member intact.  The integer value of a member is the position of the member in the
+/// definition, so the swap changes the values of the two members.
+struct SolidityEnumMembersMutator {
+    /// Information about the mutation.
+    comment_node: Option<SolidityAST>,
+}
+
+impl SolidityEnumMembersMutator {
+    /// Create a new swap enum members mutator.
+    pub fn new() -> SolidityEnumMembersMutator {
+        SolidityEnumMembersMutator { comment_node: None }
+    }
+}
+
+impl Mutator<SolidityAST> for SolidityEnumMembersMutator {
+    fn is_mutable_node(&mut self, node: &SolidityAST, _rand: &mut Pcg64) -> bool {
+        node.get_str_for_key("nodeType") == Some("EnumDefinition")
+            && node
+                .get("members")
+                .and_then(|m| m.as_array())
+                .is_some_and(|m| m.len() >= 2)
+    }
+
+    fn mutate(
+        &mut self,
+        node: &mut SolidityAST,
+        rand: &mut Pcg64,
+    ) -> Result<MutatorResult, MutagenyxError> {
+        // Remove previous comment
+        self.comment_node = None;
+
+        let mut mutation_result = MutatorResult::new();
+        mutation_result.mutation_type = Some(self.implements());
+
+        let mut hasher = Hasher::new(MessageDigest::sha256())?;
+
+        let enum_name = node.get_str_for_key("name").map(String::from);
+        let members = match node.get_array_for_key_mut("members") {
+            Some(m) if m.len() >= 2 => m,
+            _ => {
+                return Err(MutagenyxError::MalformedNode(
+                    String::from("EnumDefinition"),
+                    String::from("members"),
+                ))
+            }
+        };
+
+        // Randomly select two different members.
+        let first = (rand.next_u64() % members.len() as u64) as usize;
+        let mut second = (rand.next_u64() % (members.len() - 1) as u64) as usize;
+        if second >= first {
+            second += 1;
+        }
+
+        // Swapping the first and the second member is the same mutation as swapping the second
+        // and the first member.
+        hasher.update(&first.min(second).to_ne_bytes())?;
+        hasher.update(&first.max(second).to_ne_bytes())?;
+
+        let first_name = String::from(members[first].get_str_for_key("name").unwrap_or_default());
+        let second_name = String::from(members[second].get_str_for_key("name").unwrap_or_default());
+        members.swap(first, second);
+
+        let comment_text = format!(
+            "SwapEnumMembers Mutator: swapped '{}' and '{}' in enum '{}'",
+            first_name,
+            second_name,
+            enum_name.unwrap_or_default()
+        );
+        if let Ok(comment_node) = new_comment_node(&comment_text) {
+            self.comment_node = Some(comment_node);
+        }
+
+        mutation_result.mutated_node_id = node.get_int_for_key("id").map(|id| id as u64);
+
+        let byte_array = hasher.finish()?;
+        mutation_result.random_behavior_hash = Some(hex::encode(byte_array));
+
+        Ok(mutation_result)
+    }
+
+    fn implements(&self) -> MutationType {
+        MutationType::Solidity(SolidityMutation::SwapEnumMembers)
+    }
+
+    fn description(&self) -> &'static str {
+        "Swap two members of an enum definition."
+    }
+
+    fn example(&self) -> &'static str {
+        "enum Status { Pending, Open, Closed } could become enum Status { Closed, Open, Pending }"
+    }
+
+    fn get_comment_node(&self) -> Option<SolidityAST> {
+        self.comment_node.clone()
+    }
+}
+
+/// Return true if `node` is a for, while, or do-while loop.
+///
+/// # Arguments
+///
+/// * `node` - The node in the AST.
+fn is_loop_statement(node: &SolidityAST) -> bool {
+    matches!(
+        node.get_str_for_key("nodeType"),
+        Some("ForStatement") | Some("WhileStatement") | Some("DoWhileStatement")
+    )
+}
+
+/// Collect the JSON pointers (relative to the node passed to the first call) of the Continue
+/// and Break nodes in `node` that belong to the enclosing loop.  The function does not search
+/// nested loops since the statements in a nested loop belong to the nested loop.  Each pointer
+/// comes with true if the statement is an element of the statements of a block.
+///
+/// # Arguments
+///
+/// * `node` - The node to search.
+/// * `pointer` - The JSON pointer of `node`.
+/// * `in_block` - True if `node` is an element of the statements of a block.
+/// * `statements` - The vector that receives the JSON pointers of the statements.
+fn collect_loop_control_statements(
+    node: &SolidityAST,
+    pointer: String,
+    in_block: bool,
+    statements: &mut Vec<(String, bool)>,
+) {
+    if let Value::Object(map) = node {
+        match node.get_str_for_key("nodeType") {
+            Some("Continue") | Some("Break") => statements.push((pointer, in_block)),
+            _ if is_loop_statement(node) => (),
+            node_type => {
+                let is_block = matches!(node_type, Some("Block") | Some("UncheckedBlock"));
+                for (key, value) in map {
+                    let key_pointer = format!("{}/{}", pointer, key);
+                    match value {
+                        Value::Array(array) => {
+                            for (i, element) in array.iter().enumerate() {
+                                collect_loop_control_statements(
+                                    element,
+                                    format!("{}/{}", key_pointer, i),
+                                    is_block && key == "statements",
+                                    statements,
+                                );
+                            }
+                        }
+                        _ => collect_loop_control_statements(value, key_pointer, false, statements),
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Implements the LoopControl mutation algorithm for Solidity programs.
+///
+/// The algorithm selects a `continue` or `break` statement in the body of a loop and either
+/// comments out the statement or replaces `continue` with `break` and `break` with `continue`.
+/// Mutators only see one node at a time, so the algorithm mutates the statement through the
+/// enclosing loop node.  The algorithm only comments out the statements in a block, since
+/// commenting out the body of a loop or an if statement, as in `if (done) break;`, leaves a
+/// statement without a body.
+///
+/// # Example
+///
+/// ```solidity
+/// for (uint256 i = 0; i < n; i++) {
+///     if (skip[i]) {
+///         continue;
+///     }
+///     total += values[i];
+/// }
+/// ```
+///
+/// might become
+///
+/// ```solidity
+/// for (uint256 i = 0; i < n; i++) {
+///     if (skip[i]) {
+///         break;
+///     }
+///     total += values[i];
+/// }
+/// ```
+struct SolidityLoopControlMutator {
+    /// Information about the mutation.
+    comment_node: Option<SolidityAST>,
+}
+
+impl SolidityLoopControlMutator {
+    /// Create a new loop control mutator.
+    pub fn new() -> SolidityLoopControlMutator {
+        SolidityLoopControlMutator { comment_node: None }
+    }
+
+    /// Return the JSON pointers of the Continue and Break nodes that belong to the loop `node`.
+    /// Each pointer comes with true if the algorithm can comment out the statement.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The loop node.
+    fn loop_control_statements(node: &SolidityAST) -> Vec<(String, bool)> {
+        let mut statements: Vec<(String, bool)> = vec![];
+        if is_loop_statement(node) {
+            if let Some(body) = node.get("body") {
+                collect_loop_control_statements(
+                    body,
+                    String::from("/body"),
+                    false,
+                    &mut statements,
+                );
+            }
+        }
+        statements
+    }
+}
+
+impl Mutator<SolidityAST> for SolidityLoopControlMutator {
+    fn is_mutable_node(&mut self, node: &SolidityAST, _rand: &mut Pcg64) -> bool {
+        !SolidityLoopControlMutator::loop_control_statements(node).is_empty()
+    }
+
+    fn mutate(
+        &mut self,
+        node: &mut SolidityAST,
+        rand: &mut Pcg64,
+    ) -> Result<MutatorResult, MutagenyxError> {
+        // Remove previous comment
+        self.comment_node = None;
+
+        let mut mutation_result = MutatorResult::new();
+        mutation_result.mutation_type = Some(self.implements());
+
+        let mut hasher = Hasher::new(MessageDigest::sha256())?;
+
+        // Every statement can change into the other statement, but only the statements in a
+        // block can become comments.
+        let mut changes: Vec<(String, bool)> = vec![];
+        for (pointer, in_block) in SolidityLoopControlMutator::loop_control_statements(node) {
+            if in_block {
+                changes.push((pointer.clone(), true));
+            }
+            changes.push((pointer, false));
+        }
+
+        if let Some((pointer, remove)) = changes.choose(rand) {
+            if let Some(statement) = node.pointer_mut(pointer) {
+                hasher.update(pointer.as_bytes())?;
+                hasher.update(&[*remove as u8])?;
+
+                mutation_result.mutated_node_id =
+                    statement.get_int_for_key("id").map(|id| id as u64);
+
+                let comment_text = if *remove {
+                    let statement_s = pretty_print_node(statement);
+                    let comment = match new_comment_node_from_node(statement.take()) {
+                        Ok(node) => node,
+                        Err(_e) => {
+                            return Err(MutagenyxError::UnableToGenerateNode("comment node"))
+                        }
+                    };
+                    *statement = comment;
+                    format!("LoopControl Mutator: commented out '{}'", statement_s)
+                } else {
+                    let (original, replacement) = match statement.get_str_for_key("nodeType") {
+                        Some("Continue") => ("Continue", "Break"),
+                        _ => ("Break", "Continue"),
+                    };
+                    statement.set_str_for_key("nodeType", replacement);
+                    format!(
+                        "LoopControl Mutator: replaced '{}' with '{}'",
+                        original.to_lowercase(),
+                        replacement.to_lowercase()
+                    )
+                };
+                if let Ok(comment_node) = new_comment_node(&comment_text) {
+                    self.comment_node = Some(comment_node);
+                }
+
+                let byte_array = hasher.finish()?;
+                mutation_result.random_behavior_hash = Some(hex::encode(byte_array));
+
+                return Ok(mutation_result);
+            }
+        }
+
+        Err(MutagenyxError::MalformedNode(
+            String::from(node.get_str_for_key("nodeType").unwrap_or("ForStatement")),
+            String::from("body"),
+        ))
+    }
+
+    fn implements(&self) -> MutationType {
+        MutationType::Solidity(SolidityMutation::LoopControl)
+    }
+
+    fn description(&self) -> &'static str {
+        "Comment out a continue or break statement of a loop body, or replace continue with break \
+            and break with continue."
+    }
+
+    fn example(&self) -> &'static str {
+        "if (skip[i]) { continue; } could become if (skip[i]) { break; } or if (skip[i]) { // \
+            continue; }"
+    }
+
+    fn get_comment_node(&self) -> Option<SolidityAST> {
+        self.comment_node.clone()
+    }
+}
+
+/// Implement the [`MutatorFactory<T>`] trait to have an interface for getting mutators for requested
+/// mutation algorithms.
+pub struct SolidityMutatorFactory {
+    /// The operator groups for the operator mutators.
+    operators: OperatorRegistry,
+}
+
+impl SolidityMutatorFactory {
+    /// Return a new factory that builds the operator mutators from the groups in `operators`.
+    ///
+    /// # Arguments
+    ///
+    /// * `operators` - The operator groups for the operator mutators.
+    pub fn new(operators: OperatorRegistry) -> Self {
+        SolidityMutatorFactory { operators }
+    }
+}
+
+impl MutatorFactory<SolidityAST> for SolidityMutatorFactory {
+    fn mutator_for(&self, mutation_type: &MutationType) -> Option<Box<dyn Mutator<SolidityAST>>> {
+        match mutation_type {
+            MutationType::Generic(t) => match t {
+                GenericMutation::ArithmeticBinaryOp => Some(Box::new(BinaryOpMutator::new(
+                    self.operators.operators(ARITHMETIC_OPERATORS),
+                    MutationType::Generic(GenericMutation::ArithmeticBinaryOp),
+                ))),
+                GenericMutation::LogicalBinaryOp => Some(Box::new(BinaryOpMutator::new(
+                    self.operators.operators(LOGICAL_OPERATORS),
+                    MutationType::Generic(GenericMutation::LogicalBinaryOp),
+                ))),
+                GenericMutation::BitwiseBinaryOp => Some(Box::new(BinaryOpMutator::new(
+                    self.operators.operators(BITWISE_OPERATORS),
+                    MutationType::Generic(GenericMutation::BitwiseBinaryOp),
+                ))),
+                GenericMutation::BitshiftBinaryOp => Some(Box::new(BinaryOpMutator::new(
+                    self.operators.operators(BITSHIFT_OPERATORS),
+                    MutationType::Generic(GenericMutation::BitshiftBinaryOp),
+                ))),
+                GenericMutation::ComparisonBinaryOp => Some(Box::new(BinaryOpMutator::new(
+                    self.operators.operators(COMPARISON_OPERATORS),
+                    MutationType::Generic(GenericMutation::ComparisonBinaryOp),
+                ))),
+                GenericMutation::Assignment => Some(Box::new(AssignmentMutator::new())),
+                GenericMutation::AssignmentOperatorReplacement => {
+                    Some(Box::new(AssignmentOperatorMutator::new(
+                        self.operators.operators(COMPOUND_ASSIGNMENT_OPERATORS),
+                    )))
+                }
+                GenericMutation::BooleanLiteralFlip => {
+                    Some(Box::new(BooleanLiteralFlipMutator::new()))
+                }
+                GenericMutation::DeleteStatement => Some(Box::new(DeleteStatementMutator::new())),
+                GenericMutation::DuplicateStatement => {
+                    Some(Box::new(DuplicateStatementMutator::new()))
+                }
+                GenericMutation::FunctionCall => Some(Box::new(FunctionCallMutator::new())),
+                GenericMutation::SwapFunctionArguments => {
+                    Some(Box::new(SwapFunctionArgumentsMutator::new()))
+                }
+                GenericMutation::IfStatement => Some(Box::new(IfStatementMutator::new())),
+                GenericMutation::Integer => Some(Box::new(IntegerMutator::new())),
+                GenericMutation::SwapOperatorArguments => {
+                    Some(Box::new(SwapOperatorArgumentsMutator::new(
+                        self.operators.operators(NON_COMMUTATIVE_OPERATORS),
+                    )))
+                }
+                GenericMutation::SwapLines => Some(Box::new(SwapLinesMutator::new())),
+                GenericMutation::UnaryOp => Some(Box::new(UnaryOpMutator::new(
+                    self.operators.operators(PREFIX_OPERATORS),
+                    self.operators.operators(POSTFIX_OPERATORS),
+                ))),
+            },
+            MutationType::Solidity(t) => match t {
+                SolidityMutation::Require => Some(Box::new(SolidityRequireMutator::new())),
+                SolidityMutation::UncheckedBlock => {
+                    Some(Box::new(SolidityUncheckedBlockMutator::new()))
+                }
+                SolidityMutation::ElimDelegateCall => {
+                    Some(Box::new(SolidityElimDelegateCallMutator::new()))
+                }
+                SolidityMutation::RequireComparison => {
+                    Some(Box::new(SolidityRequireComparisonMutator::new()))
+                }
+                SolidityMutation::MemoryToStorage => {
+                    Some(Box::new(SolidityMemoryToStorageMutator::new()))
+                }
+                SolidityMutation::FixedBytesSize => {
+                    Some(Box::new(SolidityFixedBytesSizeMutator::new()))
+                }
+                SolidityMutation::CallOptions => Some(Box::new(SolidityCallOptionsMutator::new())),
+                SolidityMutation::SwapCalldataMemory => {
+                    Some(Box::new(SolidityCalldataMemoryMutator::new()))
+                }
+                SolidityMutation::NewToAddress => {
+                    Some(Box::new(SolidityNewToAddressMutator::new()))
+                }
+                SolidityMutation::SwapStructFields => {
+                    Some(Box::new(SoliditySwapStructFieldsMutator::new()))
+                }
+                SolidityMutation::ConstantImmutable => {
+                    Some(Box::new(SolidityConstantImmutableMutator::new()))
+                }
+                SolidityMutation::ArrayLength => Some(Box::new(SolidityArrayLengthMutator::new())),
+                SolidityMutation::GlobalMemberAccess => {
+                    Some(Box::new(SolidityGlobalMemberAccessMutator::new()))
+                }
+                SolidityMutation::SwapModifiers => {
+                    Some(Box::new(SoliditySwapModifiersMutator::new()))
+                }
+                SolidityMutation::LoopForm => Some(Box::new(SolidityLoopFormMutator::new())),
+                SolidityMutation::AssemblyLiteral => {
+                    Some(Box::new(SolidityAssemblyLiteralMutator::new()))
+                }
+                SolidityMutation::TernaryIfElse => {
+                    Some(Box::new(SolidityTernaryIfElseMutator::new()))
+                }
+                SolidityMutation::RevertMessage => {
+                    Some(Box::new(SolidityRevertMessageMutator::new()))
+                }
+                SolidityMutation::FallbackPayability => {
+                    Some(Box::new(SolidityFallbackPayabilityMutator::new()))
+                }
+                SolidityMutation::BalanceConstant => {
+                    Some(Box::new(SolidityBalanceConstantMutator::new()))
+                }
+                SolidityMutation::BaseConstructorArguments => {
+                    Some(Box::new(SolidityBaseConstructorArgumentsMutator::new()))
+                }
+                SolidityMutation::SwapMsgContext => {
+                    Some(Box::new(SoliditySwapMsgContextMutator::new()))
+                }
+                SolidityMutation::RemoveDelete => {
+                    Some(Box::new(SolidityRemoveDeleteMutator::new()))
+                }
+                SolidityMutation::RemovePayableCast => {
+                    Some(Box::new(SolidityRemovePayableCastMutator::new()))
+                }
+                SolidityMutation::SwapEnumMembers => {
+                    Some(Box::new(SolidityEnumMembersMutator::new()))
+                }
+                SolidityMutation::LoopControl => Some(Box::new(SolidityLoopControlMutator::new())),
+            },
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language_interface::LanguageInterface;
+    use crate::super_ast::SuperAST;
+    use crate::test_fixtures::*;
+    use crate::Language;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_base_constructor_arguments() {
+        let constructor = |kind: &str| {
+            json!({
+                "nodeType": "FunctionDefinition", "id": 20, "kind": kind, "name": "",
+                "visibility": "public", "stateMutability": "nonpayable", "virtual": false,
+                "parameters": {"nodeType": "ParameterList", "id": 21, "parameters": []},
+                "returnParameters": {"nodeType": "ParameterList", "id": 22, "parameters": []},
+                "modifiers": [
+                    {
+                        "nodeType": "ModifierInvocation", "id": 23,
+                        "kind": "baseConstructorSpecifier",
+                        "modifierName": {"nodeType": "IdentifierPath", "id": 24, "name": "Owned"},
+                        "arguments": [{
+                            "nodeType": "MemberAccess", "id": 25, "memberName": "sender",
+                            "expression": {
+                                "nodeType": "Identifier", "id": 26, "name": "msg",
+                                "typeDescriptions": {
+                                    "typeIdentifier": "t_magic_message", "typeString": "msg"
+                                }
+                            },
+                            "typeDescriptions": {"typeIdentifier": "t_address", "typeString": "address"}
+                        }]
+                    },
+                    {
+                        "nodeType": "ModifierInvocation", "id": 27, "kind": "modifierInvocation",
+                        "modifierName": {"nodeType": "IdentifierPath", "id": 28, "name": "limited"},
+                        "arguments": [{
+                            "nodeType": "Literal", "id": 29, "kind": "number", "value": "5",
+                            "typeDescriptions": {
+                                "typeIdentifier": "t_rational_5_by_1", "typeString": "int_const 5"
+                            }
+                        }]
+                    }
+                ],
+                "body": {"nodeType": "Block", "id": 30, "statements": []}
+            })
+        };
+
+        let factory = SolidityMutatorFactory::new(OperatorRegistry::default());
+        let mut mutator = factory
+            .mutator_for(&MutationType::Solidity(
+                SolidityMutation::BaseConstructorArguments,
+            ))
+            .unwrap();
+        let mut rng = Pcg64::seed_from_u64(0);
+
+        // Only constructors call base constructors.
+        assert!(!mutator.is_mutable_node(&constructor("function"), &mut rng));
+
+        let mut node = constructor("constructor");
+        assert!(mutator.is_mutable_node(&node, &mut rng));
+
+        let result = mutator.mutate(&mut node, &mut rng).unwrap();
+        assert_eq!(result.mutated_node_id, Some(20));
+        assert_eq!(
+            pretty_print_node(&node["modifiers"][0]["arguments"][0]),
+            "address(0)"
+        );
+
+        // The mutator does not change the arguments of the modifier.
+        assert_eq!(
+            node["modifiers"][1],
+            constructor("constructor")["modifiers"][1]
+        );
+
+        // The argument is now a boundary value, so the mutator has nothing left to change.
+        assert!(!mutator.is_mutable_node(&node, &mut rng));
+    }
+
+    #[test]
+    fn test_swap_msg_context() {
+        let member_access = |base: &str, declaration: i64, member: &str| {
+            json!({
+                "nodeType": "MemberAccess", "id": 42, "memberName": member,
+                "expression": {
+                    "nodeType": "Identifier", "id": 41, "name": base,
+                    "referencedDeclaration": declaration,
+                    "typeDescriptions": {
+                        "typeIdentifier": global_type_identifier(base), "typeString": base
+                    }
+                },
+                "typeDescriptions": {"typeIdentifier": "t_address", "typeString": "address"}
+            })
+        };
+        let sender = member_access("msg", -15, "sender");
+
+        let factory = SolidityMutatorFactory::new(OperatorRegistry::default());
+        let mut mutator = factory
+            .mutator_for(&MutationType::Solidity(SolidityMutation::SwapMsgContext))
+            .unwrap();
+        let mut rng = Pcg64::seed_from_u64(0);
+
+        // The value member has no replacement of the same type.
+        assert!(!mutator.is_mutable_node(&member_access("msg", -15, "value"), &mut rng));
+        assert!(!mutator.is_mutable_node(&member_access("block", -4, "coinbase"), &mut rng));
+
+        let mut node = sender.clone();
+        assert!(mutator.is_mutable_node(&node, &mut rng));
+        let result = mutator.mutate(&mut node, &mut rng).unwrap();
+        assert_eq!(result.mutated_node_id, Some(42));
+        assert_eq!(pretty_print_node(&node), "tx.origin");
+        assert_eq!(node, member_access("tx", -26, "origin"));
+
+        // Mutating the mutant gives back the original node.
+        mutator.mutate(&mut node, &mut rng).unwrap();
+        assert_eq!(node, sender);
+    }
+
+    #[test]
+    fn test_remove_delete() {
+        let identifier =
+            |id: i64, name: &str| json!({"nodeType": "Identifier", "id": id, "name": name});
+        let delete_statement = json!({
+            "nodeType": "ExpressionStatement", "id": 54,
+            "expression": {
+                "nodeType": "UnaryOperation", "id": 53, "operator": "delete", "prefix": true,
+                "subExpression": {
+                    "nodeType": "IndexAccess", "id": 52,
+                    "baseExpression": identifier(50, "balances"),
+                    "indexExpression": identifier(51, "addr")
+                }
+            }
+        });
+        let block = json!({
+            "nodeType": "Block", "id": 60,
+            "statements": [
+                delete_statement.clone(),
+                {
+                    "nodeType": "Return", "id": 56, "functionReturnParameters": 40,
+                    "expression": identifier(55, "addr")
+                }
+            ]
+        });
+
+        let factory = SolidityMutatorFactory::new(OperatorRegistry::default());
+        let mut mutator = factory
+            .mutator_for(&MutationType::Solidity(SolidityMutation::RemoveDelete))
+            .unwrap();
+        let mut rng = Pcg64::seed_from_u64(0);
+
+        // The mutator only comments out statements in a Block.
+        assert!(!mutator.is_mutable_node(&delete_statement, &mut rng));
+
+        let mut node = block.clone();
+        assert!(mutator.is_mutable_node(&node, &mut rng));
+        let result = mutator.mutate(&mut node, &mut rng).unwrap();
+        assert_eq!(result.mutated_node_id, Some(53));
+
+        // The semicolon of the statement belongs to the comment.
+        let printed = pretty_print_node(&node);
+        assert!(printed.contains("// delete balances[addr];\n"));
+        assert!(printed.contains("return addr;"));
+
+        // The compiler skips the comment, so the mutant reads as the Block without the
+        // statement.
+        let mut expected = block.clone();
+        expected["statements"].as_array_mut().unwrap().remove(0);
+        let uncommented: Vec<&str> = printed
+            .lines()
+            .filter(|line| !line.trim_start().starts_with("//"))
+            .collect();
+        assert_eq!(
+            uncommented,
+            pretty_print_node(&expected).lines().collect::<Vec<&str>>()
+        );
+
+        // The Block has no delete statement left.
+        assert!(!mutator.is_mutable_node(&node, &mut rng));
+    }
+
+    #[test]
+    fn test_boolean_literal_flip() {
+        let call = json!({
+            "nodeType": "FunctionCall", "id": 73, "kind": "functionCall",
+            "expression": {"nodeType": "Identifier", "id": 70, "name": "setPaused"},
+            "arguments": [{
+                "nodeType": "Literal", "id": 71, "kind": "bool", "value": "true",
+                "hexValue": "74727565",
+                "typeDescriptions": {"typeIdentifier": "t_bool", "typeString": "bool"}
+            }]
+        });
+
+        let factory = SolidityMutatorFactory::new(OperatorRegistry::default());
+        let mut mutator = factory
+            .mutator_for(&MutationType::Generic(GenericMutation::BooleanLiteralFlip))
+            .unwrap();
+        let mut rng = Pcg64::seed_from_u64(0);
+
+        assert!(!mutator.is_mutable_node(&call, &mut rng));
+        assert!(!mutator.is_mutable_node(&call["expression"], &mut rng));
+
+        let mut node = call.clone();
+        let argument = &mut node["arguments"][0];
+        assert!(mutator.is_mutable_node(argument, &mut rng));
+        let result = mutator.mutate(argument, &mut rng).unwrap();
+        assert_eq!(result.mutated_node_id, Some(71));
+        assert_eq!(argument["hexValue"], hex::encode("false"));
+
+        assert_eq!(pretty_print_node(&node), "setPaused(false)");
+
+        // The literal matches the literal that the compiler generates for `false`, and keeps
+        // the id and the type of the original literal.
+        assert_eq!(
+            node["arguments"][0],
+            json!({
+                "nodeType": "Literal", "id": 71, "kind": "bool", "value": "false",
+                "hexValue": "66616c7365",
+                "typeDescriptions": {"typeIdentifier": "t_bool", "typeString": "bool"}
+            })
+        );
+
+        // Flipping the literal again gives back the original call.
+        mutator.mutate(&mut node["arguments"][0], &mut rng).unwrap();
+        assert_eq!(node, call);
+    }
+
+    #[test]
+    fn test_swap_function_arguments() {
+        let argument = |id: i64, name: &str, type_string: &str| {
+            json!({
+                "nodeType": "Identifier", "id": id, "name": name,
+                "typeDescriptions": {"typeIdentifier": "t_any", "typeString": type_string}
+            })
+        };
+        let call = |arguments: Vec<SolidityAST>, names: Vec<&str>| {
+            json!({
+                "nodeType": "FunctionCall", "id": 90, "kind": "functionCall",
+                "names": names, "arguments": arguments,
+                "expression": {"nodeType": "Identifier", "id": 80, "name": "transfer"}
+            })
+        };
+        let types = |node: &SolidityAST| -> Vec<String> {
+            node["arguments"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|a| String::from(a["typeDescriptions"]["typeString"].as_str().unwrap()))
+                .collect()
+        };
+
+        let factory = SolidityMutatorFactory::new(OperatorRegistry::default());
+        let mut mutator = factory
+            .mutator_for(&MutationType::Generic(
+                GenericMutation::SwapFunctionArguments,
+            ))
+            .unwrap();
+        let mut rng = Pcg64::seed_from_u64(0);
+
+        // Two arguments of the same type trade places.
+        let mut node = call(
+            vec![
+                argument(81, "from", "address"),
+                argument(82, "to", "address"),
+            ],
+            vec![],
+        );
+        assert!(mutator.is_mutable_node(&node, &mut rng));
+        let result = mutator.mutate(&mut node, &mut rng).unwrap();
+        assert_eq!(result.mutated_node_id, Some(90));
+        assert_eq!(pretty_print_node(&node), "transfer(to, from)");
+
+        // With three arguments the mutator only swaps the two arguments of the same type, so
+        // each position keeps the type that the function expects and the call still compiles.
+        let original = call(
+            vec![
+                argument(81, "from", "address"),
+                argument(83, "amount", "uint256"),
+                argument(82, "to", "address"),
+            ],
+            vec![],
+        );
+        for seed in 0..10 {
+            let mut rng = Pcg64::seed_from_u64(seed);
+            let mut node = original.clone();
+            assert!(mutator.is_mutable_node(&node, &mut rng));
+            mutator.mutate(&mut node, &mut rng).unwrap();
+            assert_eq!(pretty_print_node(&node), "transfer(to, amount, from)");
+            assert_eq!(types(&node), types(&original));
+        }
+
+        // Arguments of different types and named arguments are never swapped.
+        let mixed = call(
+            vec![
+                argument(81, "to", "address"),
+                argument(83, "amount", "uint256"),
+            ],
+            vec![],
+        );
+        assert!(!mutator.is_mutable_node(&mixed, &mut rng));
+        let named = call(
+            vec![
+                argument(81, "from", "address"),
+                argument(82, "to", "address"),
+            ],
+            vec!["from", "to"],
+        );
+        assert!(!mutator.is_mutable_node(&named, &mut rng));
+    }
+
+    #[test]
+    fn test_remove_payable_cast() {
+        let address = |id: i64, name: &str| {
+            json!({
+                "nodeType": "Identifier", "id": id, "name": name,
+                "typeDescriptions": {"typeIdentifier": "t_address", "typeString": "address"}
+            })
+        };
+        let conversion = |mutability: &str, argument: SolidityAST| {
+            json!({
+                "nodeType": "FunctionCall", "id": 93, "kind": "typeConversion", "names": [],
+                "arguments": [argument],
+                "expression": {
+                    "nodeType": "ElementaryTypeNameExpression", "id": 92, "isPure": true,
+                    "typeName": {
+                        "nodeType": "ElementaryTypeName", "id": 91, "name": "address",
+                        "stateMutability": mutability
+                    }
+                },
+                "typeDescriptions": {
+                    "typeIdentifier": "t_address_payable", "typeString": "address payable"
+                }
+            })
+        };
+        let transfer = json!({
+            "nodeType": "FunctionCall", "id": 96, "kind": "functionCall", "names": [],
+            "arguments": [address(95, "fee")],
+            "expression": {
+                "nodeType": "MemberAccess", "id": 94, "memberName": "transfer",
+                "expression": conversion("payable", address(90, "owner"))
+            }
+        });
+
+        let factory = SolidityMutatorFactory::new(OperatorRegistry::default());
+        let mut mutator = factory
+            .mutator_for(&MutationType::Solidity(SolidityMutation::RemovePayableCast))
+            .unwrap();
+        let mut rng = Pcg64::seed_from_u64(0);
+
+        // Only payable conversions qualify, not address(...) conversions or other calls.
+        assert!(!mutator.is_mutable_node(&transfer, &mut rng));
+        assert!(!mutator.is_mutable_node(&conversion("nonpayable", address(90, "owner")), &mut rng));
+
+        let mut node = transfer.clone();
+        let target = &mut node["expression"]["expression"];
+        assert!(mutator.is_mutable_node(target, &mut rng));
+        let result = mutator.mutate(target, &mut rng).unwrap();
+        assert_eq!(result.mutated_node_id, Some(93));
+
+        // The converted expression keeps its own type descriptions and takes the id of the
+        // conversion.
+        assert_eq!(*target, address(93, "owner"));
+        assert_eq!(pretty_print_node(&node), "owner.transfer(fee)");
+        assert!(mutator
+            .get_comment_node()
+            .unwrap()
+            .get_str_for_key("text")
+            .unwrap()
+            .contains("replaced 'payable(owner)' with 'owner'"));
+        assert!(!mutator.is_mutable_node(&node["expression"]["expression"], &mut rng));
+
+        // The language interface finds the replaced node and inserts the mutation comment.
+        let ast = SuperAST::Solidity(solidity_ast_with_statements(json!([
+            {"nodeType": "ExpressionStatement", "id": 97, "expression": transfer}
+        ])));
+        let result = mutate_ast_node(
+            Language::Solidity,
+            &ast,
+            MutationType::Solidity(SolidityMutation::RemovePayableCast),
+            0,
+            0,
+        );
+        let texts = comment_texts(json_value(&result.ast.unwrap()));
+        assert_eq!(texts.len(), 1);
+        assert!(texts[0].starts_with("RemovePayableCast Mutator"));
+    }
+
+    #[test]
+    fn test_swap_enum_members() {
+        let member = |id: i64, name: &str| json!({"nodeType": "EnumValue", "id": id, "name": name});
+        let definition = |members: Vec<SolidityAST>| {
+            json!({
+                "nodeType": "EnumDefinition", "id": 70, "name": "Status",
+                "canonicalName": "Vault.Status", "members": members
+            })
+        };
+        let original = definition(vec![
+            member(71, "Pending"),
+            member(72, "Open"),
+            member(73, "Closed"),
+        ]);
+
+        let factory = SolidityMutatorFactory::new(OperatorRegistry::default());
+        let mut mutator = factory
+            .mutator_for(&MutationType::Solidity(SolidityMutation::SwapEnumMembers))
+            .unwrap();
+        let mut rng = Pcg64::seed_from_u64(4);
+
+        // An enum needs two members to swap.
+        assert!(!mutator.is_mutable_node(&definition(vec![member(71, "Only")]), &mut rng));
+        assert!(mutator.is_mutable_node(&original, &mut rng));
+
+        let mut node = original.clone();
+        let result = mutator.mutate(&mut node, &mut rng).unwrap();
+        assert_eq!(result.mutated_node_id, Some(70));
+
+        // The definition keeps the same EnumValue nodes with exactly two of them exchanged.
+        let original_members = original["members"].as_array().unwrap();
+        let members = node["members"].as_array().unwrap();
+        assert_eq!(members.len(), 3);
+        assert!(original_members.iter().all(|m| members.contains(m)));
+        let moved: Vec<usize> = (0..3)
+            .filter(|i| members[*i] != original_members[*i])
+            .collect();
+        assert_eq!(moved.len(), 2);
+        let comment = mutator.get_comment_node().unwrap();
+        let comment_text = comment.get_str_for_key("text").unwrap();
+        for i in &moved {
+            assert!(comment_text.contains(original_members[*i]["name"].as_str().unwrap()));
+        }
+
+        // The mutant is still an enum definition with a comma separated list of the members.
+        let names: Vec<&str> = members
+            .iter()
+            .map(|m| m["name"].as_str().unwrap())
+            .collect();
+        let printed = pretty_print_node(&node);
+        assert_eq!(
+            printed.split_whitespace().collect::<Vec<&str>>().join(" "),
+            format!("enum Status {{ {}, {}, {} }}", names[0], names[1], names[2])
+        );
+    }
+
+    #[test]
+    fn test_loop_control() {
+        let identifier = |name: &str| json!({"nodeType": "Identifier", "name": name});
+        let block =
+            |statements: Vec<SolidityAST>| json!({"nodeType": "Block", "statements": statements});
+        let if_statement = |condition: &str, body: SolidityAST| json!({"nodeType": "IfStatement", "condition": identifier(condition), "trueBody": body});
+        let nested_loop = json!({
+            "nodeType": "WhileStatement", "condition": identifier("inner"),
+            "body": block(vec![json!({"nodeType": "Break", "id": 40})])
+        });
+        let original = json!({
+            "nodeType": "WhileStatement", "id": 10, "condition": identifier("running"),
+            "body": block(vec![
+                if_statement("skip", block(vec![json!({"nodeType": "Continue", "id": 20})])),
+                if_statement("done", json!({"nodeType": "Break", "id": 30})),
+                nested_loop,
+            ])
+        });
+
+        let factory = SolidityMutatorFactory::new(OperatorRegistry::default());
+        let mut mutator = factory
+            .mutator_for(&MutationType::Solidity(SolidityMutation::LoopControl))
+            .unwrap();
+        let mut rng = Pcg64::seed_from_u64(1);
+
+        // Continue and Break statements only belong to the innermost loop.
+        assert!(mutator.is_mutable_node(&original, &mut rng));
+        assert!(!mutator.is_mutable_node(&block(vec![json!({"nodeType": "Break"})]), &mut rng));
+        let statements = SolidityLoopControlMutator::loop_control_statements(&original);
+        assert_eq!(
+            statements,
+            vec![
+                (
+                    String::from("/body/statements/0/trueBody/statements/0"),
+                    true
+                ),
+                (String::from("/body/statements/1/trueBody"), false),
+            ]
+        );
+
+        let mut mutants: Vec<String> = vec![];
+        for seed in 0..32 {
+            let mut rng = Pcg64::seed_from_u64(seed);
+            let mut node = original.clone();
+            let result = mutator.mutate(&mut node, &mut rng).unwrap();
+            assert!(result.mutated_node_id == Some(20) || result.mutated_node_id == Some(30));
+
+            // The nested loop never changes.
+            assert_eq!(
+                node["body"]["statements"][2],
+                original["body"]["statements"][2]
+            );
+
+            // The if statement without a block keeps its body.
+            let printed = pretty_print_node(&node)
+                .split_whitespace()
+                .collect::<Vec<&str>>()
+                .join(" ");
+            assert!(printed.contains("if(done) break;") || printed.contains("if(done) continue;"));
+            mutants.push(printed);
+        }
+
+        // The algorithm generates the removal and both swaps.
+        assert!(mutants
+            .iter()
+            .any(|m| m.contains("if(skip) { // continue; }")));
+        assert!(mutants.iter().any(|m| m.contains("if(skip) { break; }")));
+        assert!(mutants.iter().any(|m| m.contains("if(done) continue;")));
+    }
+
+    #[test]
+    fn test_array_length() {
+        let length = json!({
+            "nodeType": "MemberAccess", "id": 81, "memberName": "length",
+            "lValueRequested": false,
+            "expression": {
+                "nodeType": "Identifier", "id": 80, "name": "values",
+                "typeDescriptions": {
+                    "typeIdentifier": "t_array$_t_uint256_$dyn_storage",
+                    "typeString": "uint256[] storage ref"
+                }
+            },
+            "typeDescriptions": {"typeIdentifier": "t_uint256", "typeString": "uint256"}
+        });
+        let ast = SuperAST::Solidity(solidity_ast_with_statements(json!([
+            {"nodeType": "Return", "id": 82, "expression": length}
+        ])));
+
+        let result = mutate_ast_node(
+            Language::Solidity,
+            &ast,
+            MutationType::Solidity(SolidityMutation::ArrayLength),
+            0,
+            3,
+        );
+        assert_eq!(result.mutator_result.mutated_node_id, Some(81));
+
+        // The literal takes the id of the member access, and the mutant holds the comment.
+        let mutated = json_value(result.ast.as_ref().unwrap());
+        let literal = &mutated["nodes"][0]["nodes"][0]["body"]["statements"][1]["expression"];
+        assert_eq!(literal["nodeType"], json!("Literal"));
+        assert_eq!(literal["id"], json!(81));
+        let texts = comment_texts(mutated);
+        assert_eq!(texts.len(), 1);
+        assert!(texts[0].starts_with("ArrayLength Mutator: Replaced 'values.length'"));
+    }
+
+    #[test]
+    fn test_balance_constant() {
+        let balance = json!({
+            "nodeType": "MemberAccess", "id": 71, "memberName": "balance",
+            "lValueRequested": false,
+            "expression": {
+                "nodeType": "Identifier", "id": 70, "name": "owner",
+                "typeDescriptions": {"typeIdentifier": "t_address", "typeString": "address"}
+            },
+            "typeDescriptions": {"typeIdentifier": "t_uint256", "typeString": "uint256"}
+        });
+        let ast = SuperAST::Solidity(solidity_ast_with_statements(json!([
+            {"nodeType": "Return", "id": 72, "expression": balance}
+        ])));
+
+        let result = mutate_ast_node(
+            Language::Solidity,
+            &ast,
+            MutationType::Solidity(SolidityMutation::BalanceConstant),
+            0,
+            5,
+        );
+        assert_eq!(result.mutator_result.mutated_node_id, Some(71));
+
+        let mutated = json_value(result.ast.as_ref().unwrap());
+        let literal = &mutated["nodes"][0]["nodes"][0]["body"]["statements"][1]["expression"];
+        assert_eq!(literal["nodeType"], json!("Literal"));
+        assert_eq!(literal["id"], json!(71));
+        let texts = comment_texts(mutated);
+        assert_eq!(texts.len(), 1);
+        assert!(texts[0].starts_with("BalanceConstant Mutator: Replaced 'owner.balance'"));
+    }
+
+    #[test]
+    fn test_delete_statement_skips_unreachable_statements() {
+        let call = |id: u64, name: &str| {
+            json!({
+                "nodeType": "ExpressionStatement", "id": id, "expression": {
+                    "nodeType": "FunctionCall", "id": id + 1, "arguments": [],
+                    "expression": {"nodeType": "Identifier", "id": id + 2, "name": name}
+                }
+            })
+        };
+        let mutation_type = MutationType::Generic(GenericMutation::DeleteStatement);
+
+        // Only the call to f() runs, the calls after the return statement are unreachable.
+        let ast = SuperAST::Solidity(solidity_ast_with_statements(json!([
+            call(60, "f"),
+            {"nodeType": "Return", "id": 64},
+            call(70, "g"),
+            call(80, "h")
+        ])));
+        for seed in 0..20 {
+            let result = mutate_ast_node(Language::Solidity, &ast, mutation_type, 0, seed);
+            assert_eq!(result.mutator_result.mutated_node_id, Some(60));
+        }
+
+        // A block whose expression statements all follow a return statement has nothing to
+        // delete.
+        let ast = SuperAST::Solidity(solidity_ast_with_statements(json!([
+            {"nodeType": "Return", "id": 64},
+            call(70, "g"),
+            call(80, "h")
+        ])));
+        let mut language_object =
+            LanguageInterface::get_language_object_for_language(&Language::Solidity).unwrap();
+        language_object
+            .select_mutators_for_mutation_types(&[mutation_type])
+            .unwrap();
+        let mut rng = Pcg64::seed_from_u64(0);
+        let counts = language_object
+            .count_mutable_nodes(&ast, &mut rng, &all_permissions())
+            .unwrap();
+        assert_eq!(counts.get(&mutation_type).copied().unwrap_or_default(), 0);
+    }
+
+    #[test]
+    fn test_assignment_operator_replacement() {
+        let assignment = json!({
+            "nodeType": "Assignment", "id": 63, "operator": "+=",
+            "leftHandSide": {"nodeType": "Identifier", "id": 61, "name": "total"},
+            "rightHandSide": {"nodeType": "Identifier", "id": 62, "name": "amount"},
+            "typeDescriptions": {"typeIdentifier": "t_uint256", "typeString": "uint256"}
+        });
+        let ast = SuperAST::Solidity(solidity_ast_with_statements(json!([
+            {"nodeType": "ExpressionStatement", "id": 60, "expression": assignment.clone()}
+        ])));
+        let mutation_type = MutationType::Generic(GenericMutation::AssignmentOperatorReplacement);
+
+        let mut operators = std::collections::HashSet::new();
+        for seed in 0..50 {
+            let result = mutate_ast_node(Language::Solidity, &ast, mutation_type, 0, seed);
+            let mutated = result.ast.unwrap();
+            let body = &json_value(&mutated)["nodes"][0]["nodes"][0]["body"]["statements"];
+            let node = &body[body.as_array().unwrap().len() - 1]["expression"];
+            assert_eq!(node["leftHandSide"], assignment["leftHandSide"]);
+            assert_eq!(node["rightHandSide"], assignment["rightHandSide"]);
+            operators.insert(String::from(node["operator"].as_str().unwrap()));
         }
+
+        // The mutation drops the compound operation or picks another compound operator.
+        assert!(operators.contains("="));
+        assert!(operators.contains("-="));
+        assert!(!operators.contains("+="));
     }
 }
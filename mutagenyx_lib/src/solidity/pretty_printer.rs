@@ -1,16 +1,16 @@
 //! The `solidity::pretty_printer` module provides the code to traverse a SolidityAST and print out
 //! the source code represented in the AST.
 
+use crate::commenter::{escape_block_comment_text, BLOCK_COMMENTS_KEY};
 use crate::json::JSONMutate;
 use crate::node_printer::{NodePrinter, NodePrinterFactory};
 use crate::node_printer_helpers::*;
 use crate::preferences::{PreferenceValue, Preferences};
 use crate::pretty_printer::{
-    write_flowable_text, write_indent, write_newline, write_space, write_string, write_token,
-    PrettyPrinter,
+    write_flowable_text, write_indent, write_newline, write_raw, write_space, write_string,
+    write_token, PrettyPrinter,
 };
 use crate::solidity::ast::SolidityAST;
-use serde_json::Value;
 use std::io::Write;
 
 /// Helper function for printing out documentation sub-nodes from a node.
@@ -76,28 +76,6 @@ fn print_operator_helper(printer: &mut PrettyPrinter, stream: &mut dyn Write, no
     }
 }
 
-/// Helper function to write an array preceded by a space.
-///
-/// # Abstract
-///
-/// * `printer` - The [`PrettyPrinter`] that will write formatted output to `stream`.
-/// * `node` - The [`Write`] object that will receive formatted output.
-/// * `factory` - The [`NodePrinterFactory<AST>`] object to generate node printers.
-/// * `node` - The [`SolidityAST`] node.
-fn print_space_and_array_helper(
-    printer: &mut PrettyPrinter,
-    stream: &mut dyn Write,
-    factory: &dyn NodePrinterFactory<SolidityAST>,
-    node: &SolidityAST,
-) {
-    if let Some(array) = node.as_array() {
-        if !array.is_empty() {
-            write_space(printer, stream);
-            print_array_helper(printer, stream, factory, array);
-        }
-    }
-}
-
 /// For Block, UncheckedBlock, and YulBlock nodes, print out the statements array.
 ///
 /// # Arguments
@@ -163,11 +141,92 @@ fn print_arguments_helper(
 ) {
     if let Some(arguments_node) = node.get("arguments") {
         if let Some(arguments_array) = arguments_node.as_array() {
-            print_array_helper(printer, stream, factory, arguments_array);
+            print_wrappable_list_helper(printer, stream, factory, arguments_array);
         }
     }
 }
 
+/// The page width of the printer that measures the length of a list on one line.
+const TRIAL_PAGE_WIDTH: usize = usize::MAX;
+
+/// Print the elements of an argument or parameter list.  The caller writes the enclosing
+/// parentheses.
+///
+/// When the factory setting for `wrap_long_argument_lists` is true and the list written on one
+/// line would run past the page width of `printer`, the function writes each element on its own
+/// indented line:
+///
+/// ```text
+/// foo(
+///     first,
+///     second
+/// )
+/// ```
+///
+/// # Arguments
+///
+/// * `printer` - The [`PrettyPrinter`] object that writes formatted text to `stream`.
+/// * `stream` - The [`Write`] object that receives the formatted text.
+/// * `factory` - The [`SolidityNodePrinterFactory`] object that generates printer nodes.
+/// * `array` - The elements of the list.
+fn print_wrappable_list_helper(
+    printer: &mut PrettyPrinter,
+    stream: &mut dyn Write,
+    factory: &dyn NodePrinterFactory<SolidityAST>,
+    array: &Vec<SolidityAST>,
+) {
+    // The list always fits on the line of a trial printer, so a list inside a list that the
+    // function measures does not measure itself again.  Otherwise the lists at each level of
+    // nested calls would print the levels below them twice, once per enclosing list.
+    if array.is_empty()
+        || printer.page_width == TRIAL_PAGE_WIDTH
+        || !factory.get_preference_value_for_key(WRAP_LONG_ARGUMENT_LISTS)
+    {
+        print_array_helper(printer, stream, factory, array);
+        return;
+    }
+
+    // Measure the list by printing the list on one line to a trial buffer with a printer that
+    // never breaks lines.
+    let mut trial_printer = PrettyPrinter::new(printer.tab_width, TRIAL_PAGE_WIDTH);
+    trial_printer.column = printer.column;
+    trial_printer.indent = printer.indent;
+    trial_printer.string_quote_style = printer.string_quote_style;
+    let mut trial_output: Vec<u8> = Vec::new();
+    print_array_helper(&mut trial_printer, &mut trial_output, factory, array);
+
+    // Only the first line of an element that spans lines follows the opening parenthesis.
+    let first_line_length = trial_output
+        .iter()
+        .position(|b| *b == b'\n')
+        .unwrap_or(trial_output.len());
+
+    // The closing parenthesis must also fit on the line.
+    if printer.column + first_line_length < printer.page_width {
+        print_array_helper(printer, stream, factory, array);
+        return;
+    }
+
+    printer.increase_indent();
+    print_array_helper_with_node_handler_and_space_formatter(
+        printer,
+        stream,
+        factory,
+        array,
+        |p, s, f, n| {
+            write_newline(p, s);
+            write_indent(p, s);
+            traverse_sub_node_and_print(p, s, f, n);
+        },
+        |p, s, _f| {
+            write_token(p, s, ",");
+        },
+    );
+    printer.decrease_indent();
+    write_newline(printer, stream);
+    write_indent(printer, stream);
+}
+
 /// Write the returnParameters node with a returns statement.
 ///
 /// # Arguments
@@ -253,6 +312,18 @@ fn unencode_string(s: &str) -> String {
     unencoded_s
 }
 
+/// Return the Solidity string literal for the string value `s`: `s` quoted by
+/// [`PrettyPrinter::quote_string`] with the escapes of [`unencode_string`].
+///
+/// # Arguments
+///
+/// * `printer` - The [`PrettyPrinter`] that selects the quote style.
+/// * `s` - The string value.
+fn string_literal(printer: &PrettyPrinter, s: &str) -> String {
+    // Quote first so that quoting does not escape the backslashes of the unencoded characters.
+    unencode_string(&printer.quote_string(s))
+}
+
 /// Return true if the string only contains ASCII digits and/or the '.' character.
 ///
 /// # Arguments
@@ -286,9 +357,13 @@ impl NodePrinter<SolidityAST> for SourceUnitPrinter {
         &mut self,
         printer: &mut PrettyPrinter,
         stream: &mut dyn Write,
-        _factory: &dyn NodePrinterFactory<SolidityAST>,
+        factory: &dyn NodePrinterFactory<SolidityAST>,
         node: &SolidityAST,
     ) {
+        if !factory.get_preference_value_for_key(EMIT_SPDX_LICENSE) {
+            return;
+        }
+
         if let Some(license) = node.get_str_for_key("license") {
             write_token(printer, stream, "//");
             write_space(printer, stream);
@@ -530,12 +605,19 @@ impl NodePrinter<SolidityAST> for ElementaryTypeNamePrinter {
         _factory: &dyn NodePrinterFactory<SolidityAST>,
         node: &SolidityAST,
     ) {
-        print_name_helper(printer, stream, node);
+        // Print the name exactly as the compiler wrote it so that types like ufixed128x18 keep
+        // their MxN suffix.  Only fall back to the canonical type string when the name is missing.
+        let name = node
+            .get_str_for_key("name")
+            .or_else(|| node.get_str_for_key("typeDescriptions/typeString"));
 
-        if let Some(mutability) = node.get_str_for_key("stateMutability") {
-            if mutability == "payable" {
+        if let Some(name) = name {
+            write_token(printer, stream, name);
+
+            // Only address types take the payable mutability.
+            if name == "address" && node.get_str_for_key("stateMutability") == Some("payable") {
                 write_space(printer, stream);
-                write_token(printer, stream, mutability);
+                write_token(printer, stream, "payable");
             }
         }
     }
@@ -666,22 +748,13 @@ impl NodePrinter<SolidityAST> for VariableDeclarationPrinter {
     }
 }
 
-struct FunctionDefinitionPrinter {
-    write_nonpayable_state_mutability: bool,
-}
-
-impl FunctionDefinitionPrinter {
-    /// Create a new function definition printer.
-    ///
-    /// # Arguments
-    ///
-    /// * `write_nonpayable_state_mutability` - True if the printer should write nonpayable.
-    pub fn new(write_nonpayable_state_mutability: bool) -> FunctionDefinitionPrinter {
-        FunctionDefinitionPrinter {
-            write_nonpayable_state_mutability,
-        }
-    }
-}
+/// The printer writes `nonpayable` when the factory setting for
+/// `write_nonpayable_state_mutability` is true.
+///
+/// Solidity requires `receive` and `fallback` functions to be `external` and `receive`
+/// functions to be `payable`, so the printer always writes `receive() external payable` and
+/// `fallback(...) external` regardless of the visibility and state mutability in the node.
+struct FunctionDefinitionPrinter {}
 
 impl NodePrinter<SolidityAST> for FunctionDefinitionPrinter {
     fn on_entry(
@@ -718,11 +791,22 @@ impl NodePrinter<SolidityAST> for FunctionDefinitionPrinter {
         factory: &dyn NodePrinterFactory<SolidityAST>,
         node: &SolidityAST,
     ) {
+        let kind = node.get_str_for_key("kind").unwrap_or_default();
+        let is_receive_or_fallback = kind == "receive" || kind == "fallback";
+
         if let Some(parameters) = node.get("parameters") {
             traverse_sub_node_and_print(printer, stream, factory, parameters);
+        } else if is_receive_or_fallback {
+            write_token(printer, stream, "()");
         }
 
-        if let Some(visibility) = node.get_str_for_key("visibility") {
+        let visibility = if is_receive_or_fallback {
+            Some("external")
+        } else {
+            node.get_str_for_key("visibility")
+        };
+
+        if let Some(visibility) = visibility {
             write_space(printer, stream);
             write_token(printer, stream, visibility);
             // if visibility != "internal" {
@@ -738,9 +822,17 @@ impl NodePrinter<SolidityAST> for FunctionDefinitionPrinter {
             }
         }
 
-        if let Some(state_mutability) = node.get_str_for_key("stateMutability") {
+        let state_mutability = if kind == "receive" {
+            Some("payable")
+        } else {
+            node.get_str_for_key("stateMutability")
+        };
+
+        if let Some(state_mutability) = state_mutability {
             let do_mutability: bool = match state_mutability {
-                "nonpayable" => self.write_nonpayable_state_mutability,
+                "nonpayable" => {
+                    factory.get_preference_value_for_key(WRITE_NONPAYABLE_STATE_MUTABILITY)
+                }
                 _ => true,
             };
             if do_mutability {
@@ -754,8 +846,12 @@ impl NodePrinter<SolidityAST> for FunctionDefinitionPrinter {
             traverse_sub_node_and_print(printer, stream, factory, overrides);
         }
 
-        if let Some(modifiers) = node.get("modifiers") {
-            print_space_and_array_helper(printer, stream, factory, modifiers);
+        // Solidity separates modifiers with whitespace, not commas.
+        if let Some(modifiers) = node.get_array_for_key("modifiers") {
+            for modifier in modifiers {
+                write_space(printer, stream);
+                traverse_sub_node_and_print(printer, stream, factory, modifier);
+            }
         }
 
         print_return_parameters_helper(printer, stream, factory, node);
@@ -795,7 +891,7 @@ impl NodePrinter<SolidityAST> for ParameterListPrinter {
     ) {
         if let Some(parameters) = node.get("parameters") {
             if let Some(parameters_array) = parameters.as_array() {
-                print_array_helper(printer, stream, factory, parameters_array);
+                print_wrappable_list_helper(printer, stream, factory, parameters_array);
             }
         }
     }
@@ -812,26 +908,12 @@ impl NodePrinter<SolidityAST> for ParameterListPrinter {
 }
 
 /// Used for both Block, UncheckedBlock, and YulBlock nodes.
-struct BlockPrinter {
-    /// When true, write a block with only one statement on the same line:
-    ///
-    /// `{ <statement>; }`
-    single_statement_on_same_line: bool,
-}
-
-impl BlockPrinter {
-    /// Create a new block printer.
-    ///
-    /// # Arguments
-    ///
-    /// * `single_statements_on_same_line` - If true, the printer will write blocks with one statement
-    /// without using indents or newlines.
-    fn new(single_statement_on_same_line: bool) -> BlockPrinter {
-        BlockPrinter {
-            single_statement_on_same_line,
-        }
-    }
-}
+///
+/// When the factory setting for `single_block_statements_on_same_line` is true, the printer
+/// writes a block with only one statement on the same line:
+///
+/// `{ <statement>; }`
+struct BlockPrinter {}
 
 impl NodePrinter<SolidityAST> for BlockPrinter {
     fn on_entry(
@@ -862,7 +944,7 @@ impl NodePrinter<SolidityAST> for BlockPrinter {
             stream,
             factory,
             node,
-            self.single_statement_on_same_line,
+            factory.get_preference_value_for_key(SINGLE_BLOCK_STATEMENTS_ON_SAME_LINE),
         );
     }
 
@@ -888,24 +970,24 @@ impl NodePrinter<SolidityAST> for VariableDeclarationStatementPrinter {
         node: &SolidityAST,
     ) {
         if let Some(declarations) = node.get("declarations") {
-            if let Some(declarations_array) = declarations.as_array() {
+            if let Some(declarations_array) = declarations.as_array().filter(|a| !a.is_empty()) {
                 if declarations_array.len() > 1 {
                     write_token(printer, stream, "(");
                 }
-                let mut i = 0;
-                while i < declarations_array.len() {
-                    if let Some(declaration) = declarations_array.get(i) {
+                // A null declaration is a skipped slot of the tuple, as in `(a, , c)`.  The slot
+                // prints as nothing between the commas, except that a skipped last slot does not
+                // leave a space before the closing parenthesis, as in `(a,)`.
+                let last = declarations_array.len() - 1;
+                for (i, declaration) in declarations_array.iter().enumerate() {
+                    if !declaration.is_null() {
                         traverse_sub_node_and_print(printer, stream, factory, declaration);
-                        if i < (declarations_array.len() - 1) {
-                            write_token(printer, stream, ",");
-                            if let Some(value) = declarations_array.get(i + 1) {
-                                if !value.is_null() {
-                                    write_space(printer, stream);
-                                }
-                            }
+                    }
+                    if i < last {
+                        write_token(printer, stream, ",");
+                        if i + 1 < last || !declarations_array[last].is_null() {
+                            write_space(printer, stream);
                         }
                     }
-                    i += 1;
                 }
                 if declarations_array.len() > 1 {
                     write_token(printer, stream, ")");
@@ -956,14 +1038,31 @@ impl NodePrinter<SolidityAST> for LiteralPrinter {
         _factory: &dyn NodePrinterFactory<SolidityAST>,
         node: &SolidityAST,
     ) {
-        if let Some(value) = node.get_str_for_key("value") {
+        if node.get_str_for_key("kind") == Some("hexString") {
+            // The compiler leaves `value` null when the bytes do not decode as UTF-8, so prefer
+            // the raw bytes in `hexValue`.
+            let hex_value = match node.get_str_for_key("hexValue") {
+                Some(hex_value) => String::from(hex_value),
+                None => node
+                    .get_str_for_key("value")
+                    .unwrap_or_default()
+                    .bytes()
+                    .map(|b| format!("{:02x}", b))
+                    .collect(),
+            };
+            write_token(printer, stream, "hex");
+            let quoted_value = printer.quote_string(&hex_value);
+            write_raw(printer, stream, &quoted_value);
+        } else if let Some(value) = node.get_str_for_key("value") {
             if let Some(kind) = node.get_str_for_key("kind") {
                 if kind == "string" {
-                    let unencoded_string = unencode_string(value);
-                    write_string(printer, stream, &unencoded_string);
+                    let literal = string_literal(printer, value);
+                    write_token(printer, stream, &literal);
                 } else if kind == "unicodeString" {
+                    // A line break between the prefix and the quoted value is a syntax error.
                     write_token(printer, stream, "unicode");
-                    write_string(printer, stream, value);
+                    let quoted_value = printer.quote_string(value);
+                    write_raw(printer, stream, &quoted_value);
                 } else {
                     write_token(printer, stream, value);
                 }
@@ -979,17 +1078,9 @@ impl NodePrinter<SolidityAST> for LiteralPrinter {
     }
 }
 
-struct ExpressionStatementPrinter {
-    write_statement_semicolon: bool,
-}
-
-impl ExpressionStatementPrinter {
-    pub fn new(write_statement_semicolon: bool) -> ExpressionStatementPrinter {
-        ExpressionStatementPrinter {
-            write_statement_semicolon,
-        }
-    }
-}
+/// The printer writes the terminating semicolon when the factory setting for
+/// `write_expression_statement_semicolon` is true.
+struct ExpressionStatementPrinter {}
 
 impl NodePrinter<SolidityAST> for ExpressionStatementPrinter {
     fn print_node(
@@ -1008,10 +1099,10 @@ impl NodePrinter<SolidityAST> for ExpressionStatementPrinter {
         &mut self,
         printer: &mut PrettyPrinter,
         stream: &mut dyn Write,
-        _factory: &dyn NodePrinterFactory<SolidityAST>,
+        factory: &dyn NodePrinterFactory<SolidityAST>,
         _node: &SolidityAST,
     ) {
-        if self.write_statement_semicolon {
+        if factory.get_preference_value_for_key(WRITE_EXPRESSION_STATEMENT_SEMICOLON) {
             write_token(printer, stream, ";");
         }
     }
@@ -1081,17 +1172,19 @@ impl NodePrinter<SolidityAST> for FunctionCallPrinter {
         if let Some(names_array) = node.get_array_for_key("names") {
             if !names_array.is_empty() {
                 if let Some(arguments_array) = node.get_array_for_key("arguments") {
-                    assert_eq!(
-                        names_array.len(),
-                        arguments_array.len(),
-                        "names and arguments arrays have different lengths in FunctionCallPrinter"
-                    );
+                    if names_array.len() != arguments_array.len() {
+                        log::info!(
+                            "names and arguments arrays have different lengths in FunctionCallPrinter"
+                        );
+                        print_arguments_helper(printer, stream, factory, node);
+                        return;
+                    }
                     write_token(printer, stream, "{");
                     printer.increase_indent();
                     let mut i: usize = 0;
                     while i < names_array.len() {
-                        let name = names_array.get(i).unwrap().as_str().unwrap();
-                        let arg = arguments_array.get(i).unwrap();
+                        let name = names_array[i].as_str().unwrap_or_default();
+                        let arg = &arguments_array[i];
                         write_newline(printer, stream);
                         write_indent(printer, stream);
                         write_token(printer, stream, name);
@@ -1649,8 +1742,10 @@ impl NodePrinter<SolidityAST> for ElementaryTypeNameExpressionPrinter {
         if let Some(pure) = node.get_bool_for_key("isPure") {
             if pure {
                 if let Some(type_name) = node.get("typeName") {
-                    if let Some(mutability) = type_name.get_str_for_key("stateMutability") {
-                        write_token(printer, stream, mutability);
+                    // payable(x) converts to address payable, every other conversion prints the
+                    // name of the type.
+                    if type_name.get_str_for_key("stateMutability") == Some("payable") {
+                        write_token(printer, stream, "payable");
                     } else {
                         traverse_sub_node_and_print(printer, stream, factory, type_name);
                     }
@@ -1709,6 +1804,11 @@ impl NodePrinter<SolidityAST> for CommentPrinter {
         factory: &dyn NodePrinterFactory<SolidityAST>,
         node: &SolidityAST,
     ) {
+        if factory.get_preference_value_for_key(BLOCK_COMMENTS_KEY) {
+            print_block_comment(printer, stream, factory, node);
+            return;
+        }
+
         write_token(printer, stream, "//");
         write_space(printer, stream);
 
@@ -1724,6 +1824,56 @@ impl NodePrinter<SolidityAST> for CommentPrinter {
     }
 }
 
+/// Write the comment `node` as a block comment.  The function writes each line of the
+/// commented-out code on its own line at the current indentation.
+///
+/// # Arguments
+///
+/// * `printer` - The [`PrettyPrinter`] object that writes formatted text to `stream`.
+/// * `stream` - The [`Write`] object that receives the formatted text.
+/// * `factory` - The [`SolidityNodePrinterFactory`] object that generates printer nodes.
+/// * `node` - The Comment node.
+fn print_block_comment(
+    printer: &mut PrettyPrinter,
+    stream: &mut dyn Write,
+    factory: &dyn NodePrinterFactory<SolidityAST>,
+    node: &SolidityAST,
+) {
+    write_token(printer, stream, "/*");
+    write_space(printer, stream);
+
+    if let Some(comment_text) = node.get_str_for_key("text") {
+        if !comment_text.is_empty() {
+            write_flowable_text(
+                printer,
+                stream,
+                &escape_block_comment_text(comment_text),
+                " * ",
+            );
+            write_space(printer, stream);
+        }
+    }
+
+    if let Some(value_node) = node.get("value") {
+        let mut value_contents: Vec<u8> = Vec::new();
+        let mut value_printer = PrettyPrinter::new(4, 150);
+        value_printer.string_quote_style = printer.string_quote_style;
+        traverse_sub_node_and_print(&mut value_printer, &mut value_contents, factory, value_node);
+        let value_text = escape_block_comment_text(&String::from_utf8_lossy(&value_contents));
+
+        for (index, line) in value_text.lines().enumerate() {
+            if index > 0 {
+                write_newline(printer, stream);
+                write_indent(printer, stream);
+            }
+            write_raw(printer, stream, line);
+        }
+        write_space(printer, stream);
+    }
+
+    write_token(printer, stream, "*/");
+}
+
 struct ErrorDefinitionPrinter {}
 
 impl NodePrinter<SolidityAST> for ErrorDefinitionPrinter {
@@ -1758,15 +1908,20 @@ impl NodePrinter<SolidityAST> for ForStatementPrinter {
     ) {
         // Include the '(' in the token so it doesn't line break to the next line.
         write_token(printer, stream, "for(");
-        if let Some(initialization_node) = node.get("initializationExpression") {
-            traverse_sub_node_and_print(printer, stream, factory, initialization_node);
+        // The initialization statement prints its own ';', an empty initialization still needs
+        // the separator.
+        match node.get("initializationExpression") {
+            Some(initialization_node) if !initialization_node.is_null() => {
+                traverse_sub_node_and_print(printer, stream, factory, initialization_node);
+            }
+            _ => write_token(printer, stream, ";"),
         }
-        if let Some(condition_node) = node.get("condition") {
+        if let Some(condition_node) = node.get("condition").filter(|n| !n.is_null()) {
             write_space(printer, stream);
             traverse_sub_node_and_print(printer, stream, factory, condition_node);
-            write_token(printer, stream, ";");
         }
-        if let Some(loop_expression_node) = node.get("loopExpression") {
+        write_token(printer, stream, ";");
+        if let Some(loop_expression_node) = node.get("loopExpression").filter(|n| !n.is_null()) {
             let mut printer_settings = factory.get_settings().clone();
             printer_settings.set_value_for_key(
                 WRITE_EXPRESSION_STATEMENT_SEMICOLON,
@@ -1851,8 +2006,8 @@ impl NodePrinter<SolidityAST> for InlineAssemblyPrinter {
                     while i < flags_array.len() {
                         if let Some(value) = flags_array.get(i) {
                             if let Some(value_str) = value.as_str() {
-                                let unencoded_string = unencode_string(value_str);
-                                write_string(printer, stream, &unencoded_string);
+                                let literal = string_literal(printer, value_str);
+                                write_token(printer, stream, &literal);
                             }
                         }
 
@@ -1978,7 +2133,8 @@ impl NodePrinter<SolidityAST> for YulLiteralPrinter {
                 }
             } else if kind_str == "string" {
                 if let Some(value_str) = node.get_str_for_key("value") {
-                    write_string(printer, stream, value_str);
+                    let literal = string_literal(printer, value_str);
+                    write_raw(printer, stream, &literal);
                 }
             }
         }
@@ -2232,18 +2388,19 @@ impl NodePrinter<SolidityAST> for FunctionCallOptionsPrinter {
 
         if let Some(names_array) = node.get_array_for_key("names") {
             if let Some(options_array) = node.get_array_for_key("options") {
-                assert_eq!(
-                    names_array.len(),
-                    options_array.len(),
-                    "names and options array lengths differ in FunctionCallOptionsPrinter"
-                );
+                if names_array.len() != options_array.len() {
+                    log::info!(
+                        "names and options array lengths differ in FunctionCallOptionsPrinter"
+                    );
+                    return;
+                }
 
                 if !names_array.is_empty() {
                     write_token(printer, stream, "{");
                     let mut i: usize = 0;
                     while i < names_array.len() {
-                        let name = names_array.get(i).unwrap().as_str().unwrap();
-                        let option = options_array.get(i).unwrap();
+                        let name = names_array[i].as_str().unwrap_or_default();
+                        let option = &options_array[i];
 
                         write_token(printer, stream, name);
                         write_token(printer, stream, ":");
@@ -2569,30 +2726,147 @@ impl NodePrinter<SolidityAST> for YulLeavePrinter {
     }
 }
 
+/// Write a Yul object and its sub-objects:
+///
+/// `object "<name>" { code { ... } <sub-objects> }`
+///
+/// # Arguments
+///
+/// * `printer` - The [`PrettyPrinter`] object that writes formatted text to `stream`.
+/// * `stream` - The [`Write`] object that receives the formatted text.
+/// * `factory` - The [`SolidityNodePrinterFactory`] object that generates printer nodes.
+/// * `node` - The YulObject node.
+fn print_yul_object_helper(
+    printer: &mut PrettyPrinter,
+    stream: &mut dyn Write,
+    factory: &dyn NodePrinterFactory<SolidityAST>,
+    node: &SolidityAST,
+) {
+    write_token(printer, stream, "object");
+    write_space(printer, stream);
+    if let Some(name) = node.get_str_for_key("name") {
+        write_string(printer, stream, name);
+        write_space(printer, stream);
+    }
+    write_token(printer, stream, "{");
+    printer.increase_indent();
+    if let Some(code_node) = node.get("code") {
+        write_newline(printer, stream);
+        write_indent(printer, stream);
+        traverse_sub_node_and_print(printer, stream, factory, code_node);
+    }
+    if let Some(sub_objects) = node.get_array_for_key("subObjects") {
+        for sub_object in sub_objects {
+            write_newline(printer, stream);
+            write_newline(printer, stream);
+            write_indent(printer, stream);
+            if sub_object.get_str_for_key("nodeType") == Some("YulObject") {
+                print_yul_object_helper(printer, stream, factory, sub_object);
+            } else {
+                traverse_sub_node_and_print(printer, stream, factory, sub_object);
+            }
+        }
+    }
+    printer.decrease_indent();
+    write_newline(printer, stream);
+    write_indent(printer, stream);
+    write_token(printer, stream, "}");
+}
+
+/// Printer for the top-level object of a standalone Yul program.  The printer writes nested
+/// objects with [`print_yul_object_helper`] so that only the top-level object ends with a
+/// newline.
+struct YulObjectPrinter {}
+
+impl NodePrinter<SolidityAST> for YulObjectPrinter {
+    fn print_node(
+        &mut self,
+        printer: &mut PrettyPrinter,
+        stream: &mut dyn Write,
+        factory: &dyn NodePrinterFactory<SolidityAST>,
+        node: &SolidityAST,
+    ) {
+        print_yul_object_helper(printer, stream, factory, node);
+        write_newline(printer, stream);
+    }
+}
+
+struct YulCodePrinter {}
+
+impl NodePrinter<SolidityAST> for YulCodePrinter {
+    fn print_node(
+        &mut self,
+        printer: &mut PrettyPrinter,
+        stream: &mut dyn Write,
+        factory: &dyn NodePrinterFactory<SolidityAST>,
+        node: &SolidityAST,
+    ) {
+        write_token(printer, stream, "code");
+        write_space(printer, stream);
+        if let Some(block_node) = node.get("block") {
+            traverse_sub_node_and_print(printer, stream, factory, block_node);
+        }
+    }
+}
+
+/// The compiler does not record the name of a data section in the AST, only its value.  Data
+/// sections in compiler output hold the contract metadata, so the printer falls back to the
+/// name the compiler uses for them.
+struct YulDataPrinter {}
+
+impl NodePrinter<SolidityAST> for YulDataPrinter {
+    fn print_node(
+        &mut self,
+        printer: &mut PrettyPrinter,
+        stream: &mut dyn Write,
+        _factory: &dyn NodePrinterFactory<SolidityAST>,
+        node: &SolidityAST,
+    ) {
+        write_token(printer, stream, "data");
+        write_space(printer, stream);
+        write_string(
+            printer,
+            stream,
+            node.get_str_for_key("name").unwrap_or(".metadata"),
+        );
+        write_space(printer, stream);
+        if let Some(value) = node.get_str_for_key("value") {
+            write_token(printer, stream, &(String::from("hex\"") + value + "\""));
+        }
+    }
+}
+
 static WRITE_EXPRESSION_STATEMENT_SEMICOLON: &str = "write_expression_statement_semicolon";
 static SINGLE_BLOCK_STATEMENTS_ON_SAME_LINE: &str = "single_block_statements_on_same_line";
 static WRITE_NONPAYABLE_STATE_MUTABILITY: &str = "write_nonpayable_state_mutability";
+static WRAP_LONG_ARGUMENT_LISTS: &str = "wrap_long_argument_lists";
+static EMIT_SPDX_LICENSE: &str = "emit_spdx_license";
 
 /// Type that implements [`NodePrinterFactory<AST>`] for Solidity nodes.
 ///
-/// Use this factory object with the [`crate::pretty_print_visitor::PrettyPrintVisitor<AST>`] object.
+/// Use this factory object with [`crate::node_printer::pretty_print_node`].
 #[derive(Clone)]
 pub struct SolidityNodePrinterFactory {
     pub settings: Preferences,
 }
 
 impl SolidityNodePrinterFactory {
-    /// Create a new node printer factory using `settings`.
+    /// Create a new node printer factory using `settings`.  The factory uses the default
+    /// pretty-printer settings for the settings missing from `settings`.
     ///
     /// # Arguments
     ///
     /// * `settings` - The pretty printer settings.
     pub fn new(settings: Preferences) -> SolidityNodePrinterFactory {
-        SolidityNodePrinterFactory { settings }
+        let mut factory = SolidityNodePrinterFactory::default();
+        factory.settings.merge(&settings);
+        factory
     }
+}
 
+impl Default for SolidityNodePrinterFactory {
     /// Create a new node printer factory using default pretty-printer settings.
-    pub fn default() -> SolidityNodePrinterFactory {
+    fn default() -> SolidityNodePrinterFactory {
         let mut preferences = Preferences::new();
         preferences.set_value_for_key(
             WRITE_EXPRESSION_STATEMENT_SEMICOLON,
@@ -2606,6 +2880,9 @@ impl SolidityNodePrinterFactory {
             WRITE_NONPAYABLE_STATE_MUTABILITY,
             PreferenceValue::Boolean(false),
         );
+        preferences.set_value_for_key(WRAP_LONG_ARGUMENT_LISTS, PreferenceValue::Boolean(true));
+        preferences.set_value_for_key(BLOCK_COMMENTS_KEY, PreferenceValue::Boolean(false));
+        preferences.set_value_for_key(EMIT_SPDX_LICENSE, PreferenceValue::Boolean(true));
 
         SolidityNodePrinterFactory {
             settings: preferences,
@@ -2613,6 +2890,38 @@ impl SolidityNodePrinterFactory {
     }
 }
 
+/// Return the node printer for the Yul node type `node_type`, or a [`DummyNodePrinter`] if
+/// `node_type` is not a Yul node type.  The Solidity and the Yul node printer factories share the
+/// Yul node printers.
+///
+/// # Arguments
+///
+/// * `node_type` - The value of the `nodeType` key of the node.
+pub(crate) fn yul_node_printer_for(node_type: &str) -> Box<dyn NodePrinter<SolidityAST>> {
+    match node_type {
+        "Comment" => Box::new(CommentPrinter {}),
+        "YulAssignment" => Box::new(YulAssignmentPrinter {}),
+        "YulBlock" => Box::new(BlockPrinter {}),
+        "YulBreak" => Box::new(YulBreakPrinter {}),
+        "YulCase" => Box::new(YulCasePrinter {}),
+        "YulCode" => Box::new(YulCodePrinter {}),
+        "YulContinue" => Box::new(YulContinuePrinter {}),
+        "YulData" => Box::new(YulDataPrinter {}),
+        "YulForLoop" => Box::new(YulForLoopPrinter {}),
+        "YulFunctionCall" => Box::new(YulFunctionCallPrinter {}),
+        "YulFunctionDefinition" => Box::new(YulFunctionDefinitionPrinter {}),
+        "YulIdentifier" => Box::new(NamePrinter {}),
+        "YulIf" => Box::new(YulIfPrinter {}),
+        "YulLeave" => Box::new(YulLeavePrinter {}),
+        "YulLiteral" => Box::new(YulLiteralPrinter {}),
+        "YulObject" => Box::new(YulObjectPrinter {}),
+        "YulSwitch" => Box::new(YulSwitchPrinter {}),
+        "YulTypedName" => Box::new(NamePrinter {}),
+        "YulVariableDeclaration" => Box::new(YulVariableDeclarationPrinter {}),
+        _ => Box::new(DummyNodePrinter {}),
+    }
+}
+
 impl NodePrinterFactory<SolidityAST> for SolidityNodePrinterFactory {
     fn printer_for(&self, node: &SolidityAST) -> Box<dyn NodePrinter<SolidityAST>> {
         if let Some(node_type) = node.get_str_for_key("nodeType") {
@@ -2620,9 +2929,7 @@ impl NodePrinterFactory<SolidityAST> for SolidityNodePrinterFactory {
                 "ArrayTypeName" => Box::new(ArrayTypeNamePrinter {}),
                 "Assignment" => Box::new(AssignmentPrinter {}),
                 "BinaryOperation" => Box::new(BinaryOperationPrinter {}),
-                "Block" => Box::new(BlockPrinter::new(
-                    <SolidityNodePrinterFactory as NodePrinterFactory<Value>>::get_preference_value_for_key(self, SINGLE_BLOCK_STATEMENTS_ON_SAME_LINE),
-                )),
+                "Block" => Box::new(BlockPrinter {}),
                 "Break" => Box::new(BreakPrinter {}),
                 "Comment" => Box::new(CommentPrinter {}),
                 "Conditional" => Box::new(ConditionalPrinter {}),
@@ -2636,15 +2943,11 @@ impl NodePrinterFactory<SolidityAST> for SolidityNodePrinterFactory {
                 "EnumValue" => Box::new(NamePrinter {}),
                 "ErrorDefinition" => Box::new(ErrorDefinitionPrinter {}),
                 "EventDefinition" => Box::new(EventDefinitionPrinter {}),
-                "ExpressionStatement" => Box::new(ExpressionStatementPrinter::new(
-                    <SolidityNodePrinterFactory as NodePrinterFactory<Value>>::get_preference_value_for_key(self, WRITE_EXPRESSION_STATEMENT_SEMICOLON),
-                )),
+                "ExpressionStatement" => Box::new(ExpressionStatementPrinter {}),
                 "ForStatement" => Box::new(ForStatementPrinter {}),
                 "FunctionCall" => Box::new(FunctionCallPrinter {}),
                 "FunctionCallOptions" => Box::new(FunctionCallOptionsPrinter {}),
-                "FunctionDefinition" => Box::new(FunctionDefinitionPrinter::new(
-                    <SolidityNodePrinterFactory as NodePrinterFactory<Value>>::get_preference_value_for_key(self, WRITE_NONPAYABLE_STATE_MUTABILITY),
-                )),
+                "FunctionDefinition" => Box::new(FunctionDefinitionPrinter {}),
                 "FunctionTypeName" => Box::new(FunctionTypeNamePrinter {}),
                 "Identifier" => Box::new(IdentifierPrinter {}),
                 "IdentifierPath" => Box::new(NamePrinter {}),
@@ -2672,9 +2975,7 @@ impl NodePrinterFactory<SolidityAST> for SolidityNodePrinterFactory {
                 "TryStatement" => Box::new(TryStatementPrinter {}),
                 "TupleExpression" => Box::new(TupleExpressionPrinter {}),
                 "UnaryOperation" => Box::new(UnaryOperationPrinter {}),
-                "UncheckedBlock" => Box::new(BlockPrinter::new(
-                    <SolidityNodePrinterFactory as NodePrinterFactory<Value>>::get_preference_value_for_key(self, SINGLE_BLOCK_STATEMENTS_ON_SAME_LINE),
-                )),
+                "UncheckedBlock" => Box::new(BlockPrinter {}),
                 "UserDefinedTypeName" => Box::new(UserDefinedTypeNamePrinter {}),
                 "UserDefinedValueTypeDefinition" => {
                     Box::new(UserDefinedValueTypeDefinitionPrinter {})
@@ -2683,24 +2984,7 @@ impl NodePrinterFactory<SolidityAST> for SolidityNodePrinterFactory {
                 "VariableDeclaration" => Box::new(VariableDeclarationPrinter {}),
                 "VariableDeclarationStatement" => Box::new(VariableDeclarationStatementPrinter {}),
                 "WhileStatement" => Box::new(WhileStatementPrinter {}),
-                "YulAssignment" => Box::new(YulAssignmentPrinter {}),
-                "YulBlock" => Box::new(BlockPrinter::new(
-                    <SolidityNodePrinterFactory as NodePrinterFactory<Value>>::get_preference_value_for_key(self, SINGLE_BLOCK_STATEMENTS_ON_SAME_LINE),
-                )),
-                "YulBreak" => Box::new(YulBreakPrinter {}),
-                "YulCase" => Box::new(YulCasePrinter {}),
-                "YulContinue" => Box::new(YulContinuePrinter {}),
-                "YulForLoop" => Box::new(YulForLoopPrinter {}),
-                "YulFunctionCall" => Box::new(YulFunctionCallPrinter {}),
-                "YulFunctionDefinition" => Box::new(YulFunctionDefinitionPrinter {}),
-                "YulIdentifier" => Box::new(NamePrinter {}),
-                "YulIf" => Box::new(YulIfPrinter {}),
-                "YulLeave" => Box::new(YulLeavePrinter {}),
-                "YulLiteral" => Box::new(YulLiteralPrinter {}),
-                "YulSwitch" => Box::new(YulSwitchPrinter {}),
-                "YulTypedName" => Box::new(NamePrinter {}),
-                "YulVariableDeclaration" => Box::new(YulVariableDeclarationPrinter {}),
-                _ => Box::new(DummyNodePrinter {}),
+                _ => yul_node_printer_for(node_type),
             }
         } else {
             Box::new(DummyNodePrinter {})
@@ -2715,6 +2999,8 @@ impl NodePrinterFactory<SolidityAST> for SolidityNodePrinterFactory {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::node_printer::OverridingNodePrinterFactory;
+    use crate::pretty_printer::StringQuoteStyle;
 
     #[test]
     fn test_contains_digits_and_or_dots() {
@@ -2723,4 +3009,614 @@ mod tests {
         assert_eq!(contains_digits_and_or_dots("23.f.234"), false);
         assert_eq!(contains_digits_and_or_dots(""), false);
     }
+
+    #[test]
+    fn test_block_comment_of_multiple_statements() {
+        let assignment = |name: &str, value: serde_json::Value| {
+            serde_json::json!({
+                "nodeType": "ExpressionStatement",
+                "expression": {
+                    "nodeType": "Assignment", "operator": "=",
+                    "leftHandSide": {"nodeType": "Identifier", "name": name},
+                    "rightHandSide": value
+                }
+            })
+        };
+        let node: SolidityAST = serde_json::json!({
+            "nodeType": "Comment",
+            "value": {
+                "nodeType": "Block",
+                "statements": [
+                    assignment("a", serde_json::json!({"nodeType": "Literal", "kind": "number", "value": "1"})),
+                    assignment("s", serde_json::json!({"nodeType": "Literal", "kind": "string", "value": "x */ y // z"}))
+                ]
+            }
+        });
+
+        let mut settings = Preferences::new();
+        settings.set_value_for_key(BLOCK_COMMENTS_KEY, PreferenceValue::Boolean(true));
+        let factory = SolidityNodePrinterFactory::new(settings);
+        let mut printer = PrettyPrinter::new(4, 150);
+        let mut output: Vec<u8> = Vec::new();
+        traverse_sub_node_and_print(&mut printer, &mut output, &factory, &node);
+        let output = String::from_utf8(output).unwrap();
+
+        // Each statement keeps its own line, and only the end of the comment closes the comment.
+        assert_eq!(output, "/* {\n    a = 1;\n    s = \"x * / y // z\";\n} */");
+        assert_eq!(output.matches("*/").count(), 1);
+        assert!(output.ends_with("*/"));
+
+        // Line comments remain the default.
+        let factory = SolidityNodePrinterFactory::default();
+        let mut printer = PrettyPrinter::new(4, 150);
+        let mut output: Vec<u8> = Vec::new();
+        traverse_sub_node_and_print(&mut printer, &mut output, &factory, &node);
+        assert!(String::from_utf8(output).unwrap().starts_with("// {"));
+    }
+
+    #[test]
+    fn test_fixed_point_elementary_type_names() {
+        let print = |node: SolidityAST| {
+            let mut printer = PrettyPrinter::new(4, 150);
+            let mut output: Vec<u8> = Vec::new();
+            let factory = SolidityNodePrinterFactory::default();
+            traverse_sub_node_and_print(&mut printer, &mut output, &factory, &node);
+            String::from_utf8(output).unwrap()
+        };
+        let type_name = |name: &str, type_string: &str| {
+            serde_json::json!({
+                "nodeType": "ElementaryTypeName", "name": name,
+                "typeDescriptions": {"typeIdentifier": "t_fixed", "typeString": type_string}
+            })
+        };
+
+        // The printer keeps the name as written even though the type string is canonical.
+        assert_eq!(
+            print(type_name("ufixed128x18", "ufixed128x18")),
+            "ufixed128x18"
+        );
+        assert_eq!(print(type_name("fixed", "fixed128x18")), "fixed");
+        assert_eq!(print(type_name("fixed8x1", "fixed8x1")), "fixed8x1");
+
+        let mut payable_fixed = type_name("ufixed", "ufixed128x18");
+        payable_fixed["stateMutability"] = serde_json::json!("payable");
+        assert_eq!(print(payable_fixed), "ufixed");
+
+        let mut unnamed = type_name("", "ufixed64x10");
+        unnamed.as_object_mut().unwrap().remove("name");
+        assert_eq!(print(unnamed), "ufixed64x10");
+
+        let address = |mutability: &str| {
+            serde_json::json!({
+                "nodeType": "ElementaryTypeName", "name": "address",
+                "stateMutability": mutability
+            })
+        };
+        assert_eq!(print(address("payable")), "address payable");
+        assert_eq!(print(address("nonpayable")), "address");
+
+        let conversion = |type_name: SolidityAST| {
+            serde_json::json!({
+                "nodeType": "FunctionCall",
+                "arguments": [{"nodeType": "Identifier", "name": "x"}],
+                "expression": {
+                    "nodeType": "ElementaryTypeNameExpression", "isPure": true,
+                    "typeName": type_name
+                }
+            })
+        };
+        assert_eq!(
+            print(conversion(type_name("ufixed128x18", "ufixed128x18"))),
+            "ufixed128x18(x)"
+        );
+        assert_eq!(print(conversion(address("payable"))), "payable(x)");
+        assert_eq!(print(conversion(address("nonpayable"))), "address(x)");
+    }
+
+    #[test]
+    fn test_omit_spdx_license() {
+        let node: SolidityAST = serde_json::json!({
+            "nodeType": "SourceUnit", "license": "MIT",
+            "nodes": [{
+                "nodeType": "PragmaDirective",
+                "literals": ["solidity", "^", "0.8", ".0"]
+            }]
+        });
+        let print = |factory: &SolidityNodePrinterFactory| {
+            let mut printer = PrettyPrinter::new(4, 150);
+            let mut output: Vec<u8> = Vec::new();
+            traverse_sub_node_and_print(&mut printer, &mut output, factory, &node);
+            String::from_utf8(output).unwrap()
+        };
+
+        let licensed = print(&SolidityNodePrinterFactory::default());
+        assert!(licensed.starts_with("// SPDX-License-Identifier: MIT\n"));
+
+        let mut settings = Preferences::new();
+        settings.set_value_for_key(EMIT_SPDX_LICENSE, PreferenceValue::Boolean(false));
+        let unlicensed = print(&SolidityNodePrinterFactory::new(settings));
+        assert!(!unlicensed.contains("SPDX"));
+        assert!(unlicensed.starts_with("pragma solidity"));
+        assert_eq!(
+            unlicensed,
+            licensed.trim_start_matches("// SPDX-License-Identifier: MIT\n")
+        );
+    }
+
+    #[test]
+    fn test_function_call_with_mismatched_names() {
+        let node: SolidityAST = serde_json::json!({
+            "nodeType": "FunctionCall",
+            "expression": {"nodeType": "Identifier", "name": "foo"},
+            "arguments": [
+                {"nodeType": "Literal", "kind": "number", "value": "1"},
+                {"nodeType": "Literal", "kind": "number", "value": "2"}
+            ],
+            "names": ["a"]
+        });
+        let mut printer = PrettyPrinter::new(4, 150);
+        let mut output: Vec<u8> = Vec::new();
+        let factory = SolidityNodePrinterFactory::default();
+        traverse_sub_node_and_print(&mut printer, &mut output, &factory, &node);
+        assert_eq!(String::from_utf8(output).unwrap(), "foo(1, 2)");
+    }
+
+    #[test]
+    fn test_function_call_with_long_argument_list() {
+        let identifier = |name: &str| serde_json::json!({"nodeType": "Identifier", "name": name});
+        let node: SolidityAST = serde_json::json!({
+            "nodeType": "FunctionCall",
+            "expression": identifier("transferFrom"),
+            "arguments": [
+                identifier("senderAddress"),
+                identifier("recipientAddress"),
+                identifier("amountToTransfer")
+            ]
+        });
+
+        let mut printer = PrettyPrinter::new(4, 40);
+        let mut output: Vec<u8> = Vec::new();
+        let factory = SolidityNodePrinterFactory::default();
+        traverse_sub_node_and_print(&mut printer, &mut output, &factory, &node);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "transferFrom(\n    senderAddress,\n    recipientAddress,\n    amountToTransfer\n)"
+        );
+
+        // The call fits in a wider page.
+        let mut printer = PrettyPrinter::new(4, 150);
+        let mut output: Vec<u8> = Vec::new();
+        traverse_sub_node_and_print(&mut printer, &mut output, &factory, &node);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "transferFrom(senderAddress, recipientAddress, amountToTransfer)"
+        );
+
+        // Without the setting, the printer only breaks lines between tokens.
+        let mut settings = factory.settings.clone();
+        settings.set_value_for_key(WRAP_LONG_ARGUMENT_LISTS, PreferenceValue::Boolean(false));
+        let factory = SolidityNodePrinterFactory::new(settings);
+        let mut printer = PrettyPrinter::new(4, 40);
+        let mut output: Vec<u8> = Vec::new();
+        traverse_sub_node_and_print(&mut printer, &mut output, &factory, &node);
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .starts_with("transferFrom(senderAddress, "));
+    }
+
+    #[test]
+    fn test_nested_function_calls_measure_each_list_once() {
+        // Counts the printers that the factory creates.
+        struct CountingFactory {
+            inner: SolidityNodePrinterFactory,
+            printers: std::cell::Cell<usize>,
+        }
+
+        impl NodePrinterFactory<SolidityAST> for CountingFactory {
+            fn printer_for(&self, node: &SolidityAST) -> Box<dyn NodePrinter<SolidityAST>> {
+                self.printers.set(self.printers.get() + 1);
+                self.inner.printer_for(node)
+            }
+
+            fn get_settings(&self) -> &Preferences {
+                self.inner.get_settings()
+            }
+        }
+
+        let depth = 20;
+        let mut node: SolidityAST = serde_json::json!({"nodeType": "Identifier", "name": "x"});
+        for _ in 0..depth {
+            node = serde_json::json!({
+                "nodeType": "FunctionCall",
+                "expression": {"nodeType": "Identifier", "name": "f"},
+                "arguments": [node]
+            });
+        }
+
+        let factory = CountingFactory {
+            inner: SolidityNodePrinterFactory::default(),
+            printers: std::cell::Cell::new(0),
+        };
+        let mut printer = PrettyPrinter::new(4, 150);
+        let mut output: Vec<u8> = Vec::new();
+        traverse_sub_node_and_print(&mut printer, &mut output, &factory, &node);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "f(".repeat(depth) + "x" + &")".repeat(depth)
+        );
+
+        // Each list prints the levels below it once to measure and once to write, rather than
+        // once per enclosing list.
+        let nodes = 2 * depth + 1;
+        assert!(
+            factory.printers.get() <= nodes * nodes,
+            "{}",
+            factory.printers.get()
+        );
+    }
+
+    #[test]
+    fn test_function_call_options_with_mismatched_names() {
+        let node: SolidityAST = serde_json::json!({
+            "nodeType": "FunctionCallOptions",
+            "expression": {"nodeType": "Identifier", "name": "foo"},
+            "options": [
+                {"nodeType": "Literal", "kind": "number", "value": "1"}
+            ],
+            "names": []
+        });
+        let mut printer = PrettyPrinter::new(4, 150);
+        let mut output: Vec<u8> = Vec::new();
+        let factory = SolidityNodePrinterFactory::default();
+        traverse_sub_node_and_print(&mut printer, &mut output, &factory, &node);
+
+        // The printer keeps the called expression and leaves out the options it cannot pair.
+        assert_eq!(String::from_utf8(output).unwrap(), "foo");
+    }
+
+    #[test]
+    fn test_function_definition_with_multiple_modifiers() {
+        let modifier = |name: &str| {
+            serde_json::json!({
+                "nodeType": "ModifierInvocation",
+                "modifierName": {"nodeType": "IdentifierPath", "name": name}
+            })
+        };
+        let node: SolidityAST = serde_json::json!({
+            "nodeType": "FunctionDefinition",
+            "kind": "function",
+            "name": "withdraw",
+            "visibility": "external",
+            "stateMutability": "nonpayable",
+            "implemented": true,
+            "parameters": {"nodeType": "ParameterList", "parameters": []},
+            "returnParameters": {"nodeType": "ParameterList", "parameters": []},
+            "modifiers": [modifier("nonReentrant"), modifier("onlyOwner")],
+            "body": {"nodeType": "Block", "statements": []}
+        });
+        let mut printer = PrettyPrinter::new(4, 150);
+        let mut output: Vec<u8> = Vec::new();
+        let factory = SolidityNodePrinterFactory::default();
+        traverse_sub_node_and_print(&mut printer, &mut output, &factory, &node);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "function withdraw() external nonReentrant onlyOwner {}"
+        );
+    }
+
+    #[test]
+    fn test_receive_function_definition() {
+        // The node has a name, visibility, and state mutability that Solidity does not allow
+        // for a receive function.
+        let node: SolidityAST = serde_json::json!({
+            "nodeType": "FunctionDefinition",
+            "kind": "receive",
+            "name": "receive",
+            "visibility": "public",
+            "stateMutability": "nonpayable",
+            "implemented": true,
+            "modifiers": [],
+            "body": {"nodeType": "Block", "statements": []}
+        });
+        let mut printer = PrettyPrinter::new(4, 150);
+        let mut output: Vec<u8> = Vec::new();
+        let factory = SolidityNodePrinterFactory::default();
+        traverse_sub_node_and_print(&mut printer, &mut output, &factory, &node);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "receive() external payable {}"
+        );
+    }
+
+    #[test]
+    fn test_payable_fallback_function_definition() {
+        let node: SolidityAST = serde_json::json!({
+            "nodeType": "FunctionDefinition",
+            "kind": "fallback",
+            "name": "",
+            "visibility": "public",
+            "stateMutability": "payable",
+            "implemented": true,
+            "parameters": {"nodeType": "ParameterList", "parameters": []},
+            "returnParameters": {"nodeType": "ParameterList", "parameters": []},
+            "modifiers": [],
+            "body": {"nodeType": "Block", "statements": []}
+        });
+        let mut printer = PrettyPrinter::new(4, 150);
+        let mut output: Vec<u8> = Vec::new();
+        let factory = SolidityNodePrinterFactory::default();
+        traverse_sub_node_and_print(&mut printer, &mut output, &factory, &node);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "fallback() external payable {}"
+        );
+    }
+
+    #[test]
+    fn test_for_statement_with_empty_clauses() {
+        let node: SolidityAST = serde_json::json!({
+            "nodeType": "ForStatement",
+            "condition": {
+                "nodeType": "BinaryOperation",
+                "operator": "<",
+                "leftExpression": {"nodeType": "Identifier", "name": "i"},
+                "rightExpression": {"nodeType": "Identifier", "name": "n"}
+            },
+            "body": {"nodeType": "Block", "statements": []}
+        });
+        let mut printer = PrettyPrinter::new(4, 150);
+        let mut output: Vec<u8> = Vec::new();
+        let factory = SolidityNodePrinterFactory::default();
+        traverse_sub_node_and_print(&mut printer, &mut output, &factory, &node);
+        assert_eq!(String::from_utf8(output).unwrap(), "for(; i < n;) {}");
+    }
+
+    #[test]
+    fn test_yul_object() {
+        let node: SolidityAST = serde_json::json!({
+            "nodeType": "YulObject",
+            "name": "A",
+            "code": {
+                "nodeType": "YulCode",
+                "block": {
+                    "nodeType": "YulBlock",
+                    "statements": [
+                        {"nodeType": "YulLeave"}
+                    ]
+                }
+            },
+            "subObjects": [
+                {
+                    "nodeType": "YulObject",
+                    "name": "B",
+                    "code": {
+                        "nodeType": "YulCode",
+                        "block": {"nodeType": "YulBlock", "statements": []}
+                    },
+                    "subObjects": []
+                },
+                {"nodeType": "YulData", "value": "ff"}
+            ]
+        });
+        let mut printer = PrettyPrinter::new(4, 150);
+        let mut output: Vec<u8> = Vec::new();
+        let factory = SolidityNodePrinterFactory::default();
+        traverse_sub_node_and_print(&mut printer, &mut output, &factory, &node);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "object \"A\" {\n    code {\n        leave\n    }\n\n    object \"B\" {\n        code {}\n    }\n\n    data \".metadata\" hex\"ff\"\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_variable_declaration_statement_with_skipped_slots() {
+        let declaration = |name: &str| {
+            serde_json::json!({
+                "nodeType": "VariableDeclaration", "name": name, "stateVariable": false,
+                "storageLocation": "default", "constant": false, "visibility": "internal",
+                "typeName": {"nodeType": "ElementaryTypeName", "name": "uint256"}
+            })
+        };
+        let print = |declarations: SolidityAST| {
+            let node: SolidityAST = serde_json::json!({
+                "nodeType": "VariableDeclarationStatement",
+                "declarations": declarations,
+                "initialValue": {
+                    "nodeType": "FunctionCall", "arguments": [],
+                    "expression": {"nodeType": "Identifier", "name": "f"}
+                }
+            });
+            let mut printer = PrettyPrinter::new(4, 150);
+            let mut output: Vec<u8> = Vec::new();
+            let factory = SolidityNodePrinterFactory::default();
+            traverse_sub_node_and_print(&mut printer, &mut output, &factory, &node);
+            String::from_utf8(output).unwrap()
+        };
+
+        assert_eq!(
+            print(serde_json::json!([null, declaration("b")])),
+            "(, uint256 b) = f();"
+        );
+        assert_eq!(
+            print(serde_json::json!([
+                declaration("a"),
+                null,
+                declaration("c")
+            ])),
+            "(uint256 a, , uint256 c) = f();"
+        );
+        assert_eq!(
+            print(serde_json::json!([
+                declaration("a"),
+                null,
+                null,
+                declaration("d")
+            ])),
+            "(uint256 a, , , uint256 d) = f();"
+        );
+        assert_eq!(
+            print(serde_json::json!([declaration("a"), null])),
+            "(uint256 a,) = f();"
+        );
+
+        // A malformed statement without declarations does not stop the printer.
+        assert_eq!(print(serde_json::json!([])), " = f();");
+    }
+
+    #[test]
+    fn test_overriding_node_printer_factory() {
+        // Prints identifiers in upper case.
+        struct UppercaseIdentifierPrinter {}
+
+        impl NodePrinter<SolidityAST> for UppercaseIdentifierPrinter {
+            fn print_node(
+                &mut self,
+                printer: &mut PrettyPrinter,
+                stream: &mut dyn Write,
+                _factory: &dyn NodePrinterFactory<SolidityAST>,
+                node: &SolidityAST,
+            ) {
+                if let Some(name) = node.get_str_for_key("name") {
+                    write_token(printer, stream, &name.to_uppercase());
+                }
+            }
+        }
+
+        let print = |factory: &dyn NodePrinterFactory<SolidityAST>, node: &SolidityAST| {
+            let mut printer = PrettyPrinter::new(4, 150);
+            let mut output: Vec<u8> = Vec::new();
+            traverse_sub_node_and_print(&mut printer, &mut output, factory, node);
+            String::from_utf8(output).unwrap()
+        };
+
+        let node: SolidityAST = serde_json::json!({
+            "nodeType": "ExpressionStatement",
+            "expression": {
+                "nodeType": "Assignment", "operator": "=",
+                "leftHandSide": {"nodeType": "Identifier", "name": "total"},
+                "rightHandSide": {
+                    "nodeType": "FunctionCall", "kind": "functionCall", "names": [],
+                    "expression": {
+                        "nodeType": "MemberAccess", "memberName": "add",
+                        "expression": {"nodeType": "Identifier", "name": "total"}
+                    },
+                    "arguments": [
+                        {"nodeType": "Literal", "kind": "string", "value": "fee"},
+                        {"nodeType": "Identifier", "name": "fee"}
+                    ]
+                }
+            }
+        });
+
+        let mut settings = Preferences::new();
+        settings.set_value_for_key(EMIT_SPDX_LICENSE, PreferenceValue::Boolean(false));
+        let factory = OverridingNodePrinterFactory::new(
+            Box::new(SolidityNodePrinterFactory::new(settings)),
+            |node| node.get_str_for_key("nodeType"),
+        )
+        .with_override("Identifier", |_node, _inner| {
+            Box::new(UppercaseIdentifierPrinter {})
+        });
+
+        // Only the identifiers change, including the identifiers inside other nodes.
+        let default_factory = SolidityNodePrinterFactory::default();
+        assert_eq!(
+            print(&default_factory, &node),
+            "total = total.add(\"fee\", fee);"
+        );
+        assert_eq!(print(&factory, &node), "TOTAL = TOTAL.add(\"fee\", FEE);");
+
+        // The overriding factory passes on the settings of the wrapped factory.
+        assert!(!factory.get_preference_value_for_key(EMIT_SPDX_LICENSE));
+    }
+
+    #[test]
+    fn test_string_quote_style() {
+        let literal: SolidityAST = serde_json::json!({
+            "nodeType": "Literal",
+            "kind": "string",
+            "value": "say \"hi\" and 'bye'"
+        });
+        let import: SolidityAST = serde_json::json!({
+            "nodeType": "ImportDirective",
+            "file": "./Token.sol",
+            "symbolAliases": [],
+            "unitAlias": ""
+        });
+        let factory = SolidityNodePrinterFactory::default();
+
+        let print = |style: StringQuoteStyle, node: &SolidityAST| {
+            let mut printer = PrettyPrinter::new(4, 150);
+            printer.string_quote_style = style;
+            let mut output: Vec<u8> = Vec::new();
+            traverse_sub_node_and_print(&mut printer, &mut output, &factory, node);
+            String::from_utf8(output).unwrap()
+        };
+
+        assert_eq!(
+            print(StringQuoteStyle::Double, &literal),
+            r#""say \"hi\" and 'bye'""#
+        );
+        assert_eq!(
+            print(StringQuoteStyle::Single, &literal),
+            r#"'say "hi" and \'bye\''"#
+        );
+        assert_eq!(
+            print(StringQuoteStyle::Double, &import),
+            "import \"./Token.sol\";"
+        );
+        assert_eq!(
+            print(StringQuoteStyle::Single, &import),
+            "import './Token.sol';"
+        );
+
+        // Backslashes stay distinct from the escapes of quotes and control characters.
+        let literal: SolidityAST = serde_json::json!({
+            "nodeType": "Literal",
+            "kind": "string",
+            "value": "a\\\"b\n"
+        });
+        assert_eq!(print(StringQuoteStyle::Double, &literal), r#""a\\\"b\n""#);
+        let yul_literal: SolidityAST = serde_json::json!({
+            "nodeType": "YulLiteral",
+            "kind": "string",
+            "value": "it's \"x\"\\"
+        });
+        assert_eq!(
+            print(StringQuoteStyle::Double, &yul_literal),
+            r#""it's \"x\"\\""#
+        );
+        assert_eq!(
+            print(StringQuoteStyle::Single, &yul_literal),
+            r#"'it\'s "x"\\'"#
+        );
+    }
+
+    #[test]
+    fn test_hex_string_literal() {
+        let factory = SolidityNodePrinterFactory::default();
+        let print = |node: &SolidityAST| {
+            let mut printer = PrettyPrinter::new(4, 150);
+            let mut output: Vec<u8> = Vec::new();
+            traverse_sub_node_and_print(&mut printer, &mut output, &factory, node);
+            String::from_utf8(output).unwrap()
+        };
+
+        // The bytes do not decode as UTF-8, so the compiler leaves `value` null.
+        let literal: SolidityAST = serde_json::json!({
+            "nodeType": "Literal",
+            "kind": "hexString",
+            "hexValue": "deadbeef",
+            "value": null
+        });
+        assert_eq!(print(&literal), r#"hex"deadbeef""#);
+
+        // Without `hexValue` the printer encodes the decoded string.
+        let literal: SolidityAST = serde_json::json!({
+            "nodeType": "Literal",
+            "kind": "hexString",
+            "value": "AB"
+        });
+        assert_eq!(print(&literal), r#"hex"4142""#);
+    }
 }
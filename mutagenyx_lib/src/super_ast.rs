@@ -1,15 +1,209 @@
 //! The `super_ast` module contains the SuperAST enumeration which allows a more abstract,
 //! `generic` wrapper of a language specific abstract syntax tree.
 
+use crate::error::MutagenyxError;
 use crate::solidity::ast::SolidityAST;
 use crate::vyper::ast::VyperAST;
+use crate::yul::ast::YulAST;
 use crate::Language;
+use serde_json::Value;
 use std::cmp::PartialEq;
+use std::collections::BTreeSet;
 
 /// The SuperAST enumeration that encapsulates all the known types of language AST objects.
 pub enum SuperAST {
     Solidity(SolidityAST),
     Vyper(VyperAST),
+    Yul(YulAST),
+}
+
+/// One difference between two abstract syntax trees, as reported by [`SuperAST::diff`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodeDiff {
+    /// The id of the innermost node with an id that contains the difference, None if no node
+    /// with an id contains the difference.
+    pub node_id: Option<u64>,
+
+    /// The location of the difference as a JSON pointer from the root of the tree, for example
+    /// `/nodes/0/body/statements/1/operator`.  Use [`Value::pointer`] to read the values.
+    pub path: String,
+
+    /// The value in the first tree, None if only the second tree has the value.
+    pub left: Option<Value>,
+
+    /// The value in the second tree, None if only the first tree has the value.
+    pub right: Option<Value>,
+}
+
+impl NodeDiff {
+    /// Return a new difference with copies of the values.
+    fn new(
+        node_id: Option<u64>,
+        path: String,
+        left: Option<&Value>,
+        right: Option<&Value>,
+    ) -> Self {
+        NodeDiff {
+            node_id,
+            path,
+            left: left.cloned(),
+            right: right.cloned(),
+        }
+    }
+}
+
+/// Append the differences between `left` and `right` to `diffs`.
+///
+/// # Arguments
+///
+/// * `left` - The value from the first tree.
+/// * `right` - The value from the second tree.
+/// * `path` - The JSON pointer of the values.
+/// * `node_id` - The id of the innermost node with an id that contains the values.
+/// * `id_key` - The key of the node id in the language of the trees.
+/// * `diffs` - The list of differences.
+fn diff_values(
+    left: &Value,
+    right: &Value,
+    path: &str,
+    node_id: Option<u64>,
+    id_key: &str,
+    diffs: &mut Vec<NodeDiff>,
+) {
+    match (left, right) {
+        (Value::Object(left_map), Value::Object(right_map)) => {
+            let node_id = left_map.get(id_key).and_then(|id| id.as_u64()).or(node_id);
+
+            // Compare the entries by key, so the order of the keys does not matter.
+            let keys: BTreeSet<&String> = left_map.keys().chain(right_map.keys()).collect();
+            for key in keys {
+                let key_path = format!("{}/{}", path, key.replace('~', "~0").replace('/', "~1"));
+                match (left_map.get(key), right_map.get(key)) {
+                    (Some(l), Some(r)) => diff_values(l, r, &key_path, node_id, id_key, diffs),
+                    (l, r) => diffs.push(NodeDiff::new(node_id, key_path, l, r)),
+                }
+            }
+        }
+        (Value::Array(left_array), Value::Array(right_array)) => {
+            for i in 0..left_array.len().max(right_array.len()) {
+                let index_path = format!("{}/{}", path, i);
+                match (left_array.get(i), right_array.get(i)) {
+                    (Some(l), Some(r)) => diff_values(l, r, &index_path, node_id, id_key, diffs),
+                    (l, r) => diffs.push(NodeDiff::new(node_id, index_path, l, r)),
+                }
+            }
+        }
+        _ => {
+            if left != right {
+                diffs.push(NodeDiff::new(
+                    node_id,
+                    String::from(path),
+                    Some(left),
+                    Some(right),
+                ));
+            }
+        }
+    }
+}
+
+/// Remove the comment nodes that the mutators insert next to mutated nodes from the arrays in
+/// `value`.  The compilers do not define a comment node type.
+///
+/// # Arguments
+///
+/// * `value` - The JSON value of the tree, or of a node of the tree.
+fn remove_comment_nodes(value: &mut Value) {
+    let is_comment_node = |v: &Value| {
+        v.get("nodeType").and_then(|t| t.as_str()) == Some("Comment")
+            || v.get("ast_type").and_then(|t| t.as_str()) == Some("Comment")
+    };
+    match value {
+        Value::Object(map) => map.values_mut().for_each(remove_comment_nodes),
+        Value::Array(array) => {
+            array.retain(|v| !is_comment_node(v));
+            array.iter_mut().for_each(remove_comment_nodes);
+        }
+        _ => {}
+    }
+}
+
+impl SuperAST {
+    /// Return the [`Language`] of the abstract syntax tree contained in the SuperAST.
+    pub fn language(&self) -> Language {
+        match self {
+            SuperAST::Solidity(_) => Language::Solidity,
+            SuperAST::Vyper(_) => Language::Vyper,
+            SuperAST::Yul(_) => Language::Yul,
+        }
+    }
+
+    /// Return the abstract syntax tree contained in the SuperAST as JSON text.  The text keeps
+    /// every key of the tree, so language tools can read the text like the original AST.  The
+    /// text leaves out the comment nodes that the mutators insert next to mutated nodes, and the
+    /// statements that a mutation turns into comments.
+    ///
+    /// # Arguments
+    ///
+    /// * `pretty` - True to indent the JSON text and false to write compact text.
+    pub fn to_json_string(&self, pretty: bool) -> Result<String, MutagenyxError> {
+        let mut value = self.json_value().clone();
+        remove_comment_nodes(&mut value);
+        let text = if pretty {
+            serde_json::to_string_pretty(&value)?
+        } else {
+            serde_json::to_string(&value)?
+        };
+        Ok(text)
+    }
+
+    /// Return true if `other` holds an abstract syntax tree of the same language with the same
+    /// structure and values.  The function is the same comparison as `==`: the order of the keys
+    /// in the nodes does not matter to either.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The tree to compare.
+    pub fn structural_eq(&self, other: &SuperAST) -> bool {
+        self == other
+    }
+
+    /// Return the differences between the tree and the tree in `other`, in tree order.  Each
+    /// difference names the innermost node with an id that contains the difference and the
+    /// location of the difference in the tree.  The order of the keys in the nodes does not
+    /// matter.  A key or array element that only one tree has is a difference with a missing
+    /// value on the other side.  Trees of different languages differ at the root.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The tree to compare.
+    pub fn diff(&self, other: &SuperAST) -> Vec<NodeDiff> {
+        let mut diffs: Vec<NodeDiff> = Vec::new();
+        match (self, other) {
+            (SuperAST::Solidity(left), SuperAST::Solidity(right))
+            | (SuperAST::Yul(left), SuperAST::Yul(right)) => {
+                diff_values(left, right, "", None, "id", &mut diffs)
+            }
+            (SuperAST::Vyper(left), SuperAST::Vyper(right)) => {
+                diff_values(left, right, "", None, "node_id", &mut diffs)
+            }
+            (left, right) => diffs.push(NodeDiff::new(
+                None,
+                String::new(),
+                Some(left.json_value()),
+                Some(right.json_value()),
+            )),
+        }
+        diffs
+    }
+
+    /// Return the JSON value of the abstract syntax tree.
+    fn json_value(&self) -> &Value {
+        match self {
+            SuperAST::Solidity(ast) => ast,
+            SuperAST::Vyper(ast) => ast,
+            SuperAST::Yul(ast) => ast,
+        }
+    }
 }
 
 impl PartialEq for SuperAST {
@@ -23,6 +217,10 @@ impl PartialEq for SuperAST {
                 SuperAST::Vyper(other_ast) => ast == other_ast,
                 _ => false,
             },
+            SuperAST::Yul(ast) => match other {
+                SuperAST::Yul(other_ast) => ast == other_ast,
+                _ => false,
+            },
         }
     }
 }
@@ -33,8 +231,126 @@ impl PartialEq for SuperAST {
 ///
 /// * `ast` - A reference to an AST.
 pub fn language_for_ast(ast: &SuperAST) -> Language {
-    match ast {
-        SuperAST::Solidity(_) => Language::Solidity,
-        SuperAST::Vyper(_) => Language::Vyper,
+    ast.language()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_super_ast_language() {
+        assert_eq!(SuperAST::Solidity(json![{}]).language(), Language::Solidity);
+        assert_eq!(SuperAST::Vyper(json![{}]).language(), Language::Vyper);
+        assert_eq!(SuperAST::Yul(json![{}]).language(), Language::Yul);
+    }
+
+    #[test]
+    fn test_super_ast_to_json_string() {
+        let input = r#"{"nodeType": "SourceUnit", "id": 3, "absolutePath": "a.sol",
+            "exportedSymbols": {"C": [2]}, "license": null, "nodes": [
+            {"nodeType": "ContractDefinition", "id": 2, "name": "C", "nodes": [],
+             "src": "0:13:0", "abstract": false, "baseContracts": []}]}"#;
+        let ast = SuperAST::Solidity(serde_json::from_str(input).unwrap());
+
+        let input_value: serde_json::Value = serde_json::from_str(input).unwrap();
+        for pretty in [true, false] {
+            let text = ast.to_json_string(pretty).unwrap();
+            let output_value: serde_json::Value = serde_json::from_str(&text).unwrap();
+            assert_eq!(output_value, input_value);
+            assert_eq!(text.contains('\n'), pretty);
+        }
+
+        // The text leaves out the comment nodes of a mutant.
+        let mut mutant_value = input_value.clone();
+        let comment = serde_json::json!({"nodeType": "Comment", "id": 9999997, "text": "x"});
+        mutant_value["nodes"]
+            .as_array_mut()
+            .unwrap()
+            .insert(0, comment);
+        mutant_value["nodes"][1]["nodes"] = serde_json::json!([{
+            "ast_type": "Comment", "node_id": 9999996, "value": "y"
+        }]);
+        let mutant = SuperAST::Solidity(mutant_value);
+        let text = mutant.to_json_string(false).unwrap();
+        let output_value: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(output_value, input_value);
+    }
+
+    /// Return a Solidity AST with the statement `a + b;` followed by `extra` statements.
+    fn addition_ast(operator: &str, extra: Vec<serde_json::Value>) -> SuperAST {
+        let mut statements = vec![json!({
+            "nodeType": "ExpressionStatement", "id": 10, "expression": {
+                "nodeType": "BinaryOperation", "id": 11, "operator": operator,
+                "leftExpression": {"nodeType": "Identifier", "id": 12, "name": "a"},
+                "rightExpression": {"nodeType": "Identifier", "id": 13, "name": "b"}
+            }
+        })];
+        statements.extend(extra);
+        SuperAST::Solidity(json!({
+            "nodeType": "Block", "id": 1, "statements": statements
+        }))
+    }
+
+    #[test]
+    fn test_structural_eq_identical_asts() {
+        let ast = addition_ast("+", vec![]);
+        assert!(ast.structural_eq(&addition_ast("+", vec![])));
+        assert!(ast.diff(&addition_ast("+", vec![])).is_empty());
+
+        // The order of the keys does not matter.
+        let reordered: serde_json::Value = serde_json::from_str(
+            r#"{"statements": [{"expression": {"rightExpression": {"name": "b", "id": 13,
+            "nodeType": "Identifier"}, "operator": "+", "leftExpression": {"name": "a", "id": 12,
+            "nodeType": "Identifier"}, "id": 11, "nodeType": "BinaryOperation"}, "id": 10,
+            "nodeType": "ExpressionStatement"}], "id": 1, "nodeType": "Block"}"#,
+        )
+        .unwrap();
+        assert!(ast.structural_eq(&SuperAST::Solidity(reordered)));
+
+        // The same tree in a different language is not equal.
+        assert!(!SuperAST::Solidity(json!({})).structural_eq(&SuperAST::Vyper(json!({}))));
+    }
+
+    #[test]
+    fn test_diff_operator_change() {
+        let diffs = addition_ast("+", vec![]).diff(&addition_ast("-", vec![]));
+        assert_eq!(
+            diffs,
+            vec![NodeDiff {
+                node_id: Some(11),
+                path: String::from("/statements/0/expression/operator"),
+                left: Some(json!("+")),
+                right: Some(json!("-")),
+            }]
+        );
+        assert!(!addition_ast("+", vec![]).structural_eq(&addition_ast("-", vec![])));
+    }
+
+    #[test]
+    fn test_diff_structural_addition() {
+        let statement = json!({"nodeType": "Break", "id": 20});
+        let diffs = addition_ast("+", vec![]).diff(&addition_ast("+", vec![statement.clone()]));
+        assert_eq!(
+            diffs,
+            vec![NodeDiff {
+                node_id: Some(1),
+                path: String::from("/statements/1"),
+                left: None,
+                right: Some(statement),
+            }]
+        );
+
+        // Vyper nodes use the node_id key.
+        let left = SuperAST::Vyper(json!({"ast_type": "Module", "node_id": 0, "body": []}));
+        let right = SuperAST::Vyper(
+            json!({"ast_type": "Module", "node_id": 0, "body": [], "doc_string": "d"}),
+        );
+        let diffs = left.diff(&right);
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(diffs[0].node_id, Some(0));
+        assert_eq!(diffs[0].path, "/doc_string");
+        assert_eq!(diffs[0].left, None);
     }
 }
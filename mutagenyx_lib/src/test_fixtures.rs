@@ -0,0 +1,171 @@
+//! The `test_fixtures` module provides the ASTs and helper functions that the tests of several
+//! modules share.
+
+use crate::compiler_details::{COMPILER_KEY, PATH_KEY};
+use crate::language::Language;
+use crate::language_interface::{LanguageInterface, MutateASTResult};
+use crate::mutation::MutationType;
+use crate::permissions::{Permission, PermissionAction, PermissionScope, Permissions};
+use crate::preferences::Preferences;
+use crate::super_ast::SuperAST;
+use rand::SeedableRng;
+use rand_pcg::Pcg64;
+use serde_json::{json, Value};
+
+/// Return the AST of a contract with one function whose body holds `statements`.
+///
+/// # Arguments
+///
+/// * `statements` - The JSON array of the statements of the function body.
+pub fn solidity_ast_with_statements(statements: Value) -> Value {
+    json!({
+        "nodeType": "SourceUnit", "id": 100, "absolutePath": "sample.sol", "nodes": [{
+            "nodeType": "ContractDefinition", "id": 50, "name": "C",
+            "contractKind": "contract", "abstract": false, "baseContracts": [], "nodes": [{
+                "nodeType": "FunctionDefinition", "id": 40, "name": "add",
+                "kind": "function", "visibility": "public", "stateMutability": "pure",
+                "virtual": false, "implemented": true, "modifiers": [],
+                "parameters": {"nodeType": "ParameterList", "id": 10, "parameters": []},
+                "returnParameters": {"nodeType": "ParameterList", "id": 11, "parameters": []},
+                "body": {"nodeType": "Block", "id": 30, "statements": statements}
+            }]
+        }]
+    })
+}
+
+/// Return permissions that allow visiting and mutating every node.
+pub fn all_permissions() -> Permissions {
+    let mut permissions = Permissions::new();
+    permissions.add_permission(PermissionAction::Mutate(PermissionScope::Any(
+        Permission::Allow,
+    )));
+    permissions.add_permission(PermissionAction::Visit(PermissionScope::Any(
+        Permission::Allow,
+    )));
+    permissions
+}
+
+/// Mutate the node numbered `index` among the nodes of `ast` that `mutation_type` can mutate
+/// the way the mutation generator does, including the insertion of the mutation comment.
+///
+/// # Arguments
+///
+/// * `language` - The language of the AST.
+/// * `ast` - The AST to mutate.
+/// * `mutation_type` - The mutation algorithm.
+/// * `index` - The index of the mutable node.
+/// * `seed` - The seed of the random number generator.
+pub fn mutate_ast_node(
+    language: Language,
+    ast: &SuperAST,
+    mutation_type: MutationType,
+    index: usize,
+    seed: u64,
+) -> MutateASTResult {
+    let permissions = all_permissions();
+    let mut language_object =
+        LanguageInterface::get_language_object_for_language(&language).expect("language object");
+    language_object
+        .select_mutators_for_mutation_types(&[mutation_type])
+        .expect("mutators");
+    let path_map = language_object
+        .calculate_node_paths(ast, &permissions)
+        .expect("node paths");
+    let mut rng = Pcg64::seed_from_u64(seed);
+    language_object
+        .mutate_ast(
+            ast,
+            &mutation_type,
+            index,
+            &mut rng,
+            &permissions,
+            &path_map,
+        )
+        .expect("mutate_ast")
+}
+
+/// Return the texts of the mutation comment nodes in `value`.
+///
+/// # Arguments
+///
+/// * `value` - The JSON AST, or a node of the AST.
+pub fn comment_texts(value: &Value) -> Vec<String> {
+    let mut texts: Vec<String> = Vec::new();
+    match value {
+        Value::Object(map) => {
+            let is_comment = map.get("nodeType").and_then(|t| t.as_str()) == Some("Comment")
+                || map.get("ast_type").and_then(|t| t.as_str()) == Some("Comment");
+            if is_comment {
+                for key in ["text", "value"] {
+                    if let Some(text) = map.get(key).and_then(|t| t.as_str()) {
+                        texts.push(String::from(text));
+                    }
+                }
+            }
+            for child in map.values() {
+                texts.extend(comment_texts(child));
+            }
+        }
+        Value::Array(array) => {
+            for child in array {
+                texts.extend(comment_texts(child));
+            }
+        }
+        _ => {}
+    }
+    texts
+}
+
+/// Return the JSON value of a Solidity or Vyper `ast`.
+///
+/// # Arguments
+///
+/// * `ast` - The AST.
+pub fn json_value(ast: &SuperAST) -> &Value {
+    match ast {
+        SuperAST::Solidity(v) | SuperAST::Vyper(v) | SuperAST::Yul(v) => v,
+    }
+}
+
+/// Write the shell script `body` to the executable file `name` in `directory` and return the
+/// path of the file.  Tests use the script as a stand-in for a compiler.
+///
+/// # Arguments
+///
+/// * `directory` - The directory that receives the script.
+/// * `name` - The file name of the script.
+/// * `body` - The commands of the script, without the `#!/bin/sh` line.
+#[cfg(unix)]
+pub fn install_stub_compiler(
+    directory: &std::path::Path,
+    name: &str,
+    body: &str,
+) -> std::path::PathBuf {
+    use std::os::unix::fs::PermissionsExt;
+
+    let stub = directory.join(name);
+    std::fs::write(&stub, format!("#!/bin/sh\n{body}")).unwrap();
+    std::fs::set_permissions(&stub, std::fs::Permissions::from_mode(0o755)).unwrap();
+    stub
+}
+
+/// Return the preferences that select the compiler at `compiler` for `language`, along with
+/// the other compiler settings in `compiler_preferences`.
+///
+/// # Arguments
+///
+/// * `language` - The language of the compiler.
+/// * `compiler` - The path of the compiler.
+/// * `compiler_preferences` - The other compiler settings.
+pub fn compiler_preferences(
+    language: Language,
+    compiler: &str,
+    mut compiler_preferences: Preferences,
+) -> Preferences {
+    compiler_preferences.set_string_for_key(PATH_KEY, compiler);
+    let mut language_preferences = Preferences::new();
+    language_preferences.set_preferences_for_key(COMPILER_KEY, compiler_preferences);
+    let mut preferences = Preferences::new();
+    preferences.set_preferences_for_key(&language.to_string(), language_preferences);
+    preferences
+}
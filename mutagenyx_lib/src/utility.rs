@@ -1,8 +1,14 @@
 //! The `utility` module contains various utility functions such as `shell_execute` used by
 //! other parts of the library.
 
+use crate::compiler_details::*;
 use crate::error::MutagenyxError;
-use std::process::{Command, Output};
+use crate::language::Language;
+use crate::preferences::Preferences;
+use std::io::Read;
+use std::process::{Command, Output, Stdio};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
 
 /// Execute a command using the shell facility on the computer.
 ///
@@ -29,3 +35,301 @@ pub fn shell_execute(command: &str, arguments: Vec<String>) -> Result<Output, Mu
         Err(e) => Err(MutagenyxError::from(e)),
     }
 }
+
+/// Return a thread that reads `pipe` to the end.
+///
+/// # Arguments
+///
+/// * `pipe` - The stdout or stderr pipe of a child process.
+fn read_pipe<R: Read + Send + 'static>(pipe: Option<R>) -> JoinHandle<Vec<u8>> {
+    std::thread::spawn(move || {
+        let mut contents: Vec<u8> = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut contents);
+        }
+        contents
+    })
+}
+
+/// Execute a command like [`shell_execute`], but stop the command if the command runs for longer
+/// than `timeout`.  Return None if the function stopped the command.
+///
+/// # Arguments
+///
+/// * `command` - The command to execute.
+/// * `arguments` - The array of arguments to the command.
+/// * `timeout` - The time limit of the command, None to wait until the command finishes.
+pub fn shell_execute_with_timeout(
+    command: &str,
+    arguments: Vec<String>,
+    timeout: Option<Duration>,
+) -> Result<Option<Output>, MutagenyxError> {
+    let timeout = match timeout {
+        Some(t) => t,
+        None => return shell_execute(command, arguments).map(Some),
+    };
+
+    let mut process = if cfg!(target_os = "windows") {
+        let mut process = Command::new("cmd");
+        process.arg("/C").arg(command);
+        process
+    } else {
+        Command::new(command)
+    };
+    let mut child = process
+        .args(&arguments)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // Read the pipes while waiting so that a command that fills a pipe does not stop.
+    let stdout_reader = read_pipe(child.stdout.take());
+    let stderr_reader = read_pipe(child.stderr.take());
+
+    let deadline = Instant::now() + timeout;
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(Output {
+                status,
+                stdout: stdout_reader.join().unwrap_or_default(),
+                stderr: stderr_reader.join().unwrap_or_default(),
+            }));
+        }
+
+        if Instant::now() >= deadline {
+            // Processes started by the command may keep the pipes open, so do not wait for the
+            // reading threads.  The threads finish when the last process closes the pipes.
+            let _ = child.kill();
+            let _ = child.wait();
+            return Ok(None);
+        }
+
+        std::thread::sleep(Duration::from_millis(10));
+    }
+}
+
+/// Execute `compiler` with `arguments` to compile `file_name` and return the output of the
+/// compiler.
+///
+/// # Arguments
+///
+/// * `compiler` - The compiler to execute.
+/// * `arguments` - The array of arguments to the compiler.
+/// * `file_name` - The path to the source file that the compiler compiles.
+///
+/// # Errors
+///
+/// The function returns [`MutagenyxError::CompilerInvocation`] if the compiler does not start or
+/// exits with a failure status.
+pub fn compiler_execute(
+    compiler: &str,
+    arguments: Vec<String>,
+    file_name: &str,
+) -> Result<Output, MutagenyxError> {
+    compiler_execute_with_timeout(compiler, arguments, file_name, None)
+}
+
+/// Execute `compiler` like [`compiler_execute`], but stop the compiler if the compiler runs for
+/// longer than `timeout`.
+///
+/// # Arguments
+///
+/// * `compiler` - The compiler to execute.
+/// * `arguments` - The array of arguments to the compiler.
+/// * `file_name` - The path to the source file that the compiler compiles.
+/// * `timeout` - The time limit of the compiler, None to wait until the compiler finishes.
+///
+/// # Errors
+///
+/// The function returns [`MutagenyxError::CompilerTimeout`] if the function stopped the compiler,
+/// and [`MutagenyxError::CompilerInvocation`] if the compiler does not start or exits with a
+/// failure status.
+pub fn compiler_execute_with_timeout(
+    compiler: &str,
+    arguments: Vec<String>,
+    file_name: &str,
+    timeout: Option<Duration>,
+) -> Result<Output, MutagenyxError> {
+    let output = match shell_execute_with_timeout(compiler, arguments, timeout) {
+        Ok(Some(o)) => o,
+        Ok(None) => {
+            return Err(MutagenyxError::CompilerTimeout {
+                compiler: String::from(compiler),
+                file_name: String::from(file_name),
+                seconds: timeout.map_or(0, |t| t.as_secs()),
+            })
+        }
+        Err(e) => {
+            return Err(MutagenyxError::CompilerInvocation {
+                compiler: String::from(compiler),
+                file_name: String::from(file_name),
+                status: None,
+                stderr: e.to_string(),
+            })
+        }
+    };
+
+    if !output.status.success() {
+        let stdout_contents = String::from_utf8_lossy(&output.stdout);
+        let stderr_contents = String::from_utf8_lossy(&output.stderr);
+        log::debug!(
+            "Compilation failed:\n\tstdout: {}\n\tstderr: {}",
+            stdout_contents,
+            stderr_contents
+        );
+        return Err(MutagenyxError::CompilerInvocation {
+            compiler: String::from(compiler),
+            file_name: String::from(file_name),
+            status: output.status.code(),
+            stderr: String::from(stderr_contents.trim()),
+        });
+    }
+
+    Ok(output)
+}
+
+/// Return true if the compiler settings for `language` in `prefs` treat compiler warnings as
+/// errors.
+///
+/// # Arguments
+///
+/// * `prefs` - The [`Preferences`] object containing compiler settings.
+/// * `language` - The language of the compiler.
+pub fn compiler_warnings_are_errors(prefs: &Preferences, language: Language) -> bool {
+    let language_key = format!["{}", language];
+    prefs
+        .get_preferences_for_key(&language_key)
+        .and_then(|p| p.get_preferences_for_key(COMPILER_KEY))
+        .and_then(|p| p.get_bool_for_key(WARNINGS_AS_ERRORS_KEY))
+        .unwrap_or(false)
+}
+
+/// Return the time limit for checking that a mutant compiles from the compiler settings for
+/// `language` in `prefs`, or None if the settings do not limit the time.
+///
+/// # Arguments
+///
+/// * `prefs` - The [`Preferences`] object containing compiler settings.
+/// * `language` - The language of the compiler.
+pub fn compiler_timeout(prefs: &Preferences, language: Language) -> Option<Duration> {
+    let language_key = format!["{}", language];
+    prefs
+        .get_preferences_for_key(&language_key)
+        .and_then(|p| p.get_preferences_for_key(COMPILER_KEY))
+        .and_then(|p| p.get_int_for_key(TIMEOUT_KEY))
+        .filter(|seconds| *seconds > 0)
+        .map(|seconds| Duration::from_secs(seconds as u64))
+}
+
+/// Return the lines of the compiler diagnostics in `output` that report warnings.
+///
+/// Compilers write diagnostics to stderr (stdout may contain the AST), so the function only
+/// examines stderr.
+///
+/// # Arguments
+///
+/// * `output` - The [`Output`] of the compiler invocation.
+pub fn compiler_warnings(output: &Output) -> Vec<String> {
+    String::from_utf8_lossy(&output.stderr)
+        .lines()
+        .filter(|l| l.to_lowercase().contains("warning"))
+        .map(|l| String::from(l.trim()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    #[test]
+    fn test_compiler_warnings() {
+        use std::os::unix::process::ExitStatusExt;
+        use std::process::ExitStatus;
+
+        let output = Output {
+            status: ExitStatus::from_raw(0),
+            stdout: b"{\"warning\": \"not a diagnostic\"}".to_vec(),
+            stderr: b"Warning: Unused local variable.\n --> a.sol:4:9:\n".to_vec(),
+        };
+        assert_eq!(
+            compiler_warnings(&output),
+            vec![String::from("Warning: Unused local variable.")]
+        );
+    }
+
+    #[test]
+    fn test_compiler_warnings_are_errors() {
+        let mut compiler_prefs = Preferences::new();
+        compiler_prefs.set_bool_for_key(WARNINGS_AS_ERRORS_KEY, true);
+        let mut language_prefs = Preferences::new();
+        language_prefs.set_preferences_for_key(COMPILER_KEY, compiler_prefs);
+        let mut prefs = Preferences::new();
+        prefs.set_preferences_for_key(&Language::Solidity.to_string(), language_prefs);
+
+        assert!(compiler_warnings_are_errors(&prefs, Language::Solidity));
+        assert!(!compiler_warnings_are_errors(&prefs, Language::Vyper));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_compiler_execute_with_timeout() {
+        let shell = |script: &str| vec![String::from("-c"), String::from(script)];
+
+        // The function stops a compiler that runs for too long, even if a process started by the
+        // compiler keeps the output pipes open.
+        let start = Instant::now();
+        match compiler_execute_with_timeout(
+            "sh",
+            shell("sleep 5; exit 0"),
+            "slow.sol",
+            Some(Duration::from_millis(200)),
+        ) {
+            Err(MutagenyxError::CompilerTimeout { file_name, .. }) => {
+                assert_eq!(file_name, "slow.sol")
+            }
+            _ => panic!("expected a compiler timeout error"),
+        }
+        assert!(start.elapsed() < Duration::from_secs(3));
+
+        // A compiler that finishes in time reports its output and status as usual.
+        let output = compiler_execute_with_timeout(
+            "sh",
+            shell("echo out; echo err >&2"),
+            "fast.sol",
+            Some(Duration::from_secs(5)),
+        )
+        .unwrap();
+        assert_eq!(output.stdout, b"out\n");
+        assert_eq!(output.stderr, b"err\n");
+        match compiler_execute_with_timeout(
+            "sh",
+            shell("echo failed >&2; exit 2"),
+            "fast.sol",
+            Some(Duration::from_secs(5)),
+        ) {
+            Err(MutagenyxError::CompilerInvocation { status, stderr, .. }) => {
+                assert_eq!(status, Some(2));
+                assert_eq!(stderr, "failed");
+            }
+            _ => panic!("expected a compiler invocation error"),
+        }
+    }
+
+    #[test]
+    fn test_compiler_timeout() {
+        let mut compiler_prefs = Preferences::new();
+        compiler_prefs.set_int_for_key(TIMEOUT_KEY, 30);
+        let mut language_prefs = Preferences::new();
+        language_prefs.set_preferences_for_key(COMPILER_KEY, compiler_prefs);
+        let mut prefs = Preferences::new();
+        prefs.set_preferences_for_key(&Language::Vyper.to_string(), language_prefs);
+
+        assert_eq!(
+            compiler_timeout(&prefs, Language::Vyper),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(compiler_timeout(&prefs, Language::Solidity), None);
+    }
+}
@@ -9,3 +9,5 @@ mod mutators;
 mod node_finder;
 mod operators;
 mod pretty_printer;
+
+pub use self::pretty_printer::VyperNodePrinterFactory;
@@ -2,3 +2,4 @@
 /// for invoking the Vyper compiler to generate an AST and possibly to compile a file.
 
 pub static ROOT_PATH_KEY: &str = "root-path";
+pub static SEARCH_PATHS_KEY: &str = "search-paths";
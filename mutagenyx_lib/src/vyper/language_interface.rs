@@ -1,37 +1,45 @@
 //! The `vyper::language_interface` module provides the implementation for the [`JSONLanguageDelegate d`]
 //! trait and the function `get_vyper_sub_language_interface`.
 
+use crate::commenter::CommentDetector;
 use crate::compiler_details::*;
 use crate::error::MutagenyxError;
 use crate::id::Id;
 use crate::json::*;
 use crate::json_ast_id_maker::JSONIDMaker;
 use crate::json_ast_permitter::JSONPermitter;
+use crate::json_comment_helpers::JSONCommentDetector;
 use crate::json_comment_inserter::JSONCommentInserter;
 use crate::json_language_delegate::JSONLanguageDelegate;
 use crate::json_namer::JSONNamer;
 use crate::language::Language;
+use crate::line_table::LineTable;
 use crate::mutation_visitor::NodePath;
 use crate::mutator::*;
 use crate::namer::Namer;
 use crate::node_printer::NodePrinterFactory;
-use crate::permissions::Permissions;
+use crate::operators::OperatorRegistry;
+use crate::permissions::{Permissions, CONSTRUCTOR_NODE_KIND};
 use crate::permit::Permit;
-use crate::preferences::Preferences;
+use crate::preferences::{PreferenceValue, Preferences};
 use crate::pretty_print_visitor::PrettyPrintVisitor;
 use crate::pretty_printer::PrettyPrinter;
 use crate::super_ast::SuperAST;
-use crate::utility::shell_execute;
+use crate::utility::{
+    compiler_execute, compiler_execute_with_timeout, compiler_timeout, compiler_warnings,
+    compiler_warnings_are_errors, shell_execute,
+};
 use crate::visitor::Visitor;
 use crate::vyper::ast::VyperAST;
 use crate::vyper::commenter::VyperCommenterFactory;
-use crate::vyper::compiler_details::ROOT_PATH_KEY;
+use crate::vyper::compiler_details::{ROOT_PATH_KEY, SEARCH_PATHS_KEY};
 use crate::vyper::mutators::VyperMutatorFactory;
 use crate::vyper::node_finder::VyperNodeFinderFactory;
 use crate::vyper::pretty_printer::VyperNodePrinterFactory;
 use serde_json::Value;
 use std::env;
 use std::io::Write;
+use std::ops::RangeInclusive;
 use std::path::PathBuf;
 use std::str::FromStr;
 use versions::{Mess, Versioning};
@@ -67,8 +75,8 @@ impl JSONLanguageDelegate for VyperLanguageDelegate {
         Err(MutagenyxError::LanguageNotRecognized)
     }
 
-    fn get_mutator_factory(&self) -> Box<dyn MutatorFactory<Value>> {
-        Box::new(VyperMutatorFactory {})
+    fn get_mutator_factory(&self, operators: &OperatorRegistry) -> Box<dyn MutatorFactory<Value>> {
+        Box::new(VyperMutatorFactory::new(operators.clone()))
     }
 
     fn get_pretty_print_visitor<'a>(
@@ -95,28 +103,38 @@ impl JSONLanguageDelegate for VyperLanguageDelegate {
         file_name: &str,
         prefs: &Preferences,
     ) -> Result<SuperAST, MutagenyxError> {
-        if let Ok(s) = file_is_source_file_with_vyper_from_pip(file_name, prefs) {
-            let value = load_json_from_file_with_name(&s)?;
-            return <VyperLanguageDelegate as JSONLanguageDelegate>::get_value_as_super_ast(
-                self, value,
-            );
-        }
-
-        if let Ok(s) = file_is_source_file_with_docker(file_name, prefs) {
-            let value = load_json_from_file_with_name(&s)?;
-            return <VyperLanguageDelegate as JSONLanguageDelegate>::get_value_as_super_ast(
-                self, value,
-            );
+        let warnings_as_errors = compiler_warnings_are_errors(prefs, Language::Vyper);
+
+        let pip_error =
+            match file_is_source_file_with_vyper_from_pip(file_name, prefs, warnings_as_errors) {
+                Ok(s) => {
+                    let value = load_json_from_file_with_name(&s)?;
+                    return <VyperLanguageDelegate as JSONLanguageDelegate>::get_value_as_super_ast(
+                        self, value,
+                    );
+                }
+                Err(e @ MutagenyxError::CompilerWarnings(..)) => return Err(e),
+                Err(e) => e,
+            };
+
+        match file_is_source_file_with_docker(file_name, prefs, warnings_as_errors) {
+            Ok(s) => {
+                let value = load_json_from_file_with_name(&s)?;
+                return <VyperLanguageDelegate as JSONLanguageDelegate>::get_value_as_super_ast(
+                    self, value,
+                );
+            }
+            Err(e @ MutagenyxError::CompilerWarnings(..)) => return Err(e),
+            Err(_) => (),
         }
 
-        Err(MutagenyxError::SourceDoesNotCompile(String::from(
-            file_name,
-        )))
+        // The docker container is only a fallback, so report why the Vyper compiler failed.
+        Err(pip_error)
     }
 
     fn file_is_language_source_file(&self, file_name: &str, prefs: &Preferences) -> bool {
-        file_is_source_file_with_vyper_from_pip(file_name, prefs).is_ok()
-            || file_is_source_file_with_docker(file_name, prefs).is_ok()
+        file_is_source_file_with_vyper_from_pip(file_name, prefs, false).is_ok()
+            || file_is_source_file_with_docker(file_name, prefs, false).is_ok()
     }
 
     fn implements(&self) -> Language {
@@ -140,8 +158,12 @@ impl JSONLanguageDelegate for VyperLanguageDelegate {
         Box::new(JSONPermitter::new(permissions))
     }
 
-    fn mutant_compiles(&self, file_name: &str, prefs: &Preferences) -> bool {
-        file_compiles(file_name, prefs)
+    fn check_mutant_compiles(
+        &self,
+        file_name: &str,
+        prefs: &Preferences,
+    ) -> Result<(), MutagenyxError> {
+        check_file_compiles(file_name, prefs)
     }
 
     fn get_node_id_maker(&self) -> Box<dyn Id<Value>> {
@@ -159,13 +181,57 @@ impl JSONLanguageDelegate for VyperLanguageDelegate {
         json_comment_inserter.insert_comment_by_path(ast, comment_node, node_path)
     }
 
+    fn get_comment_text_key(&self) -> &str {
+        "value"
+    }
+
+    fn get_source_line_for_node(&self, node: &Value, _lines: Option<&LineTable>) -> Option<usize> {
+        node.get_int_for_key("lineno").map(|l| l as usize)
+    }
+
+    fn get_source_lines_for_node(
+        &self,
+        node: &Value,
+        _lines: Option<&LineTable>,
+    ) -> Option<RangeInclusive<usize>> {
+        let first = node.get_int_for_key("lineno")? as usize;
+        let last = node
+            .get_int_for_key("end_lineno")
+            .map_or(first, |l| l as usize);
+        Some(first..=last.max(first))
+    }
+
     fn get_namer(&self) -> Box<dyn Namer<Value>> {
-        Box::new(JSONNamer::new(|v| {
-            v.get_str_for_key("name").map(String::from)
+        Box::new(
+            JSONNamer::new(|v| v.get_str_for_key("name").map(String::from))
+                .with_kinds(vyper_node_kinds),
+        )
+    }
+
+    fn get_comment_detector(&self) -> Box<dyn CommentDetector<Value>> {
+        Box::new(JSONCommentDetector::new(|v| {
+            v.get_str_for_key("ast_type") == Some("Comment")
         }))
     }
 }
 
+/// Return the kinds of the Vyper AST `node` for permission checks: the node type, and
+/// [`CONSTRUCTOR_NODE_KIND`] for the `__init__` function.
+///
+/// # Arguments
+///
+/// * `node` - The Vyper AST node.
+fn vyper_node_kinds(node: &Value) -> Vec<String> {
+    let mut kinds: Vec<String> = Vec::new();
+    if let Some(ast_type) = node.get_str_for_key("ast_type") {
+        kinds.push(String::from(ast_type));
+        if ast_type == "FunctionDef" && node.get_str_for_key("name") == Some("__init__") {
+            kinds.push(String::from(CONSTRUCTOR_NODE_KIND));
+        }
+    }
+    kinds
+}
+
 /// Retrieve Vyper compiler flags from a [`Preferences`] object.
 ///
 /// # Arguments
@@ -181,6 +247,15 @@ fn get_vyper_compiler_flags_from_preferences(prefs: &Preferences) -> Vec<String>
                 args.push(String::from("-p"));
                 args.push(root_path);
             }
+
+            if let Some(search_paths) = compiler_prefs.get_array_for_key(SEARCH_PATHS_KEY) {
+                for search_path in search_paths {
+                    if let PreferenceValue::String(s) = search_path {
+                        args.push(String::from("--path"));
+                        args.push(s);
+                    }
+                }
+            }
         }
     }
 
@@ -266,9 +341,11 @@ fn get_vyper_compiler_version(compiler: &str) -> Result<Versioning, MutagenyxErr
 ///
 /// * `file_name` - The path to the source file to compile.
 /// * `preferences` - The [`Preferences`] object that might contain a value for `vyper_compiler`.
+/// * `warnings_as_errors` - True if the function should fail when the compiler reports warnings.
 fn file_is_source_file_with_vyper_from_pip(
     file_name: &str,
     preferences: &Preferences,
+    warnings_as_errors: bool,
 ) -> Result<String, MutagenyxError> {
     // This code does the following:
     // * Tries to determine the Vyper compiler version.
@@ -320,27 +397,22 @@ fn file_is_source_file_with_vyper_from_pip(
         vyper_compiler,
         full_compiler_args
     );
-    match shell_execute(&vyper_compiler, full_compiler_args) {
-        Ok(output) => {
-            if output.status.success() {
-                if post_process_compiler_output_to_file {
-                    // The compiler did not support the -o flag to output the AST to a file.  So,
-                    // we get the output from stdout and write that to the output file.
-                    let ast_contents = core::str::from_utf8(output.stdout.as_slice()).unwrap();
-                    let mut file = std::fs::File::create(full_path_to_tmp_file.to_str().unwrap())?;
-                    write!(file, "{ast_contents}")?;
-                }
-                Ok(String::from(full_path_to_tmp_file.to_str().unwrap()))
-            } else {
-                Err(MutagenyxError::SourceDoesNotCompile(String::from(
-                    file_name,
-                )))
-            }
-        }
-        Err(_e) => Err(MutagenyxError::SourceDoesNotCompile(String::from(
-            file_name,
-        ))),
+    let output = compiler_execute(&vyper_compiler, full_compiler_args, file_name)?;
+    let warnings = compiler_warnings(&output);
+    if warnings_as_errors && !warnings.is_empty() {
+        return Err(MutagenyxError::CompilerWarnings(
+            String::from(file_name),
+            warnings.join("; "),
+        ));
     }
+    if post_process_compiler_output_to_file {
+        // The compiler did not support the -o flag to output the AST to a file.  So,
+        // we get the output from stdout and write that to the output file.
+        let ast_contents = core::str::from_utf8(output.stdout.as_slice()).unwrap();
+        let mut file = std::fs::File::create(full_path_to_tmp_file.to_str().unwrap())?;
+        write!(file, "{ast_contents}")?;
+    }
+    Ok(String::from(full_path_to_tmp_file.to_str().unwrap()))
 }
 
 /// Try to execute the vyper compiler in a docker container. On success, return the path to
@@ -349,9 +421,12 @@ fn file_is_source_file_with_vyper_from_pip(
 /// # Arguments
 ///
 /// * `file_name` - The path to the source file to compile.
+/// * `preferences` - The [`Preferences`] object that might contain compiler flags.
+/// * `warnings_as_errors` - True if the function should fail when the compiler reports warnings.
 fn file_is_source_file_with_docker(
     file_name: &str,
     preferences: &Preferences,
+    warnings_as_errors: bool,
 ) -> Result<String, MutagenyxError> {
     // The docker command to invoke the Vyper compiler requires the path where the source file
     // is located to map into the container /code directory.
@@ -392,23 +467,21 @@ fn file_is_source_file_with_docker(
     args.push(out_path.clone());
 
     log::debug!("Invoking docker Vyper compiler {:?}", args);
-    match shell_execute("docker", args) {
-        Ok(output) => {
-            if output.status.success() {
-                Ok(out_path)
-            } else {
-                Err(MutagenyxError::SourceDoesNotCompile(String::from(
-                    file_name,
-                )))
-            }
-        }
-        Err(_e) => Err(MutagenyxError::SourceDoesNotCompile(String::from(
-            file_name,
-        ))),
+    let output = compiler_execute("docker", args, file_name)?;
+    let warnings = compiler_warnings(&output);
+    if warnings_as_errors && !warnings.is_empty() {
+        return Err(MutagenyxError::CompilerWarnings(
+            String::from(file_name),
+            warnings.join("; "),
+        ));
     }
+    Ok(out_path)
 }
 
-fn file_compiles_with_pip(file_name: &str, prefs: &Preferences) -> bool {
+fn check_file_compiles_with_pip(
+    file_name: &str,
+    prefs: &Preferences,
+) -> Result<(), MutagenyxError> {
     let vyper_compiler = get_vyper_compiler_from_preferences(prefs);
     let mut full_compiler_args = get_vyper_compiler_flags_from_preferences(prefs);
 
@@ -421,24 +494,19 @@ fn file_compiles_with_pip(file_name: &str, prefs: &Preferences) -> bool {
         full_compiler_args
     );
 
-    match shell_execute(&vyper_compiler, full_compiler_args) {
-        Ok(output) => {
-            if !output.status.success() {
-                let stdout_contents = core::str::from_utf8(output.stdout.as_slice()).unwrap();
-                let stderr_contents = core::str::from_utf8(output.stderr.as_slice()).unwrap();
-                log::debug!(
-                    "Compilation failed:\n\tstdout: {}\n\tstderr: {}",
-                    stdout_contents,
-                    stderr_contents
-                );
-            }
-            output.status.success()
-        }
-        Err(_e) => false,
-    }
+    compiler_execute_with_timeout(
+        &vyper_compiler,
+        full_compiler_args,
+        file_name,
+        compiler_timeout(prefs, Language::Vyper),
+    )?;
+    Ok(())
 }
 
-fn file_compiles_with_docker(file_name: &str, prefs: &Preferences) -> bool {
+fn check_file_compiles_with_docker(
+    file_name: &str,
+    prefs: &Preferences,
+) -> Result<(), MutagenyxError> {
     // The docker command to invoke the Vyper compiler requires the path where the source file
     // is located to map into the container /code directory.
     let mut directory_name = PathBuf::from_str(file_name).unwrap();
@@ -468,27 +536,105 @@ fn file_compiles_with_docker(file_name: &str, prefs: &Preferences) -> bool {
         args
     );
 
-    match shell_execute("docker", args) {
-        Ok(output) => {
-            if !output.status.success() {
-                let stdout_contents = core::str::from_utf8(output.stdout.as_slice()).unwrap();
-                let stderr_contents = core::str::from_utf8(output.stderr.as_slice()).unwrap();
-                log::debug!(
-                    "Compilation failed:\n\tstdout: {}\n\tstderr: {}",
-                    stdout_contents,
-                    stderr_contents
-                );
-            }
-            output.status.success()
+    compiler_execute_with_timeout(
+        "docker",
+        args,
+        file_name,
+        compiler_timeout(prefs, Language::Vyper),
+    )?;
+    Ok(())
+}
+
+/// Compile the file `file_name` with the installed Vyper compiler, or with the Vyper compiler in
+/// docker if the installed compiler fails.
+///
+/// # Arguments
+///
+/// * `file_name` - The name of the file in the file system to compile.
+/// * `prefs` - The [`Preferences`] object containing compiler settings.
+///
+/// # Errors
+///
+/// The function returns the [`MutagenyxError::CompilerInvocation`] error of the installed
+/// compiler if both compilers fail, unless the installed compiler did not start.  The function
+/// does not try the compiler in docker if the installed compiler runs out of time.
+fn check_file_compiles(file_name: &str, prefs: &Preferences) -> Result<(), MutagenyxError> {
+    let error = match check_file_compiles_with_pip(file_name, prefs) {
+        Ok(()) => return Ok(()),
+        Err(e @ MutagenyxError::CompilerTimeout { .. }) => return Err(e),
+        Err(e) => e,
+    };
+
+    match check_file_compiles_with_docker(file_name, prefs) {
+        Ok(()) => Ok(()),
+        Err(docker_error)
+            if matches!(
+                error,
+                MutagenyxError::CompilerInvocation { status: None, .. }
+            ) =>
+        {
+            Err(docker_error)
         }
-        Err(_e) => false,
+        Err(_) => Err(error),
     }
 }
 
-fn file_compiles(file_name: &str, prefs: &Preferences) -> bool {
-    if file_compiles_with_pip(file_name, prefs) {
-        return true;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language_interface::LanguageInterface;
+    use crate::test_fixtures::{compiler_preferences, install_stub_compiler};
+
+    #[cfg(unix)]
+    #[test]
+    fn test_vyper_search_paths_forwarded_to_compiler() {
+        let stub_dir = env::temp_dir().join("mutagenyx_stub_vyper");
+        std::fs::create_dir_all(&stub_dir).unwrap();
+        let args_file = stub_dir.join("args.txt");
+        let source_file = stub_dir.join("token.vy");
+        std::fs::write(&source_file, "x: uint256\n").unwrap();
+
+        // The stub reports a compiler version that writes the AST to stdout and records the
+        // arguments of the AST request.
+        let stub = install_stub_compiler(
+            &stub_dir,
+            "vyper",
+            &format!(
+                "if [ \"$1\" = \"--version\" ]; then echo 0.3.10+commit.91361694; exit 0; fi\n\
+                echo \"$@\" > {}\n\
+                echo '{{\"contract_name\": \"token.vy\", \"ast\": {{\"ast_type\": \"Module\", \
+                \"node_id\": 0, \"body\": []}}}}'\n",
+                args_file.to_str().unwrap()
+            ),
+        );
+
+        let mut settings = Preferences::new();
+        settings.set_string_for_key(ROOT_PATH_KEY, "/contracts");
+        settings.set_array_for_key(
+            SEARCH_PATHS_KEY,
+            vec![
+                PreferenceValue::String(String::from("/interfaces")),
+                PreferenceValue::String(String::from("/libraries")),
+            ],
+        );
+        let prefs = compiler_preferences(Language::Vyper, stub.to_str().unwrap(), settings);
+
+        let language_object =
+            LanguageInterface::get_language_object_for_language(&Language::Vyper).unwrap();
+        let ast = language_object
+            .convert_source_file_to_ast(source_file.to_str().unwrap(), &prefs)
+            .unwrap();
+        assert_eq!(ast.language(), Language::Vyper);
+
+        let args = std::fs::read_to_string(&args_file).unwrap();
+        assert_eq!(
+            args.trim(),
+            format!(
+                "-p /contracts --path /interfaces --path /libraries -f ast {}",
+                source_file.to_str().unwrap()
+            )
+        );
+
+        std::fs::remove_dir_all(&stub_dir).unwrap();
     }
-
-    file_compiles_with_docker(file_name, prefs)
 }
@@ -10,7 +10,7 @@ use crate::node_printer_helpers::traverse_sub_node_and_print;
 use crate::operators::*;
 use crate::pretty_printer::PrettyPrinter;
 use crate::vyper::ast::VyperAST;
-use crate::vyper::operators::get_python_operator_map;
+use crate::vyper::operators::{get_python_operator_map, node_type_for_operator};
 use crate::vyper::pretty_printer::VyperNodePrinterFactory;
 use num::{Float, Integer};
 use openssl::hash::{Hasher, MessageDigest};
@@ -41,12 +41,39 @@ fn new_integer_constant_node<I: Integer + fmt::Display>(
     new_json_node(&node_str)
 }
 
+/// Return the text of `value` as a Vyper decimal literal: plain decimal notation, without an
+/// exponent, with a decimal point and at most 10 fractional digits (the precision of the Vyper
+/// `decimal` type).
+///
+/// # Arguments
+///
+/// * `value` - The number to format.
+fn format_vyper_decimal<F: Float + fmt::Display>(value: F) -> String {
+    // The fixed precision format always has a decimal point, so trimming the trailing zeros
+    // cannot change the integer part.
+    let text = format!("{value:.10}");
+    let text = text.trim_end_matches('0');
+    let text = if text.ends_with('.') {
+        format!("{text}0")
+    } else {
+        String::from(text)
+    };
+
+    // A small negative value rounds to negative zero.
+    if text == "-0.0" {
+        String::from("0.0")
+    } else {
+        text
+    }
+}
+
 /// Return a new float literal node representing an float literal number.
 ///
 /// # Arguments
 ///
 /// * `value` - The number that the node should contain.
 fn new_float_constant_node<F: Float + fmt::Display>(value: F) -> Result<VyperAST, MutagenyxError> {
+    let value = format_vyper_decimal(value);
     let node_str = format!(
         "{{\
             \"node_id\": 9999998,
@@ -298,6 +325,21 @@ impl BinaryOpMutator {
             comment_node: None,
         }
     }
+
+    /// Return the operators of the mutator that a node of type `ast_type` can hold.  Replacing
+    /// the operator of a `Compare` node with an arithmetic operator, for example, would produce
+    /// an invalid program.
+    ///
+    /// # Arguments
+    ///
+    /// * `ast_type` - The `ast_type` of the node: `BinOp`, `BoolOp`, or `Compare`.
+    fn operators_for_node_type(&self, ast_type: &str) -> Vec<&'static str> {
+        self.operators
+            .iter()
+            .filter(|o| node_type_for_operator(o) == Some(ast_type))
+            .copied()
+            .collect()
+    }
 }
 
 impl Mutator<VyperAST> for BinaryOpMutator {
@@ -307,11 +349,13 @@ impl Mutator<VyperAST> for BinaryOpMutator {
             if n == "BinOp" || n == "BoolOp" || n == "Compare" {
                 // Get the operator from the node and see if the operator is in the mutator's
                 // list of supported operators. The mutator can mutate the node if it supports
-                // the node's operator.
+                // the node's operator and at least one other operator that the node can hold.
                 if let Some(op_node) = node.get("op") {
                     if let Some(op_type_str) = op_node.get_str_for_key("ast_type") {
                         if let Some(op_form_str) = self.operator_map.get(op_type_str) {
-                            return self.operators.contains(op_form_str);
+                            let operators = self.operators_for_node_type(n);
+                            return operators.contains(op_form_str)
+                                && operators.iter().any(|o| o != op_form_str);
                         }
                     }
                 }
@@ -320,6 +364,29 @@ impl Mutator<VyperAST> for BinaryOpMutator {
         false
     }
 
+    fn rejection_reason(&self, node: &VyperAST) -> Option<String> {
+        let ast_type = match node.get_str_for_key("ast_type") {
+            Some(t @ ("BinOp" | "BoolOp" | "Compare")) => t,
+            _ => return Some(String::from("not a BinOp, BoolOp or Compare node")),
+        };
+        let op = match node
+            .get_str_for_key("op/ast_type")
+            .and_then(|o| self.operator_map.get(o))
+        {
+            Some(op) => *op,
+            None => return Some(String::from("the node does not have a known operator")),
+        };
+        let operators = self.operators_for_node_type(ast_type);
+        if operators.contains(&op) {
+            Some(format!(
+                "no other operator in {:?} fits a {ast_type} node",
+                operators
+            ))
+        } else {
+            Some(format!("operator '{op}' is not one of {:?}", operators))
+        }
+    }
+
     fn mutate(
         &mut self,
         node: &mut VyperAST,
@@ -352,8 +419,9 @@ impl Mutator<VyperAST> for BinaryOpMutator {
                 // with itself, just by randomly selecting the same operator from the operator list.
                 let original_operator = self.operator_map.get(op_type_str).unwrap();
 
-                // Choose a new operator.
-                let mut chosen_operator = match self.operators.choose(rand) {
+                // Choose a new operator that the node can hold.
+                let operators = self.operators_for_node_type(&ast_type);
+                let mut chosen_operator = match operators.choose(rand) {
                     Some(o) => o,
                     None => {
                         return Err(MutagenyxError::RandomOperationFailure(
@@ -364,7 +432,7 @@ impl Mutator<VyperAST> for BinaryOpMutator {
 
                 // If we chose the original operator, keep choosing until we get a different operator.
                 while original_operator == chosen_operator {
-                    chosen_operator = match self.operators.choose(rand) {
+                    chosen_operator = match operators.choose(rand) {
                         Some(o) => o,
                         None => {
                             return Err(MutagenyxError::RandomOperationFailure(
@@ -413,6 +481,48 @@ impl Mutator<VyperAST> for BinaryOpMutator {
         self.mutation_type
     }
 
+    fn description(&self) -> &'static str {
+        match self.mutation_type {
+            MutationType::Generic(GenericMutation::ArithmeticBinaryOp) => {
+                "Replace the arithmetic operator of a BinOp expression with another arithmetic \
+                    operator."
+            }
+            MutationType::Generic(GenericMutation::LogicalBinaryOp) => {
+                "Replace the and/or operator of a BoolOp expression with the other operator."
+            }
+            MutationType::Generic(GenericMutation::BitwiseBinaryOp) => {
+                "Replace the bitwise operator of a BinOp expression with another bitwise operator."
+            }
+            MutationType::Generic(GenericMutation::BitshiftBinaryOp) => {
+                "Replace the bitshift operator of a BinOp expression with another bitshift \
+                    operator."
+            }
+            MutationType::Generic(GenericMutation::ComparisonBinaryOp) => {
+                "Replace the operator of a Compare expression with another comparison operator."
+            }
+            _ => "",
+        }
+    }
+
+    fn example(&self) -> &'static str {
+        match self.mutation_type {
+            MutationType::Generic(GenericMutation::ArithmeticBinaryOp) => {
+                "a + b might become a - b, a * b might become a / b"
+            }
+            MutationType::Generic(GenericMutation::LogicalBinaryOp) => {
+                "a or b would become a and b"
+            }
+            MutationType::Generic(GenericMutation::BitwiseBinaryOp) => "a & b might become a | b",
+            MutationType::Generic(GenericMutation::BitshiftBinaryOp) => {
+                "a << b might become a >> b"
+            }
+            MutationType::Generic(GenericMutation::ComparisonBinaryOp) => {
+                "a < b might become a > b, a == b might become a <= b"
+            }
+            _ => "",
+        }
+    }
+
     fn get_comment_node(&self) -> Option<VyperAST> {
         self.comment_node.clone()
     }
@@ -584,6 +694,15 @@ impl Mutator<VyperAST> for AssignmentMutator {
         MutationType::Generic(GenericMutation::Assignment)
     }
 
+    fn description(&self) -> &'static str {
+        "Replace the right hand side of an assignment with a random value of the type of the \
+            target."
+    }
+
+    fn example(&self) -> &'static str {
+        "self.total = amount + 10 might become self.total = 29494243244"
+    }
+
     fn get_comment_node(&self) -> Option<VyperAST> {
         self.comment_node.clone()
     }
@@ -941,6 +1060,182 @@ impl Mutator<VyperAST> for DeleteStatementMutator {
         MutationType::Generic(GenericMutation::DeleteStatement)
     }
 
+    fn description(&self) -> &'static str {
+        "Delete a randomly selected statement of a body, and add pass or a return statement if the \
+            body becomes empty."
+    }
+
+    fn example(&self) -> &'static str {
+        "self.total += amount followed by self.count += 1 might become self.count += 1"
+    }
+
+    fn get_comment_node(&self) -> Option<VyperAST> {
+        self.comment_node.clone()
+    }
+}
+
+/// Type that implements the DuplicateStatement algorithm.
+///
+/// The algorithm chooses a random statement in the body of a function, `for` loop, or `if`
+/// statement and inserts a copy of that statement immediately after the original.  The algorithm
+/// does not duplicate variable declarations or statements that end the flow of control since the
+/// copies would not compile.
+struct DuplicateStatementMutator {
+    /// Comment node detailing mutation.
+    comment_node: Option<VyperAST>,
+
+    /// The statement node types that the mutator will not duplicate.
+    ast_types_to_avoid: Vec<&'static str>,
+
+    /// The names of functions that end the flow of control when called as a statement.
+    terminating_functions: Vec<&'static str>,
+
+    /// keywords that indicate a function definition is part of a function declaration in
+    /// an `interface` declaration.
+    single_line_statements_to_avoid: Vec<&'static str>,
+}
+
+impl DuplicateStatementMutator {
+    /// Create a new duplicate statement mutator.
+    pub fn new() -> DuplicateStatementMutator {
+        DuplicateStatementMutator {
+            comment_node: None,
+            ast_types_to_avoid: vec!["AnnAssign", "Break", "Continue", "Raise", "Return"],
+            terminating_functions: vec!["raw_revert", "selfdestruct"],
+            single_line_statements_to_avoid: vec!["view", "nonpayable", "payable", "pure"],
+        }
+    }
+
+    /// Return true if the mutator can duplicate `statement`.
+    ///
+    /// # Arguments
+    ///
+    /// * `statement` - The statement node from a body array.
+    fn can_duplicate(&self, statement: &VyperAST) -> bool {
+        let ast_type = match statement.get_str_for_key("ast_type") {
+            Some(t) => t,
+            None => return false,
+        };
+
+        if self.ast_types_to_avoid.contains(&ast_type) {
+            return false;
+        }
+
+        if ast_type == "Expr" {
+            if let Some(function_name) = statement.get_str_for_key("value/func/id") {
+                if self.terminating_functions.contains(&function_name) {
+                    return false;
+                }
+            }
+
+            let statement_s = pretty_print_node(statement);
+            if self
+                .single_line_statements_to_avoid
+                .contains(&&*statement_s)
+            {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Return the indices of the statements in the body of `node` that the mutator can
+    /// duplicate.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The node with the `body` array.
+    fn duplicable_statement_indices(&self, node: &VyperAST) -> Vec<usize> {
+        match node.get_array_for_key("body") {
+            Some(body) => body
+                .iter()
+                .enumerate()
+                .filter(|(_, s)| self.can_duplicate(s))
+                .map(|(i, _)| i)
+                .collect(),
+            None => vec![],
+        }
+    }
+}
+
+impl Mutator<VyperAST> for DuplicateStatementMutator {
+    fn is_mutable_node(&mut self, node: &VyperAST, _rand: &mut Pcg64) -> bool {
+        if let Some(ast_type) = node.get_str_for_key("ast_type") {
+            // Module level statements are declarations, so only look at statements in blocks of
+            // code.
+            if ast_type == "FunctionDef" || ast_type == "For" || ast_type == "If" {
+                return !self.duplicable_statement_indices(node).is_empty();
+            }
+        }
+        false
+    }
+
+    fn mutate(
+        &mut self,
+        node: &mut VyperAST,
+        rand: &mut Pcg64,
+    ) -> Result<MutatorResult, MutagenyxError> {
+        // Remove any previous comment.
+        self.comment_node = None;
+
+        let mut mutator_result = MutatorResult::new();
+        mutator_result.mutation_type = Some(self.implements());
+
+        let mut hasher = Hasher::new(MessageDigest::sha256())?;
+
+        let node_ast_type = if let Some(ast_type) = node.get_str_for_key("ast_type") {
+            String::from(ast_type)
+        } else {
+            // Just guess here.
+            String::from("FunctionDef")
+        };
+
+        let indices = self.duplicable_statement_indices(node);
+        if let Some(index) = indices.choose(rand) {
+            if let Some(body_array) = node.get_mut("body").and_then(|body| body.as_array_mut()) {
+                hasher.update(&index.to_ne_bytes())?;
+
+                let value = body_array[*index].clone();
+                let value_s = pretty_print_node(&value);
+
+                mutator_result.mutated_node_id =
+                    value.get_int_for_key("node_id").map(|id| id as u64);
+
+                body_array.insert(*index + 1, value);
+
+                let comment_text = format!(
+                    "DuplicateStatement Mutator: duplicated statement '{}'",
+                    value_s
+                );
+                if let Ok(comment_node) = new_comment_node(&comment_text) {
+                    self.comment_node = Some(comment_node);
+                }
+
+                let byte_array = hasher.finish()?;
+                mutator_result.random_behavior_hash = Some(hex::encode(byte_array));
+
+                return Ok(mutator_result);
+            }
+        }
+        Err(MutagenyxError::MalformedNode(
+            node_ast_type,
+            String::from("body"),
+        ))
+    }
+
+    fn implements(&self) -> MutationType {
+        MutationType::Generic(GenericMutation::DuplicateStatement)
+    }
+
+    fn description(&self) -> &'static str {
+        "Insert a copy of a randomly selected statement of a body immediately after the statement."
+    }
+
+    fn example(&self) -> &'static str {
+        "self.total += amount would become self.total += amount followed by self.total += amount"
+    }
+
     fn get_comment_node(&self) -> Option<VyperAST> {
         self.comment_node.clone()
     }
@@ -1041,6 +1336,14 @@ impl Mutator<VyperAST> for FunctionCallMutator {
         MutationType::Generic(GenericMutation::FunctionCall)
     }
 
+    fn description(&self) -> &'static str {
+        "Replace a function call with one of the arguments of the call."
+    }
+
+    fn example(&self) -> &'static str {
+        "a = foo(b, c) might become a = c"
+    }
+
     fn get_comment_node(&self) -> Option<VyperAST> {
         self.comment_node.clone()
     }
@@ -1187,6 +1490,14 @@ impl Mutator<VyperAST> for SwapFunctionArgumentsMutator {
         MutationType::Generic(GenericMutation::SwapFunctionArguments)
     }
 
+    fn description(&self) -> &'static str {
+        "Swap two arguments of the same type in a function call."
+    }
+
+    fn example(&self) -> &'static str {
+        "a = foo(bar, bat, bug) might become a = foo(bug, bat, bar)"
+    }
+
     fn get_comment_node(&self) -> Option<VyperAST> {
         self.comment_node.clone()
     }
@@ -1330,6 +1641,15 @@ impl Mutator<VyperAST> for IfStatementMutator {
         MutationType::Generic(GenericMutation::IfStatement)
     }
 
+    fn description(&self) -> &'static str {
+        "Replace the test of an if statement with True, False, or the logical negation of the \
+            test."
+    }
+
+    fn example(&self) -> &'static str {
+        "if a > b: might become if True:, if c == 10: might become if not (c == 10):"
+    }
+
     fn get_comment_node(&self) -> Option<VyperAST> {
         self.comment_node.clone()
     }
@@ -1436,6 +1756,108 @@ impl Mutator<VyperAST> for IntegerMutator {
         MutationType::Generic(GenericMutation::Integer)
     }
 
+    fn description(&self) -> &'static str {
+        "Replace an integer literal with a random value."
+    }
+
+    fn example(&self) -> &'static str {
+        "a = 10 might become a = 11, a = 9, or a = 2932"
+    }
+
+    fn get_comment_node(&self) -> Option<VyperAST> {
+        self.comment_node.clone()
+    }
+}
+
+/// Return the value of `node` if the node is a `True` or `False` constant.
+///
+/// # Arguments
+///
+/// * `node` - The node in the AST.
+fn boolean_constant_value(node: &VyperAST) -> Option<bool> {
+    if node.get_str_for_key("ast_type")? != "NameConstant" {
+        return None;
+    }
+    node.get("value")?.as_bool()
+}
+
+/// Implement the BooleanLiteralFlip mutation algorithm.
+///
+/// The algorithm replaces a `True` constant with `False` and a `False` constant with `True`.
+struct BooleanLiteralFlipMutator {
+    /// Information about the mutation.
+    comment_node: Option<VyperAST>,
+}
+
+impl BooleanLiteralFlipMutator {
+    /// Create a new boolean literal flip mutator.
+    pub fn new() -> BooleanLiteralFlipMutator {
+        BooleanLiteralFlipMutator { comment_node: None }
+    }
+}
+
+impl Mutator<VyperAST> for BooleanLiteralFlipMutator {
+    fn is_mutable_node(&mut self, node: &VyperAST, _rand: &mut Pcg64) -> bool {
+        boolean_constant_value(node).is_some()
+    }
+
+    fn mutate(
+        &mut self,
+        node: &mut VyperAST,
+        _rand: &mut Pcg64,
+    ) -> Result<MutatorResult, MutagenyxError> {
+        // Remove any previous comments
+        self.comment_node = None;
+
+        let mut mutator_result = MutatorResult::new();
+        mutator_result.mutation_type = Some(self.implements());
+
+        let mut hasher = Hasher::new(MessageDigest::sha256())?;
+
+        let value = match boolean_constant_value(node) {
+            Some(v) => v,
+            None => {
+                return Err(MutagenyxError::MalformedNode(
+                    String::from("NameConstant"),
+                    String::from("value"),
+                ))
+            }
+        };
+
+        let original_value_s = pretty_print_node(node);
+        node.set_node_for_key("value", json![!value]);
+        let new_value_s = pretty_print_node(node);
+
+        hasher.update(new_value_s.as_bytes())?;
+
+        let comment_text = format!(
+            "BooleanLiteralFlip Mutator: changed '{}' to '{}'",
+            original_value_s, new_value_s
+        );
+        if let Ok(comment_node) = new_comment_node(&comment_text) {
+            self.comment_node = Some(comment_node);
+        }
+
+        mutator_result.mutated_node_id = node.get_int_for_key("node_id").map(|id| id as u64);
+
+        let byte_array = hasher.finish()?;
+        mutator_result.random_behavior_hash = Some(hex::encode(byte_array));
+
+        Ok(mutator_result)
+    }
+
+    fn implements(&self) -> MutationType {
+        MutationType::Generic(GenericMutation::BooleanLiteralFlip)
+    }
+
+    fn description(&self) -> &'static str {
+        "Replace a True or False constant with the opposite constant."
+    }
+
+    fn example(&self) -> &'static str {
+        "self.paused = True would become self.paused = False"
+    }
+
     fn get_comment_node(&self) -> Option<VyperAST> {
         self.comment_node.clone()
     }
@@ -1454,9 +1876,13 @@ struct SwapOperatorArgumentsMutator {
 
 impl SwapOperatorArgumentsMutator {
     /// Create a new mutator
-    fn new() -> SwapOperatorArgumentsMutator {
+    ///
+    /// # Arguments
+    ///
+    /// * `valid_operators` - The operators whose arguments the mutator can swap.
+    fn new(valid_operators: Vec<&'static str>) -> SwapOperatorArgumentsMutator {
         SwapOperatorArgumentsMutator {
-            valid_operators: non_commutative_operators(),
+            valid_operators,
             operator_map: get_python_operator_map(),
             comment_node: None,
         }
@@ -1552,6 +1978,14 @@ impl Mutator<VyperAST> for SwapOperatorArgumentsMutator {
         MutationType::Generic(GenericMutation::SwapOperatorArguments)
     }
 
+    fn description(&self) -> &'static str {
+        "Swap the operands of a binary operation with a non-commutative operator."
+    }
+
+    fn example(&self) -> &'static str {
+        "thing = a - b might become thing = b - a, x = y << 5 might become x = 5 << y"
+    }
+
     fn get_comment_node(&self) -> Option<VyperAST> {
         self.comment_node.clone()
     }
@@ -1718,6 +2152,14 @@ impl Mutator<VyperAST> for SwapLinesMutator {
         MutationType::Generic(GenericMutation::SwapLines)
     }
 
+    fn description(&self) -> &'static str {
+        "Swap two randomly selected statements in the body of a function."
+    }
+
+    fn example(&self) -> &'static str {
+        "a = foo - self.bar() ... foo += 8 might become foo += 8 ... a = foo - self.bar()"
+    }
+
     fn get_comment_node(&self) -> Option<VyperAST> {
         self.comment_node.clone()
     }
@@ -1754,6 +2196,10 @@ impl Mutator<VyperAST> for UnaryOpMutator {
         false
     }
 
+    fn rejection_reason(&self, _node: &VyperAST) -> Option<String> {
+        Some(String::from("not a UnaryOp node"))
+    }
+
     fn mutate(
         &mut self,
         node: &mut VyperAST,
@@ -1804,41 +2250,806 @@ impl Mutator<VyperAST> for UnaryOpMutator {
         MutationType::Generic(GenericMutation::UnaryOp)
     }
 
+    fn description(&self) -> &'static str {
+        "Remove the operator of a unary operation and keep the operand."
+    }
+
+    fn example(&self) -> &'static str {
+        "-a would become a, not done would become done"
+    }
+
     fn get_comment_node(&self) -> Option<VyperAST> {
         self.comment_node.clone()
     }
 }
 
-/// Implement the [`MutatorFactory<T>`] trait to have an interface for getting mutators for requested
-/// mutation algorithms.
-pub struct VyperMutatorFactory {}
+/// Return the list of address expressions that the low-level call target mutator can
+/// substitute for the target of a call.
+fn alternate_call_target_nodes() -> Vec<VyperAST> {
+    let msg_sender = json!({
+        "node_id": 9999995,
+        "ast_type": "Attribute",
+        "attr": "sender",
+        "value": {
+            "node_id": 9999994,
+            "ast_type": "Name",
+            "id": "msg"
+        }
+    });
+    let tx_origin = json!({
+        "node_id": 9999995,
+        "ast_type": "Attribute",
+        "attr": "origin",
+        "value": {
+            "node_id": 9999994,
+            "ast_type": "Name",
+            "id": "tx"
+        }
+    });
+    let self_node = json!({
+        "node_id": 9999995,
+        "ast_type": "Name",
+        "id": "self"
+    });
+    let empty_address = json!({
+        "node_id": 9999995,
+        "ast_type": "Call",
+        "func": {
+            "node_id": 9999994,
+            "ast_type": "Name",
+            "id": "empty"
+        },
+        "args": [
+            {
+                "node_id": 9999993,
+                "ast_type": "Name",
+                "id": "address"
+            }
+        ],
+        "keywords": []
+    });
+    vec![msg_sender, tx_origin, self_node, empty_address]
+}
 
-impl MutatorFactory<VyperAST> for VyperMutatorFactory {
-    fn mutator_for(&self, mutation_type: &MutationType) -> Option<Box<dyn Mutator<VyperAST>>> {
-        match mutation_type {
-            MutationType::Generic(t) => match t {
-                GenericMutation::ArithmeticBinaryOp => Some(Box::new(BinaryOpMutator::new(
-                    arithmetic_operators(),
-                    MutationType::Generic(GenericMutation::ArithmeticBinaryOp),
-                ))),
-                GenericMutation::LogicalBinaryOp => Some(Box::new(BinaryOpMutator::new(
-                    logical_operators(),
-                    MutationType::Generic(GenericMutation::LogicalBinaryOp),
+/// Implements the low-level call target mutation algorithm for Vyper programs.
+///
+/// The algorithm finds calls to the Vyper functions `send`, `raw_call`, and
+/// `create_from_blueprint` and replaces the first argument (the target of the call) with a
+/// different address expression.
+struct LowLevelCallTargetMutator {
+    /// Information about the mutation.
+    comment_node: Option<VyperAST>,
+
+    /// The names of the functions whose first argument is the target of a low-level call.
+    function_names: Vec<&'static str>,
+}
+
+impl LowLevelCallTargetMutator {
+    /// Create a new low-level call target mutator.
+    pub fn new() -> LowLevelCallTargetMutator {
+        LowLevelCallTargetMutator {
+            comment_node: None,
+            function_names: vec!["send", "raw_call", "create_from_blueprint"],
+        }
+    }
+
+    /// Return the list of alternate target expressions that differ from `target`.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The current target expression of the call.
+    fn alternate_targets_for(&self, target: &VyperAST) -> Vec<VyperAST> {
+        let target_s = pretty_print_node(target);
+        alternate_call_target_nodes()
+            .into_iter()
+            .filter(|n| pretty_print_node(n) != target_s)
+            .collect()
+    }
+}
+
+impl Mutator<VyperAST> for LowLevelCallTargetMutator {
+    fn is_mutable_node(&mut self, node: &VyperAST, _rand: &mut Pcg64) -> bool {
+        if let Some(ast_type) = node.get_str_for_key("ast_type") {
+            if ast_type == "Call" {
+                if let Some(func_name) = node.get_str_for_key("func/id") {
+                    if self.function_names.contains(&func_name) {
+                        if let Some(args) = node.get_array_for_key("args") {
+                            if let Some(target) = args.first() {
+                                return !self.alternate_targets_for(target).is_empty();
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        false
+    }
+
+    fn mutate(
+        &mut self,
+        node: &mut VyperAST,
+        rand: &mut Pcg64,
+    ) -> Result<MutatorResult, MutagenyxError> {
+        // Remove any previously existing comment
+        self.comment_node = None;
+
+        let mut mutator_result = MutatorResult::new();
+        mutator_result.mutation_type = Some(self.implements());
+
+        let mut hasher = Hasher::new(MessageDigest::sha256())?;
+
+        let alternates = match node.get_array_for_key("args") {
+            Some(args) if !args.is_empty() => self.alternate_targets_for(&args[0]),
+            _ => vec![],
+        };
+
+        if let Some(new_target) = alternates.choose(rand) {
+            let original_node_s = pretty_print_node(node);
+
+            node.set_node_for_key_at_index("args", 0, new_target.clone());
+
+            let new_node_s = pretty_print_node(node);
+            hasher.update(new_node_s.as_bytes())?;
+
+            let comment_text = format!(
+                "LowLevelCallTarget Mutator: Replaced '{}' with '{}'",
+                original_node_s, new_node_s
+            );
+            if let Ok(comment_node) = new_comment_node(&comment_text) {
+                self.comment_node = Some(comment_node);
+            }
+
+            if let Some(id) = node.get_int_for_key("node_id") {
+                mutator_result.mutated_node_id = Some(id as u64);
+            }
+
+            let byte_array = hasher.finish()?;
+            mutator_result.random_behavior_hash = Some(hex::encode(byte_array));
+
+            return Ok(mutator_result);
+        }
+
+        Err(MutagenyxError::MalformedNode(
+            String::from("Call"),
+            String::from("args"),
+        ))
+    }
+
+    fn implements(&self) -> MutationType {
+        MutationType::Vyper(VyperMutation::LowLevelCallTarget)
+    }
+
+    fn description(&self) -> &'static str {
+        "Replace the target argument of a send(), raw_call(), or create_from_blueprint() call with \
+            a different address expression."
+    }
+
+    fn example(&self) -> &'static str {
+        "send(self.owner, amount) might become send(msg.sender, amount)"
+    }
+
+    fn get_comment_node(&self) -> Option<VyperAST> {
+        self.comment_node.clone()
+    }
+}
+
+/// The ast_type values of the Vyper literal nodes that can initialize a `constant` variable.
+const VYPER_LITERAL_TYPES: [&str; 6] = ["Int", "Decimal", "Str", "Hex", "Bytes", "NameConstant"];
+
+/// The kinds of Vyper module-level variable declarations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum StateVariableKind {
+    /// A variable that lives in contract storage.
+    Storage,
+    /// A variable declared with `constant(...)`.
+    Constant,
+    /// A variable declared with `immutable(...)`.
+    Immutable,
+}
+
+impl StateVariableKind {
+    /// Return the name of the Vyper function that wraps the annotation of the declaration, if any.
+    fn wrapper(&self) -> Option<&'static str> {
+        match self {
+            StateVariableKind::Storage => None,
+            StateVariableKind::Constant => Some("constant"),
+            StateVariableKind::Immutable => Some("immutable"),
+        }
+    }
+}
+
+/// Return a new `Call` node that wraps `annotation` in a call to `function` (for example
+/// `public(uint256)`).
+///
+/// # Arguments
+///
+/// * `function` - The name of the wrapping function.
+/// * `annotation` - The annotation node to wrap.
+fn new_annotation_wrapper_node(function: &str, annotation: VyperAST) -> VyperAST {
+    json!({
+        "node_id": 9999990,
+        "ast_type": "Call",
+        "func": {
+            "node_id": 9999989,
+            "ast_type": "Name",
+            "id": function
+        },
+        "args": [annotation],
+        "keywords": []
+    })
+}
+
+/// If `annotation` is a call to the Vyper function `function` (such as `public(uint256)`),
+/// return the argument of the call.
+///
+/// # Arguments
+///
+/// * `annotation` - The annotation node of an `AnnAssign` node.
+/// * `function` - The name of the wrapping function.
+fn unwrap_annotation<'a>(annotation: &'a VyperAST, function: &str) -> Option<&'a VyperAST> {
+    if annotation.get_str_for_key("ast_type")? == "Call"
+        && annotation.get_str_for_key("func/id")? == function
+    {
+        return annotation.get_array_for_key("args")?.first();
+    }
+    None
+}
+
+/// The modifiers of a Vyper module-level variable declaration.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct StateVariableModifiers {
+    /// True if the declaration has a `public(...)` getter.
+    public: bool,
+
+    /// The storage kind of the declaration.
+    kind: StateVariableKind,
+}
+
+impl fmt::Display for StateVariableModifiers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let kind = self.kind.wrapper().unwrap_or("storage");
+        if self.public {
+            write!(f, "public {}", kind)
+        } else {
+            write!(f, "{}", kind)
+        }
+    }
+}
+
+/// Return the modifiers of `node` if `node` is a module-level variable declaration.
+///
+/// Current versions of the Vyper compiler produce `VariableDecl` nodes for module-level
+/// declarations.  Older versions produce `AnnAssign` nodes whose annotation wraps the type in
+/// calls to `public`, `constant`, or `immutable`.  An `AnnAssign` node without one of those
+/// wrappers is indistinguishable from a local variable declaration, so the function ignores it.
+///
+/// # Arguments
+///
+/// * `node` - The node to inspect.
+fn state_variable_modifiers(node: &VyperAST) -> Option<StateVariableModifiers> {
+    match node.get_str_for_key("ast_type")? {
+        "VariableDecl" => {
+            let kind = if node.get_bool_for_key("is_constant").unwrap_or(false) {
+                StateVariableKind::Constant
+            } else if node.get_bool_for_key("is_immutable").unwrap_or(false) {
+                StateVariableKind::Immutable
+            } else {
+                StateVariableKind::Storage
+            };
+            Some(StateVariableModifiers {
+                public: node.get_bool_for_key("is_public").unwrap_or(false),
+                kind,
+            })
+        }
+        "AnnAssign" => {
+            let mut annotation = node.get("annotation")?;
+            let public = match unwrap_annotation(annotation, "public") {
+                Some(inner) => {
+                    annotation = inner;
+                    true
+                }
+                None => false,
+            };
+            let kind = if unwrap_annotation(annotation, "constant").is_some() {
+                StateVariableKind::Constant
+            } else if unwrap_annotation(annotation, "immutable").is_some() {
+                StateVariableKind::Immutable
+            } else {
+                StateVariableKind::Storage
+            };
+            if !public && kind == StateVariableKind::Storage {
+                return None;
+            }
+            Some(StateVariableModifiers { public, kind })
+        }
+        _ => None,
+    }
+}
+
+/// Return the type annotation of an `AnnAssign` module-level declaration with the `public`,
+/// `constant`, and `immutable` wrappers removed.
+///
+/// # Arguments
+///
+/// * `annotation` - The annotation node of the declaration.
+fn bare_annotation(annotation: &VyperAST) -> &VyperAST {
+    let mut annotation = unwrap_annotation(annotation, "public").unwrap_or(annotation);
+    for wrapper in ["constant", "immutable"] {
+        if let Some(inner) = unwrap_annotation(annotation, wrapper) {
+            annotation = inner;
+        }
+    }
+    annotation
+}
+
+/// Implements the state variable modifiers mutation algorithm for Vyper programs.
+///
+/// The algorithm finds module-level variable declarations and changes the combination of
+/// `public`, `constant`, and `immutable` modifiers on the declaration.  The algorithm only
+/// produces declarations that are valid on their own: a `constant` variable must keep a
+/// literal value, and a variable that stops being `constant` loses its value.  Uses of the
+/// variable elsewhere in the program may still fail to compile, use mutant validation to filter
+/// those mutants.
+struct StateVariableModifiersMutator {
+    /// Information about the mutation.
+    comment_node: Option<VyperAST>,
+}
+
+impl StateVariableModifiersMutator {
+    /// Create a new state variable modifiers mutator.
+    pub fn new() -> StateVariableModifiersMutator {
+        StateVariableModifiersMutator { comment_node: None }
+    }
+
+    /// Return the list of modifier combinations, other than the current combination, that
+    /// produce a valid declaration for `node`.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The module-level variable declaration.
+    fn alternate_modifiers_for(&self, node: &VyperAST) -> Vec<StateVariableModifiers> {
+        let current = match state_variable_modifiers(node) {
+            Some(m) => m,
+            None => return vec![],
+        };
+
+        // A constant requires a value in the declaration.  Keep the value of an existing
+        // constant, otherwise only accept a literal value.
+        let can_be_constant = current.kind == StateVariableKind::Constant
+            || node
+                .get_str_for_key("value/ast_type")
+                .is_some_and(|t| VYPER_LITERAL_TYPES.contains(&t));
+
+        let mut alternates = vec![];
+        for public in [false, true] {
+            for kind in [
+                StateVariableKind::Storage,
+                StateVariableKind::Constant,
+                StateVariableKind::Immutable,
+            ] {
+                let candidate = StateVariableModifiers { public, kind };
+                if candidate == current || (kind == StateVariableKind::Constant && !can_be_constant)
+                {
+                    continue;
+                }
+                alternates.push(candidate);
+            }
+        }
+        alternates
+    }
+
+    /// Change the declaration in `node` to use `modifiers`.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The module-level variable declaration.
+    /// * `modifiers` - The new modifiers for the declaration.
+    fn apply_modifiers(&self, node: &mut VyperAST, modifiers: &StateVariableModifiers) {
+        if node.get_str_for_key("ast_type") == Some("VariableDecl") {
+            node.set_node_for_key("is_public", json![modifiers.public]);
+            node.set_node_for_key(
+                "is_constant",
+                json![modifiers.kind == StateVariableKind::Constant],
+            );
+            node.set_node_for_key(
+                "is_immutable",
+                json![modifiers.kind == StateVariableKind::Immutable],
+            );
+        } else if let Some(annotation) = node.get("annotation") {
+            let mut new_annotation = bare_annotation(annotation).clone();
+            if let Some(wrapper) = modifiers.kind.wrapper() {
+                new_annotation = new_annotation_wrapper_node(wrapper, new_annotation);
+            }
+            if modifiers.public {
+                new_annotation = new_annotation_wrapper_node("public", new_annotation);
+            }
+            node.set_node_for_key("annotation", new_annotation);
+        }
+
+        // Only constant variables have a value in the declaration.
+        if modifiers.kind != StateVariableKind::Constant {
+            node.set_node_for_key("value", json![null]);
+        }
+    }
+}
+
+impl Mutator<VyperAST> for StateVariableModifiersMutator {
+    fn is_mutable_node(&mut self, node: &VyperAST, _rand: &mut Pcg64) -> bool {
+        !self.alternate_modifiers_for(node).is_empty()
+    }
+
+    fn mutate(
+        &mut self,
+        node: &mut VyperAST,
+        rand: &mut Pcg64,
+    ) -> Result<MutatorResult, MutagenyxError> {
+        // Remove any previously existing comment
+        self.comment_node = None;
+
+        let mut mutator_result = MutatorResult::new();
+        mutator_result.mutation_type = Some(self.implements());
+
+        let mut hasher = Hasher::new(MessageDigest::sha256())?;
+
+        let alternates = self.alternate_modifiers_for(node);
+
+        if let Some(new_modifiers) = alternates.choose(rand) {
+            let original_node_s = pretty_print_node(node);
+
+            self.apply_modifiers(node, new_modifiers);
+
+            let new_node_s = pretty_print_node(node);
+            hasher.update(new_modifiers.to_string().as_bytes())?;
+
+            let comment_text = format!(
+                "StateVariableModifiers Mutator: Changing '{}' to '{}'",
+                original_node_s, new_node_s
+            );
+            if let Ok(comment_node) = new_comment_node(&comment_text) {
+                self.comment_node = Some(comment_node);
+            }
+
+            if let Some(id) = node.get_int_for_key("node_id") {
+                mutator_result.mutated_node_id = Some(id as u64);
+            }
+
+            let byte_array = hasher.finish()?;
+            mutator_result.random_behavior_hash = Some(hex::encode(byte_array));
+
+            return Ok(mutator_result);
+        }
+
+        Err(MutagenyxError::MalformedNode(
+            String::from("VariableDecl"),
+            String::from("is_constant"),
+        ))
+    }
+
+    fn implements(&self) -> MutationType {
+        MutationType::Vyper(VyperMutation::StateVariableModifiers)
+    }
+
+    fn description(&self) -> &'static str {
+        "Change the public, constant, and immutable modifiers of a module-level variable \
+            declaration."
+    }
+
+    fn example(&self) -> &'static str {
+        "FEE: public(constant(uint256)) = 30 might become FEE: constant(uint256) = 30"
+    }
+
+    fn get_comment_node(&self) -> Option<VyperAST> {
+        self.comment_node.clone()
+    }
+}
+
+/// Return the arguments of the `range()` call that `node` iterates over when `node` is a `For`
+/// node of the form `for i in range(...)`.
+///
+/// # Arguments
+///
+/// * `node` - The AST node.
+fn range_arguments(node: &VyperAST) -> Option<&Vec<VyperAST>> {
+    if node.get_str_for_key("ast_type")? != "For" {
+        return None;
+    }
+    let iter_node = node.get("iter")?;
+    if iter_node.get_str_for_key("ast_type")? != "Call"
+        || iter_node.get_str_for_key("func/id")? != "range"
+    {
+        return None;
+    }
+    let args = iter_node.get_array_for_key("args")?;
+    if args.is_empty() || args.len() > 2 {
+        return None;
+    }
+    Some(args)
+}
+
+/// Return the span `N` of a `range()` call of the form `range(start, start + N)`, or `None` if
+/// the arguments in `args` have a different form.  Vyper only accepts a non-constant start when
+/// the end is the start plus a literal span.
+///
+/// # Arguments
+///
+/// * `args` - The arguments of the `range()` call.
+fn range_span(args: &[VyperAST]) -> Option<i64> {
+    if args.len() != 2
+        || args[1].get_str_for_key("ast_type")? != "BinOp"
+        || args[1].get_str_for_key("op/ast_type")? != "Add"
+        || args[1].get_str_for_key("right/ast_type")? != "Int"
+    {
+        return None;
+    }
+    if pretty_print_node(args[1].get("left")?) != pretty_print_node(&args[0]) {
+        return None;
+    }
+    args[1].pointer("/right/value")?.as_i64()
+}
+
+/// Return a copy of the `range()` bound in `bound` with `delta` added to the bound, or `None`
+/// if the new bound would be a negative literal.  The function changes the literal of an
+/// integer bound and of a bound of the form `start + N`, which Vyper requires for the end of
+/// some two-argument ranges.  Any other bound gets wrapped in a binary operation, which keeps
+/// the bound a constant expression.
+///
+/// # Arguments
+///
+/// * `bound` - The argument of the `range()` call.
+/// * `delta` - The amount to add to the bound, either 1 or -1.
+fn adjusted_range_bound(bound: &VyperAST, delta: i64) -> Option<VyperAST> {
+    let literal_pointer = match bound.get_str_for_key("ast_type") {
+        Some("Int") => Some("/value"),
+        Some("BinOp")
+            if bound.get_str_for_key("op/ast_type") == Some("Add")
+                && bound.get_str_for_key("right/ast_type") == Some("Int") =>
+        {
+            Some("/right/value")
+        }
+        _ => None,
+    };
+
+    if let Some(pointer) = literal_pointer {
+        let value = bound.pointer(pointer)?.as_i64()? + delta;
+        if value < 0 {
+            return None;
+        }
+        let mut new_bound = bound.clone();
+        *new_bound.pointer_mut(pointer)? = json![value];
+        return Some(new_bound);
+    }
+
+    let op = if delta > 0 { "Add" } else { "Sub" };
+    Some(json!({
+        "node_id": 9999988,
+        "ast_type": "BinOp",
+        "left": bound.clone(),
+        "op": {
+            "node_id": 9999987,
+            "ast_type": op
+        },
+        "right": new_integer_constant_node(delta.abs()).ok()?
+    }))
+}
+
+/// The ways the [`RangeBoundMutator`] can change the bounds of a `range()` call.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RangeBoundChange {
+    /// Add one to the argument at the index.
+    Increment(usize),
+
+    /// Subtract one from the argument at the index.
+    Decrement(usize),
+
+    /// Swap the start and end arguments of a two-argument range.
+    Swap,
+}
+
+impl fmt::Display for RangeBoundChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            RangeBoundChange::Increment(i) => write!(f, "increment {i}"),
+            RangeBoundChange::Decrement(i) => write!(f, "decrement {i}"),
+            RangeBoundChange::Swap => write!(f, "swap"),
+        }
+    }
+}
+
+/// Implement the RangeBound mutation algorithm.
+///
+/// The algorithm selects a `for` loop that iterates over a `range()` call and either adds one
+/// to a bound, subtracts one from a bound, or swaps the bounds of a two-argument range.  Vyper
+/// requires the bounds of a range to be constant expressions, so the algorithm only produces
+/// constant expressions from constant expressions.
+struct RangeBoundMutator {
+    /// Information about the mutation.
+    comment_node: Option<VyperAST>,
+}
+
+impl RangeBoundMutator {
+    /// Create a new range bound mutator.
+    pub fn new() -> RangeBoundMutator {
+        RangeBoundMutator { comment_node: None }
+    }
+
+    /// Return the list of changes that the mutator can make to the `range()` call in `node`.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The `For` node.
+    fn changes_for(&self, node: &VyperAST) -> Vec<RangeBoundChange> {
+        let args = match range_arguments(node) {
+            Some(a) => a,
+            None => return vec![],
+        };
+
+        let mut changes = vec![];
+
+        // Changing the start or swapping the bounds of range(start, start + N) produces a range
+        // that Vyper rejects, so only change the span and keep the span positive.
+        if let Some(span) = range_span(args) {
+            changes.push(RangeBoundChange::Increment(1));
+            if span > 1 {
+                changes.push(RangeBoundChange::Decrement(1));
+            }
+            return changes;
+        }
+
+        for (i, arg) in args.iter().enumerate() {
+            if adjusted_range_bound(arg, 1).is_some() {
+                changes.push(RangeBoundChange::Increment(i));
+            }
+            if adjusted_range_bound(arg, -1).is_some() {
+                changes.push(RangeBoundChange::Decrement(i));
+            }
+        }
+        if args.len() == 2 && args[0] != args[1] {
+            changes.push(RangeBoundChange::Swap);
+        }
+        changes
+    }
+}
+
+impl Mutator<VyperAST> for RangeBoundMutator {
+    fn is_mutable_node(&mut self, node: &VyperAST, _rand: &mut Pcg64) -> bool {
+        !self.changes_for(node).is_empty()
+    }
+
+    fn mutate(
+        &mut self,
+        node: &mut VyperAST,
+        rand: &mut Pcg64,
+    ) -> Result<MutatorResult, MutagenyxError> {
+        // Remove any previously existing comment
+        self.comment_node = None;
+
+        let mut mutator_result = MutatorResult::new();
+        mutator_result.mutation_type = Some(self.implements());
+
+        let mut hasher = Hasher::new(MessageDigest::sha256())?;
+
+        let changes = self.changes_for(node);
+        let change = match changes.choose(rand) {
+            Some(c) => *c,
+            None => {
+                return Err(MutagenyxError::MalformedNode(
+                    String::from("For"),
+                    String::from("iter"),
+                ))
+            }
+        };
+
+        let iter_node = node.get_mut("iter").ok_or_else(|| {
+            MutagenyxError::MalformedNode(String::from("For"), String::from("iter"))
+        })?;
+        let original_iter_s = pretty_print_node(iter_node);
+
+        if let Some(args) = iter_node.get_mut("args").and_then(|a| a.as_array_mut()) {
+            match change {
+                RangeBoundChange::Increment(i) | RangeBoundChange::Decrement(i) => {
+                    let delta = if let RangeBoundChange::Increment(_) = change {
+                        1
+                    } else {
+                        -1
+                    };
+                    if let Some(new_bound) = adjusted_range_bound(&args[i], delta) {
+                        args[i] = new_bound;
+                    }
+                }
+                RangeBoundChange::Swap => args.swap(0, 1),
+            }
+        }
+
+        let new_iter_s = pretty_print_node(iter_node);
+        hasher.update(change.to_string().as_bytes())?;
+
+        let comment_text = format!(
+            "RangeBound Mutator: Changing '{}' to '{}'",
+            original_iter_s, new_iter_s
+        );
+        if let Ok(comment_node) = new_comment_node(&comment_text) {
+            self.comment_node = Some(comment_node);
+        }
+
+        if let Some(id) = node.get_int_for_key("node_id") {
+            mutator_result.mutated_node_id = Some(id as u64);
+        }
+
+        let byte_array = hasher.finish()?;
+        mutator_result.random_behavior_hash = Some(hex::encode(byte_array));
+
+        Ok(mutator_result)
+    }
+
+    fn implements(&self) -> MutationType {
+        MutationType::Vyper(VyperMutation::RangeBound)
+    }
+
+    fn description(&self) -> &'static str {
+        "Add one to or subtract one from a bound of the range() call of a for loop, or swap the \
+            bounds of a two-argument range."
+    }
+
+    fn example(&self) -> &'static str {
+        "for i in range(10): might become for i in range(11):"
+    }
+
+    fn get_comment_node(&self) -> Option<VyperAST> {
+        self.comment_node.clone()
+    }
+}
+
+/// Implement the [`MutatorFactory<T>`] trait to have an interface for getting mutators for requested
+/// mutation algorithms.
+pub struct VyperMutatorFactory {
+    /// The operator groups for the operator mutators.
+    operators: OperatorRegistry,
+}
+
+impl VyperMutatorFactory {
+    /// Return a new factory that builds the operator mutators from the groups in `operators`.
+    ///
+    /// # Arguments
+    ///
+    /// * `operators` - The operator groups for the operator mutators.
+    pub fn new(operators: OperatorRegistry) -> Self {
+        VyperMutatorFactory { operators }
+    }
+}
+
+impl MutatorFactory<VyperAST> for VyperMutatorFactory {
+    fn mutator_for(&self, mutation_type: &MutationType) -> Option<Box<dyn Mutator<VyperAST>>> {
+        match mutation_type {
+            MutationType::Generic(t) => match t {
+                GenericMutation::ArithmeticBinaryOp => Some(Box::new(BinaryOpMutator::new(
+                    self.operators.operators(ARITHMETIC_OPERATORS),
+                    MutationType::Generic(GenericMutation::ArithmeticBinaryOp),
+                ))),
+                GenericMutation::LogicalBinaryOp => Some(Box::new(BinaryOpMutator::new(
+                    self.operators.operators(LOGICAL_OPERATORS),
+                    MutationType::Generic(GenericMutation::LogicalBinaryOp),
                 ))),
                 GenericMutation::BitwiseBinaryOp => Some(Box::new(BinaryOpMutator::new(
-                    bitwise_operators(),
+                    self.operators.operators(BITWISE_OPERATORS),
                     MutationType::Generic(GenericMutation::BitwiseBinaryOp),
                 ))),
                 GenericMutation::BitshiftBinaryOp => Some(Box::new(BinaryOpMutator::new(
-                    bitshift_operators(),
+                    self.operators.operators(BITSHIFT_OPERATORS),
                     MutationType::Generic(GenericMutation::BitshiftBinaryOp),
                 ))),
                 GenericMutation::ComparisonBinaryOp => Some(Box::new(BinaryOpMutator::new(
-                    comparison_operators(),
+                    self.operators.operators(COMPARISON_OPERATORS),
                     MutationType::Generic(GenericMutation::ComparisonBinaryOp),
                 ))),
                 GenericMutation::Assignment => Some(Box::new(AssignmentMutator::new())),
+                GenericMutation::AssignmentOperatorReplacement => None,
+                GenericMutation::BooleanLiteralFlip => {
+                    Some(Box::new(BooleanLiteralFlipMutator::new()))
+                }
                 GenericMutation::DeleteStatement => Some(Box::new(DeleteStatementMutator::new())),
+                GenericMutation::DuplicateStatement => {
+                    Some(Box::new(DuplicateStatementMutator::new()))
+                }
                 GenericMutation::FunctionCall => Some(Box::new(FunctionCallMutator::new())),
                 GenericMutation::SwapFunctionArguments => {
                     Some(Box::new(SwapFunctionArgumentsMutator::new()))
@@ -1846,12 +3057,229 @@ impl MutatorFactory<VyperAST> for VyperMutatorFactory {
                 GenericMutation::IfStatement => Some(Box::new(IfStatementMutator::new())),
                 GenericMutation::Integer => Some(Box::new(IntegerMutator::new())),
                 GenericMutation::SwapOperatorArguments => {
-                    Some(Box::new(SwapOperatorArgumentsMutator::new()))
+                    Some(Box::new(SwapOperatorArgumentsMutator::new(
+                        self.operators.operators(NON_COMMUTATIVE_OPERATORS),
+                    )))
                 }
                 GenericMutation::SwapLines => Some(Box::new(SwapLinesMutator::new())),
                 GenericMutation::UnaryOp => Some(Box::new(UnaryOpMutator::new())),
             },
+            MutationType::Vyper(t) => match t {
+                VyperMutation::LowLevelCallTarget => {
+                    Some(Box::new(LowLevelCallTargetMutator::new()))
+                }
+                VyperMutation::StateVariableModifiers => {
+                    Some(Box::new(StateVariableModifiersMutator::new()))
+                }
+                VyperMutation::RangeBound => Some(Box::new(RangeBoundMutator::new())),
+            },
             _ => None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    /// Return a new expression node of type `ast_type` with the Vyper operator `operator`.
+    fn new_operator_node(ast_type: &str, operator: &str) -> VyperAST {
+        json!({
+            "ast_type": ast_type,
+            "node_id": 3,
+            "op": {"ast_type": operator},
+            "left": {"ast_type": "Name", "node_id": 1, "id": "a"},
+            "right": {"ast_type": "Name", "node_id": 2, "id": "b"}
+        })
+    }
+
+    /// Mutate a node of type `ast_type` with the operator `operator` with many seeds and return
+    /// the Vyper operators of the mutated nodes.
+    fn mutated_operators(ast_type: &str, operator: &str) -> Vec<String> {
+        let mut mutator = BinaryOpMutator::new(
+            vec![
+                "+", "-", "*", "/", "&&", "||", "&", "|", "==", "!=", "<", "<=", ">", ">=",
+            ],
+            MutationType::Generic(GenericMutation::ArithmeticBinaryOp),
+        );
+
+        (0..50)
+            .map(|seed| {
+                let mut rng = Pcg64::seed_from_u64(seed);
+                let mut node = new_operator_node(ast_type, operator);
+                assert!(mutator.is_mutable_node(&node, &mut rng));
+                mutator.mutate(&mut node, &mut rng).unwrap();
+                String::from(node["op"]["ast_type"].as_str().unwrap())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_compare_node_only_becomes_comparison() {
+        let comparisons = ["Eq", "NotEq", "Gt", "GtE", "LtE"];
+        for operator in mutated_operators("Compare", "Lt") {
+            assert!(comparisons.contains(&operator.as_str()), "{operator}");
+        }
+    }
+
+    #[test]
+    fn test_bool_op_node_only_becomes_boolean_op() {
+        for operator in mutated_operators("BoolOp", "And") {
+            assert_eq!(operator, "Or");
+        }
+    }
+
+    #[test]
+    fn test_bin_op_node_only_becomes_binary_op() {
+        let binary_operators = ["Sub", "Mult", "Div", "BitAnd", "BitOr"];
+        for operator in mutated_operators("BinOp", "Add") {
+            assert!(binary_operators.contains(&operator.as_str()), "{operator}");
+        }
+    }
+
+    #[test]
+    fn test_format_vyper_decimal() {
+        assert_eq!(format_vyper_decimal(1e-10_f64), "0.0000000001");
+        assert_eq!(format_vyper_decimal(-2.5e-9_f64), "-0.0000000025");
+        assert_eq!(format_vyper_decimal(1e-11_f64), "0.0");
+        assert_eq!(format_vyper_decimal(-1e-12_f64), "0.0");
+        assert_eq!(format_vyper_decimal(1.5_f64), "1.5");
+        assert_eq!(format_vyper_decimal(2.0_f64), "2.0");
+        assert_eq!(format_vyper_decimal(0.1_f64 + 0.2_f64), "0.3");
+        assert_eq!(format_vyper_decimal(1e20_f64), "100000000000000000000.0");
+        assert_eq!(format_vyper_decimal(1e-10_f32), "0.0000000001");
+
+        let node = new_float_constant_node(3e-10_f64).unwrap();
+        assert_eq!(node["value"], "0.0000000003");
+    }
+
+    #[test]
+    fn test_node_without_other_valid_operator_is_not_mutable() {
+        let mut mutator = BinaryOpMutator::new(
+            vec!["+", "-", "&&"],
+            MutationType::Generic(GenericMutation::ArithmeticBinaryOp),
+        );
+        let mut rng = Pcg64::seed_from_u64(1);
+        assert!(!mutator.is_mutable_node(&new_operator_node("BoolOp", "And"), &mut rng));
+        assert!(mutator.is_mutable_node(&new_operator_node("BinOp", "Add"), &mut rng));
+    }
+
+    #[test]
+    fn test_boolean_literal_flip() {
+        let call = json!({
+            "ast_type": "Call", "node_id": 14,
+            "func": {"ast_type": "Name", "node_id": 11, "id": "set_paused"},
+            "args": [{"ast_type": "NameConstant", "node_id": 12, "value": false}],
+            "keywords": []
+        });
+
+        let factory = VyperMutatorFactory::new(OperatorRegistry::default());
+        let mut mutator = factory
+            .mutator_for(&MutationType::Generic(GenericMutation::BooleanLiteralFlip))
+            .unwrap();
+        let mut rng = Pcg64::seed_from_u64(0);
+
+        // None is a NameConstant too, but it has no opposite value.
+        let none = json!({"ast_type": "NameConstant", "node_id": 13, "value": null});
+        assert!(!mutator.is_mutable_node(&none, &mut rng));
+        assert!(!mutator.is_mutable_node(&call, &mut rng));
+
+        let mut node = call.clone();
+        let argument = &mut node["args"][0];
+        assert!(mutator.is_mutable_node(argument, &mut rng));
+        let result = mutator.mutate(argument, &mut rng).unwrap();
+        assert_eq!(result.mutated_node_id, Some(12));
+
+        assert_eq!(pretty_print_node(&node), "set_paused(True)");
+
+        // Only the value of the constant changes.
+        assert_eq!(
+            node["args"][0],
+            json!({"ast_type": "NameConstant", "node_id": 12, "value": true})
+        );
+
+        // Flipping the constant again gives back the original call.
+        mutator.mutate(&mut node["args"][0], &mut rng).unwrap();
+        assert_eq!(node, call);
+    }
+
+    #[test]
+    fn test_range_bound_keeps_start_plus_span_form() {
+        let name = |node_id: u64| json!({"ast_type": "Name", "node_id": node_id, "id": "x"});
+        let int = |node_id: u64, value: i64| json!({"ast_type": "Int", "node_id": node_id, "value": value});
+        let for_node = |args: Vec<VyperAST>| {
+            json!({
+                "ast_type": "For", "node_id": 20,
+                "target": {"ast_type": "Name", "node_id": 21, "id": "i"},
+                "iter": {
+                    "ast_type": "Call", "node_id": 22,
+                    "func": {"ast_type": "Name", "node_id": 23, "id": "range"},
+                    "args": args, "keywords": []
+                },
+                "body": [{"ast_type": "Pass", "node_id": 24}]
+            })
+        };
+        let start_plus = |span: i64| {
+            json!({
+                "ast_type": "BinOp", "node_id": 31, "left": name(32),
+                "op": {"ast_type": "Add", "node_id": 33}, "right": int(34, span)
+            })
+        };
+
+        let mut mutator = RangeBoundMutator::new();
+        let mut mutants: Vec<String> = (0..20)
+            .map(|seed| {
+                let mut node = for_node(vec![name(30), start_plus(4)]);
+                let mut rng = Pcg64::seed_from_u64(seed);
+                assert!(mutator.is_mutable_node(&node, &mut rng));
+                mutator.mutate(&mut node, &mut rng).unwrap();
+                pretty_print_node(&node["iter"])
+            })
+            .collect();
+        mutants.sort();
+        mutants.dedup();
+        assert_eq!(mutants, vec!["range(x, x + 3)", "range(x, x + 5)"]);
+
+        // A span of one only grows.
+        let node = for_node(vec![name(30), start_plus(1)]);
+        assert_eq!(
+            mutator.changes_for(&node),
+            vec![RangeBoundChange::Increment(1)]
+        );
+
+        // Other ranges keep every change that does not make a bound negative.
+        let node = for_node(vec![int(30, 0), int(31, 10)]);
+        assert_eq!(
+            mutator.changes_for(&node),
+            vec![
+                RangeBoundChange::Increment(0),
+                RangeBoundChange::Increment(1),
+                RangeBoundChange::Decrement(1),
+                RangeBoundChange::Swap
+            ]
+        );
+    }
+
+    #[test]
+    fn test_binary_op_rejection_reason() {
+        let mutator = BinaryOpMutator::new(
+            vec!["+", "-", "<"],
+            MutationType::Generic(GenericMutation::ArithmeticBinaryOp),
+        );
+        let reason = |node: &VyperAST| mutator.rejection_reason(node).unwrap();
+
+        assert_eq!(
+            reason(&json!({"ast_type": "Name", "id": "a"})),
+            "not a BinOp, BoolOp or Compare node"
+        );
+        assert_eq!(
+            reason(&new_operator_node("BinOp", "Mult")),
+            "operator '*' is not one of [\"+\", \"-\"]"
+        );
+        assert_eq!(
+            reason(&new_operator_node("Compare", "Lt")),
+            "no other operator in [\"<\"] fits a Compare node"
+        );
+    }
+}
@@ -1,29 +1,58 @@
 use std::collections::HashMap;
 
+/// The text of the Python operator for each Vyper operator node type.
+static PYTHON_OPERATORS: [(&str, &str); 21] = [
+    ("Add", "+"),
+    ("Sub", "-"),
+    ("Mult", "*"),
+    ("Div", "/"),
+    ("Mod", "%"),
+    ("Pow", "**"),
+    ("And", "&&"),
+    ("Or", "||"),
+    ("BitAnd", "&"),
+    ("BitOr", "|"),
+    ("BitXor", "^"),
+    ("Eq", "=="),
+    ("NotEq", "!="),
+    ("Gt", ">"),
+    ("GtE", ">="),
+    ("Lt", "<"),
+    ("LtE", "<="),
+    ("Not", "!"),
+    ("Invert", "~"),
+    ("In", "in"),
+    ("NotIn", "not in"),
+];
+
 pub fn get_python_operator_map() -> HashMap<&'static str, &'static str> {
-    let mut operator_map: HashMap<&'static str, &'static str> = HashMap::new();
+    PYTHON_OPERATORS.iter().copied().collect()
+}
 
-    operator_map.insert("Add", "+");
-    operator_map.insert("Sub", "-");
-    operator_map.insert("Mult", "*");
-    operator_map.insert("Div", "/");
-    operator_map.insert("Mod", "%");
-    operator_map.insert("Pow", "**");
-    operator_map.insert("And", "&&");
-    operator_map.insert("Or", "||");
-    operator_map.insert("BitAnd", "&");
-    operator_map.insert("BitOr", "|");
-    operator_map.insert("BitXor", "^");
-    operator_map.insert("Eq", "==");
-    operator_map.insert("NotEq", "!=");
-    operator_map.insert("Gt", ">");
-    operator_map.insert("GtE", ">=");
-    operator_map.insert("Lt", "<");
-    operator_map.insert("LtE", "<=");
-    operator_map.insert("Not", "!");
-    operator_map.insert("Invert", "~");
-    operator_map.insert("In", "in");
-    operator_map.insert("NotIn", "not in");
+/// Return the text of the Python operator for the Vyper operator node type `ast_type`.
+///
+/// # Arguments
+///
+/// * `ast_type` - The `ast_type` value of the operator node.
+pub fn python_operator_for(ast_type: &str) -> Option<&'static str> {
+    PYTHON_OPERATORS
+        .iter()
+        .find(|(t, _)| *t == ast_type)
+        .map(|(_, operator)| *operator)
+}
 
-    operator_map
+/// Return the `ast_type` of the Vyper expression node that can hold the Python operator
+/// `operator`: `BinOp` for arithmetic and bitwise operators, `BoolOp` for boolean operators, and
+/// `Compare` for comparison operators.  Return None for the unary operators.
+///
+/// # Arguments
+///
+/// * `operator` - The text of the Python operator.
+pub fn node_type_for_operator(operator: &str) -> Option<&'static str> {
+    match operator {
+        "+" | "-" | "*" | "/" | "%" | "**" | "&" | "|" | "^" => Some("BinOp"),
+        "&&" | "||" => Some("BoolOp"),
+        "==" | "!=" | ">" | ">=" | "<" | "<=" | "in" | "not in" => Some("Compare"),
+        _ => None,
+    }
 }
@@ -1,17 +1,17 @@
 //! The `vyper::pretty_printer` module provides the code to traverse a Vyper AST and print out
 //! the source code represented in the AST.
 
+use crate::commenter::BLOCK_COMMENTS_KEY;
 use crate::json::*;
 use crate::node_printer::{NodePrinter, NodePrinterFactory};
 use crate::node_printer_helpers::*;
 use crate::preferences::*;
 use crate::pretty_printer::{
-    write_flowable_text, write_indent, write_newline, write_space, write_string, write_token,
-    write_triple_string, PrettyPrinter,
+    write_flowable_text, write_indent, write_newline, write_raw, write_space, write_string,
+    write_token, write_triple_string, PrettyPrinter,
 };
 use crate::vyper::ast::VyperAST;
-use crate::vyper::operators::get_python_operator_map;
-use std::collections::HashMap;
+use crate::vyper::operators::python_operator_for;
 use std::io::Write;
 
 /// Write the `value` element of `node` by traversing the object in `value`.
@@ -121,6 +121,80 @@ fn write_value_assignment(
     }
 }
 
+/// The calls that wrap the type of a storage variable declaration, in the order that the printer
+/// writes the calls for the `is_public`, `is_constant`, and `is_immutable` flags of a
+/// `VariableDecl` node.
+static DECLARATION_WRAPPERS: [(&str, &str); 3] = [
+    ("public", "is_public"),
+    ("constant", "is_constant"),
+    ("immutable", "is_immutable"),
+];
+
+/// Write the `annotation` element of an `AnnAssign` or `VariableDecl` node, such as
+/// `public(HashMap[address, uint256])`.
+///
+/// Depending on the compiler version a declaration records a `public(...)`, `constant(...)`, or
+/// `immutable(...)` wrapper as a flag of the node, as a `Call` node in the annotation, or both.
+/// The function writes each wrapper once, followed by the type inside the wrappers.
+///
+/// # Arguments
+///
+/// * `printer` - The [`PrettyPrinter`] object that will format the output.
+/// * `stream` - The [`Write`] object that will receive the formatted text.
+/// * `factory` - The [`NodePrinterFactory<AST>`] object that generates node printers for AST
+///   nodes.
+/// * `node` - The declaration node in the Vyper AST.
+fn write_declaration_annotation(
+    printer: &mut PrettyPrinter,
+    stream: &mut dyn Write,
+    factory: &dyn NodePrinterFactory<VyperAST>,
+    node: &VyperAST,
+) {
+    let mut wrappers: Vec<&str> = DECLARATION_WRAPPERS
+        .iter()
+        .filter(|(_, flag)| node.get_bool_for_key(flag) == Some(true))
+        .map(|(wrapper, _)| *wrapper)
+        .collect();
+
+    let mut annotation_node = match node.get("annotation") {
+        Some(a) => a,
+        None => return,
+    };
+
+    // Unwrap the wrapper calls of the annotation.
+    while annotation_node.get_str_for_key("ast_type") == Some("Call") {
+        let wrapper = match annotation_node.get_str_for_key("func/id") {
+            Some(id) if DECLARATION_WRAPPERS.iter().any(|(w, _)| *w == id) => id,
+            _ => break,
+        };
+        let argument = match annotation_node.get("args").and_then(|a| a.as_array()) {
+            Some(args) if args.len() == 1 => &args[0],
+            _ => break,
+        };
+        if !wrappers.contains(&wrapper) {
+            wrappers.push(wrapper);
+        }
+        annotation_node = argument;
+    }
+
+    for wrapper in &wrappers {
+        write_token(printer, stream, wrapper);
+        write_token(printer, stream, "(");
+    }
+
+    let mut printer_settings = factory.get_settings().clone();
+    printer_settings.set_value_for_key(
+        TUPLES_SHOULD_USE_PARENTHESES,
+        PreferenceValue::Boolean(true),
+    );
+    let sub_factory = VyperNodePrinterFactory::new(printer_settings);
+    traverse_sub_node_and_print(printer, stream, &sub_factory, annotation_node);
+
+    for _ in &wrappers {
+        write_token(printer, stream, ")");
+    }
+}
+
 /// Write the object in the `body` element as a sequence of indented text followed by a newline.
 ///
 /// # Arguments
@@ -255,10 +329,7 @@ fn pretty_print_node(node: &VyperAST, factory: &dyn NodePrinterFactory<VyperAST>
     let mut node_contents = Vec::new();
     let mut printer = PrettyPrinter::new(4, 150);
     traverse_sub_node_and_print(&mut printer, &mut node_contents, factory, node);
-
-    // s now contains the pretty-printed node.
-    let s = core::str::from_utf8(node_contents.as_slice()).unwrap();
-    String::from(s)
+    String::from_utf8_lossy(&node_contents).into_owned()
 }
 
 struct DummyNodePrinter {}
@@ -299,46 +370,7 @@ impl NodePrinter<VyperAST> for VariableDeclPrinter {
             traverse_sub_node_and_print(printer, stream, factory, target_node);
             write_token(printer, stream, ":");
             write_space(printer, stream);
-
-            let mut close_parens_needed = 0;
-            if let Some(public) = node.get_bool_for_key("is_public") {
-                if public {
-                    close_parens_needed += 1;
-                    write_token(printer, stream, "public");
-                    write_token(printer, stream, "(");
-                }
-            }
-
-            if let Some(constant) = node.get_bool_for_key("is_constant") {
-                if constant {
-                    close_parens_needed += 1;
-                    write_token(printer, stream, "constant");
-                    write_token(printer, stream, "(");
-                }
-            }
-
-            if let Some(immutable) = node.get_bool_for_key("is_immutable") {
-                if immutable {
-                    close_parens_needed += 1;
-                    write_token(printer, stream, "immutable");
-                    write_token(printer, stream, "(");
-                }
-            }
-
-            if let Some(annotation_node) = node.get("annotation") {
-                let mut printer_settings = factory.get_settings().clone();
-                printer_settings.set_value_for_key(
-                    TUPLES_SHOULD_USE_PARENTHESES,
-                    PreferenceValue::Boolean(true),
-                );
-                let sub_factory = VyperNodePrinterFactory::new(printer_settings);
-                traverse_sub_node_and_print(printer, stream, &sub_factory, annotation_node);
-            }
-
-            for _ in 0..close_parens_needed {
-                write_token(printer, stream, ")");
-            }
-
+            write_declaration_annotation(printer, stream, factory, node);
             write_value_assignment(printer, stream, factory, node);
         }
     }
@@ -386,9 +418,9 @@ impl NodePrinter<VyperAST> for IndexPrinter {
     }
 }
 
-struct TuplePrinter {
-    pub use_parentheses: bool,
-}
+/// The printer writes parentheses around the tuple when the factory setting for
+/// `tuples_should_use_parentheses` is true.
+struct TuplePrinter {}
 
 impl NodePrinter<VyperAST> for TuplePrinter {
     fn print_node(
@@ -398,11 +430,12 @@ impl NodePrinter<VyperAST> for TuplePrinter {
         factory: &dyn NodePrinterFactory<VyperAST>,
         node: &VyperAST,
     ) {
-        if self.use_parentheses {
+        let use_parentheses = factory.get_preference_value_for_key(TUPLES_SHOULD_USE_PARENTHESES);
+        if use_parentheses {
             write_token(printer, stream, "(");
         }
         write_elements_array(printer, stream, factory, node);
-        if self.use_parentheses {
+        if use_parentheses {
             write_token(printer, stream, ")");
         }
     }
@@ -547,16 +580,24 @@ impl NodePrinter<VyperAST> for ArgumentsPrinter {
                     write_args_as_array(printer, stream, factory, node);
                 } else if let Some(args_node) = node.get("args") {
                     if let Some(args_array) = args_node.as_array() {
-                        let first_default_index = args_array.len() - defaults_array.len();
+                        // There cannot be more default values than arguments.
+                        let first_default_index =
+                            args_array.len().saturating_sub(defaults_array.len());
                         let mut i: usize = 0;
                         while i < args_array.len() {
                             let arg = args_array.get(i).unwrap();
                             traverse_sub_node_and_print(printer, stream, factory, arg);
                             if i >= first_default_index {
                                 let default_index = i - first_default_index;
-                                let default_node = defaults_array.get(default_index).unwrap();
-                                write_token(printer, stream, "=");
-                                traverse_sub_node_and_print(printer, stream, factory, default_node);
+                                if let Some(default_node) = defaults_array.get(default_index) {
+                                    write_token(printer, stream, "=");
+                                    traverse_sub_node_and_print(
+                                        printer,
+                                        stream,
+                                        factory,
+                                        default_node,
+                                    );
+                                }
                             }
                             if i < (args_array.len() - 1) {
                                 write_token(printer, stream, ",");
@@ -635,17 +676,7 @@ impl NodePrinter<VyperAST> for AttributePrinter {
     }
 }
 
-struct AugAssignPrinter {
-    operator_map: HashMap<&'static str, &'static str>,
-}
-
-impl AugAssignPrinter {
-    fn new() -> AugAssignPrinter {
-        AugAssignPrinter {
-            operator_map: get_python_operator_map(),
-        }
-    }
-}
+struct AugAssignPrinter {}
 
 impl NodePrinter<VyperAST> for AugAssignPrinter {
     fn print_node(
@@ -661,8 +692,8 @@ impl NodePrinter<VyperAST> for AugAssignPrinter {
         write_space(printer, stream);
         if let Some(op_node) = node.get("op") {
             if let Some(ast_type_str) = op_node.get_str_for_key("ast_type") {
-                if self.operator_map.contains_key(ast_type_str) {
-                    write_token(printer, stream, self.operator_map[ast_type_str]);
+                if let Some(operator) = python_operator_for(ast_type_str) {
+                    write_token(printer, stream, operator);
                 }
             }
         }
@@ -707,17 +738,7 @@ impl NodePrinter<VyperAST> for ValuePrinter {
     }
 }
 
-struct BinOpPrinter {
-    operator_map: HashMap<&'static str, &'static str>,
-}
-
-impl BinOpPrinter {
-    fn new() -> BinOpPrinter {
-        BinOpPrinter {
-            operator_map: get_python_operator_map(),
-        }
-    }
-}
+struct BinOpPrinter {}
 
 impl NodePrinter<VyperAST> for BinOpPrinter {
     fn print_node(
@@ -732,9 +753,9 @@ impl NodePrinter<VyperAST> for BinOpPrinter {
         }
         if let Some(op_node) = node.get("op") {
             if let Some(ast_type_str) = op_node.get_str_for_key("ast_type") {
-                if self.operator_map.contains_key(ast_type_str) {
+                if let Some(operator) = python_operator_for(ast_type_str) {
                     write_space(printer, stream);
-                    write_token(printer, stream, self.operator_map[ast_type_str]);
+                    write_token(printer, stream, operator);
                     write_space(printer, stream);
                 }
             }
@@ -868,15 +889,43 @@ impl NodePrinter<VyperAST> for AnnAssignPrinter {
         if let Some(target_node) = node.get("target") {
             traverse_sub_node_and_print(printer, stream, factory, target_node);
         }
-        if let Some(annotation_node) = node.get("annotation") {
+        if node.get("annotation").is_some() {
             write_token(printer, stream, ":");
             write_space(printer, stream);
-            traverse_sub_node_and_print(printer, stream, factory, annotation_node);
+            write_declaration_annotation(printer, stream, factory, node);
         }
         write_value_assignment(printer, stream, factory, node);
     }
 }
 
+/// Print the `implements: <Interface>` declaration of a module.  The `ImplementsDecl` node has
+/// the same layout as an `AnnAssign` node with the name of the interface in the `annotation`
+/// element.  The printer writes a list or tuple of interfaces as a comma separated list.
+struct ImplementsDeclPrinter {}
+
+impl NodePrinter<VyperAST> for ImplementsDeclPrinter {
+    fn print_node(
+        &mut self,
+        printer: &mut PrettyPrinter,
+        stream: &mut dyn Write,
+        factory: &dyn NodePrinterFactory<VyperAST>,
+        node: &VyperAST,
+    ) {
+        write_token(printer, stream, "implements");
+        write_token(printer, stream, ":");
+        write_space(printer, stream);
+        if let Some(annotation_node) = node.get("annotation") {
+            if let Some(annotation_array) = annotation_node.as_array() {
+                print_array_helper(printer, stream, factory, annotation_array);
+            } else if annotation_node.get_str_for_key("ast_type") == Some("Tuple") {
+                write_elements_array(printer, stream, factory, annotation_node);
+            } else {
+                traverse_sub_node_and_print(printer, stream, factory, annotation_node);
+            }
+        }
+    }
+}
+
 struct ExprPrinter {}
 
 impl NodePrinter<VyperAST> for ExprPrinter {
@@ -1355,10 +1404,23 @@ impl NodePrinter<VyperAST> for CommentPrinter {
                     write_flowable_text(printer, stream, value_str, "# ");
                 }
             } else if value_node.is_object() {
-                write_token(printer, stream, "#");
-                write_space(printer, stream);
                 let s = pretty_print_node(value_node, factory);
-                write_flowable_text(printer, stream, &s, "# ");
+                if factory.get_preference_value_for_key(BLOCK_COMMENTS_KEY) {
+                    // Vyper does not have block comments, so comment out each line of the code.
+                    for (index, line) in s.lines().enumerate() {
+                        if index > 0 {
+                            write_newline(printer, stream);
+                            write_indent(printer, stream);
+                        }
+                        write_token(printer, stream, "#");
+                        write_space(printer, stream);
+                        write_raw(printer, stream, line);
+                    }
+                } else {
+                    write_token(printer, stream, "#");
+                    write_space(printer, stream);
+                    write_flowable_text(printer, stream, &s, "# ");
+                }
             }
         }
     }
@@ -1454,7 +1516,12 @@ impl NodePrinter<VyperAST> for DictPrinter {
             if let Some(keys_array) = keys_node.as_array() {
                 if let Some(values_node) = node.get("values") {
                     if let Some(values_array) = values_node.as_array() {
-                        assert_eq!(keys_array.len(), values_array.len());
+                        if keys_array.len() != values_array.len() {
+                            log::info!(
+                                "keys and values arrays have different lengths in DictPrinter"
+                            );
+                            return;
+                        }
 
                         if !keys_array.is_empty() {
                             write_newline(printer, stream);
@@ -1527,7 +1594,7 @@ static FUNCTION_DEF_USES_STRUCT_DECL_FORM: &str = "function_def_uses_struct_decl
 
 /// Type that implements [`NodePrinterFactory<AST>`] for Vyper AST nodes.
 ///
-/// Use this factory object with the [`crate::pretty_print_visitor::PrettyPrintVisitor<AST>`] object.
+/// Use this factory object with [`crate::node_printer::pretty_print_node`].
 #[derive(Clone)]
 pub struct VyperNodePrinterFactory {
     settings: Preferences,
@@ -1543,9 +1610,11 @@ impl VyperNodePrinterFactory {
     pub fn new(settings: Preferences) -> VyperNodePrinterFactory {
         VyperNodePrinterFactory { settings }
     }
+}
 
+impl Default for VyperNodePrinterFactory {
     /// Create a new node printer factory with default settings.
-    pub fn default() -> VyperNodePrinterFactory {
+    fn default() -> VyperNodePrinterFactory {
         let mut preferences = Preferences::new();
         preferences.set_value_for_key(
             TUPLES_SHOULD_USE_PARENTHESES,
@@ -1555,6 +1624,7 @@ impl VyperNodePrinterFactory {
             FUNCTION_DEF_USES_STRUCT_DECL_FORM,
             PreferenceValue::Boolean(false),
         );
+        preferences.set_value_for_key(BLOCK_COMMENTS_KEY, PreferenceValue::Boolean(false));
         VyperNodePrinterFactory {
             settings: preferences,
         }
@@ -1569,15 +1639,7 @@ impl NodePrinterFactory<VyperAST> for VyperNodePrinterFactory {
                 "VariableDecl" => Box::new(VariableDeclPrinter {}),
                 "Subscript" => Box::new(SubscriptPrinter {}),
                 "Index" => Box::new(IndexPrinter {}),
-                "Tuple" => {
-                    Box::new(TuplePrinter {
-                        use_parentheses: <VyperNodePrinterFactory as NodePrinterFactory<
-                            VyperAST,
-                        >>::get_preference_value_for_key(
-                            self, TUPLES_SHOULD_USE_PARENTHESES
-                        ),
-                    })
-                }
+                "Tuple" => Box::new(TuplePrinter {}),
                 "FunctionDef" => Box::new(
                     FunctionDefPrinter::new(<VyperNodePrinterFactory as NodePrinterFactory<
                         VyperAST,
@@ -1589,16 +1651,17 @@ impl NodePrinterFactory<VyperAST> for VyperNodePrinterFactory {
                 "arg" => Box::new(ArgPrinter {}),
                 "Assign" => Box::new(AssignPrinter {}),
                 "Attribute" => Box::new(AttributePrinter {}),
-                "AugAssign" => Box::new(AugAssignPrinter::new()),
+                "AugAssign" => Box::new(AugAssignPrinter {}),
                 "Int" => Box::new(IntPrinter {}),
                 "Decimal" => Box::new(ValuePrinter {}),
-                "BinOp" => Box::new(BinOpPrinter::new()),
+                "BinOp" => Box::new(BinOpPrinter {}),
                 "BoolOp" => Box::new(BoolOpPrinter {}),
-                "Compare" => Box::new(BinOpPrinter::new()),
+                "Compare" => Box::new(BinOpPrinter {}),
                 "Assert" => Box::new(AssertPrinter {}),
                 "Str" => Box::new(StrPrinter {}),
                 "Module" => Box::new(ModulePrinter {}),
                 "AnnAssign" => Box::new(AnnAssignPrinter {}),
+                "ImplementsDecl" => Box::new(ImplementsDeclPrinter {}),
                 "Expr" => Box::new(ExprPrinter {}),
                 "Call" => Box::new(CallPrinter {}),
                 "NameConstant" => Box::new(NameConstantPrinter {}),
@@ -1636,3 +1699,164 @@ impl NodePrinterFactory<VyperAST> for VyperNodePrinterFactory {
         &self.settings
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_module_with_implements_declarations() {
+        let name = |id: &str| json!({"ast_type": "Name", "id": id});
+        let implements = |annotation: VyperAST| {
+            json!({
+                "ast_type": "ImplementsDecl", "target": name("implements"),
+                "annotation": annotation, "value": null
+            })
+        };
+        let module: VyperAST = json!({
+            "ast_type": "Module", "doc_string": null, "body": [
+                implements(name("ERC20")),
+                implements(json!({
+                    "ast_type": "Tuple", "elements": [name("ERC165"), name("ERC721")]
+                })),
+                implements(json!([name("IERC4626")])),
+                {
+                    "ast_type": "VariableDecl", "target": name("total"),
+                    "annotation": name("uint256"), "value": null, "is_public": false,
+                    "is_constant": false, "is_immutable": false
+                }
+            ]
+        });
+
+        let mut printer = PrettyPrinter::new(4, 150);
+        let mut output: Vec<u8> = Vec::new();
+        let factory = VyperNodePrinterFactory::default();
+        traverse_sub_node_and_print(&mut printer, &mut output, &factory, &module);
+        assert!(String::from_utf8(output).unwrap().starts_with(
+            "implements: ERC20\n\nimplements: ERC165, ERC721\n\nimplements: IERC4626\n\ntotal: uint256"
+        ));
+    }
+
+    #[test]
+    fn test_block_comment_of_multiple_statements() {
+        let name = |id: &str| json!({"ast_type": "Name", "id": id});
+        let assign = |target: &str, value: i64| {
+            json!({
+                "ast_type": "Assign", "target": name(target),
+                "value": {"ast_type": "Int", "value": value}
+            })
+        };
+        let node: VyperAST = json!({
+            "ast_type": "Comment",
+            "value": {
+                "ast_type": "If", "test": name("ready"),
+                "body": [assign("a", 1), assign("b", 2)],
+                "orelse": []
+            }
+        });
+
+        let mut settings = VyperNodePrinterFactory::default().settings;
+        settings.set_value_for_key(BLOCK_COMMENTS_KEY, PreferenceValue::Boolean(true));
+        let factory = VyperNodePrinterFactory::new(settings);
+        let mut printer = PrettyPrinter::new(4, 150);
+        let mut output: Vec<u8> = Vec::new();
+        traverse_sub_node_and_print(&mut printer, &mut output, &factory, &node);
+
+        // Every line of the commented-out code starts with the comment marker.
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "# if ready:\n#     a = 1\n#     b = 2"
+        );
+    }
+
+    #[test]
+    fn test_declaration_annotation_wrappers() {
+        let name = |id: &str| json!({"ast_type": "Name", "id": id});
+        let subscript = |value: &str, elements: Vec<VyperAST>| {
+            json!({
+                "ast_type": "Subscript", "value": name(value),
+                "slice": {"ast_type": "Index", "value": {"ast_type": "Tuple", "elements": elements}}
+            })
+        };
+        let call = |func: &str, argument: VyperAST| json!({"ast_type": "Call", "func": name(func), "args": [argument], "keywords": []});
+        let balances = subscript("HashMap", vec![name("address"), name("uint256")]);
+        let print = |node: VyperAST| {
+            let mut printer = PrettyPrinter::new(4, 150);
+            let mut output: Vec<u8> = Vec::new();
+            let factory = VyperNodePrinterFactory::default();
+            traverse_sub_node_and_print(&mut printer, &mut output, &factory, &node);
+            String::from_utf8(output).unwrap()
+        };
+
+        // Older compilers declare storage variables with AnnAssign nodes.
+        let ann_assign = |target: VyperAST, annotation: VyperAST, value: VyperAST| {
+            json!({
+                "ast_type": "AnnAssign", "target": target, "annotation": annotation,
+                "value": value, "simple": 1
+            })
+        };
+        assert_eq!(
+            print(ann_assign(
+                name("balances"),
+                call("public", balances.clone()),
+                json!(null)
+            )),
+            "balances: public(HashMap[address, uint256])"
+        );
+        assert_eq!(
+            print(ann_assign(
+                name("MAX_SUPPLY"),
+                call("constant", name("uint256")),
+                json!({"ast_type": "Int", "value": 1000})
+            )),
+            "MAX_SUPPLY: constant(uint256) = 1000"
+        );
+        let items = subscript(
+            "DynArray",
+            vec![name("uint256"), json!({"ast_type": "Int", "value": 8})],
+        );
+        assert_eq!(
+            print(ann_assign(name("items"), items.clone(), json!(null))),
+            "items: DynArray[uint256, 8]"
+        );
+        let element = json!({
+            "ast_type": "Subscript", "value": name("items"),
+            "slice": {"ast_type": "Index", "value": name("i")}
+        });
+        assert_eq!(
+            print(ann_assign(element, name("uint256"), json!(null))),
+            "items[i]: uint256"
+        );
+
+        // Newer compilers record the wrappers as flags of VariableDecl nodes, and some versions also
+        // keep the wrapper call in the annotation.
+        let variable_decl = |annotation: VyperAST, is_public: bool, is_immutable: bool| {
+            json!({
+                "ast_type": "VariableDecl", "target": name("balances"), "annotation": annotation,
+                "value": null, "is_public": is_public, "is_constant": false,
+                "is_immutable": is_immutable
+            })
+        };
+        for annotation in [balances.clone(), call("public", balances.clone())] {
+            assert_eq!(
+                print(variable_decl(annotation, true, false)),
+                "balances: public(HashMap[address, uint256])"
+            );
+        }
+        assert_eq!(
+            print(variable_decl(call("immutable", items), true, false)),
+            "balances: public(immutable(DynArray[uint256, 8]))"
+        );
+
+        // Calls other than the wrappers remain part of the type.
+        assert_eq!(
+            print(variable_decl(
+                call("String", json!({"ast_type": "Int", "value": 5})),
+                false,
+                false
+            )),
+            "balances: String(5)"
+        );
+    }
+}
@@ -0,0 +1,11 @@
+//! The `yul` module contains the implementation details for standalone Yul programs.  The module
+//! reuses the Solidity compiler and the Yul node printers of the Solidity pretty-printer.
+
+pub mod ast;
+pub mod delegate;
+mod language_interface;
+mod mutators;
+mod operators;
+mod pretty_printer;
+
+pub use self::pretty_printer::YulNodePrinterFactory;
@@ -0,0 +1,7 @@
+//! The `yul::ast` module defines the type for the JSON abstract syntax tree that the Solidity
+//! compiler generates for standalone Yul programs.
+
+use serde_json::Value;
+
+/// Use the [`Value`] type directly to represent nodes of the Yul AST in JSON.
+pub type YulAST = Value;
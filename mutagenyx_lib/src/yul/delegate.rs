@@ -0,0 +1,10 @@
+//! The `delegate` module exposes `get_yul_delegate()` to get the Yul JSON language delegate
+//! object.
+
+use crate::json_language_delegate::JSONLanguageDelegate;
+use crate::yul::language_interface::YulLanguageSubDelegate;
+
+/// Return the Yul JSON language delegate.
+pub fn get_yul_delegate() -> Box<dyn JSONLanguageDelegate> {
+    Box::new(YulLanguageSubDelegate::new())
+}
@@ -0,0 +1,243 @@
+//! The `yul::language_interface` module provides the implementation for the [`JSONLanguageDelegate`]
+//! trait for standalone Yul programs.
+
+use crate::commenter::CommentDetector;
+use crate::compiler_details::*;
+use crate::error::MutagenyxError;
+use crate::id::Id;
+use crate::json::*;
+use crate::json_ast_id_maker::JSONIDMaker;
+use crate::json_ast_permitter::JSONPermitter;
+use crate::json_comment_helpers::JSONCommentDetector;
+use crate::json_language_delegate::JSONLanguageDelegate;
+use crate::json_namer::JSONNamer;
+use crate::language::Language;
+use crate::line_table::LineTable;
+use crate::mutator::*;
+use crate::namer::Namer;
+use crate::node_printer::NodePrinterFactory;
+use crate::operators::OperatorRegistry;
+use crate::permissions::Permissions;
+use crate::permit::Permit;
+use crate::preferences::Preferences;
+use crate::pretty_print_visitor::PrettyPrintVisitor;
+use crate::pretty_printer::PrettyPrinter;
+use crate::solidity::language_interface::{
+    get_solidity_compiler_flags_from_preferences, get_solidity_compiler_from_preferences,
+};
+use crate::super_ast::SuperAST;
+use crate::utility::{compiler_execute, compiler_execute_with_timeout, compiler_timeout};
+use crate::visitor::Visitor;
+use crate::yul::ast::YulAST;
+use crate::yul::mutators::YulMutatorFactory;
+use crate::yul::pretty_printer::YulNodePrinterFactory;
+use serde_json::Value;
+use std::ffi::OsStr;
+use std::io::Write;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// The type that implements [`JSONLanguageDelegate`] for standalone Yul programs.  The Yul nodes
+/// in the JSON AST match the nodes of inline assembly blocks in the Solidity AST, so the delegate
+/// uses the Yul node printers of the Solidity pretty-printer to pretty-print the AST.
+pub struct YulLanguageSubDelegate {
+    node_printer_factory: Box<dyn NodePrinterFactory<YulAST>>,
+}
+
+impl YulLanguageSubDelegate {
+    // Create a new Yul language delegate.
+    pub fn new() -> YulLanguageSubDelegate {
+        YulLanguageSubDelegate {
+            node_printer_factory: Box::new(YulNodePrinterFactory::default()),
+        }
+    }
+}
+
+impl JSONLanguageDelegate for YulLanguageSubDelegate {
+    fn recover_ast<'a>(&self, super_ast: &'a SuperAST) -> Result<&'a Value, MutagenyxError> {
+        let yul_ast = match super_ast {
+            SuperAST::Yul(yast) => yast,
+            _ => return Err(MutagenyxError::ASTTypeNotSupported),
+        };
+        Ok(yul_ast)
+    }
+
+    fn get_value_as_super_ast(&self, value: Value) -> Result<SuperAST, MutagenyxError> {
+        if <YulLanguageSubDelegate as JSONLanguageDelegate>::json_is_language_ast_json(self, &value)
+        {
+            return Ok(SuperAST::Yul(value));
+        }
+        Err(MutagenyxError::LanguageNotRecognized)
+    }
+
+    fn get_mutator_factory(&self, operators: &OperatorRegistry) -> Box<dyn MutatorFactory<Value>> {
+        Box::new(YulMutatorFactory::new(operators.clone()))
+    }
+
+    fn get_pretty_print_visitor<'a>(
+        &'a self,
+        w: &'a mut dyn Write,
+        printer: &'a mut PrettyPrinter,
+    ) -> Box<dyn Visitor<Value> + 'a> {
+        Box::new(PrettyPrintVisitor::new(
+            w,
+            printer,
+            self.node_printer_factory.as_ref(),
+        ))
+    }
+
+    fn json_is_language_ast_json(&self, value: &Value) -> bool {
+        matches!(
+            value.get_str_for_key("nodeType"),
+            Some("YulObject") | Some("YulCode") | Some("YulBlock")
+        )
+    }
+
+    fn convert_source_file_to_ast(
+        &self,
+        file_name: &str,
+        prefs: &Preferences,
+    ) -> Result<SuperAST, MutagenyxError> {
+        let value = file_is_source_file(file_name, prefs)?;
+        <YulLanguageSubDelegate as JSONLanguageDelegate>::get_value_as_super_ast(self, value)
+    }
+
+    fn file_is_language_source_file(&self, file_name: &str, prefs: &Preferences) -> bool {
+        file_is_source_file(file_name, prefs).is_ok()
+    }
+
+    fn implements(&self) -> Language {
+        Language::Yul
+    }
+
+    fn get_file_extension(&self) -> &str {
+        "yul"
+    }
+
+    fn default_compiler_settings(&self) -> Preferences {
+        let mut preferences = Preferences::new();
+        preferences.set_string_for_key(PATH_KEY, "solc");
+        preferences
+    }
+
+    fn get_node_permitter<'a>(
+        &'a self,
+        permissions: &'a Permissions,
+    ) -> Box<dyn Permit<Value> + '_> {
+        Box::new(JSONPermitter::new(permissions))
+    }
+
+    fn check_mutant_compiles(
+        &self,
+        file_name: &str,
+        prefs: &Preferences,
+    ) -> Result<(), MutagenyxError> {
+        let mut args = get_solidity_compiler_flags_from_preferences(prefs);
+        args.push(String::from("--strict-assembly"));
+        args.push(String::from(file_name));
+
+        // Yul programs use the settings of the Solidity compiler.
+        compiler_execute_with_timeout(
+            &get_solidity_compiler_from_preferences(prefs),
+            args,
+            file_name,
+            compiler_timeout(prefs, Language::Solidity),
+        )?;
+        Ok(())
+    }
+
+    fn get_node_id_maker(&self) -> Box<dyn Id<Value>> {
+        Box::new(JSONIDMaker::new(|n| {
+            n.get_int_for_key("id").map(|id| id as u64)
+        }))
+    }
+
+    fn get_comment_text_key(&self) -> &str {
+        "text"
+    }
+
+    fn get_source_line_for_node(&self, node: &Value, lines: Option<&LineTable>) -> Option<usize> {
+        // The src value has the form 'offset:length:source-index' where offset is a byte offset.
+        let src = node.get_str_for_key("src")?;
+        let offset: usize = src.split(':').next()?.parse().ok()?;
+        lines?.line_for_offset(offset)
+    }
+
+    fn get_source_lines_for_node(
+        &self,
+        node: &Value,
+        lines: Option<&LineTable>,
+    ) -> Option<RangeInclusive<usize>> {
+        let src = node.get_str_for_key("src")?;
+        let mut parts = src.split(':');
+        let offset: usize = parts.next()?.parse().ok()?;
+        let length: usize = parts.next()?.parse().ok()?;
+        let lines = lines?;
+        let first = lines.line_for_offset(offset)?;
+        let last = lines.line_for_offset(offset + length.saturating_sub(1))?;
+        Some(first..=last)
+    }
+
+    fn get_namer(&self) -> Box<dyn Namer<Value>> {
+        Box::new(
+            JSONNamer::new(|v| v.get_str_for_key("name").map(String::from)).with_kinds(|v| {
+                v.get_str_for_key("nodeType")
+                    .map(String::from)
+                    .into_iter()
+                    .collect()
+            }),
+        )
+    }
+
+    fn get_comment_detector(&self) -> Box<dyn CommentDetector<Value>> {
+        Box::new(JSONCommentDetector::new(|v| {
+            v.get_str_for_key("nodeType") == Some("Comment")
+        }))
+    }
+}
+
+/// Use the Solidity compiler in assembly mode to generate the JSON AST of the Yul program in
+/// `file_name`.  The function only considers files with the `.yul` extension because the
+/// compiler accepts many Solidity programs as valid Yul.
+///
+/// # Arguments
+///
+/// * `file_name` - The path to the source file to compile.
+/// * `prefs` - The [`Preferences`] object that may contain a value for the Solidity compiler.
+fn file_is_source_file(file_name: &str, prefs: &Preferences) -> Result<Value, MutagenyxError> {
+    let file_path = PathBuf::from_str(file_name).unwrap();
+    if file_path.extension() != Some(OsStr::new("yul")) {
+        return Err(MutagenyxError::LanguageNotRecognized);
+    }
+
+    let solidity_compiler = get_solidity_compiler_from_preferences(prefs);
+    let mut args = get_solidity_compiler_flags_from_preferences(prefs);
+    args.push(String::from("--strict-assembly"));
+    args.push(String::from("--ir-ast-json"));
+    args.push(String::from(file_name));
+
+    log::debug!(
+        "Invoking Solidity compiler {} with {:?}",
+        solidity_compiler,
+        args
+    );
+
+    let output = compiler_execute(&solidity_compiler, args, file_name)?;
+
+    // The compiler writes a banner in front of the JSON AST, so skip ahead to the start of the
+    // JSON and only read the first JSON value.
+    let malformed_ast = || MutagenyxError::MalformedAST {
+        file_name: String::from(file_name),
+        detail: String::from("the compiler output does not contain a JSON AST"),
+    };
+    let stdout_contents = String::from_utf8_lossy(&output.stdout);
+    let json_start = stdout_contents.find('{').ok_or_else(malformed_ast)?;
+    match serde_json::Deserializer::from_str(&stdout_contents[json_start..])
+        .into_iter::<Value>()
+        .next()
+    {
+        Some(Ok(value)) => Ok(value),
+        _ => Err(malformed_ast()),
+    }
+}
@@ -0,0 +1,283 @@
+//! The `yul::mutators` module provides the objects that implement the mutation algorithms for
+//! standalone Yul programs.  The module also provides the factory that implements
+//! [`MutatorFactory<T>`].  The library only mutates the binary operations of standalone Yul
+//! programs.
+
+use crate::error::MutagenyxError;
+use crate::json::*;
+use crate::mutation::{GenericMutation, MutationType};
+use crate::mutator::{Mutator, MutatorFactory};
+use crate::mutator_result::MutatorResult;
+use crate::node_printer_helpers::traverse_sub_node_and_print;
+use crate::operators::*;
+use crate::pretty_printer::PrettyPrinter;
+use crate::yul::ast::YulAST;
+use crate::yul::operators::{builtin_for_operator, operator_for_builtin};
+use crate::yul::pretty_printer::YulNodePrinterFactory;
+use openssl::hash::{Hasher, MessageDigest};
+use rand::seq::SliceRandom;
+use rand_pcg::*;
+use serde_json::json;
+
+/// Return a new comment node.
+///
+/// # Arguments
+///
+/// * `text` - The text to put in the comment.
+fn new_comment_node(text: &str) -> Result<YulAST, MutagenyxError> {
+    let node_string = "{\
+            \"nodeType\": \"Comment\",
+            \"text\": null
+        }";
+
+    let mut node = new_json_node(node_string)?;
+    node.set_node_for_key("text", json![text]);
+    Ok(node)
+}
+
+/// Pretty-print `node` to a string.
+///
+/// # Arguments
+///
+/// * `node` - The node to pretty-print.
+fn pretty_print_node(node: &YulAST) -> String {
+    let mut node_contents = Vec::new();
+    let mut printer = PrettyPrinter::new(4, 150);
+    let factory = YulNodePrinterFactory::default();
+    traverse_sub_node_and_print(&mut printer, &mut node_contents, &factory, node);
+
+    // s now contains the pretty-printed node.
+    let s = core::str::from_utf8(node_contents.as_slice()).unwrap();
+    String::from(s)
+}
+
+/// The object that implements the binary expression mutations for Yul.
+///
+/// Yul does not have binary expressions.  Instead, Yul calls built-in functions such as `add`
+/// and `lt`, so the mutator replaces the name of the called built-in function with the name of
+/// another built-in function from the same operator group.
+struct BinaryOpMutator {
+    /// The operators in the group that have a Yul built-in function.
+    operators: Vec<&'static str>,
+
+    /// The mutation algorithm implemented by the mutator.
+    mutation_type: MutationType,
+
+    /// The comment node generated when a mutation occurs.
+    comment_node: Option<YulAST>,
+}
+
+impl BinaryOpMutator {
+    /// Return a new instance of the mutator.
+    ///
+    /// # Arguments
+    ///
+    /// * `operators` - the list of operators for the mutator
+    /// * `mutation_type` - the mutation algorithm implemented by the mutator
+    pub fn new(operators: Vec<&'static str>, mutation_type: MutationType) -> BinaryOpMutator {
+        BinaryOpMutator {
+            operators: operators
+                .into_iter()
+                .filter(|o| builtin_for_operator(o).is_some())
+                .collect(),
+            mutation_type,
+            comment_node: None,
+        }
+    }
+}
+
+impl Mutator<YulAST> for BinaryOpMutator {
+    fn is_mutable_node(&mut self, node: &YulAST, _rand: &mut Pcg64) -> bool {
+        if node.get_str_for_key("nodeType") != Some("YulFunctionCall") {
+            return false;
+        }
+
+        // The mutator needs a different operator to choose.
+        if self.operators.len() < 2 {
+            return false;
+        }
+
+        match node
+            .get_str_for_key("functionName/name")
+            .and_then(operator_for_builtin)
+        {
+            Some(operator) => self.operators.contains(&operator),
+            None => false,
+        }
+    }
+
+    fn rejection_reason(&self, node: &YulAST) -> Option<String> {
+        if node.get_str_for_key("nodeType") != Some("YulFunctionCall") {
+            return Some(String::from("not a YulFunctionCall node"));
+        }
+        if self.operators.len() < 2 {
+            return Some(format!(
+                "the operators {:?} do not offer a replacement",
+                self.operators
+            ));
+        }
+        match node.get_str_for_key("functionName/name") {
+            Some(name) => Some(format!(
+                "'{name}' is not the built-in function of one of {:?}",
+                self.operators
+            )),
+            None => Some(String::from("the node does not have a function name")),
+        }
+    }
+
+    fn mutate(
+        &mut self,
+        node: &mut YulAST,
+        rand: &mut Pcg64,
+    ) -> Result<MutatorResult, MutagenyxError> {
+        // Do not hang on to any old comment node.
+        self.comment_node = None;
+
+        let mut mutator_result = MutatorResult::new();
+        mutator_result.mutation_type = Some(self.implements());
+
+        let mut hasher = Hasher::new(MessageDigest::sha256())?;
+
+        let original_operator = match node
+            .get_str_for_key("functionName/name")
+            .and_then(operator_for_builtin)
+        {
+            Some(o) => o,
+            None => {
+                return Err(MutagenyxError::MalformedNode(
+                    String::from("YulFunctionCall"),
+                    String::from("functionName"),
+                ))
+            }
+        };
+
+        // Pretty-print the original version of the node for use in the comment block.
+        let original_node_s = pretty_print_node(node);
+
+        // Choose a new operator different from the original operator.
+        let choices: Vec<&&str> = self
+            .operators
+            .iter()
+            .filter(|o| **o != original_operator)
+            .collect();
+        let chosen_operator = match choices.choose(rand) {
+            Some(o) => **o,
+            None => {
+                return Err(MutagenyxError::RandomOperationFailure(
+                    "failed to choose operator",
+                ))
+            }
+        };
+
+        hasher.update(chosen_operator.as_bytes())?;
+
+        // The constructor only keeps operators with a built-in function.
+        let builtin = builtin_for_operator(chosen_operator).unwrap();
+        if let Some(function_name) = node.get_mut("functionName") {
+            function_name.set_str_for_key("name", builtin);
+        }
+
+        // Pretty-print the new version of the node for use in the comment block.
+        let new_node_s = pretty_print_node(node);
+
+        let comment_text = format!(
+            "{} Mutator: Changed '{}' to '{}'",
+            self.mutation_type, original_node_s, new_node_s
+        );
+        if let Ok(comment_node) = new_comment_node(&comment_text) {
+            self.comment_node = Some(comment_node);
+        }
+
+        if let Some(id) = node.get_int_for_key("id") {
+            mutator_result.mutated_node_id = Some(id as u64);
+        }
+
+        let byte_array = hasher.finish()?;
+        mutator_result.random_behavior_hash = Some(hex::encode(byte_array));
+
+        Ok(mutator_result)
+    }
+
+    fn implements(&self) -> MutationType {
+        self.mutation_type
+    }
+
+    fn description(&self) -> &'static str {
+        match self.mutation_type {
+            MutationType::Generic(GenericMutation::ArithmeticBinaryOp) => {
+                "Replace a call to an arithmetic built-in function with a call to another \
+                    arithmetic built-in function."
+            }
+            MutationType::Generic(GenericMutation::BitwiseBinaryOp) => {
+                "Replace a call to a bitwise built-in function with a call to another bitwise \
+                    built-in function."
+            }
+            MutationType::Generic(GenericMutation::BitshiftBinaryOp) => {
+                "Replace a call to a bitshift built-in function with a call to another bitshift \
+                    built-in function."
+            }
+            MutationType::Generic(GenericMutation::ComparisonBinaryOp) => {
+                "Replace a call to a comparison built-in function with a call to another \
+                    comparison built-in function."
+            }
+            _ => "",
+        }
+    }
+
+    fn example(&self) -> &'static str {
+        match self.mutation_type {
+            MutationType::Generic(GenericMutation::ArithmeticBinaryOp) => {
+                "add(a, b) might become sub(a, b)"
+            }
+            MutationType::Generic(GenericMutation::BitwiseBinaryOp) => {
+                "and(a, b) might become xor(a, b)"
+            }
+            MutationType::Generic(GenericMutation::BitshiftBinaryOp) => {
+                "shl(a, b) might become shr(a, b)"
+            }
+            MutationType::Generic(GenericMutation::ComparisonBinaryOp) => {
+                "lt(a, b) might become gt(a, b)"
+            }
+            _ => "",
+        }
+    }
+
+    fn get_comment_node(&self) -> Option<YulAST> {
+        self.comment_node.clone()
+    }
+}
+
+/// Implement the [`MutatorFactory<T>`] trait to have an interface for getting mutators for requested
+/// mutation algorithms.
+pub struct YulMutatorFactory {
+    /// The operator groups that the binary operator mutators choose operators from.
+    operators: OperatorRegistry,
+}
+
+impl YulMutatorFactory {
+    /// Create a new factory that builds the binary operator mutators from the groups in
+    /// `operators`.
+    ///
+    /// # Arguments
+    ///
+    /// * `operators` - The registry of operator groups.
+    pub fn new(operators: OperatorRegistry) -> YulMutatorFactory {
+        YulMutatorFactory { operators }
+    }
+}
+
+impl MutatorFactory<YulAST> for YulMutatorFactory {
+    fn mutator_for(&self, mutation_type: &MutationType) -> Option<Box<dyn Mutator<YulAST>>> {
+        let group = match mutation_type {
+            MutationType::Generic(GenericMutation::ArithmeticBinaryOp) => ARITHMETIC_OPERATORS,
+            MutationType::Generic(GenericMutation::BitwiseBinaryOp) => BITWISE_OPERATORS,
+            MutationType::Generic(GenericMutation::BitshiftBinaryOp) => BITSHIFT_OPERATORS,
+            MutationType::Generic(GenericMutation::ComparisonBinaryOp) => COMPARISON_OPERATORS,
+            _ => return None,
+        };
+        Some(Box::new(BinaryOpMutator::new(
+            self.operators.operators(group),
+            *mutation_type,
+        )))
+    }
+}
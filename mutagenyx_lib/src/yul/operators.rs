@@ -0,0 +1,44 @@
+//! The `yul::operators` module maps the Yul built-in functions that implement binary operations
+//! to the operators in the operator groups of [`crate::operators::OperatorRegistry`].
+
+/// The operator for each Yul built-in function that implements a binary operation.
+static YUL_BINARY_OPERATORS: [(&str, &str); 14] = [
+    ("add", "+"),
+    ("sub", "-"),
+    ("mul", "*"),
+    ("div", "/"),
+    ("mod", "%"),
+    ("exp", "**"),
+    ("and", "&"),
+    ("or", "|"),
+    ("xor", "^"),
+    ("shl", "<<"),
+    ("shr", ">>"),
+    ("lt", "<"),
+    ("gt", ">"),
+    ("eq", "=="),
+];
+
+/// Return the operator for the Yul built-in function `name`.
+///
+/// # Arguments
+///
+/// * `name` - The name of the Yul built-in function.
+pub fn operator_for_builtin(name: &str) -> Option<&'static str> {
+    YUL_BINARY_OPERATORS
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map(|(_, operator)| *operator)
+}
+
+/// Return the name of the Yul built-in function for `operator`.
+///
+/// # Arguments
+///
+/// * `operator` - The operator from an operator group.
+pub fn builtin_for_operator(operator: &str) -> Option<&'static str> {
+    YUL_BINARY_OPERATORS
+        .iter()
+        .find(|(_, o)| *o == operator)
+        .map(|(name, _)| *name)
+}
@@ -0,0 +1,170 @@
+//! The `yul::pretty_printer` module provides the node printer factory for standalone Yul
+//! programs.  The factory reuses the Yul node printers of the Solidity pretty-printer.
+
+use crate::json::*;
+use crate::node_printer::{NodePrinter, NodePrinterFactory};
+use crate::preferences::Preferences;
+use crate::solidity::pretty_printer::{yul_node_printer_for, SolidityNodePrinterFactory};
+use crate::yul::ast::YulAST;
+
+/// Type that implements [`NodePrinterFactory<AST>`] for Yul AST nodes.
+///
+/// Use this factory object with [`crate::node_printer::pretty_print_node`].
+#[derive(Clone)]
+pub struct YulNodePrinterFactory {
+    settings: Preferences,
+}
+
+impl Default for YulNodePrinterFactory {
+    /// Create a new node printer factory using the default settings of the Solidity
+    /// pretty-printer.
+    fn default() -> YulNodePrinterFactory {
+        YulNodePrinterFactory {
+            settings: SolidityNodePrinterFactory::default().settings,
+        }
+    }
+}
+
+impl NodePrinterFactory<YulAST> for YulNodePrinterFactory {
+    fn printer_for(&self, node: &YulAST) -> Box<dyn NodePrinter<YulAST>> {
+        yul_node_printer_for(node.get_str_for_key("nodeType").unwrap_or_default())
+    }
+
+    fn get_settings(&self) -> &Preferences {
+        &self.settings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::language::Language;
+    use crate::language_interface::LanguageInterface;
+    use crate::mutation::{GenericMutation, MutationType};
+    use crate::permissions::{Permission, PermissionAction, PermissionScope, Permissions};
+    use crate::preferences::Preferences;
+    use crate::pretty_printer::PrettyPrinter;
+    use crate::recognizer::{FileType, Recognizer};
+    use crate::super_ast::SuperAST;
+    use rand::SeedableRng;
+    use rand_pcg::Pcg64;
+    use serde_json::{json, Value};
+    use std::env;
+
+    /// Return the AST that the Solidity compiler generates for a standalone Yul object.
+    fn yul_object_ast() -> Value {
+        let call = |name: &str, arguments: Value| {
+            json!({
+                "nodeType": "YulFunctionCall", "src": "0:1:0",
+                "functionName": {"nodeType": "YulIdentifier", "name": name, "src": "0:1:0"},
+                "arguments": arguments
+            })
+        };
+        let number = |value: &str| {
+            json!({
+                "nodeType": "YulLiteral", "kind": "number", "value": value, "type": "",
+                "src": "0:1:0"
+            })
+        };
+        json!({
+            "nodeType": "YulObject", "name": "Counter", "src": "0:1:0",
+            "code": {
+                "nodeType": "YulCode", "src": "0:1:0",
+                "block": {
+                    "nodeType": "YulBlock", "src": "0:1:0",
+                    "statements": [
+                        {
+                            "nodeType": "YulVariableDeclaration", "src": "0:1:0",
+                            "variables": [{
+                                "nodeType": "YulTypedName", "name": "x", "type": "",
+                                "src": "0:1:0"
+                            }],
+                            "value": call("add", json!([number("1"), number("2")]))
+                        },
+                        {
+                            "nodeType": "YulExpressionStatement", "src": "0:1:0",
+                            "expression": call("sstore", json!([
+                                number("0"),
+                                {"nodeType": "YulIdentifier", "name": "x", "src": "0:1:0"}
+                            ]))
+                        }
+                    ]
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_pretty_print_yul_object() {
+        let file_name = env::temp_dir().join("mutagenyx_yul_object_ast.json");
+        let file_name = file_name.to_str().unwrap();
+        std::fs::write(file_name, yul_object_ast().to_string()).unwrap();
+
+        let mut prefs = Preferences::new();
+        let recognizer = Recognizer::new(&mut prefs);
+        let recognized = recognizer.recognize_ast_file(file_name).unwrap();
+        assert_eq!(recognized.language, Language::Yul);
+
+        let mut language_object =
+            LanguageInterface::get_language_object_for_language(&Language::Yul).unwrap();
+        let ast = language_object
+            .load_ast_from_file(file_name, &FileType::AST, &prefs)
+            .unwrap();
+        std::fs::remove_file(file_name).unwrap();
+
+        // The AST survives the round trip through the file unchanged.
+        assert_eq!(
+            ast.to_json_string(false).unwrap(),
+            yul_object_ast().to_string()
+        );
+
+        let mut printed: Vec<u8> = Vec::new();
+        let mut pretty_printer = PrettyPrinter::new(4, 150);
+        language_object
+            .pretty_print_ast_to_stream(&ast, &mut printed, &mut pretty_printer)
+            .unwrap();
+        let printed = String::from_utf8(printed).unwrap();
+        assert_eq!(
+            printed,
+            "object \"Counter\" {\n    code {\n        let x := add(1, 2)\n        sstore(0, x)\n    }\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_mutate_yul_binary_operation() {
+        let mut permissions = Permissions::new();
+        permissions.add_permission(PermissionAction::Mutate(PermissionScope::Any(
+            Permission::Allow,
+        )));
+        permissions.add_permission(PermissionAction::Visit(PermissionScope::Any(
+            Permission::Allow,
+        )));
+
+        let ast = SuperAST::Yul(yul_object_ast());
+        let mut language_object =
+            LanguageInterface::get_language_object_for_language(&Language::Yul).unwrap();
+        let mutation_type = MutationType::Generic(GenericMutation::ArithmeticBinaryOp);
+        assert!(mutation_type.supports_language(Language::Yul));
+        language_object
+            .select_mutators_for_mutation_types(&[mutation_type])
+            .unwrap();
+
+        // The call to add() is mutable, the call to sstore() is not a binary operation.
+        let mut rng = Pcg64::seed_from_u64(3);
+        let counts = language_object
+            .count_mutable_nodes(&ast, &mut rng, &permissions)
+            .unwrap();
+        assert_eq!(counts[&mutation_type], 1);
+
+        let path_map = language_object
+            .calculate_node_paths(&ast, &permissions)
+            .unwrap();
+        let result = language_object
+            .mutate_ast(&ast, &mutation_type, 0, &mut rng, &permissions, &path_map)
+            .unwrap();
+        let mutated = result.ast.unwrap().to_json_string(false).unwrap();
+        let mutated: Value = serde_json::from_str(&mutated).unwrap();
+        let name = &mutated["code"]["block"]["statements"][0]["value"]["functionName"]["name"];
+        assert_ne!(name, "add");
+        assert!(["sub", "mul", "div", "mod", "exp"].contains(&name.as_str().unwrap()));
+    }
+}
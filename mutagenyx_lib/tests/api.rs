@@ -0,0 +1,144 @@
+//! Tests of the [`mutate_file`] entry point of the library.
+
+#![cfg(unix)]
+
+use mutagenyx_lib::mutation::GenericMutation;
+use mutagenyx_lib::{mutate_file, MutationType};
+use serde_json::json;
+use std::env;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+
+/// Return the AST that the Solidity compiler generates for `tests/data/Sample.sol`.
+fn sample_ast() -> serde_json::Value {
+    let identifier = |id: u64, name: &str| {
+        json!({
+            "nodeType": "Identifier", "id": id, "name": name, "src": "155:1:0",
+            "typeDescriptions": {"typeIdentifier": "t_uint256", "typeString": "uint256"}
+        })
+    };
+    let parameter = |id: u64, name: &str| {
+        json!({
+            "nodeType": "VariableDeclaration", "id": id, "name": name, "constant": false,
+            "stateVariable": false, "storageLocation": "default", "visibility": "internal",
+            "typeName": {"nodeType": "ElementaryTypeName", "id": id + 1, "name": "uint256"}
+        })
+    };
+    let body = json!({"nodeType": "Block", "id": 20, "src": "143:30:0", "statements": [{
+        "nodeType": "Return", "id": 21, "src": "153:12:0", "expression": {
+            "nodeType": "BinaryOperation", "id": 30, "src": "160:5:0", "operator": "+",
+            "leftExpression": identifier(31, "a"),
+            "rightExpression": identifier(32, "b"),
+            "typeDescriptions": {"typeIdentifier": "t_uint256", "typeString": "uint256"}
+        }
+    }]});
+    let function = json!({
+        "nodeType": "FunctionDefinition", "id": 40, "name": "f", "src": "79:94:0",
+        "kind": "function", "visibility": "public", "stateMutability": "pure",
+        "virtual": false, "implemented": true, "modifiers": [],
+        "parameters": {"nodeType": "ParameterList", "id": 10, "parameters": [
+            parameter(1, "a"), parameter(3, "b")
+        ]},
+        "returnParameters": {"nodeType": "ParameterList", "id": 11, "parameters": [
+            parameter(5, "")
+        ]},
+        "body": body
+    });
+    json!({
+        "nodeType": "SourceUnit", "id": 100, "absolutePath": "Sample.sol", "src": "0:175:0",
+        "nodes": [{
+            "nodeType": "ContractDefinition", "id": 50, "name": "Sample", "src": "57:118:0",
+            "contractKind": "contract", "abstract": false, "baseContracts": [],
+            "nodes": [function]
+        }]
+    })
+}
+
+/// Install a stand-in for the Solidity compiler at the front of the search path.  The stand-in
+/// writes the AST of the sample file to the output directory given after `-o`.
+fn install_solidity_compiler(directory: &Path) {
+    let ast_file = directory.join("Sample.ast.json");
+    std::fs::write(&ast_file, sample_ast().to_string()).unwrap();
+
+    let compiler = directory.join("solc");
+    let script = format!(
+        "#!/bin/sh\n\
+        if [ \"$1\" = \"--version\" ]; then echo 'Version: 0.8.19+commit.7dd6d404'; exit 0; fi\n\
+        case \"$*\" in *.sol) ;; *) exit 1 ;; esac\n\
+        while [ \"$1\" != \"-o\" ]; do shift; done\n\
+        cp {} \"$2/$(basename \"$3\")_json.ast\"\n",
+        ast_file.to_str().unwrap()
+    );
+    std::fs::write(&compiler, script).unwrap();
+    std::fs::set_permissions(&compiler, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    let path = env::var("PATH").unwrap_or_default();
+    env::set_var("PATH", format!("{}:{}", directory.to_str().unwrap(), path));
+}
+
+#[test]
+fn test_mutate_file() {
+    let directory = env::temp_dir().join("mutagenyx_api_mutate_file");
+    let _ = std::fs::remove_dir_all(&directory);
+    std::fs::create_dir_all(&directory).unwrap();
+    install_solidity_compiler(&directory);
+
+    let sample = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/data/Sample.sol");
+    let out_dir = directory.join("out");
+    let mutations = [MutationType::Generic(GenericMutation::ArithmeticBinaryOp)];
+
+    let mutants = mutate_file(&sample, &mutations, 3, 42, &out_dir).unwrap();
+    let files: Vec<&str> = mutants.iter().map(|m| m.file.as_str()).collect();
+    assert_eq!(
+        files,
+        vec!["Sample.sol_0.sol", "Sample.sol_1.sol", "Sample.sol_2.sol"]
+    );
+
+    // Each record describes the mutation of the addition.
+    for (index, mutant) in mutants.iter().enumerate() {
+        assert_eq!(mutant.index, index);
+        assert_eq!(mutant.source, sample.to_str().unwrap());
+        assert_eq!(mutant.mutation_type, Some(mutations[0]));
+        assert_eq!(mutant.node_id, Some(30));
+    }
+
+    // Each mutant replaces the addition with a different operator.
+    let mut operations: Vec<String> = Vec::new();
+    for mutant in &mutants {
+        let text = std::fs::read_to_string(out_dir.join(&mutant.file)).unwrap();
+        assert!(text.contains("contract Sample"));
+        assert!(!text.contains("return a + b;"));
+        let line = text.lines().find(|l| l.contains("return a ")).unwrap();
+        operations.push(String::from(line.trim()));
+    }
+    operations.sort();
+    operations.dedup();
+    assert_eq!(operations.len(), 3);
+
+    // The same seed generates the same mutants.
+    let again_dir = directory.join("again");
+    let again = mutate_file(&sample, &mutations, 3, 42, &again_dir).unwrap();
+    assert_eq!(again, mutants);
+    for mutant in &mutants {
+        assert_eq!(
+            std::fs::read_to_string(out_dir.join(&mutant.file)).unwrap(),
+            std::fs::read_to_string(again_dir.join(&mutant.file)).unwrap()
+        );
+    }
+
+    // Seeds above u64::MAX, which the command line accepts, also reproduce the mutants.
+    let seed = u128::from(u64::MAX) + 42;
+    let wide_dir = directory.join("wide");
+    let wide = mutate_file(&sample, &mutations, 3, seed, &wide_dir).unwrap();
+    let wide_again_dir = directory.join("wide_again");
+    let wide_again = mutate_file(&sample, &mutations, 3, seed, &wide_again_dir).unwrap();
+    assert_eq!(wide_again, wide);
+    for mutant in &wide {
+        assert_eq!(
+            std::fs::read_to_string(wide_dir.join(&mutant.file)).unwrap(),
+            std::fs::read_to_string(wide_again_dir.join(&mutant.file)).unwrap()
+        );
+    }
+
+    std::fs::remove_dir_all(&directory).unwrap();
+}
@@ -0,0 +1,64 @@
+//! Tests of the node printer factories that the library exports.
+
+use mutagenyx_lib::node_printer::{
+    pretty_print_node, NodePrinter, NodePrinterFactory, OverridingNodePrinterFactory,
+};
+use mutagenyx_lib::solidity::SolidityNodePrinterFactory;
+use mutagenyx_lib::PrettyPrinter;
+use serde_json::{json, Value};
+use std::io::Write;
+
+/// Prints identifiers in upper case.
+struct UppercaseIdentifierPrinter {}
+
+impl NodePrinter<Value> for UppercaseIdentifierPrinter {
+    fn print_node(
+        &mut self,
+        printer: &mut PrettyPrinter,
+        stream: &mut dyn Write,
+        _factory: &dyn NodePrinterFactory<Value>,
+        node: &Value,
+    ) {
+        if let Some(name) = node.get("name").and_then(|n| n.as_str()) {
+            printer.write_token(stream, &name.to_uppercase()).unwrap();
+        }
+    }
+}
+
+/// Return the text that the printers of `factory` write for `node`.
+fn print(factory: &dyn NodePrinterFactory<Value>, node: &Value) -> String {
+    let mut printer = PrettyPrinter::new(4, 150);
+    let mut output: Vec<u8> = Vec::new();
+    pretty_print_node(&mut printer, &mut output, factory, node);
+    String::from_utf8(output).unwrap()
+}
+
+#[test]
+fn test_override_solidity_identifier_printer() {
+    let node = json!({
+        "nodeType": "ExpressionStatement",
+        "expression": {
+            "nodeType": "Assignment", "operator": "=",
+            "leftHandSide": {"nodeType": "Identifier", "name": "total"},
+            "rightHandSide": {
+                "nodeType": "BinaryOperation", "operator": "+",
+                "leftExpression": {"nodeType": "Identifier", "name": "total"},
+                "rightExpression": {"nodeType": "Literal", "kind": "number", "value": "1"}
+            }
+        }
+    });
+
+    let factory = OverridingNodePrinterFactory::new(
+        Box::new(SolidityNodePrinterFactory::default()),
+        |node| node.get("nodeType").and_then(|t| t.as_str()),
+    )
+    .with_override("Identifier", |_node, _inner| {
+        Box::new(UppercaseIdentifierPrinter {})
+    });
+
+    assert_eq!(
+        print(&SolidityNodePrinterFactory::default(), &node),
+        "total = total + 1;"
+    );
+    assert_eq!(print(&factory, &node), "TOTAL = TOTAL + 1;");
+}